@@ -0,0 +1,21 @@
+//! 构建脚本：为 `input-log` 特性准备输入回放脚本（写入 OUT_DIR 的 `replay.txt`）。
+//! 精灵、字体、开局库与残局库等预计算数据由 `cargo xtask assets` 生成并随源码提交，见 xtask/。
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    // 输入回放脚本（`input-log` 特性）：RHESS_REPLAY 指向录制得到的文本，未设置时写入空脚本。
+    println!("cargo:rerun-if-env-changed=RHESS_REPLAY");
+    let replay = match env::var("RHESS_REPLAY") {
+        Ok(path) => {
+            println!("cargo:rerun-if-changed={path}");
+            fs::read_to_string(&path).unwrap_or_else(|e| panic!("{path}: {e}"))
+        }
+        Err(_) => String::new(),
+    };
+    fs::write(out_dir.join("replay.txt"), replay).unwrap();
+}
@@ -1,6 +1,7 @@
+/* 末尾 256K（扇区 10、11）留作对局存档，其前 128K（扇区 9）存放外观包，见 src/drivers/flash.rs */
 MEMORY
 {
-  FLASH (rx) : ORIGIN = 0x08000000, LENGTH = 1024K
+  FLASH (rx) : ORIGIN = 0x08000000, LENGTH = 640K
   RAM   (rwx): ORIGIN = 0x20000000, LENGTH = 128K
   CCM   (rwx): ORIGIN = 0x10000000, LENGTH = 64K
 }
\ No newline at end of file
@@ -0,0 +1,62 @@
+//! Host benchmark for move generation and search, run with
+//! `cargo bench -p rhess-core --target x86_64-unknown-linux-gnu`.
+//! Numbers are relative: the Cortex-M4 target is roughly two orders of magnitude slower.
+
+use std::time::Instant;
+
+use rhess_core::GameState;
+use rhess_core::ai::{AiConfig, choose_best_move};
+
+fn perft(state: &GameState, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    state
+        .generate_legal_moves()
+        .iter()
+        .map(|mv| perft(&state.make_move(*mv).unwrap(), depth - 1))
+        .sum()
+}
+
+fn main() {
+    let start = GameState::start_position();
+
+    let t = Instant::now();
+    let nodes = perft(&start, 4);
+    let elapsed = t.elapsed();
+    println!(
+        "perft(4) = {nodes} in {:.1} ms ({:.0} knps)",
+        elapsed.as_secs_f64() * 1e3,
+        nodes as f64 / elapsed.as_secs_f64() / 1e3
+    );
+
+    // Leave the book immediately so the search itself is measured.
+    let mut state = start;
+    for mv in [(12u8, 28u8), (54, 46), (3, 39), (53, 45)] {
+        state = state
+            .make_move(rhess_core::Move::quiet(mv.0, mv.1))
+            .unwrap();
+    }
+    let unlimited = (1..=3).map(|depth| AiConfig {
+        max_depth: depth,
+        node_limit: None,
+        ..AiConfig::default()
+    });
+    let mobility = AiConfig {
+        mobility: true,
+        ..AiConfig::default()
+    };
+    for cfg in unlimited.chain([AiConfig::default(), mobility]) {
+        let t = Instant::now();
+        let mv = choose_best_move(&state, state.side_to_move, cfg, || {});
+        println!(
+            "search depth {} / nodes {:?} / mobility {}: {:?} in {:.1} ms, eval cache {}% hits",
+            cfg.max_depth,
+            cfg.node_limit,
+            cfg.mobility,
+            mv.map(|r| (r.mv.from, r.mv.to, r.origin, r.depth, r.nodes)),
+            t.elapsed().as_secs_f64() * 1e3,
+            mv.map_or(0, |r| r.stats.eval_hit_percent())
+        );
+    }
+}
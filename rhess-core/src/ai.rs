@@ -0,0 +1,1479 @@
+use crate::book::{self, BookPolicy};
+use crate::phase::{self, ENDGAME_MATERIAL};
+use crate::profile::EvalProfile;
+use crate::tablebase::{self, Probe, Wdl};
+use crate::tables::{self, FILE_MASKS, KNIGHT_ATTACKS, PAWN_ATTACKS};
+use crate::{Color, GameState, Move, MoveList, PieceKind};
+use core::sync::atomic::{AtomicBool, Ordering};
+
+// Mate score large enough to dominate any material/eval.
+const MATE_SCORE: i32 = 30_000;
+// Endgame-table wins rank below mates the search sees and above any evaluation.
+const TABLEBASE_WIN: i32 = MATE_SCORE - 1_000;
+
+// Tiny transposition table: 2^10 = 1024 entries (~24 KB).
+const TT_BITS: usize = 10;
+const TT_SIZE: usize = 1 << TT_BITS;
+const TT_MASK: usize = TT_SIZE - 1;
+
+// Static-eval cache kept next to the TT: 2^10 = 1024 entries (8 KB). Leaves repeated by
+// iterative deepening and quiescence skip `evaluate`; the bench sees 4-7% hits per search.
+const EVAL_CACHE_BITS: usize = 10;
+const EVAL_CACHE_SIZE: usize = 1 << EVAL_CACHE_BITS;
+const EVAL_CACHE_MASK: usize = EVAL_CACHE_SIZE - 1;
+
+#[derive(Clone, Copy)]
+pub struct AiConfig {
+    /// Maximum search depth for iterative deepening (plies).
+    pub max_depth: u8,
+    /// Optional safety cap on explored nodes.
+    pub node_limit: Option<u32>,
+    /// Maximum quiescence plies below the horizon; deeper nodes return the static eval.
+    pub max_quiesce_ply: u8,
+    /// Delta pruning margin (centipawns): a capture is skipped when even winning the
+    /// captured piece plus this margin cannot reach the window. `None` disables pruning.
+    pub delta_margin: Option<i32>,
+    /// Scale `node_limit` by root complexity: forced positions get a fraction of the
+    /// budget, wide positions with captures and checks available get more.
+    pub adaptive_budget: bool,
+    /// Choose among all matching opening-book lines with this seed instead of always
+    /// taking the first one. `None` keeps book play deterministic.
+    pub book_seed: Option<u32>,
+    /// Add a mobility term (attacked squares per piece) to the evaluation. Off until
+    /// its cost per node has been weighed against the depth it loses on the target;
+    /// the `bench` console command measures both settings. A profile that weighs mobility
+    /// above the standard ([`EvalProfile::counts_mobility`]) adds the term regardless.
+    pub mobility: bool,
+    /// Weights of the evaluation terms: the engine's playing style.
+    pub profile: EvalProfile,
+    /// Play a random one of the near-equal best root moves instead of always the best,
+    /// so weak settings vary their play. `None` always plays the best move.
+    pub variety: Option<Variety>,
+}
+
+impl Default for AiConfig {
+    fn default() -> Self {
+        AiConfig {
+            max_depth: 6,
+            node_limit: Some(20_000),
+            max_quiesce_ply: 6,
+            delta_margin: Some(200),
+            adaptive_budget: true,
+            book_seed: None,
+            mobility: false,
+            profile: EvalProfile::BALANCED,
+            variety: None,
+        }
+    }
+}
+
+/// Random choice among the best root moves (see [`AiConfig::variety`]). Candidates are
+/// the moves the last completed iteration scored within `margin` of the best, at most
+/// [`VARIETY_CANDIDATES`] of them; the pick depends on `seed` and the position, so the
+/// same seed replays the same game. Forced mates and tablebase wins are never varied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Variety {
+    /// Centipawns below the best score a candidate may be; a negative margin counts as 0,
+    /// leaving only moves that tie the best.
+    pub margin: i32,
+    pub seed: u32,
+}
+
+/// Most root moves [`Variety`] chooses among.
+pub const VARIETY_CANDIDATES: usize = 4;
+
+/// The best-scoring root moves of one iteration, highest first.
+#[derive(Clone, Copy)]
+struct Shortlist {
+    moves: [(Move, i32); VARIETY_CANDIDATES],
+    len: usize,
+}
+
+impl Shortlist {
+    fn new() -> Self {
+        Shortlist {
+            moves: [(Move::quiet(0, 0), 0); VARIETY_CANDIDATES],
+            len: 0,
+        }
+    }
+
+    fn insert(&mut self, mv: Move, score: i32) {
+        let at = self.moves[..self.len]
+            .iter()
+            .position(|&(_, s)| score > s)
+            .unwrap_or(self.len);
+        if at == VARIETY_CANDIDATES {
+            return;
+        }
+        self.len = (self.len + 1).min(VARIETY_CANDIDATES);
+        self.moves.copy_within(at..self.len - 1, at + 1);
+        self.moves[at] = (mv, score);
+    }
+
+    /// A candidate picked by `variety.seed` and the root `hash`, or `None` when the best
+    /// move wins by force.
+    fn pick(&self, variety: Variety, hash: u64) -> Option<Move> {
+        let (_, best) = *self.moves[..self.len].first()?;
+        // Table wins lose at most 255 plies from TABLEBASE_WIN; evaluations never get close.
+        if best > TABLEBASE_WIN - 256 {
+            return None;
+        }
+        let count = self.moves[..self.len]
+            .iter()
+            .take_while(|&&(_, score)| best - score <= variety.margin.max(0))
+            .count();
+        let mix = (hash ^ variety.seed as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 32;
+        Some(self.moves[mix as usize % count].0)
+    }
+}
+
+/// Where a chosen move came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveOrigin {
+    /// Opening book, no search.
+    Book,
+    /// The root was already searched to full depth; the stored best move was replayed.
+    TableHit,
+    /// Fresh iterative-deepening search.
+    Search,
+    /// Endgame tables, no search.
+    Tablebase,
+}
+
+impl MoveOrigin {
+    pub fn label(self) -> &'static str {
+        match self {
+            MoveOrigin::Book => "book",
+            MoveOrigin::TableHit => "TT",
+            MoveOrigin::Search => "search",
+            MoveOrigin::Tablebase => "tablebase",
+        }
+    }
+}
+
+/// A chosen move plus its provenance, so callers can tell instant replies from long thinks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SearchResult {
+    pub mv: Move,
+    pub origin: MoveOrigin,
+    /// Depth of the last completed iteration (plies); 0 for book and tablebase moves, and
+    /// when the search was cut off before depth 1 finished.
+    pub depth: u8,
+    /// Nodes visited by this search; 0 for book moves, table hits and tablebase moves.
+    pub nodes: u32,
+    /// Evaluation counters; all zero for book moves, table hits and tablebase moves.
+    pub stats: SearchStats,
+}
+
+/// Counters gathered during one search.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Static evaluations the search asked for.
+    pub evals: u32,
+    /// Of those, answered from the evaluation cache without recomputing.
+    pub eval_hits: u32,
+}
+
+impl SearchStats {
+    /// Share of evaluations answered from the cache, in percent (0 when nothing was evaluated).
+    pub fn eval_hit_percent(&self) -> u32 {
+        match self.evals {
+            0 => 0,
+            evals => (self.eval_hits as u64 * 100 / evals as u64) as u32,
+        }
+    }
+}
+
+/// Transposition table that can outlive a single search, so a position reached again
+/// (a repetition, a restored checkpoint) is answered without searching.
+///
+/// Entries are only valid for one [`AiConfig`] per colour; [`clear`](Self::clear) it when
+/// the configuration changes. The two colours' entries are kept apart, so the sides of a
+/// computer-vs-computer game may search with different [`EvalProfile`]s.
+pub struct TranspositionTable {
+    entries: [TtEntry; TT_SIZE],
+    /// Separate static-eval cache: an eval is cheap to store but would evict search
+    /// results from the main table.
+    evals: [EvalEntry; EVAL_CACHE_SIZE],
+}
+
+impl TranspositionTable {
+    pub const fn new() -> Self {
+        TranspositionTable {
+            entries: [TtEntry::EMPTY; TT_SIZE],
+            evals: [EvalEntry::EMPTY; EVAL_CACHE_SIZE],
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries = [TtEntry::EMPTY; TT_SIZE];
+        self.evals = [EvalEntry::EMPTY; EVAL_CACHE_SIZE];
+    }
+
+    /// Number of search results held (static evals not counted).
+    pub fn filled(&self) -> usize {
+        self.entries.iter().filter(|e| e.key != 0).count()
+    }
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Pick a move for `ai_color`: book first (trap lines too at low depth, see
+/// [`BookPolicy::for_depth`]), then the endgame tables for won and lost three-man
+/// endings, then iterative-deepening alpha-beta, which probes the tables once captures
+/// can reach them. With [`AiConfig::variety`] the search plays one of its best few moves.
+///
+/// `tick` is called regularly during the search so callers can animate progress
+/// indicators. Returns `None` if it is not `ai_color`'s turn or no legal move exists.
+pub fn choose_best_move<F: FnMut()>(
+    state: &GameState,
+    ai_color: Color,
+    cfg: AiConfig,
+    tick: F,
+) -> Option<SearchResult> {
+    let mut table = TranspositionTable::new();
+    choose_best_move_with(state, ai_color, cfg, &mut table, tick)
+}
+
+/// Like [`choose_best_move`], but searches with a caller-owned `table` that keeps its
+/// entries between calls. A root already searched to `cfg.max_depth` is answered from
+/// the table directly ([`MoveOrigin::TableHit`]).
+pub fn choose_best_move_with<F: FnMut()>(
+    state: &GameState,
+    ai_color: Color,
+    cfg: AiConfig,
+    table: &mut TranspositionTable,
+    tick: F,
+) -> Option<SearchResult> {
+    choose_best_move_reporting(state, ai_color, cfg, table, tick, |_| {})
+}
+
+/// Like [`choose_best_move_with`], but `on_iteration` receives the best move after each
+/// completed iterative-deepening depth, so a long search can be checkpointed and its
+/// best move so far played if the search never finishes. Book moves, table hits and
+/// tablebase moves return without reporting.
+pub fn choose_best_move_reporting<F: FnMut(), R: FnMut(SearchResult)>(
+    state: &GameState,
+    ai_color: Color,
+    cfg: AiConfig,
+    table: &mut TranspositionTable,
+    tick: F,
+    on_iteration: R,
+) -> Option<SearchResult> {
+    let never = AtomicBool::new(false);
+    choose_best_move_until(state, ai_color, cfg, table, tick, on_iteration, &never)
+}
+
+/// Like [`choose_best_move_reporting`], but the search also ends as soon as `stop` is set,
+/// exactly as if it had run out of nodes: the best move found so far is returned. `stop`
+/// is checked at every node, so another context (a timer interrupt, say) can cut off a
+/// search that runs away even between two `tick`s.
+pub fn choose_best_move_until<F: FnMut(), R: FnMut(SearchResult)>(
+    state: &GameState,
+    ai_color: Color,
+    cfg: AiConfig,
+    table: &mut TranspositionTable,
+    mut tick: F,
+    mut on_iteration: R,
+    stop: &AtomicBool,
+) -> Option<SearchResult> {
+    if state.side_to_move != ai_color {
+        return None;
+    }
+
+    // 开局表优先，匹配不到再进入搜索。
+    let policy = BookPolicy::for_depth(cfg.max_depth);
+    let book_mv = match cfg.book_seed {
+        Some(seed) => book::book_move_seeded(state, policy, seed),
+        None => book::book_move_with(state, policy),
+    };
+    if let Some(book_mv) = book_mv {
+        return Some(SearchResult {
+            mv: book_mv,
+            origin: MoveOrigin::Book,
+            depth: 0,
+            nodes: 0,
+            stats: SearchStats::default(),
+        });
+    }
+    if let Some(mv) = tablebase::best_move(state) {
+        return Some(SearchResult {
+            mv,
+            origin: MoveOrigin::Tablebase,
+            depth: 0,
+            nodes: 0,
+            stats: SearchStats::default(),
+        });
+    }
+    let depth_limit = cfg.max_depth.clamp(1, 8);
+    let mut moves = state.generate_legal_moves();
+    if moves.len == 0 {
+        return None;
+    }
+
+    let mut ctx = SearchCtx::new(&cfg, table, ai_color, stop);
+    let hash = ctx.key(state);
+    if let Some(entry) = ctx.tt_probe(hash)
+        && entry.flag == Bound::Exact
+        && entry.depth >= depth_limit
+        && let Some(mv) = entry.best_move
+        && state.is_move_legal(mv)
+    {
+        return Some(SearchResult {
+            mv,
+            origin: MoveOrigin::TableHit,
+            depth: entry.depth,
+            nodes: 0,
+            stats: SearchStats::default(),
+        });
+    }
+
+    ctx.node_limit = scaled_node_limit(state, &moves, &cfg);
+    // One capture away from the tables or closer: probe them inside the search.
+    ctx.tablebase = state.board.iter().flatten().count() <= tablebase::MAX_PIECES + 1;
+    // The search plays and takes back moves on this copy instead of copying every node.
+    let mut position = *state;
+    let mut best = None;
+    let mut best_score = i32::MIN + 1;
+    let mut best_depth = 0;
+    // Best root moves of the last completed iteration, for `cfg.variety`.
+    let mut shortlist = Shortlist::new();
+
+    for depth in 1..=depth_limit {
+        tick();
+        let tt_hint = ctx.tt_probe(hash).and_then(|e| e.best_move);
+
+        sort_moves(state, &mut moves, tt_hint, true);
+        let mut local_best = best;
+        let mut local_best_score = i32::MIN + 1;
+        let mut local_shortlist = Shortlist::new();
+
+        for mv in moves.iter() {
+            tick();
+            ctx.bump();
+            let undo = position.apply_move_unchecked(*mv);
+            let score = alphabeta(
+                &mut position,
+                ai_color,
+                depth.saturating_sub(1),
+                i32::MIN + 1,
+                i32::MAX - 1,
+                &mut ctx,
+            );
+            position.unmake_move(*mv, undo);
+            if score > local_best_score {
+                local_best_score = score;
+                local_best = Some(*mv);
+            }
+            if ctx.hit_limit() {
+                break;
+            }
+            local_shortlist.insert(*mv, score);
+        }
+
+        // A cut-off iteration still searched the previous best move first, so its best move
+        // is kept; the depth it reports is only claimed once an iteration finishes.
+        if local_best.is_some() {
+            best = local_best;
+            best_score = local_best_score;
+        }
+
+        if ctx.hit_limit() {
+            break;
+        }
+        best_depth = depth;
+        // Root moves are searched with a full window, so a completed iteration is exact.
+        ctx.tt_store(hash, depth, best_score, Bound::Exact, best);
+        shortlist = local_shortlist;
+        if let Some(mv) = best {
+            on_iteration(SearchResult {
+                mv,
+                origin: MoveOrigin::Search,
+                depth,
+                nodes: ctx.nodes,
+                stats: ctx.stats,
+            });
+        }
+    }
+
+    if let Some(variety) = cfg.variety
+        && let Some(mv) = shortlist.pick(variety, hash)
+    {
+        best = Some(mv);
+    }
+    best.map(|mv| SearchResult {
+        mv,
+        origin: MoveOrigin::Search,
+        depth: best_depth,
+        nodes: ctx.nodes,
+        stats: ctx.stats,
+    })
+}
+
+/// Node budget the search will use for `state` under `cfg` (see [`AiConfig::adaptive_budget`]).
+pub fn node_budget(state: &GameState, cfg: &AiConfig) -> Option<u32> {
+    scaled_node_limit(state, &state.generate_legal_moves(), cfg)
+}
+
+fn scaled_node_limit(state: &GameState, moves: &MoveList, cfg: &AiConfig) -> Option<u32> {
+    let limit = cfg.node_limit?;
+    if !cfg.adaptive_budget {
+        return Some(limit);
+    }
+    let percent = budget_percent(state, moves);
+    Some((limit as u64 * percent as u64 / 100).max(1) as u32)
+}
+
+/// Budget scale in percent from the root move count, check status and tactics on offer.
+fn budget_percent(state: &GameState, moves: &MoveList) -> u32 {
+    let count = moves.len;
+    if count <= 2 {
+        return 20;
+    }
+    if state.is_in_check(state.side_to_move) {
+        // Check evasions are few and forcing.
+        return 50;
+    }
+    let mut percent = match count {
+        0..10 => 60,
+        10..25 => 100,
+        _ => 140,
+    };
+    let opponent = state.side_to_move.opposite();
+    let mut captures = false;
+    let mut checks = false;
+    for mv in moves.iter() {
+        captures |= is_capture(state, *mv);
+        if !checks {
+            checks = state
+                .make_move(*mv)
+                .is_some_and(|next| next.is_in_check(opponent));
+        }
+        if captures && checks {
+            break;
+        }
+    }
+    if captures {
+        percent += 30;
+    }
+    if checks {
+        percent += 30;
+    }
+    percent
+}
+
+/// Static-eval gain (centipawns) that counts as winning material.
+const WINS_MATERIAL_MARGIN: i32 = 80;
+
+/// Short human-readable reason for a move, shown next to AI moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveReason {
+    BookMove,
+    OnlyLegalMove,
+    EscapesCheck,
+    Promotes,
+    WinsMaterial,
+    /// Fallback when nothing more specific applies.
+    ImprovesPiece,
+}
+
+impl MoveReason {
+    pub fn label(self) -> &'static str {
+        match self {
+            MoveReason::BookMove => "book move",
+            MoveReason::OnlyLegalMove => "only legal move",
+            MoveReason::EscapesCheck => "escapes check",
+            MoveReason::Promotes => "promotes",
+            MoveReason::WinsMaterial => "wins material",
+            MoveReason::ImprovesPiece => "improves worst piece",
+        }
+    }
+}
+
+/// Tag `mv` (played from `before`) using move metadata and a before/after static
+/// eval comparison from the mover's point of view. The most specific reason wins.
+pub fn explain_move(before: &GameState, mv: Move) -> MoveReason {
+    let mover = before.side_to_move;
+    if book::is_book_move(before, mv) {
+        return MoveReason::BookMove;
+    }
+    if before.generate_legal_moves().len == 1 {
+        return MoveReason::OnlyLegalMove;
+    }
+    if before.is_in_check(mover) {
+        return MoveReason::EscapesCheck;
+    }
+    if mv.promotion.is_some() {
+        return MoveReason::Promotes;
+    }
+    let Some(after) = before.make_move(mv) else {
+        return MoveReason::ImprovesPiece;
+    };
+    if static_eval(&after, mover) - static_eval(before, mover) >= WINS_MATERIAL_MARGIN {
+        MoveReason::WinsMaterial
+    } else {
+        MoveReason::ImprovesPiece
+    }
+}
+
+/// Static evaluation in centipawns from `perspective`'s point of view: material,
+/// piece-square tables, rook activity (open files, seventh rank, connected rooks),
+/// endgame terms for passed pawns and the opposition, king shelter and a small check
+/// term, and doubled/isolated pawns, without any search. Uses
+/// [`EvalProfile::BALANCED`].
+pub fn static_eval(state: &GameState, perspective: Color) -> i32 {
+    evaluate(state, perspective, false, &EvalProfile::BALANCED)
+}
+
+/// [`static_eval`] with the optional terms `cfg` switches on ([`AiConfig::mobility`]),
+/// weighted by its [`AiConfig::profile`].
+pub fn static_eval_with(state: &GameState, perspective: Color, cfg: &AiConfig) -> i32 {
+    evaluate(state, perspective, cfg.mobility, &cfg.profile)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+#[derive(Clone, Copy)]
+struct TtEntry {
+    key: u64,
+    depth: u8,
+    value: i32,
+    flag: Bound,
+    best_move: Option<Move>,
+}
+
+impl TtEntry {
+    const EMPTY: TtEntry = TtEntry {
+        key: 0,
+        depth: 0,
+        value: 0,
+        flag: Bound::Exact,
+        best_move: None,
+    };
+}
+
+/// Cached static eval; the slot index supplies the low key bits, `check` holds the high half.
+#[derive(Clone, Copy)]
+struct EvalEntry {
+    check: u32,
+    value: i32,
+}
+
+impl EvalEntry {
+    const EMPTY: EvalEntry = EvalEntry { check: 0, value: 0 };
+}
+
+struct SearchCtx<'t> {
+    nodes: u32,
+    node_limit: Option<u32>,
+    max_quiesce_ply: u8,
+    delta_margin: Option<i32>,
+    mobility: bool,
+    profile: EvalProfile,
+    tt: &'t mut TranspositionTable,
+    /// Mixed into every key: scores are from the AI's side, so a table shared by both
+    /// colours must keep their entries apart.
+    key_salt: u64,
+    stats: SearchStats,
+    /// Probe the endgame tables at every node; only set when the root is close enough.
+    tablebase: bool,
+    /// Set from outside to end the search early (see [`choose_best_move_until`]).
+    stop: &'t AtomicBool,
+}
+
+impl<'t> SearchCtx<'t> {
+    fn new(
+        cfg: &AiConfig,
+        tt: &'t mut TranspositionTable,
+        ai_color: Color,
+        stop: &'t AtomicBool,
+    ) -> Self {
+        SearchCtx {
+            nodes: 0,
+            node_limit: cfg.node_limit,
+            max_quiesce_ply: cfg.max_quiesce_ply,
+            delta_margin: cfg.delta_margin,
+            mobility: cfg.mobility,
+            profile: cfg.profile,
+            tt,
+            key_salt: match ai_color {
+                Color::White => 0,
+                Color::Black => AI_BLACK_KEY,
+            },
+            stats: SearchStats::default(),
+            tablebase: false,
+            stop,
+        }
+    }
+
+    /// Score from the endgame tables when probing is on and `state` is covered.
+    fn tablebase_score(&self, state: &GameState, ai_color: Color) -> Option<i32> {
+        if !self.tablebase {
+            return None;
+        }
+        let Probe { wdl, plies } = tablebase::probe(state)?;
+        let score = match wdl {
+            Wdl::Win => TABLEBASE_WIN - plies as i32,
+            Wdl::Draw => 0,
+            Wdl::Loss => plies as i32 - TABLEBASE_WIN,
+        };
+        Some(if state.side_to_move == ai_color {
+            score
+        } else {
+            -score
+        })
+    }
+
+    /// `evaluate` through the eval cache; `key` is the position's [`key`](Self::key), whose
+    /// salt keeps the AI's perspective apart (`mobility` and `profile` are fixed per table
+    /// and colour, see [`TranspositionTable`]).
+    fn eval(&mut self, state: &GameState, key: u64, ai_color: Color) -> i32 {
+        self.stats.evals = self.stats.evals.saturating_add(1);
+        let check = (key >> 32) as u32;
+        let entry = &mut self.tt.evals[(key as usize) & EVAL_CACHE_MASK];
+        if entry.check == check {
+            self.stats.eval_hits = self.stats.eval_hits.saturating_add(1);
+            return entry.value;
+        }
+        let value = evaluate(state, ai_color, self.mobility, &self.profile);
+        *entry = EvalEntry { check, value };
+        value
+    }
+
+    fn key(&self, state: &GameState) -> u64 {
+        zobrist(state) ^ self.key_salt
+    }
+
+    fn bump(&mut self) {
+        self.nodes = self.nodes.saturating_add(1);
+    }
+
+    fn hit_limit(&self) -> bool {
+        let out_of_nodes = match self.node_limit {
+            Some(limit) => self.nodes >= limit,
+            None => false,
+        };
+        out_of_nodes || self.stop.load(Ordering::Relaxed)
+    }
+
+    /// Delta pruning: the capture cannot move the score past the window even with
+    /// the full value of the captured piece plus the safety margin.
+    fn delta_prunes(
+        &self,
+        state: &GameState,
+        mv: Move,
+        maximizing: bool,
+        stand_pat: i32,
+        alpha: i32,
+        beta: i32,
+    ) -> bool {
+        let Some(margin) = self.delta_margin else {
+            return false;
+        };
+        let gain = if mv.is_en_passant {
+            piece_value(PieceKind::Pawn)
+        } else {
+            state.board[mv.to as usize].map_or(0, |p| piece_value(p.kind))
+        };
+        if maximizing {
+            stand_pat + gain + margin <= alpha
+        } else {
+            stand_pat - gain - margin >= beta
+        }
+    }
+
+    fn tt_probe(&self, key: u64) -> Option<TtEntry> {
+        let idx = (key as usize) & TT_MASK;
+        let entry = self.tt.entries[idx];
+        if entry.key == key { Some(entry) } else { None }
+    }
+
+    /// Nothing is stored once the node limit or the stop flag has cut the search off: the
+    /// node's score may then be a static eval standing in for an unfinished subtree, and
+    /// the table outlives the search.
+    fn tt_store(&mut self, key: u64, depth: u8, value: i32, flag: Bound, best_move: Option<Move>) {
+        if self.hit_limit() {
+            return;
+        }
+        let idx = (key as usize) & TT_MASK;
+        let entry = &mut self.tt.entries[idx];
+        if entry.key != key || depth >= entry.depth {
+            *entry = TtEntry {
+                key,
+                depth,
+                value,
+                flag,
+                best_move,
+            };
+        }
+    }
+}
+
+fn alphabeta(
+    state: &mut GameState,
+    ai_color: Color,
+    depth: u8,
+    mut alpha: i32,
+    mut beta: i32,
+    ctx: &mut SearchCtx<'_>,
+) -> i32 {
+    let orig_alpha = alpha;
+    let orig_beta = beta;
+    ctx.bump();
+    let hash = ctx.key(state);
+    if ctx.hit_limit() {
+        return ctx.eval(state, hash, ai_color);
+    }
+    if let Some(score) = ctx.tablebase_score(state, ai_color) {
+        return score;
+    }
+
+    if let Some(entry) = ctx.tt_probe(hash) {
+        if entry.depth >= depth {
+            match entry.flag {
+                Bound::Exact => return entry.value,
+                Bound::Lower if entry.value > alpha => alpha = entry.value,
+                Bound::Upper if entry.value < beta => beta = entry.value,
+                _ => {}
+            }
+            if alpha >= beta {
+                return entry.value;
+            }
+        }
+    }
+
+    if depth == 0 {
+        return quiesce(state, ai_color, alpha, beta, 0, ctx);
+    }
+
+    let mut moves = state.generate_legal_moves();
+    if moves.len == 0 {
+        return terminal_score(state, ai_color);
+    }
+
+    let tt_hint = ctx.tt_probe(hash).and_then(|e| e.best_move);
+    sort_moves(state, &mut moves, tt_hint, state.side_to_move == ai_color);
+
+    let maximizing = state.side_to_move == ai_color;
+    let mut best = if maximizing {
+        i32::MIN + 1
+    } else {
+        i32::MAX - 1
+    };
+    let mut best_move = None;
+
+    for mv in moves.iter() {
+        let undo = state.apply_move_unchecked(*mv);
+        let score = alphabeta(state, ai_color, depth - 1, alpha, beta, ctx);
+        state.unmake_move(*mv, undo);
+        if maximizing {
+            if score > best {
+                best = score;
+                best_move = Some(*mv);
+            }
+            if best > alpha {
+                alpha = best;
+            }
+        } else if score < best {
+            best = score;
+            best_move = Some(*mv);
+            if best < beta {
+                beta = best;
+            }
+        }
+        if beta <= alpha || ctx.hit_limit() {
+            break;
+        }
+    }
+
+    let flag = if best <= orig_alpha {
+        Bound::Upper
+    } else if best >= orig_beta {
+        Bound::Lower
+    } else {
+        Bound::Exact
+    };
+    ctx.tt_store(hash, depth, best, flag, best_move);
+    best
+}
+
+fn quiesce(
+    state: &mut GameState,
+    ai_color: Color,
+    mut alpha: i32,
+    mut beta: i32,
+    qply: u8,
+    ctx: &mut SearchCtx<'_>,
+) -> i32 {
+    if let Some(score) = ctx.tablebase_score(state, ai_color) {
+        return score;
+    }
+    let maximizing = state.side_to_move == ai_color;
+    // Stand pat: the side to move may decline every capture, so the static eval bounds
+    // the score from its own side (below for the AI, above for the opponent).
+    let stand_pat = ctx.eval(state, ctx.key(state), ai_color);
+    if maximizing {
+        if stand_pat >= beta {
+            return beta;
+        }
+        alpha = alpha.max(stand_pat);
+    } else {
+        if stand_pat <= alpha {
+            return alpha;
+        }
+        beta = beta.min(stand_pat);
+    }
+
+    let mut moves = state.generate_legal_moves();
+    if moves.len == 0 {
+        return terminal_score(state, ai_color);
+    }
+    if qply >= ctx.max_quiesce_ply {
+        return stand_pat;
+    }
+
+    sort_moves(state, &mut moves, None, true);
+    let mut best = if maximizing { alpha } else { beta };
+    for mv in moves.iter() {
+        if !is_capture(state, *mv) && mv.promotion.is_none() {
+            continue;
+        }
+        if mv.promotion.is_none()
+            && ctx.delta_prunes(state, *mv, maximizing, stand_pat, alpha, beta)
+        {
+            continue;
+        }
+        ctx.bump();
+        let undo = state.apply_move_unchecked(*mv);
+        let score = if maximizing {
+            quiesce(state, ai_color, best, beta, qply + 1, ctx)
+        } else {
+            quiesce(state, ai_color, alpha, best, qply + 1, ctx)
+        };
+        state.unmake_move(*mv, undo);
+        if maximizing {
+            best = best.max(score);
+            if best >= beta {
+                break;
+            }
+        } else {
+            best = best.min(score);
+            if best <= alpha {
+                break;
+            }
+        }
+        if ctx.hit_limit() {
+            break;
+        }
+    }
+    best
+}
+
+fn terminal_score(state: &GameState, ai_color: Color) -> i32 {
+    if state.is_in_check(state.side_to_move) {
+        if state.side_to_move == ai_color {
+            -MATE_SCORE
+        } else {
+            MATE_SCORE
+        }
+    } else {
+        0
+    }
+}
+
+fn evaluate(state: &GameState, ai_color: Color, mobility: bool, profile: &EvalProfile) -> i32 {
+    // Material + PST + rook activity + endgame terms + king safety + pawn structure
+    // (+ mobility) + aggression, the weighted terms scaled by `profile`.
+    let mut material = 0i32;
+    let mut pst = 0i32;
+    let mut advanced = 0i32;
+    let (mut king_middlegame, mut king_endgame) = (0i32, 0i32);
+    let mut files = FileTables::default();
+    for sq in 0..64 {
+        if let Some(piece) = state.board[sq] {
+            let sign = if piece.color == ai_color { 1 } else { -1 };
+            material += sign * piece_value(piece.kind);
+            if piece.kind == PieceKind::King {
+                let side = side_index(piece.color);
+                king_middlegame += sign * PIECE_SQUARE[side][kind_index(piece.kind)][sq] as i32;
+                king_endgame += sign * KING_ENDGAME_SQUARE[side][sq] as i32;
+            } else {
+                pst += sign * piece_square_bonus(piece.kind, piece.color, sq as u8) as i32;
+            }
+            let is_piece = !matches!(piece.kind, PieceKind::Pawn | PieceKind::King);
+            if is_piece && relative_rank(piece.color, sq as u8) >= 4 {
+                advanced += sign;
+            }
+            files.record(piece.kind, piece.color, sq as u8);
+        }
+    }
+    // The king shelters in the middlegame and heads for the centre in the endgame.
+    pst += phase::taper(king_middlegame, king_endgame, files.material());
+    let mut score = EvalProfile::weigh(material, profile.material)
+        + EvalProfile::weigh(pst, profile.pst)
+        + advanced * profile.aggression;
+    score += rook_activity(state, &files, ai_color);
+    score -= rook_activity(state, &files, ai_color.opposite());
+    let white_endgame = endgame_balance(state, &files);
+    let white_structure =
+        pawn_structure(&files, Color::White) - pawn_structure(&files, Color::Black);
+    let mut king_safety = king_shelter(&files, Color::White) - king_shelter(&files, Color::Black);
+    // The check term: the side to move being in check counts against it.
+    if state.is_in_check(state.side_to_move) {
+        king_safety += match state.side_to_move {
+            Color::White => -CHECK_PENALTY,
+            Color::Black => CHECK_PENALTY,
+        };
+    }
+    let white = white_endgame
+        + EvalProfile::weigh(white_structure, profile.pawn_structure)
+        + EvalProfile::weigh(king_safety, profile.king_safety);
+    score += if ai_color == Color::White {
+        white
+    } else {
+        -white
+    };
+    if mobility || profile.counts_mobility() {
+        score += EvalProfile::weigh(mobility_balance(state, ai_color), profile.mobility);
+    }
+    score
+}
+
+/// Penalty for being the side in check.
+const CHECK_PENALTY: i32 = 30;
+
+// King shelter: own pawns on the king's file and its neighbours one or two ranks in front
+// of a king still on its back rank. Fades out with the pieces, like the endgame terms
+// fade in.
+const SHIELD_PAWN: i32 = 8;
+
+/// Pawn shield for `color`'s king, scaled by the game phase.
+fn king_shelter(files: &FileTables, color: Color) -> i32 {
+    let side = side_index(color);
+    let Some(king) = files.kings[side] else {
+        return 0;
+    };
+    if relative_rank(color, king) != 0 {
+        return 0;
+    }
+    let file = (king % 8) as usize;
+    let mut span = FILE_MASKS[file];
+    if file > 0 {
+        span |= FILE_MASKS[file - 1];
+    }
+    if file < 7 {
+        span |= FILE_MASKS[file + 1];
+    }
+    let ranks = match color {
+        Color::White => 0x0000_0000_00FF_FF00u64,
+        Color::Black => 0x00FF_FF00_0000_0000u64,
+    };
+    let shield = (files.pawn_squares[side] & span & ranks).count_ones() as i32;
+    phase::taper(SHIELD_PAWN * shield, 0, files.material())
+}
+
+// Pawn structure (centipawns): each pawn beyond the first on a file, and each pawn with
+// no own pawn on a neighbouring file.
+const DOUBLED_PAWN: i32 = 12;
+const ISOLATED_PAWN: i32 = 10;
+
+/// Doubled and isolated pawn penalties for `color` (zero or negative).
+fn pawn_structure(files: &FileTables, color: Color) -> i32 {
+    let pawns = &files.pawns[side_index(color)];
+    let mut score = 0;
+    for file in 0..8 {
+        let count = pawns[file] as i32;
+        if count == 0 {
+            continue;
+        }
+        score -= DOUBLED_PAWN * (count - 1);
+        let left = file > 0 && pawns[file - 1] > 0;
+        let right = file < 7 && pawns[file + 1] > 0;
+        if !left && !right {
+            score -= ISOLATED_PAWN * count;
+        }
+    }
+    score
+}
+
+// Rook activity (centipawns): a file free of own pawns, the seventh rank, and two rooks
+// defending each other along a rank or file.
+const ROOK_OPEN_FILE: i32 = 15;
+const ROOK_HALF_OPEN_FILE: i32 = 8;
+const ROOK_ON_SEVENTH: i32 = 15;
+const CONNECTED_ROOKS: i32 = 10;
+
+/// Per-file pawn counts plus pawn, rook and king squares and the game phase for both
+/// colours, filled in during the material pass so the rook and endgame terms need no
+/// second scan of the board.
+#[derive(Default)]
+struct FileTables {
+    pawns: [[u8; 8]; 2],
+    pawn_squares: [u64; 2],
+    /// Non-pawn material in phase units (see [`phase::units`]).
+    phase: [i32; 2],
+    /// Pawns still on their starting rank: targets for an enemy rook on its seventh.
+    home_pawns: [u8; 2],
+    /// First two rooks of each colour (a third only appears after an underpromotion).
+    rooks: [[u8; 2]; 2],
+    rook_count: [usize; 2],
+    kings: [Option<u8>; 2],
+}
+
+impl FileTables {
+    fn record(&mut self, kind: PieceKind, color: Color, sq: u8) {
+        let side = side_index(color);
+        match kind {
+            PieceKind::Pawn => {
+                self.pawns[side][(sq % 8) as usize] += 1;
+                self.pawn_squares[side] |= 1 << sq;
+                let home_rank = match color {
+                    Color::White => 1,
+                    Color::Black => 6,
+                };
+                if sq / 8 == home_rank {
+                    self.home_pawns[side] += 1;
+                }
+            }
+            PieceKind::Rook => {
+                if self.rook_count[side] < 2 {
+                    self.rooks[side][self.rook_count[side]] = sq;
+                    self.rook_count[side] += 1;
+                }
+            }
+            PieceKind::King => self.kings[side] = Some(sq),
+            PieceKind::Knight | PieceKind::Bishop | PieceKind::Queen => {}
+        }
+        self.phase[side] += phase::units(kind);
+    }
+
+    /// Phase units for both colours.
+    fn material(&self) -> i32 {
+        self.phase[0] + self.phase[1]
+    }
+}
+
+fn rook_activity(state: &GameState, files: &FileTables, color: Color) -> i32 {
+    let (us, them) = (side_index(color), side_index(color.opposite()));
+    let (seventh, back_rank) = match color {
+        Color::White => (6, 7),
+        Color::Black => (1, 0),
+    };
+    let rooks = &files.rooks[us][..files.rook_count[us]];
+    let mut score = 0;
+    for &sq in rooks {
+        let file = (sq % 8) as usize;
+        if files.pawns[us][file] == 0 {
+            score += if files.pawns[them][file] == 0 {
+                ROOK_OPEN_FILE
+            } else {
+                ROOK_HALF_OPEN_FILE
+            };
+        }
+        // The seventh rank only pays with pawns to eat there or the king cut off behind it.
+        let king_cut_off = files.kings[them].is_some_and(|k| k / 8 == back_rank);
+        if sq / 8 == seventh && (files.home_pawns[them] > 0 || king_cut_off) {
+            score += ROOK_ON_SEVENTH;
+        }
+    }
+    if let [a, b] = *rooks
+        && rooks_connected(state, a, b)
+    {
+        score += CONNECTED_ROOKS;
+    }
+    score
+}
+
+/// Two rooks on one rank or file with only empty squares between them.
+fn rooks_connected(state: &GameState, a: u8, b: u8) -> bool {
+    let (lo, hi) = (a.min(b), a.max(b));
+    let step = if lo / 8 == hi / 8 {
+        1
+    } else if lo % 8 == hi % 8 {
+        8
+    } else {
+        return false;
+    };
+    (lo + step..hi)
+        .step_by(step as usize)
+        .all(|sq| state.board[sq as usize].is_none())
+}
+
+// Endgame terms fade in as pieces come off (see `phase::taper`), so they are off with a
+// queen and a rook each or more and at full weight in a pawn ending.
+/// Passed pawn bonus by rank counted from the pawn's own side (second to seventh).
+const PASSED_PAWN: [i32; 8] = [0, 5, 10, 20, 35, 60, 100, 0];
+/// Per square the enemy king stands further from the promotion square than our king
+/// from the pawn.
+const PASSER_KING_TROPISM: i32 = 5;
+/// A passer the defending king cannot catch and no piece can stop: close to a queen.
+const UNSTOPPABLE_PASSER: i32 = 500;
+/// Holding the opposition in a king and pawn ending.
+const OPPOSITION: i32 = 20;
+
+/// White's endgame terms minus Black's, already scaled by the game phase.
+fn endgame_balance(state: &GameState, files: &FileTables) -> i32 {
+    let material = files.material();
+    if material >= ENDGAME_MATERIAL {
+        return 0;
+    }
+    let mut score = passed_pawn_terms(state, files, Color::White)
+        - passed_pawn_terms(state, files, Color::Black);
+    if material == 0 {
+        score += match opposition(state, files) {
+            Some(Color::White) => OPPOSITION,
+            Some(Color::Black) => -OPPOSITION,
+            None => 0,
+        };
+    }
+    phase::taper(0, score, material)
+}
+
+/// Passed pawn, king tropism and rule-of-the-square terms for `color`'s pawns.
+fn passed_pawn_terms(state: &GameState, files: &FileTables, color: Color) -> i32 {
+    let (us, them) = (side_index(color), side_index(color.opposite()));
+    let (Some(king), Some(enemy_king)) = (files.kings[us], files.kings[them]) else {
+        return 0;
+    };
+    let mut score = 0;
+    let mut unstoppable = false;
+    let mut pawns = files.pawn_squares[us];
+    while pawns != 0 {
+        let sq = pawns.trailing_zeros() as u8;
+        pawns &= pawns - 1;
+        if files.pawn_squares[them] & passed_pawn_span(color, sq) != 0 {
+            continue;
+        }
+        let rank = relative_rank(color, sq);
+        let promotion = match color {
+            Color::White => 56 + sq % 8,
+            Color::Black => sq % 8,
+        };
+        score += PASSED_PAWN[rank as usize];
+        score +=
+            PASSER_KING_TROPISM * (king_distance(enemy_king, promotion) - king_distance(king, sq));
+        // Rule of the square: without pieces to sacrifice, only the king can stop the pawn.
+        if files.phase[them] == 0 && !unstoppable {
+            let path_clear = (rank + 1..8).all(|r| {
+                let ahead = match color {
+                    Color::White => r * 8,
+                    Color::Black => (7 - r) * 8,
+                } + (sq % 8) as i32;
+                state.board[ahead as usize].is_none()
+            });
+            // A pawn on its starting rank gains a step with the double push.
+            let steps = 7 - rank - (rank == 1) as i32;
+            let tempo = (state.side_to_move != color) as i32;
+            unstoppable = path_clear && king_distance(enemy_king, promotion) - tempo > steps;
+        }
+    }
+    if unstoppable {
+        score += UNSTOPPABLE_PASSER;
+    }
+    score
+}
+
+/// Squares on the pawn's file and both neighbouring files in front of it: a passed
+/// pawn has no enemy pawn there.
+fn passed_pawn_span(color: Color, sq: u8) -> u64 {
+    let file = (sq % 8) as usize;
+    let mut files = FILE_MASKS[file];
+    if file > 0 {
+        files |= FILE_MASKS[file - 1];
+    }
+    if file < 7 {
+        files |= FILE_MASKS[file + 1];
+    }
+    let rank = sq / 8;
+    match color {
+        Color::White if rank < 7 => files & (!0u64 << ((rank + 1) * 8)),
+        Color::Black => files & ((1u64 << (rank * 8)) - 1),
+        Color::White => 0,
+    }
+}
+
+/// Rank counted from `color`'s own back rank (0..=7).
+fn relative_rank(color: Color, sq: u8) -> i32 {
+    match color {
+        Color::White => (sq / 8) as i32,
+        Color::Black => 7 - (sq / 8) as i32,
+    }
+}
+
+/// King moves between two squares (Chebyshev distance).
+fn king_distance(a: u8, b: u8) -> i32 {
+    let files = ((a % 8) as i32 - (b % 8) as i32).abs();
+    let ranks = ((a / 8) as i32 - (b / 8) as i32).abs();
+    files.max(ranks)
+}
+
+/// The side holding the opposition in a king and pawn ending: kings on one file or rank
+/// with an odd number of squares between them, the side not to move holding it.
+fn opposition(state: &GameState, files: &FileTables) -> Option<Color> {
+    if files.pawn_squares == [0, 0] {
+        return None;
+    }
+    let (white, black) = (files.kings[0]?, files.kings[1]?);
+    let same_line = white % 8 == black % 8 || white / 8 == black / 8;
+    let gap = king_distance(white, black) - 1;
+    (same_line && gap % 2 == 1).then(|| state.side_to_move.opposite())
+}
+
+// Mobility (centipawns per attacked square) and the square count each piece type is
+// expected to reach; a piece above its baseline scores, one below it is penalised.
+const KNIGHT_MOBILITY: (i32, i32) = (4, 4);
+const BISHOP_MOBILITY: (i32, i32) = (3, 6);
+const ROOK_MOBILITY: (i32, i32) = (2, 7);
+const QUEEN_MOBILITY: (i32, i32) = (1, 13);
+
+const BISHOP_RAYS: [(i8, i8); 4] = [(1, 1), (1, -1), (-1, 1), (-1, -1)];
+const ROOK_RAYS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+
+/// Mobility of `ai_color` minus the opponent's. Attacks are approximate: sliders stop
+/// at the first blocker with no x-rays, pins are ignored, and squares holding an own
+/// piece or covered by an enemy pawn do not count.
+fn mobility_balance(state: &GameState, ai_color: Color) -> i32 {
+    let mut occupied = [0u64; 2];
+    let mut pawn_cover = [0u64; 2];
+    for (sq, piece) in state.board.iter().enumerate() {
+        let Some(piece) = piece else { continue };
+        let side = side_index(piece.color);
+        occupied[side] |= 1 << sq;
+        if piece.kind == PieceKind::Pawn {
+            pawn_cover[side] |= PAWN_ATTACKS[side][sq];
+        }
+    }
+    let all = occupied[0] | occupied[1];
+
+    let mut score = 0;
+    for (sq, piece) in state.board.iter().enumerate() {
+        let Some(piece) = piece else { continue };
+        let (weight, baseline) = match piece.kind {
+            PieceKind::Knight => KNIGHT_MOBILITY,
+            PieceKind::Bishop => BISHOP_MOBILITY,
+            PieceKind::Rook => ROOK_MOBILITY,
+            PieceKind::Queen => QUEEN_MOBILITY,
+            PieceKind::Pawn | PieceKind::King => continue,
+        };
+        let attacks = match piece.kind {
+            PieceKind::Knight => KNIGHT_ATTACKS[sq],
+            PieceKind::Bishop => ray_attacks(sq as u8, all, &BISHOP_RAYS),
+            PieceKind::Rook => ray_attacks(sq as u8, all, &ROOK_RAYS),
+            _ => ray_attacks(sq as u8, all, &BISHOP_RAYS) | ray_attacks(sq as u8, all, &ROOK_RAYS),
+        };
+        let (us, them) = (side_index(piece.color), side_index(piece.color.opposite()));
+        let reach = (attacks & !occupied[us] & !pawn_cover[them]).count_ones() as i32;
+        let term = weight * (reach - baseline);
+        score += if piece.color == ai_color { term } else { -term };
+    }
+    score
+}
+
+/// Squares reached along `rays` from `sq`, each ray ending on (and including) the
+/// first occupied square.
+fn ray_attacks(sq: u8, occupied: u64, rays: &[(i8, i8)]) -> u64 {
+    let mut bits = 0;
+    for &(df, dr) in rays {
+        let (mut file, mut rank) = ((sq % 8) as i8 + df, (sq / 8) as i8 + dr);
+        while (0..8).contains(&file) && (0..8).contains(&rank) {
+            let bit = 1u64 << (rank * 8 + file);
+            bits |= bit;
+            if occupied & bit != 0 {
+                break;
+            }
+            file += df;
+            rank += dr;
+        }
+    }
+    bits
+}
+
+fn side_index(color: Color) -> usize {
+    match color {
+        Color::White => 0,
+        Color::Black => 1,
+    }
+}
+
+fn piece_square_bonus(kind: PieceKind, color: Color, sq: u8) -> i16 {
+    PIECE_SQUARE[side_index(color)][kind_index(kind)][sq as usize]
+}
+
+fn is_capture(state: &GameState, mv: Move) -> bool {
+    mv.is_en_passant || state.board[mv.to as usize].is_some()
+}
+
+fn piece_value(kind: PieceKind) -> i32 {
+    match kind {
+        PieceKind::Pawn => 100,
+        PieceKind::Knight => 320,
+        PieceKind::Bishop => 330,
+        PieceKind::Rook => 500,
+        PieceKind::Queen => 900,
+        PieceKind::King => 0,
+    }
+}
+
+fn move_heuristic(state: &GameState, mv: Move, tt_hint: Option<Move>) -> i32 {
+    if tt_hint.map_or(false, |m| m == mv) {
+        return 10_000;
+    }
+
+    let mut score = 0;
+    if mv.is_castling {
+        score += 50;
+    }
+
+    // Capture ordering: MVV/LVA.
+    if mv.is_en_passant {
+        score += 800;
+    } else if let Some(target) = state.board[mv.to as usize] {
+        let victim = piece_value(target.kind);
+        let attacker = state.board[mv.from as usize]
+            .map(|p| piece_value(p.kind))
+            .unwrap_or(100);
+        score += victim * 10 - attacker;
+    }
+
+    if let Some(prom) = mv.promotion {
+        score += piece_value(prom) + 400;
+    }
+
+    score
+}
+
+fn sort_moves(state: &GameState, list: &mut MoveList, tt_hint: Option<Move>, descending: bool) {
+    // Simple insertion sort using heuristic; cheap for small lists.
+    let mut i = 1;
+    while i < list.len {
+        let key = list.moves[i];
+        let key_h = move_heuristic(state, key, tt_hint);
+        let mut j = i;
+        while j > 0 {
+            let prev = list.moves[j - 1];
+            let prev_h = move_heuristic(state, prev, tt_hint);
+            let swap = if descending {
+                key_h > prev_h
+            } else {
+                key_h < prev_h
+            };
+            if swap {
+                list.moves[j] = prev;
+                j -= 1;
+            } else {
+                break;
+            }
+        }
+        list.moves[j] = key;
+        i += 1;
+    }
+}
+
+// Zobrist hashing for TT keys.
+pub(crate) fn zobrist(state: &GameState) -> u64 {
+    let mut h = 0u64;
+    for idx in 0..64u8 {
+        if let Some(piece) = state.board[idx as usize] {
+            let piece_idx = piece_index(piece.color, piece.kind);
+            h ^= zobrist_key(piece_idx, idx);
+        }
+    }
+    if state.side_to_move == Color::White {
+        h ^= SIDE_KEY;
+    }
+    h
+}
+
+fn piece_index(color: Color, kind: PieceKind) -> usize {
+    side_index(color) * 6 + kind_index(kind)
+}
+
+fn kind_index(kind: PieceKind) -> usize {
+    match kind {
+        PieceKind::Pawn => 0,
+        PieceKind::Knight => 1,
+        PieceKind::Bishop => 2,
+        PieceKind::Rook => 3,
+        PieceKind::Queen => 4,
+        PieceKind::King => 5,
+    }
+}
+
+pub(crate) fn zobrist_key(piece_idx: usize, square: u8) -> u64 {
+    // SplitMix64 keyed by piece+square ensures deterministic hash without large tables.
+    let mut x = ((piece_idx as u64) << 8) ^ square as u64 ^ 0x9E37_79B9_7F4A_7C15;
+    x = x.wrapping_add(0xBF58_476D_1CE4_E5B9);
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
+// Piece-square tables (coarse, midgame-oriented; the king's is tapered into
+// `KING_ENDGAME_PST` as material comes off), from White's side and indexed
+// `[side][kind]` once Black's mirrored copies are added.
+const PIECE_SQUARE: [[[i16; 64]; 6]; 2] = tables::with_mirrored([
+    PAWN_PST, KNIGHT_PST, BISHOP_PST, ROOK_PST, QUEEN_PST, KING_PST,
+]);
+
+const PAWN_PST: [i16; 64] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 5, 5, 5, -5, -5, 5, 5, 5, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 2, 3, 3, 2, 1,
+    1, 1, 1, 1, 2, 2, 1, 1, 1, 0, 0, 0, 1, 1, 0, 0, 0, 0, -1, -1, 0, 0, -1, -1, 0, 0, 0, 0, 0, 0,
+    0, 0, 0,
+];
+
+const KNIGHT_PST: [i16; 64] = [
+    -5, -4, -3, -3, -3, -3, -4, -5, -4, -2, 0, 0, 0, 0, -2, -4, -3, 0, 1, 1, 1, 1, 0, -3, -3, 0, 2,
+    3, 3, 2, 0, -3, -3, 0, 2, 3, 3, 2, 0, -3, -3, 0, 1, 2, 2, 1, 0, -3, -4, -2, 0, 0, 0, 0, -2, -4,
+    -5, -4, -3, -3, -3, -3, -4, -5,
+];
+
+const BISHOP_PST: [i16; 64] = [
+    -2, -1, -1, -1, -1, -1, -1, -2, -1, 0, 0, 0, 0, 0, 0, -1, -1, 0, 1, 1, 1, 1, 0, -1, -1, 1, 1,
+    1, 1, 1, 1, -1, -1, 0, 1, 1, 1, 1, 0, -1, -1, 0, 0, 1, 1, 0, 0, -1, -2, -1, -1, -1, -1, -1, -1,
+    -2, -2, -1, -1, -1, -1, -1, -1, -2,
+];
+
+const ROOK_PST: [i16; 64] = [
+    0, 0, 1, 2, 2, 1, 0, 0, -2, -2, -2, -2, -2, -2, -2, -2, -1, -1, 0, 0, 0, 0, -1, -1, -1, -1, 0,
+    0, 0, 0, -1, -1, -1, -1, 0, 0, 0, 0, -1, -1, -1, -1, 0, 1, 1, 0, -1, -1, -1, -1, 2, 2, 2, 2,
+    -1, -1, 0, 0, 0, 2, 2, 0, 0, 0,
+];
+
+const QUEEN_PST: [i16; 64] = [
+    -4, -2, -2, -1, -1, -2, -2, -4, -2, 0, 0, 0, 0, 0, 0, -2, -2, 0, 1, 1, 1, 1, 0, -2, -1, 0, 1,
+    1, 1, 1, 0, -1, -1, 0, 1, 1, 1, 1, 0, -1, -1, 0, 1, 1, 1, 1, 0, -1, -2, -2, 0, 0, 0, 0, -2, -2,
+    -4, -2, -2, -1, -1, -2, -2, -4,
+];
+
+const KING_PST: [i16; 64] = [
+    -3, -4, -4, -5, -5, -4, -4, -3, -3, -4, -4, -5, -5, -4, -4, -3, -3, -4, -4, -5, -5, -4, -4, -3,
+    -3, -4, -4, -5, -5, -4, -4, -3, -2, -3, -3, -4, -4, -3, -3, -2, -1, -2, -2, -2, -2, -2, -2, -1,
+    2, 2, 0, 0, 0, 0, 2, 2, 2, 3, 1, 0, 0, 1, 3, 2,
+];
+
+const KING_ENDGAME_SQUARE: [[i16; 64]; 2] = {
+    let [[white], [black]] = tables::with_mirrored([KING_ENDGAME_PST]);
+    [white, black]
+};
+
+// Endgame king: away from the edges and corners, towards the centre.
+const KING_ENDGAME_PST: [i16; 64] = [
+    -5, -4, -3, -2, -2, -3, -4, -5, -4, -2, -1, 0, 0, -1, -2, -4, -3, -1, 1, 2, 2, 1, -1, -3, -2,
+    0, 2, 3, 3, 2, 0, -2, -2, 0, 2, 3, 3, 2, 0, -2, -3, -1, 1, 2, 2, 1, -1, -3, -4, -2, -1, 0, 0,
+    -1, -2, -4, -5, -4, -3, -2, -2, -3, -4, -5,
+];
+
+// Zobrist side key (piece-square keys are generated on the fly).
+const SIDE_KEY: u64 = 0x9E37_79B9_7F4A_7C15;
+const AI_BLACK_KEY: u64 = 0xD1B5_4A32_D192_ED03;
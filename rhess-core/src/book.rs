@@ -0,0 +1,163 @@
+use crate::phase::Phase;
+use crate::{GameState, Move, PieceKind};
+
+/// 一条开局线，存放自起始局面的连续走法。
+pub struct BookLine {
+    pub moves: &'static [Move],
+}
+
+/// 选书策略：低难度优先走陷阱线，让弱档 AI 更像人类、也更有趣。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BookPolicy {
+    /// 只用主线开局表。
+    MainLines,
+    /// 先查陷阱线，未命中再查主线。
+    TrapsFirst,
+}
+
+/// 搜索深度不超过该值视为低难度，启用陷阱线。
+pub const TRAP_BOOK_MAX_DEPTH: u8 = 3;
+
+impl BookPolicy {
+    /// 按难度（搜索深度）选择策略。
+    pub fn for_depth(max_depth: u8) -> Self {
+        if max_depth <= TRAP_BOOK_MAX_DEPTH {
+            BookPolicy::TrapsFirst
+        } else {
+            BookPolicy::MainLines
+        }
+    }
+}
+
+/// 通过模拟标准起始局面，匹配当前局面是否命中开局线前缀。
+pub fn book_move(state: &GameState) -> Option<Move> {
+    lookup(state, BOOK_LINES)
+}
+
+/// 按策略查表：陷阱线优先于主线。
+pub fn book_move_with(state: &GameState, policy: BookPolicy) -> Option<Move> {
+    match policy {
+        BookPolicy::MainLines => book_move(state),
+        BookPolicy::TrapsFirst => lookup(state, TRAP_LINES).or_else(|| book_move(state)),
+    }
+}
+
+/// 与 `book_move_with` 相同的策略，但在命中的所有开局线中按 `seed` 选一步
+/// （如起始局面可走 e4 或 d4），让不同种子下的对局开局各不相同。
+pub fn book_move_seeded(state: &GameState, policy: BookPolicy, seed: u32) -> Option<Move> {
+    match policy {
+        BookPolicy::MainLines => pick(state, BOOK_LINES, seed),
+        BookPolicy::TrapsFirst => {
+            pick(state, TRAP_LINES, seed).or_else(|| pick(state, BOOK_LINES, seed))
+        }
+    }
+}
+
+/// `mv` 是否为某条开局线（主线或陷阱线）在该局面下的续着。
+pub fn is_book_move(state: &GameState, mv: Move) -> bool {
+    in_opening(state)
+        && BOOK_LINES
+            .iter()
+            .chain(TRAP_LINES)
+            .any(|line| match_prefix(state, line.moves) == Some(mv))
+}
+
+/// 按对局记录判断何时离开开局库：`moves` 为自标准起始局面起的着法，返回第一个不再是
+/// 任何开局线（主线或陷阱线）同一前缀续着的半回合序号；全部着法仍在书内时为 None。
+/// 逐线比对着法序列而不是局面，经别的次序转入书中局面不算在书内。
+pub fn book_exit(moves: impl IntoIterator<Item = Move>) -> Option<usize> {
+    // 仍与对局记录一致的开局线，按线数定长，开局表再长也不会溢出。
+    let mut alive = [true; LINE_COUNT];
+    for (ply, mv) in moves.into_iter().enumerate() {
+        for (alive, line) in alive.iter_mut().zip(BOOK_LINES.iter().chain(TRAP_LINES)) {
+            *alive &= line.moves.get(ply).is_some_and(|book| {
+                (book.from, book.to, book.promotion) == (mv.from, mv.to, mv.promotion)
+            });
+        }
+        if !alive.contains(&true) {
+            return Some(ply);
+        }
+    }
+    None
+}
+
+/// 主线与陷阱线的总条数。
+const LINE_COUNT: usize = BOOK_LINES.len() + TRAP_LINES.len();
+
+/// 按种子在命中的续着中选一步；多条线给出同一步时只算一次，免得分支多的开局被偏重。
+fn pick(state: &GameState, lines: &[BookLine], seed: u32) -> Option<Move> {
+    if !in_opening(state) {
+        return None;
+    }
+    let mut found = [None; MAX_CANDIDATES];
+    let mut count = 0;
+    for mv in lines
+        .iter()
+        .filter_map(|line| match_prefix(state, line.moves))
+    {
+        if count < MAX_CANDIDATES && !found[..count].contains(&Some(mv)) {
+            found[count] = Some(mv);
+            count += 1;
+        }
+    }
+    if count == 0 {
+        return None;
+    }
+    found[seed as usize % count]
+}
+
+/// 同一局面下最多考虑的不同续着数。
+const MAX_CANDIDATES: usize = 8;
+
+fn lookup(state: &GameState, lines: &[BookLine]) -> Option<Move> {
+    if !in_opening(state) {
+        return None;
+    }
+    lines
+        .iter()
+        .find_map(|line| match_prefix(state, line.moves))
+}
+
+/// 开局阶段（见 `phase`）之外不查表：所有开局线都在开局阶段之内，出了开局就不必逐线模拟。
+/// 每次查表只判断一次，不在逐线的 `match_prefix` 里重复。
+fn in_opening(state: &GameState) -> bool {
+    Phase::of(state) == Phase::Opening
+}
+
+fn match_prefix(state: &GameState, line: &[Move]) -> Option<Move> {
+    let mut sim = GameState::start_position();
+    for (idx, mv) in line.iter().enumerate() {
+        if sim == *state {
+            return line.get(idx).copied();
+        }
+        if let Some(next) = sim.make_move(*mv) {
+            sim = next;
+        } else {
+            break;
+        }
+    }
+    None
+}
+
+const fn q(from: u8, to: u8) -> Move {
+    Move {
+        from,
+        to,
+        promotion: None,
+        is_en_passant: false,
+        is_castling: false,
+    }
+}
+
+const fn castle(from: u8, to: u8) -> Move {
+    Move {
+        from,
+        to,
+        promotion: None,
+        is_en_passant: false,
+        is_castling: true,
+    }
+}
+
+// 开局线由 `cargo xtask assets` 根据 assets/book.txt 生成，提供 BOOK_LINES 与 TRAP_LINES。
+include!("generated/book.rs");
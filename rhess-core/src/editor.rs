@@ -0,0 +1,111 @@
+//! Board editor: build an arbitrary position piece by piece and validate it before play.
+//!
+//! Also parses the compact square-list syntax used by the serial `set` command, e.g.
+//! `Kc1 Qd8 Pa2b2 ke8 pe7f7 b`: an uppercase letter places white pieces, lowercase black,
+//! followed by one or more squares; a trailing `w`/`b` picks the side to move.
+
+use crate::integrity::{self, IntegrityError};
+use crate::{CastlingRights, Color, GameState, Piece, PieceKind};
+
+/// Why a square list or edited position was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SetupError {
+    /// Token is not `<piece><square>...` or a side marker.
+    BadToken,
+    /// Each side needs exactly one king.
+    KingCount,
+    /// Pawns cannot stand on the first or last rank.
+    PawnOnBackRank,
+    /// The side not to move is in check, which no legal game can reach.
+    OpponentInCheck,
+}
+
+/// Mutable scratch position; nothing is checked until [`BoardEditor::finish`].
+pub struct BoardEditor {
+    state: GameState,
+}
+
+impl BoardEditor {
+    /// Empty board, white to move, no castling or en passant.
+    pub fn new() -> Self {
+        let mut state = GameState::start_position();
+        state.board = [None; 64];
+        state.castling = CastlingRights::new();
+        BoardEditor { state }
+    }
+
+    pub fn place(&mut self, square: u8, piece: Piece) {
+        self.state.board[square as usize] = Some(piece);
+    }
+
+    pub fn remove(&mut self, square: u8) {
+        self.state.board[square as usize] = None;
+    }
+
+    pub fn set_side_to_move(&mut self, color: Color) {
+        self.state.side_to_move = color;
+    }
+
+    /// Validate and hand out the position.
+    pub fn finish(self) -> Result<GameState, SetupError> {
+        match integrity::check(&self.state) {
+            Ok(()) => Ok(self.state),
+            Err(IntegrityError::KingCount(_)) => Err(SetupError::KingCount),
+            Err(IntegrityError::PawnOnBackRank(_)) => Err(SetupError::PawnOnBackRank),
+            Err(IntegrityError::OpponentInCheck) => Err(SetupError::OpponentInCheck),
+            // The editor never sets en passant or castling rights.
+            Err(IntegrityError::BadEnPassant(_) | IntegrityError::BadCastling(_)) => {
+                Err(SetupError::BadToken)
+            }
+        }
+    }
+}
+
+impl Default for BoardEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parse a whitespace-separated square list (see module docs) into a validated position.
+pub fn parse_square_list(text: &str) -> Result<GameState, SetupError> {
+    let mut editor = BoardEditor::new();
+    for token in text.split_whitespace() {
+        match token {
+            "w" => editor.set_side_to_move(Color::White),
+            "b" => editor.set_side_to_move(Color::Black),
+            _ => place_token(&mut editor, token)?,
+        }
+    }
+    editor.finish()
+}
+
+fn place_token(editor: &mut BoardEditor, token: &str) -> Result<(), SetupError> {
+    let bytes = token.as_bytes();
+    let (&letter, squares) = bytes.split_first().ok_or(SetupError::BadToken)?;
+    let color = if letter.is_ascii_uppercase() {
+        Color::White
+    } else {
+        Color::Black
+    };
+    let kind = match letter.to_ascii_lowercase() {
+        b'k' => PieceKind::King,
+        b'q' => PieceKind::Queen,
+        b'r' => PieceKind::Rook,
+        b'b' => PieceKind::Bishop,
+        b'n' => PieceKind::Knight,
+        b'p' => PieceKind::Pawn,
+        _ => return Err(SetupError::BadToken),
+    };
+    if squares.is_empty() || squares.len() % 2 != 0 {
+        return Err(SetupError::BadToken);
+    }
+    for pair in squares.chunks(2) {
+        let (file, rank) = (pair[0].to_ascii_lowercase(), pair[1]);
+        if !(b'a'..=b'h').contains(&file) || !(b'1'..=b'8').contains(&rank) {
+            return Err(SetupError::BadToken);
+        }
+        editor.place((rank - b'1') * 8 + (file - b'a'), Piece { color, kind });
+    }
+    Ok(())
+}
@@ -0,0 +1,132 @@
+//! Forsyth–Edwards Notation (FEN) output, for handing a position to another engine or
+//! board: `rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1`.
+//!
+//! The en-passant field is written whenever the state records a target square, i.e.
+//! after every double pawn push, as the original FEN definition does.
+
+use core::fmt::{self, Write};
+
+use crate::{Color, GameState, PieceKind};
+
+/// Longest FEN: 71 bytes of placement plus side, castling, en passant and two
+/// five-digit clocks.
+const FEN_LEN: usize = 96;
+
+/// A position in FEN, stored inline so it can be built without allocation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Fen {
+    buf: [u8; FEN_LEN],
+    len: u8,
+}
+
+impl Fen {
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len as usize]).unwrap_or("")
+    }
+}
+
+impl Write for Fen {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let start = self.len as usize;
+        let end = start + s.len();
+        if end > FEN_LEN {
+            return Err(fmt::Error);
+        }
+        self.buf[start..end].copy_from_slice(s.as_bytes());
+        self.len = end as u8;
+        Ok(())
+    }
+}
+
+impl fmt::Display for Fen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Fen {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// FEN for `state`.
+pub fn fen(state: &GameState) -> Fen {
+    let mut out = Fen {
+        buf: [0; FEN_LEN],
+        len: 0,
+    };
+    // Every field fits in `FEN_LEN`, so the writes cannot fail.
+    let _ = write_fields(state, &mut out);
+    out
+}
+
+fn write_fields(state: &GameState, out: &mut Fen) -> fmt::Result {
+    for rank in (0..8).rev() {
+        let mut empty = 0;
+        for file in 0..8 {
+            match state.board[rank * 8 + file] {
+                None => empty += 1,
+                Some(piece) => {
+                    if empty > 0 {
+                        write!(out, "{empty}")?;
+                        empty = 0;
+                    }
+                    let letter = piece_letter(piece.kind);
+                    out.write_char(match piece.color {
+                        Color::White => letter.to_ascii_uppercase(),
+                        Color::Black => letter,
+                    })?;
+                }
+            }
+        }
+        if empty > 0 {
+            write!(out, "{empty}")?;
+        }
+        if rank > 0 {
+            out.write_char('/')?;
+        }
+    }
+
+    out.write_str(match state.side_to_move {
+        Color::White => " w ",
+        Color::Black => " b ",
+    })?;
+    let rights = [
+        (Color::White, true, 'K'),
+        (Color::White, false, 'Q'),
+        (Color::Black, true, 'k'),
+        (Color::Black, false, 'q'),
+    ];
+    let mut any = false;
+    for (color, king_side, letter) in rights {
+        if state.castling.can_castle(color, king_side) {
+            out.write_char(letter)?;
+            any = true;
+        }
+    }
+    if !any {
+        out.write_char('-')?;
+    }
+    match state.en_passant {
+        Some(sq) => write!(
+            out,
+            " {}{}",
+            (b'a' + sq % 8) as char,
+            (b'1' + sq / 8) as char
+        )?,
+        None => out.write_str(" -")?,
+    }
+    write!(out, " {} {}", state.halfmove_clock, state.fullmove_number)
+}
+
+fn piece_letter(kind: PieceKind) -> char {
+    match kind {
+        PieceKind::King => 'k',
+        PieceKind::Queen => 'q',
+        PieceKind::Rook => 'r',
+        PieceKind::Bishop => 'b',
+        PieceKind::Knight => 'n',
+        PieceKind::Pawn => 'p',
+    }
+}
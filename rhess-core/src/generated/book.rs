@@ -0,0 +1,124 @@
+// @generated by `cargo xtask assets` from assets/book.txt — edit that instead.
+
+const ITALIAN: &[Move] = &[
+    q(12, 28), // 1. e4
+    q(52, 36), // ... e5
+    q(6, 21),  // 2. Nf3
+    q(57, 42), // ... Nc6
+    q(5, 26),  // 3. Bc4
+    q(61, 34), // ... Bc5
+    q(21, 38), // 4. Ng5
+    q(34, 13), // ... Bxf2+
+];
+
+const RUY_LOPEZ_MAIN: &[Move] = &[
+    q(12, 28),    // 1. e4
+    q(52, 36),    // ... e5
+    q(6, 21),     // 2. Nf3
+    q(57, 42),    // ... Nc6
+    q(5, 33),     // 3. Bb5
+    q(48, 40),    // ... a6
+    q(33, 24),    // 4. Ba4
+    q(62, 45),    // ... Nf6
+    castle(4, 6), // 5. O-O
+    q(61, 52),    // ... Be7
+];
+
+const QUEENS_GAMBIT: &[Move] = &[
+    q(11, 27), // 1. d4
+    q(51, 35), // ... d5
+    q(10, 26), // 2. c4
+    q(52, 44), // ... e6
+    q(1, 18),  // 3. Nc3
+    q(62, 45), // ... Nf6
+    q(2, 38),  // 4. Bg5
+];
+
+const SICILIAN_NAJDORFISH: &[Move] = &[
+    q(12, 28), // 1. e4
+    q(50, 34), // ... c5
+    q(6, 21),  // 2. Nf3
+    q(51, 43), // ... d6
+    q(11, 27), // 3. d4
+    q(34, 27), // ... cxd4
+    q(21, 27), // 4. Nxd4
+    q(48, 40), // ... a6
+    q(27, 17), // 5. Nb3
+    q(57, 42), // ... Nc6
+];
+
+const CARO_KANN: &[Move] = &[
+    q(12, 28), // 1. e4
+    q(50, 42), // ... c6
+    q(6, 21),  // 2. Nf3
+    q(51, 35), // ... d5
+    q(28, 35), // 3. exd5
+    q(42, 35), // ... cxd5
+    q(10, 26), // 4. c4
+    q(57, 42), // ... Nc6
+];
+
+const BOOK_LINES: &[BookLine] = &[
+    BookLine { moves: ITALIAN },
+    BookLine { moves: RUY_LOPEZ_MAIN },
+    BookLine { moves: QUEENS_GAMBIT },
+    BookLine { moves: SICILIAN_NAJDORFISH },
+    BookLine { moves: CARO_KANN },
+];
+
+const STAFFORD_GAMBIT: &[Move] = &[
+    q(12, 28), // 1. e4
+    q(52, 36), // ... e5
+    q(6, 21),  // 2. Nf3
+    q(62, 45), // ... Nf6
+    q(21, 36), // 3. Nxe5
+    q(57, 42), // ... Nc6
+    q(36, 42), // 4. Nxc6
+    q(51, 42), // ... dxc6
+    q(11, 19), // 5. d3
+    q(61, 34), // ... Bc5
+    q(2, 38),  // 6. Bg5
+    q(45, 28), // ... Nxe4!
+    q(38, 59), // 7. Bxd8
+    q(34, 13), // ... Bxf2+
+    q(4, 12),  // 8. Ke2
+    q(58, 30), // ... Bg4#
+];
+
+const FRIED_LIVER: &[Move] = &[
+    q(12, 28), // 1. e4
+    q(52, 36), // ... e5
+    q(6, 21),  // 2. Nf3
+    q(57, 42), // ... Nc6
+    q(5, 26),  // 3. Bc4
+    q(62, 45), // ... Nf6
+    q(21, 38), // 4. Ng5
+    q(51, 35), // ... d5
+    q(28, 35), // 5. exd5
+    q(45, 35), // ... Nxd5?
+    q(38, 53), // 6. Nxf7
+    q(60, 53), // ... Kxf7
+    q(3, 21),  // 7. Qf3+
+];
+
+const LEGAL_MATE: &[Move] = &[
+    q(12, 28), // 1. e4
+    q(52, 36), // ... e5
+    q(6, 21),  // 2. Nf3
+    q(51, 43), // ... d6
+    q(5, 26),  // 3. Bc4
+    q(58, 30), // ... Bg4
+    q(1, 18),  // 4. Nc3
+    q(54, 46), // ... g6?
+    q(21, 36), // 5. Nxe5!
+    q(30, 3),  // ... Bxd1?
+    q(26, 53), // 6. Bxf7+
+    q(60, 52), // ... Ke7
+    q(18, 35), // 7. Nd5#
+];
+
+const TRAP_LINES: &[BookLine] = &[
+    BookLine { moves: STAFFORD_GAMBIT },
+    BookLine { moves: FRIED_LIVER },
+    BookLine { moves: LEGAL_MATE },
+];
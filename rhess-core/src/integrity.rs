@@ -0,0 +1,102 @@
+//! Position integrity checks: catch states no legal game can reach (a missing king, a stale
+//! en-passant square, castling rights without the pieces) before move generation runs on them.
+//!
+//! Such states only arise from bugs, corrupted memory or unchecked input; the generator's
+//! behaviour on them is unspecified, so callers should check and recover instead of playing on.
+
+use crate::{Color, GameState, Piece, PieceKind};
+
+/// First inconsistency found by [`check`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntegrityError {
+    /// That side has no king, or more than one.
+    KingCount(Color),
+    /// A pawn stands on the first or last rank.
+    PawnOnBackRank(u8),
+    /// The en-passant square is not right behind a pawn that just double-stepped.
+    BadEnPassant(u8),
+    /// A castling right is set although the king or rook has left its home square.
+    BadCastling(Color),
+    /// The side not to move is in check, so its king could be captured.
+    OpponentInCheck,
+}
+
+impl IntegrityError {
+    /// Short human-readable description, suitable for a status line.
+    pub fn message(self) -> &'static str {
+        match self {
+            IntegrityError::KingCount(_) => "wrong number of kings",
+            IntegrityError::PawnOnBackRank(_) => "pawn on first/last rank",
+            IntegrityError::BadEnPassant(_) => "stale en-passant square",
+            IntegrityError::BadCastling(_) => "castling right without pieces",
+            IntegrityError::OpponentInCheck => "king can be captured",
+        }
+    }
+}
+
+/// Check `state` for inconsistencies, cheapest checks first.
+pub fn check(state: &GameState) -> Result<(), IntegrityError> {
+    let mut kings = [0u8; 2];
+    for (sq, piece) in state.board.iter().enumerate() {
+        let Some(piece) = piece else {
+            continue;
+        };
+        match piece.kind {
+            PieceKind::King => kings[piece.color as usize] += 1,
+            PieceKind::Pawn if !(8..56).contains(&sq) => {
+                return Err(IntegrityError::PawnOnBackRank(sq as u8));
+            }
+            _ => {}
+        }
+    }
+    for color in [Color::White, Color::Black] {
+        if kings[color as usize] != 1 {
+            return Err(IntegrityError::KingCount(color));
+        }
+    }
+    if let Some(ep) = state.en_passant
+        && !en_passant_is_consistent(state, ep)
+    {
+        return Err(IntegrityError::BadEnPassant(ep));
+    }
+    for color in [Color::White, Color::Black] {
+        if !castling_is_consistent(state, color) {
+            return Err(IntegrityError::BadCastling(color));
+        }
+    }
+    if state.is_in_check(state.side_to_move.opposite()) {
+        return Err(IntegrityError::OpponentInCheck);
+    }
+    Ok(())
+}
+
+/// The side that just moved pushed a pawn two squares: the target square is empty, on that
+/// side's third rank, with the pawn directly in front of it.
+fn en_passant_is_consistent(state: &GameState, ep: u8) -> bool {
+    let mover = state.side_to_move.opposite();
+    let (rank, pawn_sq) = match mover {
+        Color::White => (2, ep.wrapping_add(8)),
+        Color::Black => (5, ep.wrapping_sub(8)),
+    };
+    ep < 64
+        && ep / 8 == rank
+        && state.board[ep as usize].is_none()
+        && state.board[pawn_sq as usize]
+            == Some(Piece {
+                color: mover,
+                kind: PieceKind::Pawn,
+            })
+}
+
+fn castling_is_consistent(state: &GameState, color: Color) -> bool {
+    let (king_sq, rook_sqs) = match color {
+        Color::White => (4, [7, 0]),
+        Color::Black => (60, [63, 56]),
+    };
+    let has = |sq: usize, kind| state.board[sq] == Some(Piece { color, kind });
+    [true, false]
+        .into_iter()
+        .zip(rook_sqs)
+        .filter(|&(king_side, _)| state.castling.can_castle(color, king_side))
+        .all(|(_, rook_sq)| has(king_sq, PieceKind::King) && has(rook_sq, PieceKind::Rook))
+}
@@ -1,9 +1,21 @@
-// Core chess engine targeted at bare-metal: no heap, fixed-size arrays only.
-// Coordinates use 0..63 (a1 = 0, h8 = 63) with rank = idx / 8, file = idx % 8.
-
-// Re-export the actual `core` crate so macros (e.g., RTT) using `core::...` keep working.
-pub use ::core::*;
-
+//! Core chess engine targeted at bare-metal: no heap, fixed-size arrays only.
+//!
+//! Squares are numbered 0..63 (a1 = 0, h8 = 63) with rank = idx / 8, file = idx % 8.
+//! The crate has no hardware dependencies, so it builds for any `no_std` target and
+//! runs its tests/benchmarks on the host:
+//!
+//! ```text
+//! cargo test -p rhess-core --target x86_64-unknown-linux-gnu
+//! cargo bench -p rhess-core --target x86_64-unknown-linux-gnu
+//! ```
+//!
+//! Typical use: keep a [`GameState`], list moves with
+//! [`GameState::generate_legal_moves`], apply them with [`GameState::make_move`], and
+//! ask [`ai::choose_best_move`] for a computer reply.
+
+#![no_std]
+
+/// Side of the board.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Color {
     White,
@@ -11,8 +23,8 @@ pub enum Color {
 }
 
 impl Color {
-    // Returns opponent color.
-    fn opposite(self) -> Color {
+    /// Returns the opponent color.
+    pub fn opposite(self) -> Color {
         match self {
             Color::White => Color::Black,
             Color::Black => Color::White,
@@ -44,6 +56,7 @@ impl Color {
     }
 }
 
+/// Piece type, independent of color.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PieceKind {
     Pawn,
@@ -54,12 +67,47 @@ pub enum PieceKind {
     King,
 }
 
+/// A colored piece occupying a square.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Piece {
     pub color: Color,
     pub kind: PieceKind,
 }
 
+impl Piece {
+    /// Squares this piece could move to from `from` on an otherwise empty board: its raw
+    /// movement pattern, ignoring blockers, checks and castling. Pawns include the double
+    /// step from their home rank and both diagonal capture squares.
+    pub fn movement_pattern(self, from: u8) -> SquareSet {
+        let mut set = SquareSet::EMPTY;
+        if from >= 64 {
+            return set;
+        }
+        let mut state = GameState::start_position();
+        state.board = [None; 64];
+        state.board[from as usize] = Some(self);
+        state.side_to_move = self.color;
+        state.castling = CastlingRights::new();
+        let mut list = MoveList::new();
+        state.generate_pseudo_legal_moves(&mut list);
+        for mv in list.iter() {
+            set.insert(mv.to);
+        }
+        // Pawn captures need a target, so the diagonals are added by hand.
+        if self.kind == PieceKind::Pawn {
+            let dir = self.color.pawn_direction();
+            for off in [7 * dir, 9 * dir] {
+                let target = from as i16 + off as i16;
+                if (0..64).contains(&target) && file_distance(from, target as u8) == 1 {
+                    set.insert(target as u8);
+                }
+            }
+        }
+        set
+    }
+}
+
+/// A move between two squares; special moves carry their flags explicitly.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Move {
     pub from: u8,
@@ -70,7 +118,7 @@ pub struct Move {
 }
 
 impl Move {
-    // Helper for quiet/non-special moves.
+    /// Helper for quiet/non-special moves.
     pub const fn quiet(from: u8, to: u8) -> Move {
         Move {
             from,
@@ -82,6 +130,7 @@ impl Move {
     }
 }
 
+/// Remaining castling rights for both sides, packed into four bits.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CastlingRights {
     bits: u8,
@@ -93,10 +142,12 @@ impl CastlingRights {
     const BLACK_KING: u8 = 0b0100;
     const BLACK_QUEEN: u8 = 0b1000;
 
+    /// No castling rights.
     pub const fn new() -> CastlingRights {
         CastlingRights { bits: 0 }
     }
 
+    /// All four castling rights, as in the initial position.
     pub const fn full() -> CastlingRights {
         CastlingRights {
             bits: Self::WHITE_KING | Self::WHITE_QUEEN | Self::BLACK_KING | Self::BLACK_QUEEN,
@@ -127,7 +178,8 @@ impl CastlingRights {
         self.bits &= !Self::BLACK_QUEEN;
     }
 
-    fn can_castle(&self, color: Color, king_side: bool) -> bool {
+    /// Whether `color` still has the right to castle on that side.
+    pub fn can_castle(&self, color: Color, king_side: bool) -> bool {
         match (color, king_side) {
             (Color::White, true) => self.bits & Self::WHITE_KING != 0,
             (Color::White, false) => self.bits & Self::WHITE_QUEEN != 0,
@@ -137,6 +189,29 @@ impl CastlingRights {
     }
 }
 
+impl Default for CastlingRights {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What [`GameState::apply_move_unchecked`] overwrote, so [`GameState::unmake_move`] can
+/// restore the position without keeping a copy of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UndoInfo {
+    /// The piece that moved, before any promotion; `None` if the move was not applied.
+    moved: Option<Piece>,
+    /// The captured piece, including a pawn taken en passant.
+    captured: Option<Piece>,
+    castling: CastlingRights,
+    en_passant: Option<u8>,
+    halfmove_clock: u16,
+    fullmove_number: u16,
+}
+
+/// Complete position: piece placement plus side to move, castling, en-passant and clocks.
+/// `Copy` so callers can branch by value; the search instead plays and takes back moves
+/// in place (see [`GameState::unmake_move`]).
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct GameState {
     pub board: [Option<Piece>; 64],
@@ -148,7 +223,7 @@ pub struct GameState {
 }
 
 impl GameState {
-    // Standard initial position.
+    /// Standard initial position.
     pub const fn start_position() -> GameState {
         use Color::*;
         use PieceKind::*;
@@ -246,24 +321,85 @@ impl GameState {
         }
     }
 
-    // Generate fully legal moves by filtering pseudo-legal moves that leave king in check.
+    /// Generate fully legal moves by filtering pseudo-legal moves that leave king in check.
     pub fn generate_legal_moves(&self) -> MoveList {
         let mut list = MoveList::new();
         self.generate_pseudo_legal_moves(&mut list);
+        let mut scratch = *self;
         list.retain(|mv| {
-            let mut cloned = *self;
-            cloned.apply_move_unchecked(*mv);
-            !cloned.is_in_check(self.side_to_move)
+            // King-capture guard: only reachable from a corrupted position (see `integrity`).
+            if matches!(self.board[mv.to as usize], Some(p) if p.kind == PieceKind::King) {
+                return false;
+            }
+            let undo = scratch.apply_move_unchecked(*mv);
+            let safe = !scratch.is_in_check(self.side_to_move);
+            scratch.unmake_move(*mv, undo);
+            safe
         });
         list
     }
 
-    // Lightweight legality test against generated list.
+    /// Legal destinations of the piece on `from`, split into quiet moves and captures
+    /// (en passant counts as a capture). Empty unless `from` holds a piece of the side to
+    /// move; a pawn's four promotions to the same square appear once.
+    pub fn legal_destinations(&self, from: u8) -> Destinations {
+        let mut out = Destinations::default();
+        for mv in self
+            .generate_legal_moves()
+            .iter()
+            .filter(|mv| mv.from == from)
+        {
+            if mv.is_en_passant || self.board[mv.to as usize].is_some() {
+                out.captures.insert(mv.to);
+            } else {
+                out.quiet.insert(mv.to);
+            }
+        }
+        out
+    }
+
+    /// Lightweight legality test against generated list.
     pub fn is_move_legal(&self, mv: Move) -> bool {
         self.generate_legal_moves().iter().any(|m| *m == mv)
     }
 
-    // Play a move if legal and return new state.
+    /// Explain why `mv` cannot be played here, or `Ok(())` if it is legal.
+    pub fn check_move(&self, mv: Move) -> Result<(), ChessError> {
+        let piece = self.board[mv.from as usize].ok_or(ChessError::NoPieceOnSquare(mv.from))?;
+        if piece.color != self.side_to_move {
+            return Err(ChessError::WrongSideToMove);
+        }
+        if !self.is_move_legal(mv) {
+            return Err(ChessError::IllegalMove);
+        }
+        Ok(())
+    }
+
+    /// Consistency check for positions of unknown origin; see [`integrity::check`].
+    pub fn check_integrity(&self) -> Result<(), integrity::IntegrityError> {
+        integrity::check(self)
+    }
+
+    /// How the game ends in this position, if it does: checkmate, stalemate or
+    /// insufficient material. Endings that need the game history are left to the caller.
+    pub fn result(&self) -> Option<result::GameResult> {
+        result::of_position(self)
+    }
+
+    /// [`result`](Self::result) plus the fifty-move rule and threefold repetition, given
+    /// the positions played before this one. A mate on the hundredth ply still counts.
+    pub fn result_with(&self, repetitions: &repetition::Repetitions) -> Option<result::GameResult> {
+        result::of_game(self, repetitions)
+    }
+
+    /// Whether the game is drawn here: stalemate, insufficient material, the fifty-move
+    /// rule or threefold repetition.
+    pub fn is_draw(&self, repetitions: &repetition::Repetitions) -> bool {
+        self.result_with(repetitions)
+            .is_some_and(|result| result.winner().is_none())
+    }
+
+    /// Play a move if legal and return new state.
     pub fn make_move(&self, mv: Move) -> Option<GameState> {
         if !self.is_move_legal(mv) {
             return None;
@@ -273,6 +409,38 @@ impl GameState {
         Some(next)
     }
 
+    /// Left-right reflection (a-file ↔ h-file), same side to move. Castling rights are
+    /// dropped because the king and rooks no longer stand on their castling squares.
+    pub fn mirrored(&self) -> GameState {
+        let mut out = *self;
+        for sq in 0..64 {
+            out.board[sq ^ 7] = self.board[sq];
+        }
+        out.castling = CastlingRights::new();
+        out.en_passant = self.en_passant.map(|sq| sq ^ 7);
+        out
+    }
+
+    /// The same position seen from the other side: ranks flipped, piece colours,
+    /// side to move and castling rights swapped. Evaluations for one colour should
+    /// equal those for the other colour in the original position.
+    pub fn color_swapped(&self) -> GameState {
+        let mut out = *self;
+        for sq in 0..64 {
+            out.board[sq ^ 56] = self.board[sq].map(|p| Piece {
+                color: p.color.opposite(),
+                kind: p.kind,
+            });
+        }
+        out.side_to_move = self.side_to_move.opposite();
+        let bits = self.castling.bits;
+        out.castling = CastlingRights {
+            bits: (bits & 0b0011) << 2 | (bits >> 2) & 0b0011,
+        };
+        out.en_passant = self.en_passant.map(|sq| sq ^ 56);
+        out
+    }
+
     // Pseudo-legal generator (no self-check filtering).
     fn generate_pseudo_legal_moves(&self, list: &mut MoveList) {
         for idx in 0..64 {
@@ -512,8 +680,27 @@ impl GameState {
         }
     }
 
-    fn apply_move_unchecked(&mut self, mv: Move) {
-        let moving_piece = self.board[mv.from as usize].unwrap();
+    /// Applies `mv` without legality checks, for moves already known to be legal (such as
+    /// those from [`generate_legal_moves`](Self::generate_legal_moves)). Moves from an empty
+    /// square or onto a king (possible only in corrupted positions) leave the state untouched
+    /// instead of panicking. The returned [`UndoInfo`] takes the move back with
+    /// [`unmake_move`](Self::unmake_move).
+    pub fn apply_move_unchecked(&mut self, mv: Move) -> UndoInfo {
+        let mut undo = UndoInfo {
+            moved: None,
+            captured: self.board[mv.to as usize],
+            castling: self.castling,
+            en_passant: self.en_passant,
+            halfmove_clock: self.halfmove_clock,
+            fullmove_number: self.fullmove_number,
+        };
+        let Some(moving_piece) = self.board[mv.from as usize] else {
+            return undo;
+        };
+        if matches!(undo.captured, Some(p) if p.kind == PieceKind::King) {
+            return undo;
+        }
+        undo.moved = Some(moving_piece);
         // Reset en-passant; may be set again for double pawn pushes.
         self.en_passant = None;
         self.halfmove_clock += 1;
@@ -526,7 +713,7 @@ impl GameState {
                 8
             };
             let captured_sq = (mv.to as i16 + dir) as u8;
-            self.board[captured_sq as usize] = None;
+            undo.captured = self.board[captured_sq as usize].take();
         } else if self.board[mv.to as usize].is_some() {
             self.halfmove_clock = 0;
         }
@@ -606,48 +793,119 @@ impl GameState {
             self.fullmove_number += 1;
         }
         self.side_to_move = self.side_to_move.opposite();
+        undo
     }
 
+    /// Takes back `mv`, which must be the last move applied with
+    /// [`apply_move_unchecked`](Self::apply_move_unchecked), given the [`UndoInfo`] it
+    /// returned. A move that was not applied is not taken back either.
+    pub fn unmake_move(&mut self, mv: Move, undo: UndoInfo) {
+        let Some(moved) = undo.moved else {
+            return;
+        };
+        self.side_to_move = self.side_to_move.opposite();
+
+        let rook = match (moved.color, mv.to) {
+            (Color::White, 6) => Some((7, 5)),
+            (Color::White, 2) => Some((0, 3)),
+            (Color::Black, 62) => Some((63, 61)),
+            (Color::Black, 58) => Some((56, 59)),
+            _ => None,
+        };
+        if mv.is_castling
+            && let Some((rook_from, rook_to)) = rook
+        {
+            self.board[rook_from] = self.board[rook_to].take();
+        }
+
+        self.board[mv.from as usize] = Some(moved);
+        if mv.is_en_passant {
+            self.board[mv.to as usize] = None;
+            let dir = if moved.color == Color::White { -8 } else { 8 };
+            self.board[(mv.to as i16 + dir) as usize] = undo.captured;
+        } else {
+            self.board[mv.to as usize] = undo.captured;
+        }
+
+        self.castling = undo.castling;
+        self.en_passant = undo.en_passant;
+        self.halfmove_clock = undo.halfmove_clock;
+        self.fullmove_number = undo.fullmove_number;
+    }
+
+    /// Differences from `self` to `other`: iterates the squares whose contents changed
+    /// (a1 first) and reports whether side to move, castling rights or the en-passant
+    /// square changed. Move counters are not compared.
+    pub fn diff<'a>(&'a self, other: &'a GameState) -> SquareDiffIter<'a> {
+        SquareDiffIter {
+            before: self,
+            after: other,
+            next: 0,
+        }
+    }
+
+    /// Square of `color`'s king (the first one found if a setup has several).
+    pub fn king_square(&self, color: Color) -> Option<u8> {
+        self.board
+            .iter()
+            .position(
+                |p| matches!(p, Some(Piece { color: c, kind: PieceKind::King }) if *c == color),
+            )
+            .map(|sq| sq as u8)
+    }
+
+    /// Whether `color`'s king is attacked. Positions without that king report `false`.
     pub fn is_in_check(&self, color: Color) -> bool {
-        let king_sq = self.board.iter().position(
-            |p| matches!(p, Some(Piece { color: c, kind: PieceKind::King }) if *c == color),
-        );
-        match king_sq {
-            Some(sq) => self.is_square_attacked(sq as u8, color.opposite()),
+        match self.king_square(color) {
+            Some(sq) => self.is_square_attacked(sq, color.opposite()),
             None => false,
         }
     }
 
-    // Attack detection for a given square and attacker color.
-    fn is_square_attacked(&self, sq: u8, by: Color) -> bool {
-        // Pawn attacks
-        let dir = by.pawn_direction();
-        for off in [7 * dir, 9 * dir].iter() {
-            let target = sq as i16 + *off as i16;
-            if target >= 0 && target < 64 {
-                let from = target as u8;
-                if file_distance(sq, from) == 1 {
-                    if let Some(piece) = self.board[from as usize] {
-                        if piece.color == by && piece.kind == PieceKind::Pawn {
-                            return true;
-                        }
-                    }
-                }
-            }
-        }
-        // Knights
-        const KNIGHT_OFFSETS: [i8; 8] = [17, 15, 10, 6, -17, -15, -10, -6];
-        for off in KNIGHT_OFFSETS.iter() {
-            let target = sq as i16 + *off as i16;
-            if target >= 0 && target < 64 {
-                let from = target as u8;
-                if knight_move_wraps(from, sq) {
-                    continue;
-                }
-                if let Some(piece) = self.board[from as usize] {
-                    if piece.color == by && piece.kind == PieceKind::Knight {
-                        return true;
-                    }
+    /// Square of the side to move's king when it is in check, for highlighting it.
+    pub fn checked_king(&self) -> Option<u8> {
+        let side = self.side_to_move;
+        self.king_square(side)
+            .filter(|&sq| self.is_square_attacked(sq, side.opposite()))
+    }
+
+    /// Whether any of `by`'s pieces attacks `sq` (pins are ignored: a pinned piece still attacks).
+    pub fn is_square_attacked(&self, sq: u8, by: Color) -> bool {
+        self.scan_attackers(sq, by, |_| true)
+    }
+
+    /// Squares of all of `by`'s pieces attacking `sq`. Only direct attacks count: a slider
+    /// behind another attacker on the same line (an x-ray) is not included.
+    pub fn attackers(&self, sq: u8, by: Color) -> SquareSet {
+        let mut set = SquareSet::EMPTY;
+        self.scan_attackers(sq, by, |from| {
+            set.insert(from);
+            false
+        });
+        set
+    }
+
+    /// Calls `found` with the square of each of `by`'s attackers of `sq`; stops and returns
+    /// true as soon as `found` does.
+    fn scan_attackers(&self, sq: u8, by: Color, mut found: impl FnMut(u8) -> bool) -> bool {
+        let holds = |from: u8, kind: PieceKind| {
+            self.board[from as usize] == Some(Piece { color: by, kind })
+        };
+        // Leapers attack symmetrically, so their attackers stand on the squares a piece of
+        // the same kind on `sq` would attack; for pawns that is a pawn of the other colour.
+        let pawn_table = match by {
+            Color::White => tables::PAWN_ATTACKS[1],
+            Color::Black => tables::PAWN_ATTACKS[0],
+        };
+        let leapers = [
+            (pawn_table, PieceKind::Pawn),
+            (tables::KNIGHT_ATTACKS, PieceKind::Knight),
+            (tables::KING_ATTACKS, PieceKind::King),
+        ];
+        for (table, kind) in leapers {
+            for from in SquareSet(table[sq as usize]).iter() {
+                if holds(from, kind) && found(from) {
+                    return true;
                 }
             }
         }
@@ -662,49 +920,153 @@ impl GameState {
             (-7, PieceKind::Bishop),
             (-9, PieceKind::Bishop),
         ];
-        for (dir, base) in directions.iter() {
+        for (dir, base) in directions {
             let mut cur = sq as i16;
             loop {
-                cur += *dir as i16;
-                if cur < 0 || cur >= 64 {
+                cur += dir as i16;
+                if !(0..64).contains(&cur) {
                     break;
                 }
                 let from = cur as u8;
-                if wraps(sq, from, *dir) {
+                if wraps(sq, from, dir) {
                     break;
                 }
                 if let Some(piece) = self.board[from as usize] {
-                    if piece.color != by {
-                        break;
-                    }
-                    match (base, piece.kind) {
-                        (PieceKind::Rook, PieceKind::Rook | PieceKind::Queen) => return true,
-                        (PieceKind::Bishop, PieceKind::Bishop | PieceKind::Queen) => return true,
-                        _ => {}
+                    let slides = match base {
+                        PieceKind::Rook => matches!(piece.kind, PieceKind::Rook | PieceKind::Queen),
+                        _ => matches!(piece.kind, PieceKind::Bishop | PieceKind::Queen),
+                    };
+                    if piece.color == by && slides && found(from) {
+                        return true;
                     }
                     break;
                 }
             }
         }
-        // King
-        for off in [1, -1, 8, -8, 9, 7, -7, -9].iter() {
-            let target = sq as i16 + *off as i16;
-            if target >= 0 && target < 64 {
-                let from = target as u8;
-                if king_wraps(from, sq) {
-                    continue;
-                }
-                if let Some(piece) = self.board[from as usize] {
-                    if piece.color == by && piece.kind == PieceKind::King {
-                        return true;
-                    }
-                }
+        false
+    }
+}
+
+/// Set of squares as a 64-bit mask, bit `sq` for square `sq`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SquareSet(pub u64);
+
+impl SquareSet {
+    pub const EMPTY: SquareSet = SquareSet(0);
+
+    pub fn contains(self, sq: u8) -> bool {
+        sq < 64 && self.0 & (1 << sq) != 0
+    }
+
+    pub fn insert(&mut self, sq: u8) {
+        if sq < 64 {
+            self.0 |= 1 << sq;
+        }
+    }
+
+    pub fn union(self, other: SquareSet) -> SquareSet {
+        SquareSet(self.0 | other.0)
+    }
+
+    pub fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Squares in ascending order (a1 first).
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        let mut bits = self.0;
+        core::iter::from_fn(move || {
+            (bits != 0).then(|| {
+                let sq = bits.trailing_zeros() as u8;
+                bits &= bits - 1;
+                sq
+            })
+        })
+    }
+}
+
+/// Where one piece can move, see [`GameState::legal_destinations`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Destinations {
+    pub quiet: SquareSet,
+    pub captures: SquareSet,
+}
+
+impl Destinations {
+    pub fn all(self) -> SquareSet {
+        self.quiet.union(self.captures)
+    }
+}
+
+/// A square whose contents differ between two positions, see [`GameState::diff`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SquareChange {
+    pub square: u8,
+    pub before: Option<Piece>,
+    pub after: Option<Piece>,
+}
+
+/// Iterator over the changed squares between two positions, built by [`GameState::diff`].
+/// The metadata accessors look at the whole positions and do not depend on how far the
+/// iteration has gone.
+#[derive(Clone, Debug)]
+pub struct SquareDiffIter<'a> {
+    before: &'a GameState,
+    after: &'a GameState,
+    next: u8,
+}
+
+impl SquareDiffIter<'_> {
+    pub fn side_to_move_changed(&self) -> bool {
+        self.before.side_to_move != self.after.side_to_move
+    }
+
+    pub fn castling_changed(&self) -> bool {
+        self.before.castling != self.after.castling
+    }
+
+    pub fn en_passant_changed(&self) -> bool {
+        self.before.en_passant != self.after.en_passant
+    }
+
+    /// The changed squares not yet yielded, as a set.
+    pub fn squares(self) -> SquareSet {
+        let mut set = SquareSet::EMPTY;
+        for change in self {
+            set.insert(change.square);
+        }
+        set
+    }
+}
+
+impl Iterator for SquareDiffIter<'_> {
+    type Item = SquareChange;
+
+    fn next(&mut self) -> Option<SquareChange> {
+        while self.next < 64 {
+            let sq = self.next;
+            self.next += 1;
+            let (before, after) = (
+                self.before.board[sq as usize],
+                self.after.board[sq as usize],
+            );
+            if before != after {
+                return Some(SquareChange {
+                    square: sq,
+                    before,
+                    after,
+                });
             }
         }
-        false
+        None
     }
 }
 
+/// Fixed-capacity move buffer; only the first `len` entries are valid.
 #[derive(Clone, Copy, Debug)]
 pub struct MoveList {
     pub moves: [Move; MoveList::MAX_MOVES],
@@ -712,9 +1074,10 @@ pub struct MoveList {
 }
 
 impl MoveList {
-    // Theoretical upper bound of chess branching factor used to bound the array.
+    /// Theoretical upper bound of chess branching factor used to bound the array.
     pub const MAX_MOVES: usize = 218; // Upper bound for chess branching factor.
 
+    /// Empty list.
     pub const fn new() -> MoveList {
         MoveList {
             moves: [Move {
@@ -747,48 +1110,141 @@ impl MoveList {
         self.len = write;
     }
 
+    /// Iterate over the valid moves.
     pub fn iter(&self) -> core::slice::Iter<'_, Move> {
         self.moves[..self.len].iter()
     }
 }
 
+impl Default for MoveList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Convenience wrapper that owns a position and only accepts legal moves. It also keeps
+/// the positions played so far, so it can tell when the game is drawn by repetition.
 pub struct Engine {
     state: GameState,
+    repetitions: repetition::Repetitions,
 }
 
 impl Engine {
-    // Construct engine with standard initial position.
+    /// Construct engine with standard initial position.
     pub const fn new_startpos() -> Engine {
         Engine {
             state: GameState::start_position(),
+            repetitions: repetition::Repetitions::new(),
         }
     }
 
-    // Read-only access to current state.
+    /// Read-only access to current state.
     pub fn state(&self) -> &GameState {
         &self.state
     }
 
-    // List all legal moves from current state.
+    /// List all legal moves from current state.
     pub fn legal_moves(&self) -> MoveList {
         self.state.generate_legal_moves()
     }
 
-    // Play a move if legal; otherwise return MoveError.
-    pub fn play_move(&mut self, mv: Move) -> Result<(), MoveError> {
+    /// Play a move if legal; otherwise report why it was rejected.
+    pub fn play_move(&mut self, mv: Move) -> Result<(), ChessError> {
         match self.state.make_move(mv) {
             Some(next) => {
+                self.repetitions.record(&self.state);
                 self.state = next;
                 Ok(())
             }
-            None => Err(MoveError::Illegal),
+            // Only diagnose on the failure path, keeping the legal path a single move generation.
+            None => self.state.check_move(mv).and(Err(ChessError::IllegalMove)),
         }
     }
+
+    /// Replace the position with one given in square-list syntax (see [`editor`]).
+    pub fn set_position(&mut self, text: &str) -> Result<(), ChessError> {
+        self.state = editor::parse_square_list(text)?;
+        self.repetitions.clear();
+        Ok(())
+    }
+
+    /// How the game has ended, if it has, including the fifty-move rule and repetition.
+    pub fn result(&self) -> Option<result::GameResult> {
+        self.state.result_with(&self.repetitions)
+    }
+
+    /// See [`GameState::is_draw`].
+    pub fn is_draw(&self) -> bool {
+        self.state.is_draw(&self.repetitions)
+    }
 }
 
-#[derive(Debug)]
-pub enum MoveError {
-    Illegal,
+/// Error type shared by the engine, setup parsing and the firmware console.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChessError {
+    /// The origin square of the move is empty.
+    NoPieceOnSquare(u8),
+    /// The piece on the origin square belongs to the side not to move.
+    WrongSideToMove,
+    /// The piece cannot make that move, or it would leave its own king in check.
+    IllegalMove,
+    /// A position description was malformed or describes an unreachable setup.
+    InvalidSetup(editor::SetupError),
+    /// Typed move text does not name exactly one legal move.
+    InvalidMoveText(notation::NotationError),
+}
+
+impl ChessError {
+    /// Short human-readable description, suitable for a serial reply.
+    pub fn message(self) -> &'static str {
+        match self {
+            ChessError::NoPieceOnSquare(_) => "no piece on that square",
+            ChessError::WrongSideToMove => "not that side's turn",
+            ChessError::IllegalMove => "illegal move",
+            ChessError::InvalidSetup(editor::SetupError::BadToken) => {
+                "bad token, expected e.g. Kc1 or pe7f7"
+            }
+            ChessError::InvalidSetup(editor::SetupError::KingCount) => {
+                "need exactly one king per side"
+            }
+            ChessError::InvalidSetup(editor::SetupError::PawnOnBackRank) => {
+                "pawn on first/last rank"
+            }
+            ChessError::InvalidSetup(editor::SetupError::OpponentInCheck) => {
+                "side not to move is in check"
+            }
+            ChessError::InvalidMoveText(notation::NotationError::Syntax) => {
+                "not a move, expected e.g. e2e4, Nf3 or O-O"
+            }
+            ChessError::InvalidMoveText(notation::NotationError::NoMatch) => {
+                "no legal move matches"
+            }
+            ChessError::InvalidMoveText(notation::NotationError::Ambiguous) => {
+                "ambiguous, add the from file or rank"
+            }
+            ChessError::InvalidMoveText(notation::NotationError::MissingPromotion) => {
+                "add the promotion piece, e.g. e7e8q"
+            }
+        }
+    }
+}
+
+impl From<editor::SetupError> for ChessError {
+    fn from(err: editor::SetupError) -> Self {
+        ChessError::InvalidSetup(err)
+    }
+}
+
+impl From<notation::NotationError> for ChessError {
+    fn from(err: notation::NotationError) -> Self {
+        ChessError::InvalidMoveText(err)
+    }
+}
+
+impl core::fmt::Display for ChessError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
 }
 
 fn rank_of(sq: u8) -> u8 {
@@ -805,12 +1261,14 @@ fn file_distance(a: u8, b: u8) -> u8 {
     if fa > fb { fa - fb } else { fb - fa }
 }
 
+// A ray from `from` has left the board sideways once the file stops moving in the
+// ray's horizontal direction (eastward rays must increase the file, westward decrease).
 fn wraps(from: u8, to: u8, dir: i8) -> bool {
     let f_from = file_of(from);
     let f_to = file_of(to);
     match dir {
-        1 | -1 | 9 | -7 => f_to <= f_from.wrapping_sub(1),
-        -9 | 7 => f_to >= f_from.wrapping_add(1),
+        1 | 9 | -7 => f_to <= f_from,
+        -1 | -9 | 7 => f_to >= f_from,
         _ => false,
     }
 }
@@ -857,3 +1315,17 @@ impl core::fmt::Display for GameState {
 
 pub mod ai;
 pub mod book;
+pub mod editor;
+pub mod fen;
+pub mod integrity;
+pub mod notation;
+pub mod phase;
+pub mod profile;
+pub mod rating;
+pub mod record;
+pub mod repetition;
+pub mod result;
+pub mod san;
+pub mod tablebase;
+pub mod tables;
+pub mod time_control;
@@ -0,0 +1,263 @@
+//! Moves typed by hand, as on the serial console. Accepts coordinate notation with or
+//! without separators and in either case (`e2e4`, `E2-E4`, `e7e8q`, `e7-e8=Q`, `Ng1-f3`),
+//! SAN with or without capture marks and check suffixes (`Nf3`, `exd5`, `ed5`, `e8=Q+`,
+//! `nf3`), and castling written with letters or zeros (`O-O`, `0-0-0`, `oo`).
+//!
+//! Parsing is split in two: [`MoveText::parse`] checks only the shape of the text, so a
+//! command line can tell moves from other input without a position, and
+//! [`MoveText::resolve`] finds the one legal move it names. A lowercase `b` in front is
+//! read as the b-file first (`bc3` = `bxc3`) and as a bishop only when no pawn move fits.
+
+use core::fmt;
+
+use crate::{ChessError, GameState, Move, PieceKind};
+
+/// Longest accepted move once separators are dropped, e.g. `Ng1f3` or `e7e8q`.
+const TEXT_LEN: usize = 8;
+
+/// Why typed text does not name a move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotationError {
+    /// Not a move in any accepted form.
+    Syntax,
+    /// Well-formed, but no legal move fits it.
+    NoMatch,
+    /// More than one legal move fits; the from-file or rank tells them apart.
+    Ambiguous,
+    /// A pawn reaching the last rank needs the promotion piece.
+    MissingPromotion,
+}
+
+/// A parsed but not yet resolved move; see the module documentation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoveText {
+    /// Castling towards the g-file (`true`) or the c-file; the other fields are unused.
+    castle: Option<bool>,
+    /// `None` for bare coordinates (`g1f3`), which name the piece by its square.
+    piece: Option<PieceKind>,
+    from_file: Option<u8>,
+    from_rank: Option<u8>,
+    to: u8,
+    promotion: Option<PieceKind>,
+    /// A leading lowercase `b` read as the b-file may have meant a bishop.
+    maybe_bishop: bool,
+}
+
+impl MoveText {
+    pub fn parse(text: &str) -> Result<MoveText, NotationError> {
+        let mut buf = [0u8; TEXT_LEN];
+        let mut len = 0;
+        for &byte in text.as_bytes() {
+            if matches!(
+                byte,
+                b'-' | b'x' | b'X' | b':' | b'=' | b'+' | b'#' | b'!' | b'?'
+            ) || byte.is_ascii_whitespace()
+            {
+                continue;
+            }
+            if len == TEXT_LEN {
+                return Err(NotationError::Syntax);
+            }
+            buf[len] = byte;
+            len += 1;
+        }
+        let text = &buf[..len];
+
+        let mut out = MoveText {
+            castle: None,
+            piece: None,
+            from_file: None,
+            from_rank: None,
+            to: 0,
+            promotion: None,
+            maybe_bishop: false,
+        };
+        if matches!(len, 2 | 3) && text.iter().all(|b| matches!(b, b'o' | b'O' | b'0')) {
+            out.castle = Some(len == 2);
+            return Ok(out);
+        }
+
+        // From the end: optional promotion piece, then the target square.
+        let mut end = len;
+        if end >= 3
+            && text[end - 2].is_ascii_digit()
+            && let Some(kind) = piece_kind(text[end - 1].to_ascii_uppercase())
+            && !matches!(kind, PieceKind::King | PieceKind::Pawn)
+        {
+            out.promotion = Some(kind);
+            end -= 1;
+        }
+        if end < 2 {
+            return Err(NotationError::Syntax);
+        }
+        out.to = square(text[end - 2], text[end - 1]).ok_or(NotationError::Syntax)?;
+
+        // Then from the front: optional piece letter, from-file and from-rank.
+        // `b`, and `B` in front of a rank (`B1-C3`), is a file rather than a bishop.
+        let mut prefix = &text[..end - 2];
+        let b_file = prefix.first() == Some(&b'b');
+        let b_square = matches!(prefix, [b'B', rank, ..] if rank.is_ascii_digit());
+        if let Some(&first) = prefix.first()
+            && !b_file
+            && !b_square
+            && let Some(kind) = piece_kind(first.to_ascii_uppercase())
+        {
+            out.piece = Some(kind);
+            prefix = &prefix[1..];
+        }
+        if let Some(&first) = prefix.first()
+            && let Some(file) = file(first)
+        {
+            out.from_file = Some(file);
+            prefix = &prefix[1..];
+        }
+        if let Some(&first) = prefix.first()
+            && let Some(rank) = rank(first)
+        {
+            out.from_rank = Some(rank);
+            prefix = &prefix[1..];
+        }
+        if !prefix.is_empty() {
+            return Err(NotationError::Syntax);
+        }
+
+        // Without a piece letter SAN means a pawn, unless a full from-square names it.
+        if out.piece.is_none() && (out.from_file.is_none() || out.from_rank.is_none()) {
+            out.piece = Some(PieceKind::Pawn);
+            out.maybe_bishop = b_file && out.from_rank.is_none();
+        }
+        Ok(out)
+    }
+
+    /// The legal move in `state` this text names. A full from-square that matches
+    /// nothing reports why, like a move entered on the board would.
+    pub fn resolve(&self, state: &GameState) -> Result<Move, ChessError> {
+        if let Some(king_side) = self.castle {
+            let file = if king_side { 6 } else { 2 };
+            return state
+                .generate_legal_moves()
+                .iter()
+                .find(|m| m.is_castling && m.to % 8 == file)
+                .copied()
+                .ok_or(ChessError::IllegalMove);
+        }
+        let found = match self.find(state, self.piece, self.from_file) {
+            Err(NotationError::NoMatch) if self.maybe_bishop => {
+                self.find(state, Some(PieceKind::Bishop), None)
+            }
+            found => found,
+        };
+        match (found, self.from_file, self.from_rank) {
+            (Err(NotationError::NoMatch), Some(file), Some(rank)) => {
+                let from = rank * 8 + file;
+                state.check_move(Move::quiet(from, self.to))?;
+                Err(NotationError::NoMatch.into())
+            }
+            (found, _, _) => found.map_err(ChessError::from),
+        }
+    }
+
+    fn find(
+        &self,
+        state: &GameState,
+        piece: Option<PieceKind>,
+        from_file: Option<u8>,
+    ) -> Result<Move, NotationError> {
+        let mut found: Option<Move> = None;
+        for mv in state.generate_legal_moves().iter() {
+            let fits = mv.to == self.to
+                && piece.is_none_or(|kind| {
+                    state.board[mv.from as usize].is_some_and(|p| p.kind == kind)
+                })
+                && from_file.is_none_or(|file| mv.from % 8 == file)
+                && self.from_rank.is_none_or(|rank| mv.from / 8 == rank)
+                && (self.promotion.is_none() || mv.promotion == self.promotion);
+            if !fits {
+                continue;
+            }
+            match found {
+                // Promotions to different pieces share their squares.
+                Some(other) if other.from != mv.from => return Err(NotationError::Ambiguous),
+                _ => found = Some(*mv),
+            }
+        }
+        let mv = found.ok_or(NotationError::NoMatch)?;
+        if mv.promotion.is_some() && self.promotion.is_none() {
+            return Err(NotationError::MissingPromotion);
+        }
+        Ok(mv)
+    }
+}
+
+/// A move in coordinate notation (`e2e4`, `e7e8q`), as UCI writes it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Uci {
+    buf: [u8; 5],
+    len: u8,
+}
+
+impl Uci {
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len as usize]).unwrap_or("")
+    }
+}
+
+impl fmt::Display for Uci {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for Uci {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Coordinate notation for `mv`; castling is the king's two-square step (`e1g1`).
+pub fn uci(mv: Move) -> Uci {
+    let mut out = Uci {
+        buf: [
+            b'a' + mv.from % 8,
+            b'1' + mv.from / 8,
+            b'a' + mv.to % 8,
+            b'1' + mv.to / 8,
+            0,
+        ],
+        len: 4,
+    };
+    if let Some(kind) = mv.promotion {
+        out.buf[4] = match kind {
+            PieceKind::Rook => b'r',
+            PieceKind::Bishop => b'b',
+            PieceKind::Knight => b'n',
+            _ => b'q',
+        };
+        out.len = 5;
+    }
+    out
+}
+
+fn piece_kind(letter: u8) -> Option<PieceKind> {
+    match letter {
+        b'K' => Some(PieceKind::King),
+        b'Q' => Some(PieceKind::Queen),
+        b'R' => Some(PieceKind::Rook),
+        b'B' => Some(PieceKind::Bishop),
+        b'N' => Some(PieceKind::Knight),
+        b'P' => Some(PieceKind::Pawn),
+        _ => None,
+    }
+}
+
+fn file(byte: u8) -> Option<u8> {
+    matches!(byte.to_ascii_lowercase(), b'a'..=b'h').then(|| byte.to_ascii_lowercase() - b'a')
+}
+
+fn rank(byte: u8) -> Option<u8> {
+    matches!(byte, b'1'..=b'8').then(|| byte - b'1')
+}
+
+fn square(file_byte: u8, rank_byte: u8) -> Option<u8> {
+    Some(rank(rank_byte)? * 8 + file(file_byte)?)
+}
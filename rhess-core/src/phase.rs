@@ -0,0 +1,74 @@
+//! Game phase: opening, middlegame or endgame. The opening is counted in moves; the
+//! endgame begins once enough non-pawn material has come off, whatever the move number.
+//!
+//! Material is counted in phase units (a minor 1, a rook 2, a queen 4, so 24 with every
+//! piece on the board). The evaluation tapers its middlegame and endgame terms over the
+//! same units, the opening book stops answering once the opening is over, and the
+//! firmware labels the side panel from [`Phase::of`], so all of them agree on where one
+//! phase ends and the next begins.
+
+use crate::{GameState, PieceKind};
+
+/// Phase units with every piece on the board.
+pub const FULL_MATERIAL: i32 = 24;
+/// Below this many units the game is an endgame: endgame terms start to fade in here and
+/// reach full weight with only kings and pawns left, while middlegame terms fade out.
+pub const ENDGAME_MATERIAL: i32 = 12;
+/// The opening lasts through this move number. Material does not cut it short: the trap
+/// lines in the opening book win a queen by move 6 and still play on from the book.
+pub const OPENING_MOVES: u16 = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Phase {
+    Opening,
+    Middlegame,
+    Endgame,
+}
+
+impl Phase {
+    pub fn of(state: &GameState) -> Self {
+        Self::classify(material(state), state.fullmove_number)
+    }
+
+    /// Phase for `material` units left at move `fullmove_number`.
+    pub fn classify(material: i32, fullmove_number: u16) -> Self {
+        if material < ENDGAME_MATERIAL {
+            Phase::Endgame
+        } else if fullmove_number <= OPENING_MOVES {
+            Phase::Opening
+        } else {
+            Phase::Middlegame
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Phase::Opening => "Opening",
+            Phase::Middlegame => "Middlegame",
+            Phase::Endgame => "Endgame",
+        }
+    }
+}
+
+/// Phase units of one piece; pawns and kings count nothing.
+pub const fn units(kind: PieceKind) -> i32 {
+    match kind {
+        PieceKind::Knight | PieceKind::Bishop => 1,
+        PieceKind::Rook => 2,
+        PieceKind::Queen => 4,
+        PieceKind::Pawn | PieceKind::King => 0,
+    }
+}
+
+/// Phase units on the board for both sides (more than [`FULL_MATERIAL`] after
+/// promotions).
+pub fn material(state: &GameState) -> i32 {
+    state.board.iter().flatten().map(|p| units(p.kind)).sum()
+}
+
+/// Blends a middlegame and an endgame value by `material`: all middlegame from
+/// [`ENDGAME_MATERIAL`] units up, all endgame with none left, linear in between.
+pub fn taper(middlegame: i32, endgame: i32, material: i32) -> i32 {
+    let material = material.clamp(0, ENDGAME_MATERIAL);
+    (middlegame * material + endgame * (ENDGAME_MATERIAL - material)) / ENDGAME_MATERIAL
+}
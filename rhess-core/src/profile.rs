@@ -0,0 +1,103 @@
+//! Evaluation personalities. An [`EvalProfile`] weighs the terms of the static
+//! evaluation, so one search can play in different styles: a side that prizes piece
+//! activity over its own king's cover, one that hoards material, and so on.
+//!
+//! Term weights are percentages of the standard weight (100 leaves the term unchanged, 0
+//! switches it off); `aggression` is an extra bonus in centipawns. The firmware's
+//! personality choice, difficulty levels and any calibration all pick or tune a profile
+//! here rather than adding their own knobs to the evaluation.
+
+/// Weights of the evaluation terms; see the module documentation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvalProfile {
+    /// Short name for menus and logs.
+    pub name: &'static str,
+    /// Piece values.
+    pub material: i32,
+    /// Piece-square tables.
+    pub pst: i32,
+    /// Pawn shield in front of a king on its back rank, and the check term.
+    pub king_safety: i32,
+    /// Attacked squares per piece. Above 100 the profile turns the term on by itself (see
+    /// [`counts_mobility`](Self::counts_mobility)); otherwise it only scales the term when
+    /// [`AiConfig::mobility`] computes it.
+    ///
+    /// [`AiConfig::mobility`]: crate::ai::AiConfig::mobility
+    pub mobility: i32,
+    /// Doubled and isolated pawns.
+    pub pawn_structure: i32,
+    /// Centipawns per knight, bishop, rook or queen standing in the opponent's half.
+    pub aggression: i32,
+}
+
+impl EvalProfile {
+    /// Every term at its standard weight, no aggression bonus.
+    pub const BALANCED: EvalProfile = EvalProfile {
+        name: "Balanced",
+        material: 100,
+        pst: 100,
+        king_safety: 100,
+        mobility: 100,
+        pawn_structure: 100,
+        aggression: 0,
+    };
+
+    /// Pushes pieces forward and trades king cover and pawn shape for activity.
+    pub const AGGRESSIVE: EvalProfile = EvalProfile {
+        name: "Aggressive",
+        material: 100,
+        pst: 90,
+        king_safety: 50,
+        mobility: 140,
+        pawn_structure: 60,
+        aggression: 8,
+    };
+
+    /// Keeps its king covered and its pawns healthy.
+    pub const SOLID: EvalProfile = EvalProfile {
+        name: "Solid",
+        material: 100,
+        pst: 100,
+        king_safety: 180,
+        mobility: 80,
+        pawn_structure: 150,
+        aggression: 0,
+    };
+
+    /// Grabs material and cares less where the pieces stand.
+    pub const MATERIALIST: EvalProfile = EvalProfile {
+        name: "Materialist",
+        material: 125,
+        pst: 60,
+        king_safety: 100,
+        mobility: 60,
+        pawn_structure: 70,
+        aggression: 0,
+    };
+
+    /// The named presets, [`BALANCED`](Self::BALANCED) first.
+    pub const PRESETS: [EvalProfile; 4] = [
+        EvalProfile::BALANCED,
+        EvalProfile::AGGRESSIVE,
+        EvalProfile::SOLID,
+        EvalProfile::MATERIALIST,
+    ];
+
+    /// Whether the profile prizes mobility enough to count it even with
+    /// [`AiConfig::mobility`](crate::ai::AiConfig::mobility) off, as the aggressive one does.
+    pub const fn counts_mobility(&self) -> bool {
+        self.mobility > 100
+    }
+
+    /// `term` at `percent` of its standard weight. Truncates towards zero, so the two
+    /// sides' terms stay exact negatives of each other.
+    pub(crate) fn weigh(term: i32, percent: i32) -> i32 {
+        term * percent / 100
+    }
+}
+
+impl Default for EvalProfile {
+    fn default() -> Self {
+        EvalProfile::BALANCED
+    }
+}
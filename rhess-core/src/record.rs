@@ -0,0 +1,222 @@
+//! Compact binary game records for persistent storage: a fixed-size position encoding
+//! and two-byte move codes, so a whole game fits in a small flash slot.
+//!
+//! A position takes [`POSITION_LEN`] bytes: 32 bytes of square nibbles (the even square
+//! in the low nibble), then side to move, castling bits, en-passant square (`0xFF` for
+//! none) and the halfmove/fullmove clocks as little-endian `u16`, plus one spare zero
+//! byte. A move is `from | to << 6 | promotion << 12`; decoding matches the code against
+//! the legal moves, so castling/en-passant flags come back set and corrupt data is
+//! rejected rather than played.
+//!
+//! A [`GameSetup`] takes [`SETUP_LEN`] bytes: the seed as little-endian `u32`, the search
+//! depth in bits 0-3 (the [`TimeControl::ALL`] index in bits 4-6, bit 7 set when the
+//! engine varies among close moves), the node budget as `u32`
+//! (0 for none), a flag byte (bit 0 adaptive budget, bit 1 mobility, bits 2/3 White/Black
+//! played by the engine, bits 4-5 and 6-7 the White/Black [`EvalProfile::PRESETS`] index)
+//! and the three firmware version numbers.
+
+use crate::ai::{AiConfig, Variety};
+use crate::profile::EvalProfile;
+use crate::time_control::TimeControl;
+use crate::{CastlingRights, Color, GameState, Move, Piece, PieceKind};
+
+/// Encoded size of a position.
+pub const POSITION_LEN: usize = 40;
+/// Encoded size of a [`GameSetup`].
+pub const SETUP_LEN: usize = 13;
+
+const NO_EN_PASSANT: u8 = 0xFF;
+/// Set in a square nibble for black pieces; the low three bits hold the kind.
+const BLACK_BIT: u8 = 0b1000;
+
+/// Pack `state` into [`POSITION_LEN`] bytes.
+pub fn encode_position(state: &GameState) -> [u8; POSITION_LEN] {
+    let mut out = [0u8; POSITION_LEN];
+    for (i, pair) in state.board.chunks_exact(2).enumerate() {
+        out[i] = piece_nibble(pair[0]) | piece_nibble(pair[1]) << 4;
+    }
+    out[32] = match state.side_to_move {
+        Color::White => 0,
+        Color::Black => 1,
+    };
+    out[33] = state.castling.bits;
+    out[34] = state.en_passant.unwrap_or(NO_EN_PASSANT);
+    out[35..37].copy_from_slice(&state.halfmove_clock.to_le_bytes());
+    out[37..39].copy_from_slice(&state.fullmove_number.to_le_bytes());
+    out
+}
+
+/// Unpack a position written by [`encode_position`]; `None` if any field is out of
+/// range or the result fails [`GameState::check_integrity`].
+pub fn decode_position(bytes: &[u8; POSITION_LEN]) -> Option<GameState> {
+    let mut state = GameState::start_position();
+    for (i, byte) in bytes[..32].iter().enumerate() {
+        state.board[2 * i] = nibble_piece(byte & 0x0F)?;
+        state.board[2 * i + 1] = nibble_piece(byte >> 4)?;
+    }
+    state.side_to_move = match bytes[32] {
+        0 => Color::White,
+        1 => Color::Black,
+        _ => return None,
+    };
+    if bytes[33] > 0x0F {
+        return None;
+    }
+    state.castling = CastlingRights { bits: bytes[33] };
+    state.en_passant = match bytes[34] {
+        NO_EN_PASSANT => None,
+        sq if sq < 64 => Some(sq),
+        _ => return None,
+    };
+    state.halfmove_clock = u16::from_le_bytes([bytes[35], bytes[36]]);
+    state.fullmove_number = u16::from_le_bytes([bytes[37], bytes[38]]);
+    state.check_integrity().ok()?;
+    Some(state)
+}
+
+/// Two-byte code for `mv`; the special-move flags are implied by the position.
+pub fn encode_move(mv: Move) -> u16 {
+    let promotion = match mv.promotion {
+        None => 0,
+        Some(PieceKind::Knight) => 1,
+        Some(PieceKind::Bishop) => 2,
+        Some(PieceKind::Rook) => 3,
+        Some(_) => 4,
+    };
+    mv.from as u16 | (mv.to as u16) << 6 | promotion << 12
+}
+
+/// The legal move in `state` matching `code`, or `None` if there is none.
+pub fn decode_move(state: &GameState, code: u16) -> Option<Move> {
+    let from = (code & 0x3F) as u8;
+    let to = (code >> 6 & 0x3F) as u8;
+    let promotion = match code >> 12 {
+        0 => None,
+        1 => Some(PieceKind::Knight),
+        2 => Some(PieceKind::Bishop),
+        3 => Some(PieceKind::Rook),
+        4 => Some(PieceKind::Queen),
+        _ => return None,
+    };
+    state
+        .generate_legal_moves()
+        .iter()
+        .find(|m| m.from == from && m.to == to && m.promotion == promotion)
+        .copied()
+}
+
+/// What a game was played with, saved beside its moves so it can be replayed or picked up
+/// again: the seed the engine's book and variety picks derive from, which sides the engine
+/// plays, its search limits, each side's evaluation profile, the time control and the
+/// firmware version.
+#[derive(Clone, Copy)]
+pub struct GameSetup {
+    pub seed: u32,
+    pub ai_sides: [bool; 2],
+    pub ai: AiConfig,
+    /// White's and Black's evaluation profiles.
+    pub profiles: [EvalProfile; 2],
+    pub time_control: TimeControl,
+    pub version: [u8; 3],
+}
+
+impl GameSetup {
+    /// Pack the setup into [`SETUP_LEN`] bytes. Only the search limits listed in the
+    /// module docs are kept; a profile that is not a preset is saved as Balanced.
+    pub fn encode(&self) -> [u8; SETUP_LEN] {
+        let mut out = [0; SETUP_LEN];
+        out[0..4].copy_from_slice(&self.seed.to_le_bytes());
+        out[4] = self.ai.max_depth & 0x0F
+            | (self.time_control.index() as u8) << 4
+            | (self.ai.variety.is_some() as u8) << 7;
+        out[5..9].copy_from_slice(&self.ai.node_limit.unwrap_or(0).to_le_bytes());
+        out[9] = self.ai.adaptive_budget as u8
+            | (self.ai.mobility as u8) << 1
+            | (self.ai_sides[0] as u8) << 2
+            | (self.ai_sides[1] as u8) << 3
+            | profile_code(self.profiles[0]) << 4
+            | profile_code(self.profiles[1]) << 6;
+        out[10..13].copy_from_slice(&self.version);
+        out
+    }
+
+    /// Unpack a setup written by [`encode`](Self::encode). The variety margin is not
+    /// stored, only whether variety was on, so it comes back as `variety_margin` with the
+    /// seed left at 0; the other search settings are the defaults.
+    pub fn decode(bytes: &[u8; SETUP_LEN], variety_margin: i32) -> Self {
+        let nodes = u32::from_le_bytes([bytes[5], bytes[6], bytes[7], bytes[8]]);
+        let flags = bytes[9];
+        GameSetup {
+            seed: u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            ai_sides: [flags & 4 != 0, flags & 8 != 0],
+            ai: AiConfig {
+                max_depth: bytes[4] & 0x0F,
+                node_limit: (nodes != 0).then_some(nodes),
+                adaptive_budget: flags & 1 != 0,
+                mobility: flags & 2 != 0,
+                variety: (bytes[4] & 0x80 != 0).then_some(Variety {
+                    margin: variety_margin,
+                    seed: 0,
+                }),
+                ..AiConfig::default()
+            },
+            profiles: [profile_from_code(flags >> 4), profile_from_code(flags >> 6)],
+            time_control: TimeControl::ALL
+                .get((bytes[4] >> 4 & 0x07) as usize)
+                .copied()
+                .unwrap_or(TimeControl::Untimed),
+            version: [bytes[10], bytes[11], bytes[12]],
+        }
+    }
+}
+
+fn profile_code(profile: EvalProfile) -> u8 {
+    EvalProfile::PRESETS
+        .iter()
+        .position(|&preset| preset == profile)
+        .unwrap_or(0) as u8
+}
+
+fn profile_from_code(code: u8) -> EvalProfile {
+    EvalProfile::PRESETS[(code & 3) as usize]
+}
+
+fn piece_nibble(square: Option<Piece>) -> u8 {
+    let Some(piece) = square else {
+        return 0;
+    };
+    let kind = match piece.kind {
+        PieceKind::Pawn => 1,
+        PieceKind::Knight => 2,
+        PieceKind::Bishop => 3,
+        PieceKind::Rook => 4,
+        PieceKind::Queen => 5,
+        PieceKind::King => 6,
+    };
+    match piece.color {
+        Color::White => kind,
+        Color::Black => kind | BLACK_BIT,
+    }
+}
+
+/// `Some(square contents)` for a valid nibble, `None` for an unused code.
+fn nibble_piece(nibble: u8) -> Option<Option<Piece>> {
+    if nibble == 0 {
+        return Some(None);
+    }
+    let kind = match nibble & !BLACK_BIT {
+        1 => PieceKind::Pawn,
+        2 => PieceKind::Knight,
+        3 => PieceKind::Bishop,
+        4 => PieceKind::Rook,
+        5 => PieceKind::Queen,
+        6 => PieceKind::King,
+        _ => return None,
+    };
+    let color = if nibble & BLACK_BIT == 0 {
+        Color::White
+    } else {
+        Color::Black
+    };
+    Some(Some(Piece { color, kind }))
+}
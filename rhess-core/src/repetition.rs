@@ -0,0 +1,69 @@
+//! Position history for threefold repetition: a ring buffer of position keys, one per
+//! position left behind in the game. Only positions since the last capture or pawn move
+//! can repeat, and the fifty-move rule ends the game 100 plies after that, so the buffer
+//! never needs more than [`CAPACITY`] entries.
+//!
+//! Keys are the search's Zobrist key with castling rights and the en-passant square mixed
+//! in, so positions only match when the same moves are available in them.
+
+use crate::GameState;
+use crate::ai::{zobrist, zobrist_key};
+
+/// Positions kept; a power of two above the 100 plies the fifty-move rule allows.
+pub const CAPACITY: usize = 128;
+
+// Pseudo piece indices for the castling and en-passant keys, past the twelve real pieces.
+const CASTLING_KEY: usize = 12;
+const EN_PASSANT_KEY: usize = 13;
+
+/// Keys of the positions played before the current one; see the module documentation.
+#[derive(Clone)]
+pub struct Repetitions {
+    keys: [u64; CAPACITY],
+    /// Positions recorded so far; the newest is at `(len - 1) % CAPACITY`.
+    len: usize,
+}
+
+impl Repetitions {
+    pub const fn new() -> Self {
+        Repetitions {
+            keys: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Record `state` as the position a move was just played from.
+    pub fn record(&mut self, state: &GameState) {
+        self.keys[self.len % CAPACITY] = key(state);
+        self.len += 1;
+    }
+
+    /// How often `state`, the current position, has occurred, counting itself. Only the
+    /// last `halfmove_clock` recorded positions are compared: anything older came before
+    /// an irreversible move.
+    pub fn count(&self, state: &GameState) -> usize {
+        let key = key(state);
+        let recent = (state.halfmove_clock as usize).min(self.len).min(CAPACITY);
+        1 + (1..=recent)
+            .filter(|back| self.keys[(self.len - back) % CAPACITY] == key)
+            .count()
+    }
+}
+
+impl Default for Repetitions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn key(state: &GameState) -> u64 {
+    let mut key = zobrist(state) ^ zobrist_key(CASTLING_KEY, state.castling.bits);
+    if let Some(square) = state.en_passant {
+        key ^= zobrist_key(EN_PASSANT_KEY, square);
+    }
+    key
+}
@@ -0,0 +1,212 @@
+//! How a game ended. [`GameResult`] carries both the outcome and the reason, so the end
+//! screen, the PGN `Result`/`Termination` tags, saved games, statistics and the serial
+//! broadcast all describe a finished game the same way.
+//!
+//! [`GameState::result`] recognises the endings visible in the position alone: checkmate,
+//! stalemate and insufficient material. [`GameState::result_with`] adds the fifty-move rule
+//! and threefold repetition from the positions played before (see [`repetition`]).
+//! Resignation, flag fall and agreement depend on the players and are decided by the
+//! caller, as is adjudication from the endgame tables ([`tablebase::adjudicate`]).
+//!
+//! [`repetition`]: crate::repetition
+//! [`tablebase::adjudicate`]: crate::tablebase::adjudicate
+
+use crate::rating::GameScore;
+use crate::repetition::Repetitions;
+use crate::{Color, GameState, PieceKind};
+
+/// Plies without a capture or pawn move after which the game is drawn.
+pub const FIFTY_MOVE_PLIES: u16 = 100;
+
+/// Final result of a game together with the reason it ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameResult {
+    Checkmate {
+        winner: Color,
+    },
+    Stalemate,
+    FiftyMoveRule,
+    ThreefoldRepetition,
+    /// Neither side has enough material left to mate.
+    InsufficientMaterial,
+    Resignation {
+        winner: Color,
+    },
+    FlagFall {
+        winner: Color,
+    },
+    Agreement,
+    /// Adjudicated from the endgame tables (see [`tablebase`](crate::tablebase)); `None`
+    /// for a drawn ending.
+    Tablebase {
+        winner: Option<Color>,
+    },
+}
+
+impl GameResult {
+    /// The side that won, or `None` for a draw.
+    pub fn winner(self) -> Option<Color> {
+        match self {
+            GameResult::Checkmate { winner }
+            | GameResult::Resignation { winner }
+            | GameResult::FlagFall { winner } => Some(winner),
+            GameResult::Tablebase { winner } => winner,
+            GameResult::Stalemate
+            | GameResult::FiftyMoveRule
+            | GameResult::ThreefoldRepetition
+            | GameResult::InsufficientMaterial
+            | GameResult::Agreement => None,
+        }
+    }
+
+    /// PGN result token: `1-0`, `0-1` or `1/2-1/2`.
+    pub fn pgn(self) -> &'static str {
+        match self.winner() {
+            Some(Color::White) => "1-0",
+            Some(Color::Black) => "0-1",
+            None => "1/2-1/2",
+        }
+    }
+
+    /// Readable reason, e.g. for a result banner.
+    pub fn reason(self) -> &'static str {
+        match self {
+            GameResult::Checkmate { .. } => "Checkmate",
+            GameResult::Stalemate => "Stalemate",
+            GameResult::FiftyMoveRule => "Fifty-move rule",
+            GameResult::ThreefoldRepetition => "Threefold repetition",
+            GameResult::InsufficientMaterial => "Insufficient material",
+            GameResult::Resignation { .. } => "Resignation",
+            GameResult::FlagFall { .. } => "Time forfeit",
+            GameResult::Agreement => "Draw agreed",
+            GameResult::Tablebase { winner: Some(_) } => "Tablebase win",
+            GameResult::Tablebase { winner: None } => "Tablebase draw",
+        }
+    }
+
+    /// Lower-case identifier of the reason for machine-readable output.
+    pub fn key(self) -> &'static str {
+        match self {
+            GameResult::Checkmate { .. } => "checkmate",
+            GameResult::Stalemate => "stalemate",
+            GameResult::FiftyMoveRule => "fifty_move",
+            GameResult::ThreefoldRepetition => "repetition",
+            GameResult::InsufficientMaterial => "insufficient_material",
+            GameResult::Resignation { .. } => "resignation",
+            GameResult::FlagFall { .. } => "flag_fall",
+            GameResult::Agreement => "agreement",
+            GameResult::Tablebase { .. } => "tablebase",
+        }
+    }
+
+    /// Value of the PGN `Termination` tag.
+    pub fn termination(self) -> &'static str {
+        match self {
+            GameResult::FlagFall { .. } => "time forfeit",
+            GameResult::Tablebase { .. } => "adjudication",
+            _ => "normal",
+        }
+    }
+
+    /// The result from `side`'s point of view, for rating updates and win/draw/loss counts.
+    pub fn score_for(self, side: Color) -> GameScore {
+        match self.winner() {
+            None => GameScore::Draw,
+            Some(winner) if winner == side => GameScore::Win,
+            Some(_) => GameScore::Loss,
+        }
+    }
+
+    /// One-byte storage code. 0, 1 and 2 keep their older meaning of White wins, Black wins
+    /// and draw, which were only ever reached by checkmate and stalemate.
+    pub fn code(self) -> u8 {
+        let side = |winner| match winner {
+            Color::White => 0,
+            Color::Black => 1,
+        };
+        match self {
+            GameResult::Checkmate { winner } => side(winner),
+            GameResult::Stalemate => 2,
+            GameResult::FiftyMoveRule => 3,
+            GameResult::ThreefoldRepetition => 4,
+            GameResult::InsufficientMaterial => 5,
+            GameResult::Resignation { winner } => 6 + side(winner),
+            GameResult::FlagFall { winner } => 8 + side(winner),
+            GameResult::Agreement => 10,
+            GameResult::Tablebase { winner: None } => 11,
+            GameResult::Tablebase {
+                winner: Some(winner),
+            } => 12 + side(winner),
+        }
+    }
+
+    /// Inverse of [`code`](Self::code); `None` for unknown codes.
+    pub fn from_code(code: u8) -> Option<GameResult> {
+        let winner = if code & 1 == 0 {
+            Color::White
+        } else {
+            Color::Black
+        };
+        Some(match code {
+            0 | 1 => GameResult::Checkmate { winner },
+            2 => GameResult::Stalemate,
+            3 => GameResult::FiftyMoveRule,
+            4 => GameResult::ThreefoldRepetition,
+            5 => GameResult::InsufficientMaterial,
+            6 | 7 => GameResult::Resignation { winner },
+            8 | 9 => GameResult::FlagFall { winner },
+            10 => GameResult::Agreement,
+            11 => GameResult::Tablebase { winner: None },
+            12 | 13 => GameResult::Tablebase {
+                winner: Some(winner),
+            },
+            _ => return None,
+        })
+    }
+}
+
+/// See [`GameState::result`].
+pub(crate) fn of_position(state: &GameState) -> Option<GameResult> {
+    if state.generate_legal_moves().len == 0 {
+        return Some(if state.is_in_check(state.side_to_move) {
+            GameResult::Checkmate {
+                winner: state.side_to_move.opposite(),
+            }
+        } else {
+            GameResult::Stalemate
+        });
+    }
+    insufficient_material(state).then_some(GameResult::InsufficientMaterial)
+}
+
+/// See [`GameState::result_with`].
+pub(crate) fn of_game(state: &GameState, repetitions: &Repetitions) -> Option<GameResult> {
+    if let Some(result) = of_position(state) {
+        return Some(result);
+    }
+    if state.halfmove_clock >= FIFTY_MOVE_PLIES {
+        return Some(GameResult::FiftyMoveRule);
+    }
+    (repetitions.count(state) >= 3).then_some(GameResult::ThreefoldRepetition)
+}
+
+/// Kings alone, plus at most one knight or any number of bishops all on squares of one
+/// colour: no sequence of legal moves can mate.
+fn insufficient_material(state: &GameState) -> bool {
+    let mut knights = 0;
+    let mut bishop_squares = [false; 2];
+    for (sq, piece) in state.board.iter().enumerate() {
+        let Some(piece) = piece else { continue };
+        match piece.kind {
+            PieceKind::King => {}
+            PieceKind::Knight => knights += 1,
+            PieceKind::Bishop => bishop_squares[((sq >> 3) + sq) & 1] = true,
+            PieceKind::Pawn | PieceKind::Rook | PieceKind::Queen => return false,
+        }
+    }
+    match knights {
+        0 => !(bishop_squares[0] && bishop_squares[1]),
+        1 => !bishop_squares[0] && !bishop_squares[1],
+        _ => false,
+    }
+}
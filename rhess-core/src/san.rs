@@ -0,0 +1,198 @@
+//! Standard algebraic notation (SAN) for moves, as used in PGN: `Nf3`, `exd6`, `e8=Q`,
+//! `O-O-O`, with file/rank disambiguation (`Nbd2`, `R1e2`) and `+`/`#` suffixes.
+//!
+//! En-passant captures are written like any pawn capture (`exd6`); the optional `e.p.`
+//! marker is left out, as PGN export requires.
+
+use core::fmt;
+
+use crate::{GameState, Move, MoveList, PieceKind};
+
+/// Longest SAN is 7 bytes, e.g. `exd8=Q#` or `Qh4xe1+`.
+const SAN_LEN: usize = 8;
+
+/// Check marker after a move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Suffix {
+    None,
+    Check,
+    Mate,
+}
+
+impl Suffix {
+    /// Marker for the position reached by a move (`after`, with the opponent to move).
+    pub fn of(after: &GameState) -> Self {
+        if !after.is_in_check(after.side_to_move) {
+            Suffix::None
+        } else if after.generate_legal_moves().len == 0 {
+            Suffix::Mate
+        } else {
+            Suffix::Check
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Suffix::None => "",
+            Suffix::Check => "+",
+            Suffix::Mate => "#",
+        }
+    }
+}
+
+/// What SAN adds after the piece letter to tell a move apart from other pieces of the
+/// same kind that can reach the same square.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Disambiguation {
+    None,
+    /// Origin file, as in `Nbd2`.
+    File,
+    /// Origin rank, as in `R1e2`.
+    Rank,
+    /// Both, as in `Qa8d5`.
+    Square,
+}
+
+impl Disambiguation {
+    /// Disambiguation for `mv` played from `before`. Pawn and king moves never need any.
+    pub fn of(before: &GameState, mv: Move) -> Self {
+        Self::among(before, &before.generate_legal_moves(), mv)
+    }
+
+    fn among(before: &GameState, legal: &MoveList, mv: Move) -> Self {
+        let Some(piece) = before.board[mv.from as usize] else {
+            return Disambiguation::None;
+        };
+        if piece_letter(piece.kind).is_none() {
+            return Disambiguation::None;
+        }
+        // Other pieces of the same kind that can also reach the target square.
+        let (mut rivals, mut same_file, mut same_rank) = (false, false, false);
+        for other in legal.iter() {
+            let rival = other.to == mv.to
+                && other.from != mv.from
+                && before.board[other.from as usize] == Some(piece);
+            if rival {
+                rivals = true;
+                same_file |= other.from % 8 == mv.from % 8;
+                same_rank |= other.from / 8 == mv.from / 8;
+            }
+        }
+        match (rivals, same_file, same_rank) {
+            (false, _, _) => Disambiguation::None,
+            (true, false, _) => Disambiguation::File,
+            (true, true, false) => Disambiguation::Rank,
+            (true, true, true) => Disambiguation::Square,
+        }
+    }
+
+    /// Bytes to write for a move from `from`.
+    pub fn bytes(self, from: u8) -> ([u8; 2], usize) {
+        match self {
+            Disambiguation::None => ([0; 2], 0),
+            Disambiguation::File => ([file_char(from), 0], 1),
+            Disambiguation::Rank => ([rank_char(from), 0], 1),
+            Disambiguation::Square => ([file_char(from), rank_char(from)], 2),
+        }
+    }
+}
+
+/// A move in SAN, stored inline so it can be built without allocation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct San {
+    buf: [u8; SAN_LEN],
+    len: u8,
+}
+
+impl San {
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len as usize]).unwrap_or("")
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        let start = self.len as usize;
+        self.buf[start..start + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len() as u8;
+    }
+}
+
+impl fmt::Display for San {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for San {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// SAN for `mv` played from `before`, or `None` if the move is not legal there.
+pub fn san(before: &GameState, mv: Move) -> Option<San> {
+    let legal = before.generate_legal_moves();
+    if !legal.iter().any(|m| *m == mv) {
+        return None;
+    }
+    let after = before.make_move(mv)?;
+    let piece = before.board[mv.from as usize]?;
+    let mut out = San {
+        buf: [0; SAN_LEN],
+        len: 0,
+    };
+
+    if mv.is_castling {
+        out.push(if mv.to % 8 == 6 { b"O-O" } else { b"O-O-O" });
+    } else {
+        let capture = mv.is_en_passant || before.board[mv.to as usize].is_some();
+        match piece_letter(piece.kind) {
+            Some(letter) => {
+                out.push(&[letter]);
+                let (bytes, len) = Disambiguation::among(before, &legal, mv).bytes(mv.from);
+                out.push(&bytes[..len]);
+            }
+            // Pawn captures always name the file they leave from.
+            None if capture => out.push(&[file_char(mv.from)]),
+            None => {}
+        }
+        if capture {
+            out.push(b"x");
+        }
+        out.push(&[file_char(mv.to), rank_char(mv.to)]);
+        if let Some(kind) = mv.promotion {
+            out.push(&[b'=', piece_letter(kind).unwrap_or(b'Q')]);
+        }
+    }
+    out.push(Suffix::of(&after).as_str().as_bytes());
+    Some(out)
+}
+
+/// Writes the SAN for `mv` played from `before` into `buf` and returns it; empty if the
+/// move is not legal there. Output that does not fit is cut short.
+pub fn format_move<'b>(before: &GameState, mv: Move, buf: &'b mut [u8]) -> &'b str {
+    let Some(san) = san(before, mv) else {
+        return "";
+    };
+    let len = san.as_str().len().min(buf.len());
+    buf[..len].copy_from_slice(&san.as_str().as_bytes()[..len]);
+    core::str::from_utf8(&buf[..len]).unwrap_or("")
+}
+
+fn piece_letter(kind: PieceKind) -> Option<u8> {
+    match kind {
+        PieceKind::King => Some(b'K'),
+        PieceKind::Queen => Some(b'Q'),
+        PieceKind::Rook => Some(b'R'),
+        PieceKind::Bishop => Some(b'B'),
+        PieceKind::Knight => Some(b'N'),
+        PieceKind::Pawn => None,
+    }
+}
+
+fn file_char(sq: u8) -> u8 {
+    b'a' + sq % 8
+}
+
+fn rank_char(sq: u8) -> u8 {
+    b'1' + sq / 8
+}
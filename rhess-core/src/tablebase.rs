@@ -0,0 +1,233 @@
+//! Endgame tables for the three-man endings KQK, KRK and KPK, solved by retrograde
+//! analysis in `cargo xtask assets` (`xtask/src/tablebase.rs`) and kept in flash: 88 KB
+//! for the three.
+//!
+//! Only positions with the extra piece's side to move are stored, one nibble each, for
+//! the piece on White's side (a position with a black piece is flipped top to bottom
+//! first). A position with the lone king to move is looked up through its replies.
+//!
+//! - KQK and KRK (20 KB each): the stronger king is reflected into the a1-d1-d4 triangle
+//!   and the entry is the moves to mate minus one. The stronger side always wins when it
+//!   is to move.
+//! - KPK (48 KB): the pawn is reflected onto files a-d. The entry is 0 for a draw,
+//!   otherwise the moves until the pawn promotes into a won KQK or KRK, beyond the
+//!   pawn's fewest pushes to promote, plus one.
+//!
+//! KPK thus counts moves to a winning promotion rather than to mate; the KQK or KRK
+//! table takes over from there, so following the shortest counts still mates.
+
+use crate::result::GameResult;
+use crate::{CastlingRights, Color, GameState, Move, PieceKind};
+
+static KQK: &[u8; 20480] = include_bytes!("generated/kqk.bin");
+static KRK: &[u8; 20480] = include_bytes!("generated/krk.bin");
+static KPK: &[u8; 49152] = include_bytes!("generated/kpk.bin");
+
+/// Most pieces, kings included, in a position the tables cover.
+pub const MAX_PIECES: usize = 3;
+
+/// Table slot of each square for the stronger king in KQK and KRK; `NONE` outside the
+/// a1-d1-d4 triangle.
+const TRIANGLE: [u8; 64] = {
+    let mut out = [NONE; 64];
+    let squares = [0, 1, 2, 3, 9, 10, 11, 18, 19, 27];
+    let mut i = 0;
+    while i < squares.len() {
+        out[squares[i]] = i as u8;
+        i += 1;
+    }
+    out
+};
+const NONE: u8 = u8::MAX;
+
+/// Outcome for the side to move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wdl {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// A table answer for the side to move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Probe {
+    pub wdl: Wdl,
+    /// Plies until mate, or in KPK until the pawn promotes into a won ending; 0 for draws.
+    pub plies: u8,
+}
+
+/// The pieces of a covered ending, flipped so the extra piece is White's.
+struct Ending {
+    kind: PieceKind,
+    strong_king: u8,
+    weak_king: u8,
+    piece: u8,
+}
+
+/// Look `state` up, or `None` when it is not a three-man ending the tables cover (or
+/// still has castling rights).
+pub fn probe(state: &GameState) -> Option<Probe> {
+    let (ending, strong) = ending(state)?;
+    if state.side_to_move == strong {
+        return Some(lookup(&ending));
+    }
+    // The lone king to move: lost only if every reply leaves a won position.
+    let moves = state.generate_legal_moves();
+    if moves.len == 0 {
+        let wdl = if state.is_in_check(state.side_to_move) {
+            Wdl::Loss
+        } else {
+            Wdl::Draw
+        };
+        return Some(Probe { wdl, plies: 0 });
+    }
+    let mut longest = 0;
+    for mv in moves.iter() {
+        match state.make_move(*mv).and_then(|next| probe(&next)) {
+            Some(Probe {
+                wdl: Wdl::Win,
+                plies,
+            }) => longest = longest.max(plies),
+            // Taking the piece leaves bare kings.
+            _ => return Some(DRAW),
+        }
+    }
+    Some(Probe {
+        wdl: Wdl::Loss,
+        plies: longest + 1,
+    })
+}
+
+const DRAW: Probe = Probe {
+    wdl: Wdl::Draw,
+    plies: 0,
+};
+
+/// The table move for a won or lost position: the fastest win, or the longest
+/// resistance. `None` for draws and positions the tables do not cover, which are left to
+/// the search.
+pub fn best_move(state: &GameState) -> Option<Move> {
+    let wdl = probe(state)?.wdl;
+    let mut best: Option<(Move, (bool, u8))> = None;
+    for mv in state.generate_legal_moves().iter() {
+        let Some(next) = state.make_move(*mv) else {
+            continue;
+        };
+        let reply = probe(&next).unwrap_or(DRAW);
+        let rank = match (wdl, reply.wdl) {
+            // A promotion ends KPK's count, so it ranks before any other winning move.
+            (Wdl::Win, Wdl::Loss) => (mv.promotion.is_none(), reply.plies),
+            (Wdl::Loss, Wdl::Win) => (false, u8::MAX - reply.plies),
+            _ => continue,
+        };
+        if best.is_none_or(|(_, best_rank)| rank < best_rank) {
+            best = Some((*mv, rank));
+        }
+    }
+    best.map(|(mv, _)| mv)
+}
+
+/// The result the tables give for a covered position: the winner when the side with the
+/// extra piece wins with best play, a draw otherwise.
+pub fn adjudicate(state: &GameState) -> Option<GameResult> {
+    let winner = match probe(state)?.wdl {
+        Wdl::Win => Some(state.side_to_move),
+        Wdl::Loss => Some(state.side_to_move.opposite()),
+        Wdl::Draw => None,
+    };
+    Some(GameResult::Tablebase { winner })
+}
+
+/// Sort out a covered ending and the side that owns the extra piece.
+fn ending(state: &GameState) -> Option<(Ending, Color)> {
+    if state.castling != CastlingRights::new() {
+        return None;
+    }
+    let mut kings = [None; 2];
+    let mut extra = None;
+    let mut count = 0;
+    for (sq, piece) in state.board.iter().enumerate() {
+        let Some(piece) = piece else {
+            continue;
+        };
+        count += 1;
+        if count > MAX_PIECES {
+            return None;
+        }
+        if piece.kind == PieceKind::King {
+            kings[piece.color as usize] = Some(sq as u8);
+        } else {
+            extra = Some((sq as u8, *piece));
+        }
+    }
+    let (square, piece) = extra?;
+    if !matches!(
+        piece.kind,
+        PieceKind::Queen | PieceKind::Rook | PieceKind::Pawn
+    ) {
+        return None;
+    }
+    let strong = piece.color;
+    let flip = |sq: u8| match strong {
+        Color::White => sq,
+        Color::Black => sq ^ 56,
+    };
+    let ending = Ending {
+        kind: piece.kind,
+        strong_king: flip(kings[strong as usize]?),
+        weak_king: flip(kings[strong.opposite() as usize]?),
+        piece: flip(square),
+    };
+    Some((ending, strong))
+}
+
+/// The stored answer for the side with the extra piece to move.
+fn lookup(ending: &Ending) -> Probe {
+    let (mut wk, mut bk, mut piece) = (ending.strong_king, ending.weak_king, ending.piece);
+    if ending.kind == PieceKind::Pawn {
+        if piece % 8 > 3 {
+            (wk, bk, piece) = (wk ^ 7, bk ^ 7, piece ^ 7);
+        }
+        let rank = piece / 8;
+        let slot = (rank as usize - 1) * 4 + (piece % 8) as usize;
+        let entry = nibble(KPK, (slot * 64 + wk as usize) * 64 + bk as usize);
+        if entry == 0 {
+            return DRAW;
+        }
+        let pushes = (7 - rank).min(5);
+        return won(entry + pushes - 1);
+    }
+
+    if wk % 8 > 3 {
+        (wk, bk, piece) = (wk ^ 7, bk ^ 7, piece ^ 7);
+    }
+    if wk / 8 > 3 {
+        (wk, bk, piece) = (wk ^ 56, bk ^ 56, piece ^ 56);
+    }
+    if wk / 8 > wk % 8 {
+        (wk, bk, piece) = (transpose(wk), transpose(bk), transpose(piece));
+    }
+    let table = match ending.kind {
+        PieceKind::Queen => KQK,
+        _ => KRK,
+    };
+    let slot = TRIANGLE[wk as usize] as usize;
+    won(nibble(table, (slot * 64 + bk as usize) * 64 + piece as usize) + 1)
+}
+
+/// A win in `moves` full moves for the side to move.
+fn won(moves: u8) -> Probe {
+    Probe {
+        wdl: Wdl::Win,
+        plies: 2 * moves - 1,
+    }
+}
+
+fn nibble(table: &[u8], idx: usize) -> u8 {
+    (table[idx / 2] >> (idx % 2 * 4)) & 0x0F
+}
+
+/// Reflect across the a1-h8 diagonal.
+fn transpose(sq: u8) -> u8 {
+    (sq % 8) * 8 + sq / 8
+}
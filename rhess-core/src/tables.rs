@@ -0,0 +1,98 @@
+//! Lookup tables generated at compile time by `const fn`s, so they live in flash and
+//! cost nothing at run time: leaper attack sets, file and rank masks, and mirroring of
+//! White's piece-square tables for Black. Masks use bit `sq` for square `sq`, the same
+//! layout as [`SquareSet`](crate::SquareSet); they are the starting point for a
+//! bitboard move generator.
+
+/// Squares a knight on each square attacks.
+pub const KNIGHT_ATTACKS: [u64; 64] = leaper_attacks(&[
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+]);
+
+/// Squares a king on each square attacks.
+pub const KING_ATTACKS: [u64; 64] = leaper_attacks(&[
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+]);
+
+/// Squares a pawn on each square attacks, indexed `[side][sq]` with White first.
+pub const PAWN_ATTACKS: [[u64; 64]; 2] = [
+    leaper_attacks(&[(-1, 1), (1, 1)]),
+    leaper_attacks(&[(-1, -1), (1, -1)]),
+];
+
+/// All squares of each file, a-file first.
+pub const FILE_MASKS: [u64; 8] = line_masks(0x0101_0101_0101_0101, 1);
+
+/// All squares of each rank, first rank first.
+pub const RANK_MASKS: [u64; 8] = line_masks(0xFF, 8);
+
+/// The square seen from the other side: same file, rank flipped (a1 <-> a8).
+pub const fn mirror_square(sq: u8) -> u8 {
+    sq ^ 56
+}
+
+/// A White-oriented square table as seen from Black.
+pub const fn mirrored(table: &[i16; 64]) -> [i16; 64] {
+    let mut out = [0; 64];
+    let mut sq = 0;
+    while sq < 64 {
+        out[sq] = table[mirror_square(sq as u8) as usize];
+        sq += 1;
+    }
+    out
+}
+
+/// White's tables followed by their mirrored copies for Black, indexed `[side][table]`.
+pub const fn with_mirrored<const N: usize>(white: [[i16; 64]; N]) -> [[[i16; 64]; N]; 2] {
+    let mut black = [[0; 64]; N];
+    let mut i = 0;
+    while i < N {
+        black[i] = mirrored(&white[i]);
+        i += 1;
+    }
+    [white, black]
+}
+
+/// For each square, the targets of the (file, rank) steps that stay on the board.
+const fn leaper_attacks(steps: &[(i8, i8)]) -> [u64; 64] {
+    let mut table = [0u64; 64];
+    let mut sq = 0;
+    while sq < 64 {
+        let mut i = 0;
+        while i < steps.len() {
+            let file = (sq % 8) as i8 + steps[i].0;
+            let rank = (sq / 8) as i8 + steps[i].1;
+            if file >= 0 && file < 8 && rank >= 0 && rank < 8 {
+                table[sq] |= 1 << (rank * 8 + file);
+            }
+            i += 1;
+        }
+        sq += 1;
+    }
+    table
+}
+
+/// Eight copies of `first`, each shifted `shift` bits further than the last.
+const fn line_masks(first: u64, shift: u32) -> [u64; 8] {
+    let mut masks = [0u64; 8];
+    let mut i = 0;
+    while i < 8 {
+        masks[i] = first << (i as u32 * shift);
+        i += 1;
+    }
+    masks
+}
@@ -0,0 +1,51 @@
+//! Time controls a game can be played under: a base time per side plus seconds added per
+//! move, or untimed. Saved with the game's [`GameSetup`](crate::record::GameSetup) and
+//! written to PGN as the `TimeControl` tag.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeControl {
+    /// No clock; the firmware counts the game's elapsed time up instead.
+    Untimed,
+    Blitz3Plus2,
+    Blitz5,
+    Rapid10,
+}
+
+impl TimeControl {
+    /// Every time control in [`next`](Self::next) order, [`Untimed`](Self::Untimed) first.
+    pub const ALL: [TimeControl; 4] = [
+        TimeControl::Untimed,
+        TimeControl::Blitz3Plus2,
+        TimeControl::Blitz5,
+        TimeControl::Rapid10,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TimeControl::Untimed => "Untimed",
+            TimeControl::Blitz3Plus2 => "3+2",
+            TimeControl::Blitz5 => "5+0",
+            TimeControl::Rapid10 => "10+0",
+        }
+    }
+
+    pub fn next(self) -> TimeControl {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    /// Base seconds per side and seconds added per move; `None` when untimed.
+    pub fn seconds(self) -> Option<(u32, u32)> {
+        let (minutes, increment) = match self {
+            TimeControl::Untimed => return None,
+            TimeControl::Blitz3Plus2 => (3, 2),
+            TimeControl::Blitz5 => (5, 0),
+            TimeControl::Rapid10 => (10, 0),
+        };
+        Some((minutes * 60, increment))
+    }
+
+    /// Position in [`ALL`](Self::ALL), which is also the code saved with a game.
+    pub(crate) fn index(self) -> usize {
+        Self::ALL.iter().position(|&t| t == self).unwrap_or(0)
+    }
+}
@@ -0,0 +1,23 @@
+//! Helpers shared by the integration tests. Each test crate uses only some of them.
+#![allow(dead_code)]
+
+use rhess_core::GameState;
+
+/// Square index of an algebraic name such as `"e4"`.
+pub fn sq(name: &str) -> u8 {
+    let b = name.as_bytes();
+    (b[1] - b'1') * 8 + (b[0] - b'a')
+}
+
+/// Plays the legal, non-promoting moves `from`-`to` in turn from `state`.
+pub fn play(mut state: GameState, moves: &[(&str, &str)]) -> GameState {
+    for (from, to) in moves {
+        let mv = *state
+            .generate_legal_moves()
+            .iter()
+            .find(|m| m.from == sq(from) && m.to == sq(to) && m.promotion.is_none())
+            .unwrap_or_else(|| panic!("{from}{to} is not legal"));
+        state = state.make_move(mv).unwrap();
+    }
+    state
+}
@@ -0,0 +1,177 @@
+//! Differential fuzzing of move generation against shakmaty.
+//!
+//! Plays seeded random games with both generators in lockstep: at every ply the legal move
+//! sets must match exactly (compared as UCI strings), the SAN of the move played must match,
+//! and every few plies perft counts and the attackers of every square are cross-checked. The game length, count and seed can be raised for a longer soak run:
+//!
+//! ```text
+//! RHESS_FUZZ_GAMES=2000 RHESS_FUZZ_SEED=7 \
+//!     cargo test -p rhess-core --target x86_64-unknown-linux-gnu --release --test differential
+//! ```
+
+use rhess_core::san::san;
+use rhess_core::{Color, GameState, Move, PieceKind};
+use shakmaty::san::SanPlus;
+use shakmaty::uci::UciMove;
+use shakmaty::{CastlingMode, Chess, Position, Square};
+
+const DEFAULT_GAMES: u64 = 24;
+const DEFAULT_SEED: u64 = 0x5EED_C0DE;
+const MAX_PLIES: usize = 160;
+/// Perft is cross-checked every this many plies, at this depth.
+const PERFT_EVERY: usize = 16;
+const PERFT_DEPTH: u8 = 2;
+
+/// xorshift64*: tiny, deterministic and good enough to pick moves.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+fn env_u64(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn square_name(sq: u8) -> String {
+    let file = (b'a' + sq % 8) as char;
+    let rank = (b'1' + sq / 8) as char;
+    format!("{file}{rank}")
+}
+
+/// Castling is encoded king-from/king-to, matching shakmaty's standard UCI output.
+fn uci(mv: &Move) -> String {
+    let promotion = match mv.promotion {
+        None => "",
+        Some(PieceKind::Queen) => "q",
+        Some(PieceKind::Rook) => "r",
+        Some(PieceKind::Bishop) => "b",
+        Some(PieceKind::Knight) => "n",
+        Some(kind) => panic!("invalid promotion piece {kind:?}"),
+    };
+    format!(
+        "{}{}{}",
+        square_name(mv.from),
+        square_name(mv.to),
+        promotion
+    )
+}
+
+fn rhess_moves(state: &GameState) -> Vec<String> {
+    let mut moves: Vec<String> = state.generate_legal_moves().iter().map(uci).collect();
+    moves.sort();
+    moves
+}
+
+fn reference_moves(pos: &Chess) -> Vec<String> {
+    let mut moves: Vec<String> = pos
+        .legal_moves()
+        .iter()
+        .map(|m| m.to_uci(CastlingMode::Standard).to_string())
+        .collect();
+    moves.sort();
+    moves
+}
+
+/// Attackers of every square for both colours, as bitmasks indexed like `GameState::board`.
+fn rhess_attackers(state: &GameState) -> Vec<(u64, u64)> {
+    (0..64)
+        .map(|sq| {
+            let by = |color| state.attackers(sq, color).0;
+            (by(Color::White), by(Color::Black))
+        })
+        .collect()
+}
+
+fn reference_attackers(pos: &Chess) -> Vec<(u64, u64)> {
+    let board = pos.board();
+    Square::ALL
+        .iter()
+        .map(|&sq| {
+            let by = |color| u64::from(board.attacks_to(sq, color, board.occupied()));
+            (by(shakmaty::Color::White), by(shakmaty::Color::Black))
+        })
+        .collect()
+}
+
+fn perft(state: &GameState, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    state
+        .generate_legal_moves()
+        .iter()
+        .map(|mv| perft(&state.make_move(*mv).unwrap(), depth - 1))
+        .sum()
+}
+
+/// Plays one random game; returns the number of plies played. Panics with the move
+/// sequence on the first disagreement so the failure can be replayed by hand.
+fn play_game(rng: &mut Rng) -> usize {
+    let mut state = GameState::start_position();
+    let mut pos = Chess::default();
+    let mut line: Vec<String> = Vec::new();
+
+    for ply in 0..MAX_PLIES {
+        let ours = rhess_moves(&state);
+        let theirs = reference_moves(&pos);
+        assert_eq!(ours, theirs, "legal moves differ after: {}", line.join(" "));
+
+        if ply % PERFT_EVERY == 0 {
+            let reference = shakmaty::perft(&pos, PERFT_DEPTH as u32);
+            assert_eq!(
+                perft(&state, PERFT_DEPTH),
+                reference,
+                "perft({PERFT_DEPTH}) differs after: {}",
+                line.join(" ")
+            );
+            assert_eq!(
+                rhess_attackers(&state),
+                reference_attackers(&pos),
+                "attackers differ after: {}",
+                line.join(" ")
+            );
+        }
+
+        let legal = state.generate_legal_moves();
+        if legal.len == 0 {
+            return ply;
+        }
+        let mv = legal.moves[rng.below(legal.len)];
+        let text = uci(&mv);
+        let reference = UciMove::from_ascii(text.as_bytes())
+            .unwrap()
+            .to_move(&pos)
+            .unwrap_or_else(|_| panic!("{text} rejected by reference after: {}", line.join(" ")));
+        assert_eq!(
+            san(&state, mv).map(|s| s.to_string()),
+            Some(SanPlus::from_move(pos.clone(), reference.clone()).to_string()),
+            "SAN of {text} differs after: {}",
+            line.join(" ")
+        );
+        state = state.make_move(mv).unwrap();
+        pos = pos.play(reference).unwrap();
+        line.push(text);
+    }
+    MAX_PLIES
+}
+
+#[test]
+fn random_playouts_match_reference_generator() {
+    let games = env_u64("RHESS_FUZZ_GAMES", DEFAULT_GAMES);
+    let mut rng = Rng(env_u64("RHESS_FUZZ_SEED", DEFAULT_SEED).max(1));
+    let plies: usize = (0..games).map(|_| play_game(&mut rng)).sum();
+    assert!(plies > 0);
+}
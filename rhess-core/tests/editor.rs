@@ -0,0 +1,48 @@
+//! Board editor and square-list parsing used by the serial `set` command.
+
+use rhess_core::editor::{SetupError, parse_square_list};
+use rhess_core::{Color, Piece, PieceKind};
+
+#[test]
+fn square_list_places_pieces_and_side() {
+    let state = parse_square_list("Kc1 Qd8 Pa2b2 ke8 pe7f7 b").unwrap();
+    let at = |sq: u8| state.board[sq as usize];
+    let white = |kind| {
+        Some(Piece {
+            color: Color::White,
+            kind,
+        })
+    };
+    let black = |kind| {
+        Some(Piece {
+            color: Color::Black,
+            kind,
+        })
+    };
+    assert_eq!(at(2), white(PieceKind::King));
+    assert_eq!(at(59), white(PieceKind::Queen));
+    assert_eq!(at(8), white(PieceKind::Pawn));
+    assert_eq!(at(9), white(PieceKind::Pawn));
+    assert_eq!(at(60), black(PieceKind::King));
+    assert_eq!(at(52), black(PieceKind::Pawn));
+    assert_eq!(at(53), black(PieceKind::Pawn));
+    assert_eq!(state.board.iter().flatten().count(), 7);
+    assert_eq!(state.side_to_move, Color::Black);
+    assert!(state.is_in_check(Color::Black));
+}
+
+#[test]
+fn square_list_rejects_bad_positions() {
+    assert_eq!(parse_square_list("Kc1 Xd4 ke8"), Err(SetupError::BadToken));
+    assert_eq!(parse_square_list("Kc1 Qd"), Err(SetupError::BadToken));
+    assert_eq!(parse_square_list("Kc1"), Err(SetupError::KingCount));
+    assert_eq!(
+        parse_square_list("Kc1 ke8 Pa8"),
+        Err(SetupError::PawnOnBackRank)
+    );
+    // Black to move would leave white's king capturable.
+    assert_eq!(
+        parse_square_list("Ke1 ke8 re2 b"),
+        Err(SetupError::OpponentInCheck)
+    );
+}
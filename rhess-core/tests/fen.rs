@@ -0,0 +1,33 @@
+//! FEN output: piece placement, side to move, castling rights, en passant and clocks.
+
+use rhess_core::fen::fen;
+use rhess_core::{GameState, Move};
+
+fn play(moves: &[(u8, u8)]) -> GameState {
+    let mut state = GameState::start_position();
+    for &(from, to) in moves {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    state
+}
+
+#[test]
+fn start_position_and_double_push() {
+    assert_eq!(
+        fen(&GameState::start_position()).as_str(),
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
+    );
+    assert_eq!(
+        fen(&play(&[(12, 28)])).as_str(),
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    );
+}
+
+#[test]
+fn castling_rights_and_clocks_follow_the_game() {
+    // 1. e4 e5 2. Ke2: white loses both rights, the king move starts the halfmove clock.
+    assert_eq!(
+        fen(&play(&[(12, 28), (52, 36), (4, 12)])).as_str(),
+        "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPPKPPP/RNBQ1BNR b kq - 1 2"
+    );
+}
@@ -0,0 +1,132 @@
+//! Typed move input: coordinate and SAN forms in any case, castling spellings, and the
+//! errors reported for text that names no move, several moves or an illegal one.
+
+mod common;
+
+use common::sq;
+use rhess_core::editor::parse_square_list;
+use rhess_core::notation::{MoveText, NotationError, uci};
+use rhess_core::{ChessError, GameState, Move, PieceKind};
+
+fn resolve(state: &GameState, text: &str) -> Result<Move, ChessError> {
+    MoveText::parse(text)?.resolve(state)
+}
+
+fn squares(state: &GameState, text: &str) -> (u8, u8) {
+    let mv = resolve(state, text).unwrap_or_else(|err| panic!("{text}: {err}"));
+    (mv.from, mv.to)
+}
+
+#[test]
+fn coordinate_and_san_spellings_name_the_same_move() {
+    let start = GameState::start_position();
+    for text in ["e2e4", "E2-E4", "e2 e4", "e4", "E4", "Pe4", "e2-e4!"] {
+        assert_eq!(squares(&start, text), (sq("e2"), sq("e4")), "{text}");
+    }
+    for text in ["Nf3", "nf3", "g1f3", "Ng1-f3", "G1F3", "Ngf3", "Nf3+"] {
+        assert_eq!(squares(&start, text), (sq("g1"), sq("f3")), "{text}");
+    }
+    // `b` and `B` in front of a rank are the b-file.
+    assert_eq!(squares(&start, "b1c3"), (sq("b1"), sq("c3")));
+    assert_eq!(squares(&start, "B1-C3"), (sq("b1"), sq("c3")));
+    assert_eq!(squares(&start, "b4"), (sq("b2"), sq("b4")));
+}
+
+#[test]
+fn lowercase_b_is_a_pawn_file_before_a_bishop() {
+    let white = parse_square_list("Ke1 Bf1 Pb2 ke8 pc3").unwrap();
+    // bxc3 for White; bc4 has no pawn move, so it is the bishop.
+    assert_eq!(squares(&white, "bc3"), (sq("b2"), sq("c3")));
+    assert_eq!(squares(&white, "bxc3"), (sq("b2"), sq("c3")));
+    assert_eq!(squares(&white, "bc4"), (sq("f1"), sq("c4")));
+    assert_eq!(squares(&white, "Bc4"), (sq("f1"), sq("c4")));
+    assert_eq!(
+        resolve(&white, "Bc3"),
+        Err(ChessError::InvalidMoveText(NotationError::NoMatch))
+    );
+}
+
+#[test]
+fn castling_with_letters_or_zeros() {
+    let mut state = parse_square_list("Ke1 Ra1 Rh1 ke8").unwrap();
+    state.castling = rhess_core::CastlingRights::full();
+    for text in ["O-O", "0-0", "o-o", "oo", "O-O+"] {
+        let mv = resolve(&state, text).unwrap();
+        assert!(mv.is_castling && mv.to == sq("g1"), "{text}");
+    }
+    for text in ["O-O-O", "0-0-0", "ooo"] {
+        let mv = resolve(&state, text).unwrap();
+        assert!(mv.is_castling && mv.to == sq("c1"), "{text}");
+    }
+    assert_eq!(
+        resolve(&GameState::start_position(), "O-O"),
+        Err(ChessError::IllegalMove)
+    );
+}
+
+#[test]
+fn promotions_need_the_piece() {
+    let state = parse_square_list("Ke1 Pe7 ka1").unwrap();
+    for (text, kind) in [
+        ("e8=Q", PieceKind::Queen),
+        ("e7e8q", PieceKind::Queen),
+        ("e7-e8=N", PieceKind::Knight),
+        ("e8r", PieceKind::Rook),
+        ("e8b", PieceKind::Bishop),
+    ] {
+        let mv = resolve(&state, text).unwrap();
+        assert_eq!((mv.to, mv.promotion), (sq("e8"), Some(kind)), "{text}");
+    }
+    for text in ["e8", "e7e8"] {
+        assert_eq!(
+            resolve(&state, text),
+            Err(ChessError::InvalidMoveText(NotationError::MissingPromotion)),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn errors_say_what_is_wrong() {
+    let start = GameState::start_position();
+    for text in ["", "hello", "e9", "i2i4", "Ke2e4e5", "e2e4k"] {
+        assert_eq!(
+            MoveText::parse(text),
+            Err(NotationError::Syntax),
+            "{text:?}"
+        );
+    }
+    // Full from-squares get the same diagnosis as moves entered on the board.
+    assert_eq!(
+        resolve(&start, "e3e4"),
+        Err(ChessError::NoPieceOnSquare(sq("e3")))
+    );
+    assert_eq!(resolve(&start, "e7e5"), Err(ChessError::WrongSideToMove));
+    assert_eq!(resolve(&start, "e2e5"), Err(ChessError::IllegalMove));
+    assert_eq!(
+        resolve(&start, "Qd4"),
+        Err(ChessError::InvalidMoveText(NotationError::NoMatch))
+    );
+
+    let rooks = parse_square_list("Ke2 Ra1 Rh1 ke8").unwrap();
+    assert_eq!(
+        resolve(&rooks, "Rd1"),
+        Err(ChessError::InvalidMoveText(NotationError::Ambiguous))
+    );
+    assert_eq!(squares(&rooks, "Rad1"), (sq("a1"), sq("d1")));
+    assert_eq!(squares(&rooks, "rhd1"), (sq("h1"), sq("d1")));
+}
+
+#[test]
+fn uci_writes_coordinates_and_promotion_letters() {
+    let start = GameState::start_position();
+    let mv = resolve(&start, "Nf3").unwrap();
+    assert_eq!(uci(mv).as_str(), "g1f3");
+    let state = parse_square_list("Ke1 Pe7 ka1").unwrap();
+    let mv = resolve(&state, "e8=N").unwrap();
+    assert_eq!(uci(mv).as_str(), "e7e8n");
+    // Every legal move reads back from its own coordinate form.
+    for mv in start.generate_legal_moves().iter() {
+        assert_eq!(resolve(&start, uci(*mv).as_str()), Ok(*mv));
+    }
+}
@@ -0,0 +1,63 @@
+//! Game phase: classification by move number and material, the taper the evaluation
+//! blends its terms with, and the opening book falling silent once the opening is over.
+
+use rhess_core::GameState;
+use rhess_core::book::{BookPolicy, book_move, book_move_seeded, book_move_with, is_book_move};
+use rhess_core::editor::parse_square_list;
+use rhess_core::phase::{self, ENDGAME_MATERIAL, FULL_MATERIAL, OPENING_MOVES, Phase};
+
+#[test]
+fn the_opening_ends_by_move_number() {
+    let mut state = GameState::start_position();
+    assert_eq!(phase::material(&state), FULL_MATERIAL);
+    assert_eq!(Phase::of(&state), Phase::Opening);
+    state.fullmove_number = OPENING_MOVES;
+    assert_eq!(Phase::of(&state), Phase::Opening);
+    state.fullmove_number = OPENING_MOVES + 1;
+    assert_eq!(Phase::of(&state), Phase::Middlegame);
+}
+
+#[test]
+fn the_endgame_starts_when_material_comes_off() {
+    // A queen and a rook each is the last middlegame material.
+    let state = parse_square_list("Kg1 Qd1 Ra1 kg8 qd8 ra8 w").unwrap();
+    assert_eq!(phase::material(&state), ENDGAME_MATERIAL);
+    assert_eq!(Phase::classify(ENDGAME_MATERIAL, 30), Phase::Middlegame);
+
+    // Fewer pieces make an endgame even at move 1.
+    let state = parse_square_list("Kg1 Ra1 Nb1 kg8 ra8 w").unwrap();
+    assert_eq!(Phase::of(&state), Phase::Endgame);
+    assert_eq!(Phase::classify(0, 1), Phase::Endgame);
+}
+
+#[test]
+fn taper_blends_between_the_phases() {
+    assert_eq!(phase::taper(40, -20, FULL_MATERIAL), 40);
+    assert_eq!(phase::taper(40, -20, ENDGAME_MATERIAL), 40);
+    assert_eq!(phase::taper(40, -20, ENDGAME_MATERIAL / 2), 10);
+    assert_eq!(phase::taper(40, -20, 0), -20);
+}
+
+#[test]
+fn the_book_answers_only_in_the_opening() {
+    // Every line of both books, under a spread of seeds, is played out within the opening.
+    for policy in [BookPolicy::MainLines, BookPolicy::TrapsFirst] {
+        for seed in 0..32 {
+            let mut state = GameState::start_position();
+            while let Some(mv) = book_move_seeded(&state, policy, seed) {
+                assert_eq!(Phase::of(&state), Phase::Opening);
+                assert!(is_book_move(&state, mv));
+                state = state.make_move(mv).unwrap();
+            }
+        }
+    }
+
+    // A book position counted past move `OPENING_MOVES` is out of book.
+    let mut state = GameState::start_position();
+    let first = book_move(&state).unwrap();
+    state.fullmove_number = OPENING_MOVES + 1;
+    assert_eq!(book_move(&state), None);
+    assert_eq!(book_move_with(&state, BookPolicy::TrapsFirst), None);
+    assert_eq!(book_move_seeded(&state, BookPolicy::MainLines, 0), None);
+    assert!(!is_book_move(&state, first));
+}
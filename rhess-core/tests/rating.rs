@@ -0,0 +1,53 @@
+//! Elo-style rating against the engine: expected scores, updates and the engine's
+//! nominal rating.
+
+use rhess_core::ai::AiConfig;
+use rhess_core::rating::{
+    GameScore, INITIAL_RATING, MAX_RATING, MIN_RATING, engine_rating, expected_score, updated,
+};
+
+#[test]
+fn expected_score_is_symmetric_and_saturates() {
+    assert_eq!(expected_score(1500, 1500), 500);
+    assert_eq!(expected_score(1600, 1400), 760);
+    assert_eq!(expected_score(1400, 1600), 240);
+    // Interpolated between the 100 and 150 point entries (640 and 703).
+    assert_eq!(expected_score(1625, 1500), 671);
+    for gap in [0u16, 7, 120, 399, 800, 1500] {
+        let up = expected_score(1000 + gap, 1000);
+        let down = expected_score(1000, 1000 + gap);
+        assert_eq!(up + down, 1000, "gap {gap}");
+    }
+    assert_eq!(expected_score(2900, 200), 990);
+}
+
+#[test]
+fn updates_reward_upsets_and_stay_in_range() {
+    assert_eq!(updated(1500, 1500, GameScore::Win), 1516);
+    assert_eq!(updated(1500, 1500, GameScore::Loss), 1484);
+    assert_eq!(updated(1500, 1500, GameScore::Draw), 1500);
+    // Beating a much stronger engine gains more than beating a weaker one.
+    let upset = updated(INITIAL_RATING, 1800, GameScore::Win) - INITIAL_RATING;
+    let expected_win = updated(INITIAL_RATING, 800, GameScore::Win) - INITIAL_RATING;
+    assert!(upset > expected_win);
+    // A draw against a stronger engine is still a gain.
+    assert!(updated(INITIAL_RATING, 1500, GameScore::Draw) > INITIAL_RATING);
+    assert_eq!(updated(MIN_RATING, 1500, GameScore::Loss), MIN_RATING);
+    assert_eq!(updated(MAX_RATING, 1500, GameScore::Win), MAX_RATING);
+}
+
+#[test]
+fn engine_rating_follows_search_limits() {
+    let default = AiConfig::default();
+    assert_eq!(engine_rating(&default), 1500);
+    let shallow = AiConfig {
+        max_depth: 2,
+        ..AiConfig::default()
+    };
+    let unlimited = AiConfig {
+        node_limit: None,
+        ..AiConfig::default()
+    };
+    assert!(engine_rating(&shallow) < engine_rating(&default));
+    assert!(engine_rating(&unlimited) > engine_rating(&default));
+}
@@ -0,0 +1,163 @@
+//! Binary game records: positions, moves and game setups survive an encode/decode round
+//! trip, and damaged bytes are rejected.
+
+mod common;
+
+use common::{play, sq};
+use rhess_core::ai::{AiConfig, Variety};
+use rhess_core::editor::parse_square_list;
+use rhess_core::profile::EvalProfile;
+use rhess_core::record::{GameSetup, decode_move, decode_position, encode_move, encode_position};
+use rhess_core::time_control::TimeControl;
+use rhess_core::{GameState, Move};
+
+#[test]
+fn positions_round_trip() {
+    // Black to move with an en-passant square, partial castling rights and clocks set.
+    let state = play(
+        GameState::start_position(),
+        &[
+            ("g1", "f3"),
+            ("g8", "f6"),
+            ("h1", "g1"),
+            ("f6", "g8"),
+            ("e2", "e4"),
+        ],
+    );
+    assert_eq!(state.en_passant, Some(sq("e3")));
+    let bytes = encode_position(&state);
+    assert_eq!(decode_position(&bytes), Some(state));
+
+    let state = parse_square_list("Ka1 Qc2 Pb2 kh8 nd8 pg7 b").unwrap();
+    assert_eq!(decode_position(&encode_position(&state)), Some(state));
+}
+
+#[test]
+fn every_legal_move_round_trips_with_its_flags() {
+    // Castling both ways, en passant and all four under-/promotions are available.
+    let positions = [
+        play(
+            GameState::start_position(),
+            &[
+                ("e2", "e4"),
+                ("a7", "a6"),
+                ("e4", "e5"),
+                ("a6", "a5"),
+                ("g1", "f3"),
+                ("a5", "a4"),
+                ("f1", "e2"),
+                ("d7", "d5"),
+            ],
+        ),
+        parse_square_list("Ke1 Ra1h1 Pb7 ke8 rd8 w").unwrap(),
+    ];
+    let special =
+        |state: &GameState, pick: fn(&Move) -> bool| state.generate_legal_moves().iter().any(pick);
+    assert!(special(&positions[0], |m| m.is_castling));
+    assert!(special(&positions[0], |m| m.is_en_passant));
+    assert!(special(&positions[1], |m| m.promotion.is_some()));
+    for state in positions {
+        for mv in state.generate_legal_moves().iter() {
+            assert_eq!(decode_move(&state, encode_move(*mv)), Some(*mv));
+        }
+    }
+}
+
+#[test]
+fn damaged_records_are_rejected() {
+    let start = GameState::start_position();
+    let good = encode_position(&start);
+
+    let mut bad_piece = good;
+    bad_piece[0] = 0x07;
+    assert_eq!(decode_position(&bad_piece), None);
+
+    let mut bad_side = good;
+    bad_side[32] = 2;
+    assert_eq!(decode_position(&bad_side), None);
+
+    // A third king passes the nibble check but not the integrity check.
+    let mut extra_king = good;
+    extra_king[12] = 0x06;
+    assert_eq!(decode_position(&extra_king), None);
+
+    // Moves that are not legal in the position do not decode.
+    assert_eq!(
+        decode_move(&start, encode_move(Move::quiet(sq("e2"), sq("e5")))),
+        None
+    );
+}
+
+#[test]
+fn game_setups_round_trip() {
+    // The settings a resumed game has to pick up again: a weak engine with variety playing
+    // Black in an aggressive style against a solid White engine.
+    let setup = GameSetup {
+        seed: 0xDEAD_BEEF,
+        ai_sides: [false, true],
+        ai: AiConfig {
+            max_depth: 3,
+            node_limit: Some(5_000),
+            adaptive_budget: false,
+            mobility: true,
+            variety: Some(Variety {
+                margin: 30,
+                seed: 0,
+            }),
+            ..AiConfig::default()
+        },
+        profiles: [EvalProfile::SOLID, EvalProfile::AGGRESSIVE],
+        time_control: TimeControl::Blitz3Plus2,
+        version: [1, 4, 2],
+    };
+    let back = GameSetup::decode(&setup.encode(), 30);
+    assert_eq!(back.seed, setup.seed);
+    assert_eq!(back.ai_sides, setup.ai_sides);
+    assert_eq!(back.ai.max_depth, 3);
+    assert_eq!(back.ai.node_limit, Some(5_000));
+    assert!(!back.ai.adaptive_budget && back.ai.mobility);
+    assert_eq!(back.ai.variety, setup.ai.variety);
+    assert_eq!(back.profiles, setup.profiles);
+    assert_eq!(back.time_control, TimeControl::Blitz3Plus2);
+    assert_eq!(back.version, setup.version);
+
+    // Every time control comes back, next to the deepest search the engine runs.
+    for time_control in TimeControl::ALL {
+        let timed = GameSetup {
+            time_control,
+            ai: AiConfig {
+                max_depth: 8,
+                ..setup.ai
+            },
+            ..setup
+        };
+        let back = GameSetup::decode(&timed.encode(), 30);
+        assert_eq!((back.time_control, back.ai.max_depth), (time_control, 8));
+        assert_eq!(back.ai.variety, setup.ai.variety);
+    }
+
+    // The defaults: no node limit and no variety come back as such.
+    let plain = GameSetup {
+        ai: AiConfig::default(),
+        profiles: [EvalProfile::BALANCED; 2],
+        time_control: TimeControl::Untimed,
+        ..setup
+    };
+    let back = GameSetup::decode(&plain.encode(), 30);
+    assert_eq!(back.ai.node_limit, AiConfig::default().node_limit);
+    assert_eq!(back.ai.variety, None);
+    assert_eq!(back.profiles, [EvalProfile::BALANCED; 2]);
+    assert_eq!(back.time_control, TimeControl::Untimed);
+}
+
+#[test]
+fn time_controls_cycle_and_count_seconds() {
+    assert_eq!(TimeControl::Untimed.seconds(), None);
+    assert_eq!(TimeControl::Blitz3Plus2.seconds(), Some((180, 2)));
+    assert_eq!(TimeControl::Rapid10.seconds(), Some((600, 0)));
+    let mut control = TimeControl::Untimed;
+    for expected in TimeControl::ALL.iter().cycle().skip(1).take(4) {
+        control = control.next();
+        assert_eq!(control, *expected);
+    }
+}
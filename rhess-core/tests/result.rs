@@ -0,0 +1,149 @@
+//! Game results: detection from the position and the game's history, PGN tokens and the
+//! one-byte storage code.
+
+use rhess_core::editor::parse_square_list;
+use rhess_core::notation::MoveText;
+use rhess_core::rating::GameScore;
+use rhess_core::repetition::Repetitions;
+use rhess_core::result::GameResult;
+use rhess_core::{Color, Engine, GameState};
+
+fn result(squares: &str) -> Option<GameResult> {
+    parse_square_list(squares).unwrap().result()
+}
+
+#[test]
+fn position_endings_are_detected() {
+    assert_eq!(GameState::start_position().result(), None);
+    assert_eq!(
+        result("Kg1 Ra8 kh8 pg7h7 b"),
+        Some(GameResult::Checkmate {
+            winner: Color::White
+        })
+    );
+    assert_eq!(result("Kf7 Qg6 kh8 b"), Some(GameResult::Stalemate));
+    assert_eq!(result("Ke1 ke8"), Some(GameResult::InsufficientMaterial));
+    assert_eq!(
+        result("Ke1 Nb1 ke8"),
+        Some(GameResult::InsufficientMaterial)
+    );
+    // Bishops on light squares only (c4, f1 and e6 are all light).
+    assert_eq!(
+        result("Ke1 Bc4f1 ke8 be6"),
+        Some(GameResult::InsufficientMaterial)
+    );
+    assert_eq!(result("Ke1 Bc4 ke8 bd6"), None);
+    assert_eq!(result("Ke1 Nb1c3 ke8"), None);
+    assert_eq!(result("Ke1 Bc4 ke8 nb8"), None);
+    assert_eq!(result("Ke1 Pa2 ke8"), None);
+}
+
+fn play(engine: &mut Engine, moves: &str) {
+    for text in moves.split_whitespace() {
+        let mv = MoveText::parse(text)
+            .unwrap()
+            .resolve(engine.state())
+            .unwrap();
+        engine.play_move(mv).unwrap();
+    }
+}
+
+#[test]
+fn threefold_repetition_draws() {
+    let mut engine = Engine::new_startpos();
+    play(&mut engine, "Nf3 Nf6 Ng1 Ng8");
+    assert_eq!(engine.result(), None);
+    play(&mut engine, "Nf3 Nf6 Ng1");
+    assert!(!engine.is_draw());
+    play(&mut engine, "Ng8");
+    assert_eq!(engine.result(), Some(GameResult::ThreefoldRepetition));
+    assert!(engine.is_draw());
+
+    engine.set_position("Ke1 Ra1 ke8").unwrap();
+    assert_eq!(engine.result(), None);
+}
+
+#[test]
+fn repetition_needs_the_same_castling_rights() {
+    let mut engine = Engine::new_startpos();
+    // The position after 2... Ke8 differs from the one after 1... e5 only in castling.
+    play(&mut engine, "e4 e5 Ke2 Ke7 Ke1 Ke8 Ke2 Ke7 Ke1 Ke8");
+    assert_eq!(engine.result(), None);
+    play(&mut engine, "Ke2 Ke7 Ke1 Ke8");
+    assert_eq!(engine.result(), Some(GameResult::ThreefoldRepetition));
+}
+
+#[test]
+fn fifty_move_rule_and_stalemate_are_draws() {
+    let none = Repetitions::new();
+    let mut rook = parse_square_list("Ke1 Ra1 ke8").unwrap();
+    rook.halfmove_clock = 99;
+    assert_eq!(rook.result_with(&none), None);
+    rook.halfmove_clock = 100;
+    assert_eq!(rook.result_with(&none), Some(GameResult::FiftyMoveRule));
+    assert!(rook.is_draw(&none));
+
+    assert!(parse_square_list("Kf7 Qg6 kh8 b").unwrap().is_draw(&none));
+    // Mate on the hundredth ply still wins.
+    let mut mate = parse_square_list("Kg1 Ra8 kh8 pg7h7 b").unwrap();
+    mate.halfmove_clock = 100;
+    assert_eq!(
+        mate.result_with(&none),
+        Some(GameResult::Checkmate {
+            winner: Color::White
+        })
+    );
+    assert!(!mate.is_draw(&none));
+}
+
+#[test]
+fn results_name_winner_and_reason() {
+    let mate = GameResult::Checkmate {
+        winner: Color::Black,
+    };
+    assert_eq!(mate.pgn(), "0-1");
+    assert_eq!(mate.reason(), "Checkmate");
+    assert_eq!(mate.score_for(Color::Black), GameScore::Win);
+    assert_eq!(mate.score_for(Color::White), GameScore::Loss);
+    assert_eq!(GameResult::ThreefoldRepetition.pgn(), "1/2-1/2");
+    assert_eq!(
+        GameResult::ThreefoldRepetition.score_for(Color::White),
+        GameScore::Draw
+    );
+    let flag = GameResult::FlagFall {
+        winner: Color::White,
+    };
+    assert_eq!(flag.pgn(), "1-0");
+    assert_eq!(flag.termination(), "time forfeit");
+    assert_eq!(GameResult::Agreement.termination(), "normal");
+}
+
+#[test]
+fn storage_codes_round_trip_and_keep_old_meanings() {
+    for code in 0..=u8::MAX {
+        if let Some(result) = GameResult::from_code(code) {
+            assert_eq!(result.code(), code);
+        }
+    }
+    assert_eq!(
+        GameResult::from_code(0),
+        Some(GameResult::Checkmate {
+            winner: Color::White
+        })
+    );
+    assert_eq!(
+        GameResult::from_code(1),
+        Some(GameResult::Checkmate {
+            winner: Color::Black
+        })
+    );
+    assert_eq!(GameResult::from_code(2), Some(GameResult::Stalemate));
+    assert_eq!(GameResult::from_code(10), Some(GameResult::Agreement));
+    assert_eq!(
+        GameResult::from_code(13),
+        Some(GameResult::Tablebase {
+            winner: Some(Color::Black)
+        })
+    );
+    assert_eq!(GameResult::from_code(14), None);
+}
@@ -0,0 +1,1280 @@
+//! Host-side rule checks: perft counts from well-known positions plus special moves.
+
+use rhess_core::ai::{
+    AiConfig, MoveOrigin, MoveReason, SearchStats, TranspositionTable, Variety, choose_best_move,
+    choose_best_move_reporting, choose_best_move_until, choose_best_move_with, explain_move,
+    node_budget, static_eval, static_eval_with,
+};
+use rhess_core::book::{BookPolicy, book_exit, book_move_seeded, book_move_with, is_book_move};
+use rhess_core::editor::SetupError;
+use rhess_core::integrity::IntegrityError;
+use rhess_core::profile::EvalProfile;
+use rhess_core::{
+    CastlingRights, ChessError, Color, Destinations, Engine, GameState, Move, Piece, PieceKind,
+    SquareChange, SquareSet,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+fn perft(state: &GameState, depth: u8) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+    let moves = state.generate_legal_moves();
+    if depth == 1 {
+        return moves.len as u64;
+    }
+    moves
+        .iter()
+        .map(|mv| perft(&state.make_move(*mv).unwrap(), depth - 1))
+        .sum()
+}
+
+fn empty_state(side_to_move: Color) -> GameState {
+    let mut state = GameState::start_position();
+    state.board = [None; 64];
+    state.side_to_move = side_to_move;
+    state.castling = rhess_core::CastlingRights::new();
+    state
+}
+
+fn put(state: &mut GameState, sq: u8, color: Color, kind: PieceKind) {
+    state.board[sq as usize] = Some(Piece { color, kind });
+}
+
+#[test]
+fn perft_start_position() {
+    let start = GameState::start_position();
+    assert_eq!(perft(&start, 1), 20);
+    assert_eq!(perft(&start, 2), 400);
+    assert_eq!(perft(&start, 3), 8_902);
+}
+
+#[test]
+fn rooks_on_the_edge_files_stay_on_their_lines() {
+    // Rooks on a4, d4 and h4 with the kings on f7 and b6, clear of the rooks' lines.
+    for sq in [24u8, 27, 31] {
+        let mut state = empty_state(Color::White);
+        put(&mut state, 53, Color::White, PieceKind::King);
+        put(&mut state, 41, Color::Black, PieceKind::King);
+        put(&mut state, sq, Color::White, PieceKind::Rook);
+        let mut targets: Vec<u8> = state
+            .generate_legal_moves()
+            .iter()
+            .filter(|mv| mv.from == sq)
+            .map(|mv| mv.to)
+            .collect();
+        targets.sort();
+        let mut expected: Vec<u8> = (0..64)
+            .filter(|&to| to != sq && (to / 8 == sq / 8 || to % 8 == sq % 8))
+            .collect();
+        expected.sort();
+        assert_eq!(targets, expected, "rook on square {sq}");
+    }
+}
+
+#[test]
+fn castling_and_en_passant_are_generated() {
+    // White: Ke1 Rh1 Pe5, Black: Ke8 Pd7; black just played ...d7-d5.
+    let mut state = empty_state(Color::Black);
+    put(&mut state, 4, Color::White, PieceKind::King);
+    put(&mut state, 7, Color::White, PieceKind::Rook);
+    put(&mut state, 36, Color::White, PieceKind::Pawn);
+    put(&mut state, 60, Color::Black, PieceKind::King);
+    put(&mut state, 51, Color::Black, PieceKind::Pawn);
+    state.castling = rhess_core::CastlingRights::full();
+
+    let state = state.make_move(Move::quiet(51, 35)).unwrap();
+    assert_eq!(state.en_passant, Some(43));
+
+    let moves = state.generate_legal_moves();
+    assert!(
+        moves
+            .iter()
+            .any(|m| m.is_en_passant && m.from == 36 && m.to == 43)
+    );
+    assert!(moves.iter().any(|m| m.is_castling && m.to == 6));
+
+    let ep = *moves.iter().find(|m| m.is_en_passant).unwrap();
+    let after = state.make_move(ep).unwrap();
+    assert_eq!(after.board[35], None);
+}
+
+/// Plays every line to `depth` with make/unmake, checking each step against `make_move`
+/// and that taking the move back restores the position exactly.
+fn check_unmake(state: &mut GameState, depth: u8) {
+    if depth == 0 {
+        return;
+    }
+    let before = *state;
+    for mv in before.generate_legal_moves().iter() {
+        let undo = state.apply_move_unchecked(*mv);
+        assert_eq!(Some(*state), before.make_move(*mv), "{mv:?}");
+        check_unmake(state, depth - 1);
+        state.unmake_move(*mv, undo);
+        assert_eq!(*state, before, "{mv:?}");
+    }
+}
+
+#[test]
+fn unmake_move_restores_every_position() {
+    check_unmake(&mut GameState::start_position(), 3);
+
+    // Castling both ways, en passant on d6, promotions with and without capture on a8,
+    // and rook captures that take castling rights away.
+    let mut state = empty_state(Color::White);
+    put(&mut state, 4, Color::White, PieceKind::King);
+    put(&mut state, 0, Color::White, PieceKind::Rook);
+    put(&mut state, 7, Color::White, PieceKind::Rook);
+    put(&mut state, 36, Color::White, PieceKind::Pawn);
+    put(&mut state, 49, Color::White, PieceKind::Pawn);
+    put(&mut state, 60, Color::Black, PieceKind::King);
+    put(&mut state, 56, Color::Black, PieceKind::Rook);
+    put(&mut state, 63, Color::Black, PieceKind::Rook);
+    put(&mut state, 35, Color::Black, PieceKind::Pawn);
+    put(&mut state, 14, Color::Black, PieceKind::Pawn);
+    state.castling = rhess_core::CastlingRights::full();
+    state.en_passant = Some(43);
+    state.halfmove_clock = 7;
+    check_unmake(&mut state, 3);
+
+    // A move from an empty square is not applied, so there is nothing to take back.
+    let mut start = GameState::start_position();
+    let undo = start.apply_move_unchecked(Move::quiet(20, 28));
+    assert_eq!(start, GameState::start_position());
+    start.unmake_move(Move::quiet(20, 28), undo);
+    assert_eq!(start, GameState::start_position());
+}
+
+#[test]
+fn promotion_offers_all_four_pieces() {
+    let mut state = empty_state(Color::White);
+    put(&mut state, 4, Color::White, PieceKind::King);
+    put(&mut state, 52, Color::White, PieceKind::Pawn);
+    put(&mut state, 63, Color::Black, PieceKind::King);
+
+    let promos = state
+        .generate_legal_moves()
+        .iter()
+        .filter(|m| m.from == 52 && m.promotion.is_some())
+        .count();
+    assert_eq!(promos, 4);
+}
+
+#[test]
+fn pinned_piece_cannot_move() {
+    // White Ke1 Be2 pinned by black Re8.
+    let mut state = empty_state(Color::White);
+    put(&mut state, 4, Color::White, PieceKind::King);
+    put(&mut state, 12, Color::White, PieceKind::Bishop);
+    put(&mut state, 60, Color::Black, PieceKind::Rook);
+    put(&mut state, 63, Color::Black, PieceKind::King);
+
+    assert!(state.generate_legal_moves().iter().all(|m| m.from != 12));
+}
+
+#[test]
+fn king_cannot_step_onto_pawn_attacked_squares() {
+    // Black Kd7 facing a white pawn on d5: c6 and e6 are covered, d6 is not.
+    let mut state = empty_state(Color::Black);
+    put(&mut state, 51, Color::Black, PieceKind::King);
+    put(&mut state, 35, Color::White, PieceKind::Pawn);
+    put(&mut state, 4, Color::White, PieceKind::King);
+
+    let targets: Vec<u8> = state.generate_legal_moves().iter().map(|m| m.to).collect();
+    assert!(!targets.contains(&42) && !targets.contains(&44));
+    assert!(targets.contains(&43));
+    assert!(!state.is_in_check(Color::Black));
+    put(&mut state, 42, Color::White, PieceKind::Pawn);
+    state.board[35] = None;
+    // A pawn on c6 gives check to d7.
+    assert!(state.is_in_check(Color::Black));
+}
+
+#[test]
+fn attackers_lists_direct_attackers_of_each_colour() {
+    let start = GameState::start_position();
+    // f3 is covered by the e2 and g2 pawns and the g1 knight.
+    let f3: Vec<u8> = start.attackers(21, Color::White).iter().collect();
+    assert_eq!(f3, vec![6, 12, 14]);
+    assert!(start.attackers(21, Color::Black).is_empty());
+    assert!(start.is_square_attacked(21, Color::White));
+
+    // Doubled rooks on d1/d2: only the front rook attacks d8, the back one is an x-ray.
+    let mut state = empty_state(Color::White);
+    put(&mut state, 6, Color::White, PieceKind::King);
+    put(&mut state, 3, Color::White, PieceKind::Rook);
+    put(&mut state, 11, Color::White, PieceKind::Rook);
+    put(&mut state, 52, Color::Black, PieceKind::King);
+    let d8 = state.attackers(59, Color::White);
+    assert_eq!(d8.iter().collect::<Vec<_>>(), vec![11]);
+    assert_eq!(d8.len(), 1);
+    assert!(d8.contains(11) && !d8.contains(3));
+    assert_eq!(
+        state.attackers(59, Color::Black).iter().collect::<Vec<_>>(),
+        vec![52]
+    );
+}
+
+#[test]
+fn movement_pattern_ignores_the_rest_of_the_board() {
+    let piece = |color, kind| Piece { color, kind };
+    let squares = |kind, color, from| {
+        let set = Piece::movement_pattern(piece(color, kind), from);
+        set.iter().collect::<Vec<u8>>()
+    };
+    // Knight in the corner: b3 and c2.
+    assert_eq!(squares(PieceKind::Knight, Color::White, 0), vec![10, 17]);
+    // Sliders on d4 cover whole lines regardless of colour.
+    let rook = Piece::movement_pattern(piece(Color::Black, PieceKind::Rook), 27);
+    assert_eq!(rook.len(), 14);
+    let queen = Piece::movement_pattern(piece(Color::White, PieceKind::Queen), 27);
+    assert_eq!(queen.len(), 27);
+    assert_eq!(
+        Piece::movement_pattern(piece(Color::White, PieceKind::Bishop), 0).len(),
+        7
+    );
+    // King on e1: five neighbours, no castling squares.
+    assert_eq!(
+        squares(PieceKind::King, Color::White, 4),
+        vec![3, 5, 11, 12, 13]
+    );
+    // Pawns: single and double step plus both capture diagonals, without wrapping.
+    assert_eq!(
+        squares(PieceKind::Pawn, Color::White, 12),
+        vec![19, 20, 21, 28]
+    );
+    assert_eq!(
+        squares(PieceKind::Pawn, Color::Black, 52),
+        vec![36, 43, 44, 45]
+    );
+    assert_eq!(squares(PieceKind::Pawn, Color::White, 8), vec![16, 17, 24]);
+    assert_eq!(squares(PieceKind::Pawn, Color::White, 20), vec![27, 28, 29]);
+
+    // Blockers do not matter: the c1 bishop cannot move in the start position but still
+    // sweeps both diagonals.
+    let start = GameState::start_position();
+    let bishop = start.board[2].unwrap();
+    assert_eq!(bishop.movement_pattern(2).len(), 7);
+}
+
+#[test]
+fn integrity_check_flags_corrupted_positions() {
+    let start = GameState::start_position();
+    assert_eq!(start.check_integrity(), Ok(()));
+    let after_e4 = start.make_move(Move::quiet(12, 28)).unwrap();
+    assert_eq!(after_e4.check_integrity(), Ok(()));
+
+    let mut missing_king = start;
+    missing_king.board[60] = None;
+    assert_eq!(
+        missing_king.check_integrity(),
+        Err(IntegrityError::KingCount(Color::Black))
+    );
+    // Generation on the broken position must not panic.
+    let _ = missing_king.generate_legal_moves();
+
+    let mut stale_ep = after_e4;
+    stale_ep.en_passant = Some(21);
+    assert_eq!(
+        stale_ep.check_integrity(),
+        Err(IntegrityError::BadEnPassant(21))
+    );
+
+    let mut rook_gone = start;
+    rook_gone.board[7] = None;
+    assert_eq!(
+        rook_gone.check_integrity(),
+        Err(IntegrityError::BadCastling(Color::White))
+    );
+}
+
+#[test]
+fn king_captures_are_never_generated() {
+    // Corrupted: black to move while white's king is already attacked by the rook.
+    let mut state = empty_state(Color::Black);
+    put(&mut state, 4, Color::White, PieceKind::King);
+    put(&mut state, 60, Color::Black, PieceKind::King);
+    put(&mut state, 12, Color::Black, PieceKind::Rook);
+    assert_eq!(
+        state.check_integrity(),
+        Err(IntegrityError::OpponentInCheck)
+    );
+    assert!(state.generate_legal_moves().iter().all(|m| m.to != 4));
+}
+
+#[test]
+fn engine_rejects_illegal_moves() {
+    let mut engine = Engine::new_startpos();
+    assert!(engine.play_move(Move::quiet(12, 36)).is_err());
+    assert!(engine.play_move(Move::quiet(12, 28)).is_ok());
+    assert_eq!(engine.state().side_to_move, Color::Black);
+}
+
+#[test]
+fn engine_reports_why_a_move_was_rejected() {
+    let mut engine = Engine::new_startpos();
+    assert_eq!(
+        engine.play_move(Move::quiet(20, 28)),
+        Err(ChessError::NoPieceOnSquare(20))
+    );
+    assert_eq!(
+        engine.play_move(Move::quiet(52, 44)),
+        Err(ChessError::WrongSideToMove)
+    );
+    assert_eq!(
+        engine.play_move(Move::quiet(12, 36)),
+        Err(ChessError::IllegalMove)
+    );
+    assert_eq!(
+        engine.set_position("Kc1"),
+        Err(ChessError::InvalidSetup(SetupError::KingCount))
+    );
+    assert!(engine.set_position("Kc1 ke8 b").is_ok());
+    assert_eq!(engine.state().side_to_move, Color::Black);
+}
+
+#[test]
+fn ai_plays_a_legal_move_only_on_its_turn() {
+    let mut state = GameState::start_position();
+    // Leave the opening book so the search path is exercised.
+    for (from, to) in [(12u8, 28u8), (54, 46), (3, 39), (53, 45)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    let cfg = AiConfig {
+        max_depth: 3,
+        node_limit: Some(5_000),
+        ..AiConfig::default()
+    };
+
+    assert_eq!(choose_best_move(&state, Color::Black, cfg, || {}), None);
+    let mv = choose_best_move(&state, Color::White, cfg, || {})
+        .unwrap()
+        .mv;
+    assert!(state.is_move_legal(mv));
+}
+
+#[test]
+fn capped_quiescence_still_wins_a_hanging_queen() {
+    let mut state = empty_state(Color::White);
+    put(&mut state, 6, Color::White, PieceKind::King);
+    put(&mut state, 3, Color::White, PieceKind::Rook);
+    put(&mut state, 35, Color::Black, PieceKind::Queen);
+    put(&mut state, 63, Color::Black, PieceKind::King);
+
+    for max_quiesce_ply in [0, 2] {
+        let cfg = AiConfig {
+            max_depth: 2,
+            node_limit: None,
+            max_quiesce_ply,
+            delta_margin: Some(200),
+            adaptive_budget: false,
+            book_seed: None,
+            mobility: false,
+            profile: EvalProfile::BALANCED,
+            variety: None,
+        };
+        let mv = choose_best_move(&state, Color::White, cfg, || {})
+            .unwrap()
+            .mv;
+        assert_eq!((mv.from, mv.to), (3, 35));
+    }
+}
+
+#[test]
+fn search_reports_where_the_move_came_from() {
+    let cfg = AiConfig {
+        max_depth: 3,
+        node_limit: None,
+        ..AiConfig::default()
+    };
+    let mut table = TranspositionTable::new();
+    let start = GameState::start_position();
+    let book = choose_best_move_with(&start, Color::White, cfg, &mut table, || {}).unwrap();
+    assert_eq!((book.origin, book.depth), (MoveOrigin::Book, 0));
+
+    let mut state = start;
+    for (from, to) in [(12, 28), (54, 46), (3, 39), (53, 45)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    let searched = choose_best_move_with(&state, Color::White, cfg, &mut table, || {}).unwrap();
+    assert_eq!((searched.origin, searched.depth), (MoveOrigin::Search, 3));
+
+    // The same root again is answered from the table with the same move.
+    let cached = choose_best_move_with(&state, Color::White, cfg, &mut table, || {}).unwrap();
+    assert_eq!(cached.origin, MoveOrigin::TableHit);
+    assert_eq!(cached.mv, searched.mv);
+}
+
+#[test]
+fn reporting_search_hands_out_each_completed_depth() {
+    let cfg = AiConfig {
+        max_depth: 3,
+        node_limit: None,
+        ..AiConfig::default()
+    };
+    let mut state = GameState::start_position();
+    for (from, to) in [(12, 28), (54, 46), (3, 39), (53, 45)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    let mut table = TranspositionTable::new();
+    let mut reports = Vec::new();
+    let result = choose_best_move_reporting(
+        &state,
+        Color::White,
+        cfg,
+        &mut table,
+        || {},
+        |r| reports.push(r),
+    )
+    .unwrap();
+    let depths: Vec<u8> = reports.iter().map(|r| r.depth).collect();
+    assert_eq!(depths, [1, 2, 3]);
+    assert!(reports.windows(2).all(|w| w[0].nodes <= w[1].nodes));
+    // The last completed depth is the answer, and a plain search agrees with it.
+    assert_eq!(reports.last(), Some(&result));
+    let plain = choose_best_move(&state, Color::White, cfg, || {}).unwrap();
+    assert_eq!(plain, result);
+}
+
+#[test]
+fn stop_flag_ends_the_search_with_the_best_move_so_far() {
+    let cfg = AiConfig {
+        max_depth: 6,
+        node_limit: None,
+        ..AiConfig::default()
+    };
+    let mut state = GameState::start_position();
+    for (from, to) in [(12, 28), (54, 46), (3, 39), (53, 45)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    let stop = AtomicBool::new(false);
+    let mut table = TranspositionTable::new();
+    let mut depths = Vec::new();
+    // Raise the flag as soon as depth 2 is done, as a watchdog interrupt would.
+    let result = choose_best_move_until(
+        &state,
+        Color::White,
+        cfg,
+        &mut table,
+        || {},
+        |r| {
+            depths.push(r.depth);
+            if r.depth == 2 {
+                stop.store(true, Ordering::Relaxed);
+            }
+        },
+        &stop,
+    )
+    .unwrap();
+    assert_eq!(depths, [1, 2]);
+    assert_eq!(result.depth, 2);
+    assert!(state.is_move_legal(result.mv));
+}
+
+#[test]
+fn node_limit_cut_off_leaves_no_entries_behind() {
+    let mut state = GameState::start_position();
+    for (from, to) in [(12, 28), (54, 46), (3, 39), (53, 45)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    let depth_one = AiConfig {
+        max_depth: 1,
+        node_limit: None,
+        ..AiConfig::default()
+    };
+    let mut table = TranspositionTable::new();
+    let first = choose_best_move_with(&state, Color::White, depth_one, &mut table, || {}).unwrap();
+    // Depth 1 stores only the root: its replies go straight to quiescence.
+    assert_eq!(table.filled(), 1);
+
+    // Stop in the first reply of depth 2, before that node has searched all its moves.
+    let cfg = AiConfig {
+        max_depth: 3,
+        node_limit: Some(first.nodes + 3),
+        adaptive_budget: false,
+        ..AiConfig::default()
+    };
+    let mut table = TranspositionTable::new();
+    let result = choose_best_move_with(&state, Color::White, cfg, &mut table, || {}).unwrap();
+    assert!(state.is_move_legal(result.mv));
+    // The cut-off depth 2 is not claimed, and only the completed depth 1 is left in the
+    // table.
+    assert_eq!(result.depth, 1);
+    assert_eq!(table.filled(), 1);
+}
+
+#[test]
+fn search_reports_eval_cache_hits() {
+    let cfg = AiConfig {
+        max_depth: 3,
+        node_limit: None,
+        ..AiConfig::default()
+    };
+    let mut state = GameState::start_position();
+    for (from, to) in [(12, 28), (54, 46), (3, 39), (53, 45)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    let result = choose_best_move(&state, Color::White, cfg, || {}).unwrap();
+    let stats = result.stats;
+    assert!(stats.evals > 0);
+    assert!(stats.eval_hits > 0 && stats.eval_hits < stats.evals);
+    assert_eq!(
+        stats.eval_hit_percent(),
+        stats.eval_hits * 100 / stats.evals
+    );
+
+    // Book moves are not searched, so nothing is evaluated.
+    let book = choose_best_move(&GameState::start_position(), Color::White, cfg, || {}).unwrap();
+    assert_eq!(book.origin, MoveOrigin::Book);
+    assert_eq!(book.stats, SearchStats::default());
+}
+
+#[test]
+fn variety_picks_among_near_equal_moves_but_never_skips_a_mate() {
+    let cfg = |variety| AiConfig {
+        max_depth: 2,
+        node_limit: None,
+        variety,
+        ..AiConfig::default()
+    };
+    // 1. a3 leaves the book, and Black has several replies of about equal worth.
+    let state = GameState::start_position()
+        .make_move(Move::quiet(8, 16))
+        .unwrap();
+    let best = choose_best_move(&state, Color::Black, cfg(None), || {}).unwrap();
+    let mut picks: Vec<Move> = Vec::new();
+    for seed in 0..16 {
+        let variety = Some(Variety { margin: 30, seed });
+        let mv = choose_best_move(&state, Color::Black, cfg(variety), || {})
+            .unwrap()
+            .mv;
+        // The same seed replays the same move.
+        let again = choose_best_move(&state, Color::Black, cfg(variety), || {}).unwrap();
+        assert_eq!(again.mv, mv);
+        if !picks.contains(&mv) {
+            picks.push(mv);
+        }
+    }
+    assert!(picks.len() > 1 && picks.contains(&best.mv), "{picks:?}");
+
+    // Qb8# (or Qh7#): no margin is wide enough to pass over the mate.
+    let mut mate = empty_state(Color::White);
+    put(&mut mate, 46, Color::White, PieceKind::King);
+    put(&mut mate, 1, Color::White, PieceKind::Queen);
+    put(&mut mate, 48, Color::Black, PieceKind::Pawn);
+    put(&mut mate, 63, Color::Black, PieceKind::King);
+    for seed in 0..8 {
+        let variety = Some(Variety {
+            margin: 100_000,
+            seed,
+        });
+        let mv = choose_best_move(&mate, Color::White, cfg(variety), || {})
+            .unwrap()
+            .mv;
+        let after = mate.make_move(mv).unwrap();
+        assert!(after.is_in_check(Color::Black) && after.generate_legal_moves().len == 0);
+    }
+}
+
+#[test]
+fn negative_variety_margin_counts_as_zero() {
+    let cfg = |variety| AiConfig {
+        max_depth: 2,
+        node_limit: None,
+        variety,
+        ..AiConfig::default()
+    };
+    let state = GameState::start_position()
+        .make_move(Move::quiet(8, 16))
+        .unwrap();
+    for seed in 0..4 {
+        let pick = |margin| {
+            let variety = Some(Variety { margin, seed });
+            choose_best_move(&state, Color::Black, cfg(variety), || {})
+                .unwrap()
+                .mv
+        };
+        assert_eq!(pick(-1), pick(0));
+    }
+}
+
+#[test]
+fn explain_move_tags_book_and_material_wins() {
+    let start = GameState::start_position();
+    assert_eq!(
+        explain_move(&start, Move::quiet(12, 28)),
+        MoveReason::BookMove
+    );
+
+    let mut state = empty_state(Color::White);
+    put(&mut state, 6, Color::White, PieceKind::King);
+    put(&mut state, 3, Color::White, PieceKind::Rook);
+    put(&mut state, 35, Color::Black, PieceKind::Queen);
+    put(&mut state, 63, Color::Black, PieceKind::King);
+    assert_eq!(
+        explain_move(&state, Move::quiet(3, 35)),
+        MoveReason::WinsMaterial
+    );
+    assert_eq!(
+        explain_move(&state, Move::quiet(6, 5)),
+        MoveReason::ImprovesPiece
+    );
+}
+
+#[test]
+fn node_budget_shrinks_for_forced_positions() {
+    let cfg = AiConfig::default();
+    let base = cfg.node_limit.unwrap();
+
+    // Lone king in the corner with two flight squares.
+    let mut forced = empty_state(Color::Black);
+    put(&mut forced, 63, Color::Black, PieceKind::King);
+    put(&mut forced, 0, Color::White, PieceKind::King);
+    put(&mut forced, 46, Color::White, PieceKind::Rook);
+    assert!(node_budget(&forced, &cfg).unwrap() < base);
+
+    let fixed = AiConfig {
+        adaptive_budget: false,
+        ..cfg
+    };
+    assert_eq!(node_budget(&forced, &fixed), Some(base));
+    assert_eq!(
+        node_budget(
+            &forced,
+            &AiConfig {
+                node_limit: None,
+                ..cfg
+            }
+        ),
+        None
+    );
+}
+
+#[test]
+fn low_depth_prefers_trap_lines() {
+    let mut state = GameState::start_position();
+    for (from, to) in [(12u8, 28u8), (52, 36), (6, 21)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    let main = book_move_with(&state, BookPolicy::for_depth(6)).unwrap();
+    let trap = book_move_with(&state, BookPolicy::for_depth(2)).unwrap();
+    assert_eq!((main.from, main.to), (57, 42)); // ... Nc6
+    assert_eq!((trap.from, trap.to), (62, 45)); // ... Nf6, Stafford Gambit
+}
+
+#[test]
+fn book_exit_finds_the_first_move_off_every_line() {
+    let moves = |pairs: &[(u8, u8)]| {
+        pairs
+            .iter()
+            .map(|&(from, to)| Move::quiet(from, to))
+            .collect::<Vec<_>>()
+    };
+    // 1. e4 e5 2. Nf3 Nc6 is shared by several lines; 3. a3 follows none of them.
+    let open_game = [(12, 28), (52, 36), (6, 21), (57, 42)];
+    assert_eq!(book_exit(moves(&open_game)), None);
+    let mut left = open_game.to_vec();
+    left.push((8, 16));
+    assert_eq!(book_exit(moves(&left)), Some(4));
+    // 1. a3 leaves at once; no moves at all are still in book.
+    assert_eq!(book_exit(moves(&[(8, 16)])), Some(0));
+    assert_eq!(book_exit(moves(&[])), None);
+    // A line's last move stays in book and the move after it leaves.
+    let mut state = GameState::start_position();
+    let mut played = Vec::new();
+    while let Some(mv) = book_move_with(&state, BookPolicy::MainLines) {
+        played.push(mv);
+        state = state.make_move(mv).unwrap();
+    }
+    assert_eq!(book_exit(played.iter().copied()), None);
+    let reply = state.generate_legal_moves().iter().copied().next().unwrap();
+    played.push(reply);
+    assert_eq!(book_exit(played.iter().copied()), Some(played.len() - 1));
+}
+
+#[test]
+fn book_lines_play_legally_to_their_last_move() {
+    let lines: [&[(u8, u8)]; 3] = [
+        // Italian: ... 4. Ng5 Bxf2+
+        &[
+            (12, 28),
+            (52, 36),
+            (6, 21),
+            (57, 42),
+            (5, 26),
+            (61, 34),
+            (21, 38),
+            (34, 13),
+        ],
+        // Sicilian: ... 3. d4 cxd4 4. Nxd4 a6 5. Nb3 Nc6
+        &[
+            (12, 28),
+            (50, 34),
+            (6, 21),
+            (51, 43),
+            (11, 27),
+            (34, 27),
+            (21, 27),
+            (48, 40),
+            (27, 17),
+            (57, 42),
+        ],
+        // Caro-Kann: 1. e4 c6 2. Nf3 d5 3. exd5 cxd5 4. c4 Nc6
+        &[
+            (12, 28),
+            (50, 42),
+            (6, 21),
+            (51, 35),
+            (28, 35),
+            (42, 35),
+            (10, 26),
+            (57, 42),
+        ],
+    ];
+    for line in lines {
+        let mut state = GameState::start_position();
+        for &(from, to) in line {
+            let legal = state.generate_legal_moves();
+            let mv = *legal
+                .iter()
+                .find(|mv| mv.from == from && mv.to == to)
+                .expect("book move is legal");
+            assert!(is_book_move(&state, mv));
+            state = state.make_move(mv).unwrap();
+        }
+    }
+}
+
+#[test]
+fn seeded_book_varies_the_opening() {
+    let start = GameState::start_position();
+    let mut firsts: Vec<(u8, u8)> = Vec::new();
+    for seed in 0..8 {
+        let mv = book_move_seeded(&start, BookPolicy::MainLines, seed).unwrap();
+        assert!(is_book_move(&start, mv));
+        if !firsts.contains(&(mv.from, mv.to)) {
+            firsts.push((mv.from, mv.to));
+        }
+    }
+    // 1. e4 and 1. d4 both appear; the unseeded lookup stays on the first line.
+    assert!(firsts.contains(&(12, 28)) && firsts.contains(&(11, 27)));
+    let fixed = book_move_with(&start, BookPolicy::MainLines).unwrap();
+    assert_eq!((fixed.from, fixed.to), (12, 28));
+}
+
+#[test]
+fn static_eval_is_symmetric_and_counts_material() {
+    let start = GameState::start_position();
+    assert_eq!(static_eval(&start, Color::White), 0);
+
+    // 1. e4 d5 2. exd5: white is a pawn up.
+    let mut state = start;
+    for (from, to) in [(12u8, 28u8), (51, 35), (28, 35)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    let white = static_eval(&state, Color::White);
+    assert_eq!(static_eval(&state, Color::Black), -white);
+    assert!(white > 50);
+}
+
+#[test]
+fn rook_activity_rewards_open_files_the_seventh_and_connected_rooks() {
+    let eval = |pieces: &[(u8, Color, PieceKind)]| {
+        let mut state = empty_state(Color::White);
+        for &(sq, color, kind) in pieces {
+            put(&mut state, sq, color, kind);
+        }
+        static_eval(&state, Color::White)
+    };
+    let kings = [
+        (6, Color::White, PieceKind::King),
+        (62, Color::Black, PieceKind::King),
+    ];
+    let with = |extra: &[(u8, Color, PieceKind)]| {
+        let mut pieces = kings.to_vec();
+        pieces.extend_from_slice(extra);
+        eval(&pieces)
+    };
+
+    // Own pawn on a2, enemy pawn on c7: a-file closed, c-file half-open, d-file open.
+    let pawns = [
+        (8, Color::White, PieceKind::Pawn),
+        (50, Color::Black, PieceKind::Pawn),
+    ];
+    let rook_on = |sq| with(&[pawns[0], pawns[1], (sq, Color::White, PieceKind::Rook)]);
+    let (closed, half_open, open) = (rook_on(0), rook_on(2), rook_on(3));
+    assert!(
+        half_open - closed >= 5,
+        "half-open {half_open} vs closed {closed}"
+    );
+    assert!(
+        open - half_open >= 5,
+        "open {open} vs half-open {half_open}"
+    );
+
+    // d7 against d6 with the black king on its back rank.
+    let seventh = with(&[(51, Color::White, PieceKind::Rook)]);
+    let sixth = with(&[(43, Color::White, PieceKind::Rook)]);
+    assert!(seventh - sixth >= 10, "seventh {seventh} vs sixth {sixth}");
+
+    // Rooks on a1 and e1, with a bishop on b2 (connected) or b1 (in between).
+    let rooks = [
+        (0, Color::White, PieceKind::Rook),
+        (4, Color::White, PieceKind::Rook),
+    ];
+    let bishop_on = |sq| with(&[rooks[0], rooks[1], (sq, Color::White, PieceKind::Bishop)]);
+    let (connected, blocked) = (bishop_on(9), bishop_on(1));
+    assert!(
+        connected - blocked >= 5,
+        "connected {connected} vs blocked {blocked}"
+    );
+}
+
+#[test]
+fn endgame_terms_race_passed_pawns_and_take_the_opposition() {
+    let eval = |side_to_move: Color, pieces: &[(u8, Color, PieceKind)]| {
+        let mut state = empty_state(side_to_move);
+        for &(sq, color, kind) in pieces {
+            put(&mut state, sq, color, kind);
+        }
+        static_eval(&state, Color::White)
+    };
+    let (white_king, black_king) = (
+        |sq| (sq, Color::White, PieceKind::King),
+        |sq| (sq, Color::Black, PieceKind::King),
+    );
+    let white_pawn = |sq| (sq, Color::White, PieceKind::Pawn);
+
+    // Pawn on a5 with the black king on h8 (outside the square) or c7 (inside).
+    let outside = eval(
+        Color::White,
+        &[white_king(7), black_king(63), white_pawn(32)],
+    );
+    let inside = eval(
+        Color::White,
+        &[white_king(7), black_king(50), white_pawn(32)],
+    );
+    assert!(
+        outside - inside > 300,
+        "outside {outside} vs inside {inside}"
+    );
+    // On e6 the king is one step short, unless Black is to move.
+    let short = eval(
+        Color::White,
+        &[white_king(7), black_king(44), white_pawn(32)],
+    );
+    let in_time = eval(
+        Color::Black,
+        &[white_king(7), black_king(44), white_pawn(32)],
+    );
+    assert!(short - in_time > 300, "short {short} vs in time {in_time}");
+    // A black rook can give itself up for the pawn, so no race bonus.
+    let rook = (47, Color::Black, PieceKind::Rook);
+    let with_rook = eval(
+        Color::White,
+        &[white_king(7), black_king(63), white_pawn(32), rook],
+    );
+    let without_race = eval(
+        Color::White,
+        &[white_king(7), black_king(50), white_pawn(32), rook],
+    );
+    assert!((with_rook - without_race).abs() < 100);
+
+    // The king escorting a passed d5 pawn beats one left in the corner.
+    let escort = eval(
+        Color::White,
+        &[white_king(27), black_king(55), white_pawn(35)],
+    );
+    let corner = eval(
+        Color::White,
+        &[white_king(0), black_king(55), white_pawn(35)],
+    );
+    assert!(escort > corner, "escort {escort} vs corner {corner}");
+
+    // Kings on e4 and e6 with blocked a-pawns: the side that just moved has the opposition.
+    let blocked = [
+        white_king(28),
+        black_king(44),
+        white_pawn(24),
+        (32, Color::Black, PieceKind::Pawn),
+    ];
+    assert!(eval(Color::Black, &blocked) > eval(Color::White, &blocked));
+
+    // With queens and rooks on, the king's distance to the pawn no longer counts.
+    let heavy = |king, pawns: &[(u8, Color, PieceKind)]| {
+        let mut pieces = vec![
+            (3, Color::White, PieceKind::Queen),
+            (0, Color::White, PieceKind::Rook),
+            (59, Color::Black, PieceKind::Queen),
+            (56, Color::Black, PieceKind::Rook),
+            white_king(king),
+            black_king(55),
+        ];
+        pieces.extend_from_slice(pawns);
+        eval(Color::White, &pieces)
+    };
+    let passer = [white_pawn(35)];
+    assert_eq!(
+        heavy(26, &passer) - heavy(1, &passer),
+        heavy(26, &[]) - heavy(1, &[])
+    );
+}
+
+#[test]
+fn search_converts_a_won_king_and_pawn_ending_at_depth_three() {
+    // White Kd6, Pe5 against Kd8: the king must clear the way before the pawn runs.
+    let mut state = empty_state(Color::White);
+    put(&mut state, 43, Color::White, PieceKind::King);
+    put(&mut state, 36, Color::White, PieceKind::Pawn);
+    put(&mut state, 59, Color::Black, PieceKind::King);
+    let cfg = AiConfig {
+        max_depth: 3,
+        node_limit: None,
+        ..AiConfig::default()
+    };
+    for _ in 0..30 {
+        let Some(result) = choose_best_move(&state, state.side_to_move, cfg, || {}) else {
+            break;
+        };
+        state = state.make_move(result.mv).unwrap();
+        if state
+            .board
+            .iter()
+            .flatten()
+            .any(|p| p.kind == PieceKind::Queen)
+        {
+            return;
+        }
+    }
+    panic!("no promotion within 30 plies");
+}
+
+#[test]
+fn eval_profiles_weigh_king_cover_pawn_shape_and_advanced_pieces() {
+    let position = |pieces: &[(u8, Color, PieceKind)]| {
+        let mut state = empty_state(Color::White);
+        // Kings on g1 and g8 with a rook and a knight each, so the shelter term counts.
+        for (sq, color, kind) in [
+            (6, Color::White, PieceKind::King),
+            (62, Color::Black, PieceKind::King),
+            (0, Color::White, PieceKind::Rook),
+            (56, Color::Black, PieceKind::Rook),
+            (1, Color::White, PieceKind::Knight),
+            (57, Color::Black, PieceKind::Knight),
+        ] {
+            put(&mut state, sq, color, kind);
+        }
+        for &(sq, color, kind) in pieces {
+            put(&mut state, sq, color, kind);
+        }
+        state
+    };
+    let eval = |profile: EvalProfile, pieces: &[(u8, Color, PieceKind)]| {
+        let cfg = AiConfig {
+            profile,
+            ..AiConfig::default()
+        };
+        static_eval_with(&position(pieces), Color::White, &cfg)
+    };
+    let white_pawn = |sq| (sq, Color::White, PieceKind::Pawn);
+    let black_pawns = [
+        (53, Color::Black, PieceKind::Pawn),
+        (54, Color::Black, PieceKind::Pawn),
+        (55, Color::Black, PieceKind::Pawn),
+    ];
+    let with_black = |profile, white: &[(u8, Color, PieceKind)]| {
+        let mut pieces = black_pawns.to_vec();
+        pieces.extend_from_slice(white);
+        eval(profile, &pieces)
+    };
+
+    // The default profile is the plain static eval.
+    assert_eq!(AiConfig::default().profile, EvalProfile::BALANCED);
+    let sample = [white_pawn(8), white_pawn(16), black_pawns[1]];
+    assert_eq!(
+        eval(EvalProfile::BALANCED, &sample),
+        static_eval(&position(&sample), Color::White)
+    );
+
+    // f2 g2 h2 in front of the king against the same pawns pushed to the fourth rank
+    // (the black pawns stay on f7 g7 h7 in both).
+    let sheltered = [white_pawn(13), white_pawn(14), white_pawn(15)];
+    let pushed = [white_pawn(29), white_pawn(30), white_pawn(31)];
+    let shelter = |profile| with_black(profile, &sheltered) - with_black(profile, &pushed);
+    assert!(shelter(EvalProfile::BALANCED) > 0);
+    assert!(shelter(EvalProfile::SOLID) > shelter(EvalProfile::BALANCED));
+    assert!(shelter(EvalProfile::AGGRESSIVE) < shelter(EvalProfile::BALANCED));
+
+    // Doubled, isolated a-pawns against connected a- and b-pawns.
+    let healthy = [white_pawn(8), white_pawn(9)];
+    let doubled = [white_pawn(8), white_pawn(16)];
+    let shape = |profile| with_black(profile, &healthy) - with_black(profile, &doubled);
+    assert!(shape(EvalProfile::BALANCED) >= 30);
+    assert!(shape(EvalProfile::SOLID) > shape(EvalProfile::BALANCED));
+
+    // A knight on c5 against one on c3, seven free squares each so mobility does not
+    // tip it: only the aggressive profile pays for the fifth rank.
+    let knight = |profile, sq| with_black(profile, &[(sq, Color::White, PieceKind::Knight)]);
+    let advance = |profile| knight(profile, 34) - knight(profile, 18);
+    assert!(advance(EvalProfile::AGGRESSIVE) >= advance(EvalProfile::BALANCED) + 5);
+
+    // Only the aggressive profile counts mobility without `AiConfig::mobility`, so turning
+    // the switch on changes nothing for it.
+    assert!(EvalProfile::AGGRESSIVE.counts_mobility());
+    assert!(!EvalProfile::BALANCED.counts_mobility() && !EvalProfile::SOLID.counts_mobility());
+    let rook = position(&[(27, Color::White, PieceKind::Rook)]);
+    let switched = |profile, mobility| {
+        let cfg = AiConfig {
+            profile,
+            mobility,
+            ..AiConfig::default()
+        };
+        static_eval_with(&rook, Color::White, &cfg)
+    };
+    let aggressive = EvalProfile::AGGRESSIVE;
+    assert_eq!(switched(aggressive, false), switched(aggressive, true));
+    assert_ne!(
+        switched(EvalProfile::BALANCED, false),
+        switched(EvalProfile::BALANCED, true)
+    );
+
+    // An extra queen is worth more to the materialist.
+    let queen = [(3, Color::White, PieceKind::Queen)];
+    let extra = |profile| with_black(profile, &queen) - with_black(profile, &[]);
+    assert!(extra(EvalProfile::MATERIALIST) > extra(EvalProfile::BALANCED) + 100);
+}
+
+#[test]
+fn mobility_rewards_free_pieces_and_ignores_squares_covered_by_pawns() {
+    let mobility = AiConfig {
+        mobility: true,
+        ..AiConfig::default()
+    };
+    let with = |pieces: &[(u8, Color, PieceKind)]| {
+        let mut state = empty_state(Color::White);
+        put(&mut state, 4, Color::White, PieceKind::King);
+        put(&mut state, 60, Color::Black, PieceKind::King);
+        for &(sq, color, kind) in pieces {
+            put(&mut state, sq, color, kind);
+        }
+        static_eval_with(&state, Color::White, &mobility) - static_eval(&state, Color::White)
+    };
+    // Off by default, so the plain eval is unchanged.
+    assert!(!AiConfig::default().mobility);
+
+    // A knight on e4 reaches eight squares, one in the corner only two.
+    let knight_on = |sq| with(&[(sq, Color::White, PieceKind::Knight)]);
+    assert!(knight_on(28) > knight_on(0));
+    // A rook boxed in by its own pawns scores less than one on an open board.
+    let own_pawns = [
+        (8, Color::White, PieceKind::Pawn),
+        (1, Color::White, PieceKind::Pawn),
+    ];
+    let boxed = with(&[
+        own_pawns[0],
+        own_pawns[1],
+        (0, Color::White, PieceKind::Rook),
+    ]);
+    assert!(with(&[(0, Color::White, PieceKind::Rook)]) > boxed);
+    // Squares an enemy pawn covers do not count.
+    let d4 = (27, Color::White, PieceKind::Knight);
+    let guarded = with(&[
+        d4,
+        (51, Color::Black, PieceKind::Pawn),
+        (53, Color::Black, PieceKind::Pawn),
+    ]);
+    let guarded_mobility = guarded
+        - with(&[
+            (51, Color::Black, PieceKind::Pawn),
+            (53, Color::Black, PieceKind::Pawn),
+        ]);
+    assert!(guarded_mobility < with(&[d4]));
+}
+
+#[test]
+fn mirrored_and_color_swapped_positions_keep_the_game_intact() {
+    let mut state = GameState::start_position();
+    for ply in 0..40 {
+        let swapped = state.color_swapped();
+        assert_eq!(swapped.color_swapped(), state);
+        assert_eq!(
+            swapped.generate_legal_moves().iter().count(),
+            state.generate_legal_moves().iter().count()
+        );
+        let mirrored = state.mirrored();
+        assert_eq!(mirrored.mirrored().mirrored(), mirrored);
+        assert_eq!(
+            mirrored.generate_legal_moves().iter().count(),
+            GameState {
+                castling: mirrored.castling,
+                ..state
+            }
+            .generate_legal_moves()
+            .iter()
+            .count()
+        );
+
+        // Evaluation must not depend on which side of the board a position is played on.
+        let mobility = AiConfig {
+            mobility: true,
+            ..AiConfig::default()
+        };
+        for color in [Color::White, Color::Black] {
+            assert_eq!(
+                static_eval(&swapped, color.opposite()),
+                static_eval(&state, color),
+                "colour swap changed the eval at ply {ply}"
+            );
+            assert_eq!(
+                static_eval(&mirrored, color),
+                static_eval(&state, color),
+                "left-right mirror changed the eval at ply {ply}"
+            );
+            assert_eq!(
+                static_eval_with(&swapped, color.opposite(), &mobility),
+                static_eval_with(&state, color, &mobility),
+                "colour swap changed the mobility eval at ply {ply}"
+            );
+            assert_eq!(
+                static_eval_with(&mirrored, color, &mobility),
+                static_eval_with(&state, color, &mobility),
+                "left-right mirror changed the mobility eval at ply {ply}"
+            );
+        }
+
+        let moves: Vec<Move> = state.generate_legal_moves().iter().copied().collect();
+        if moves.is_empty() {
+            break;
+        }
+        state = state.make_move(moves[(ply * 7 + 3) % moves.len()]).unwrap();
+    }
+}
+
+#[test]
+fn legal_destinations_split_quiet_moves_from_captures() {
+    let set = |squares: &[u8]| {
+        squares.iter().fold(SquareSet::EMPTY, |mut set, &sq| {
+            set.insert(sq);
+            set
+        })
+    };
+    let start = GameState::start_position();
+    // Knight b1: a3 and c3; the e2 pawn: e3 and e4.
+    assert_eq!(
+        start.legal_destinations(1),
+        Destinations {
+            quiet: set(&[16, 18]),
+            captures: SquareSet::EMPTY,
+        }
+    );
+    assert_eq!(start.legal_destinations(12).all(), set(&[20, 28]));
+    // Black's pieces and empty squares go nowhere.
+    assert_eq!(start.legal_destinations(52), Destinations::default());
+    assert_eq!(start.legal_destinations(28), Destinations::default());
+
+    // 1. e4 a6 2. e5 d5: the e5 pawn can push to e6 or take d6 en passant.
+    let mut state = start;
+    for (from, to) in [(12u8, 28u8), (48, 40), (28, 36), (51, 35)] {
+        state = state.make_move(Move::quiet(from, to)).unwrap();
+    }
+    assert_eq!(
+        state.legal_destinations(36),
+        Destinations {
+            quiet: set(&[44]),
+            captures: set(&[43]),
+        }
+    );
+}
+
+#[test]
+fn checked_king_reports_the_side_to_move_only() {
+    let start = GameState::start_position();
+    assert_eq!(start.king_square(Color::White), Some(4));
+    assert_eq!(start.king_square(Color::Black), Some(60));
+    assert_eq!(start.checked_king(), None);
+
+    // White king e1, black queen e8: White is in check; with Black to move it is not reported.
+    let mut state = empty_state(Color::White);
+    put(&mut state, 4, Color::White, PieceKind::King);
+    put(&mut state, 63, Color::Black, PieceKind::King);
+    put(&mut state, 60, Color::Black, PieceKind::Queen);
+    assert_eq!(state.checked_king(), Some(4));
+    state.side_to_move = Color::Black;
+    assert_eq!(state.checked_king(), None);
+
+    // No king on the board: nothing to highlight.
+    let mut bare = empty_state(Color::White);
+    put(&mut bare, 60, Color::Black, PieceKind::Queen);
+    assert_eq!(bare.king_square(Color::White), None);
+    assert_eq!(bare.checked_king(), None);
+}
+
+#[test]
+fn diff_lists_changed_squares_and_metadata() {
+    let start = GameState::start_position();
+    let diff = start.diff(&start);
+    assert!(!diff.side_to_move_changed() && !diff.castling_changed() && !diff.en_passant_changed());
+    assert_eq!(diff.count(), 0);
+
+    let pawn = Some(Piece {
+        color: Color::White,
+        kind: PieceKind::Pawn,
+    });
+    let after = start.make_move(Move::quiet(12, 28)).unwrap();
+    let diff = start.diff(&after);
+    assert!(diff.side_to_move_changed());
+    assert!(!diff.castling_changed());
+    assert_eq!(diff.en_passant_changed(), after.en_passant.is_some());
+    assert_eq!(
+        diff.clone().collect::<Vec<_>>(),
+        [
+            SquareChange {
+                square: 12,
+                before: pawn,
+                after: None,
+            },
+            SquareChange {
+                square: 28,
+                before: None,
+                after: pawn,
+            },
+        ]
+    );
+    assert_eq!(diff.squares().iter().collect::<Vec<_>>(), [12, 28]);
+
+    let no_castling = GameState {
+        castling: CastlingRights::new(),
+        ..start
+    };
+    let diff = start.diff(&no_castling);
+    assert!(diff.castling_changed() && !diff.side_to_move_changed());
+    assert!(diff.squares().is_empty());
+}
+
+#[test]
+fn piece_square_tables_are_left_right_symmetric() {
+    for kind in [
+        PieceKind::Pawn,
+        PieceKind::Knight,
+        PieceKind::Bishop,
+        PieceKind::Rook,
+        PieceKind::Queen,
+    ] {
+        for sq in 0..64u8 {
+            // Skip the king squares and their mirror images, and pawns on the back ranks.
+            let back_rank = !(8..56).contains(&sq);
+            if matches!(sq, 3 | 4 | 59 | 60) || (kind == PieceKind::Pawn && back_rank) {
+                continue;
+            }
+            let mut state = empty_state(Color::White);
+            put(&mut state, 4, Color::White, PieceKind::King);
+            put(&mut state, 60, Color::Black, PieceKind::King);
+            put(&mut state, sq, Color::White, kind);
+            assert_eq!(
+                static_eval(&state.mirrored(), Color::White),
+                static_eval(&state, Color::White),
+                "{kind:?} on square {sq}"
+            );
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! SAN output: piece moves, captures, promotions, castling, en passant, disambiguation
+//! and check/mate suffixes.
+
+mod common;
+
+use common::{play, sq};
+use rhess_core::editor::parse_square_list;
+use rhess_core::san::{Disambiguation, Suffix, format_move, san};
+use rhess_core::{GameState, Move, PieceKind};
+
+/// Find the legal move `from`-`to` (with `promotion`) so special-move flags are set.
+fn legal(state: &GameState, from: &str, to: &str, promotion: Option<PieceKind>) -> Move {
+    *state
+        .generate_legal_moves()
+        .iter()
+        .find(|m| m.from == sq(from) && m.to == sq(to) && m.promotion == promotion)
+        .unwrap_or_else(|| panic!("{from}{to} is not legal"))
+}
+
+fn san_of(state: &GameState, from: &str, to: &str) -> String {
+    san_promo(state, from, to, None)
+}
+
+fn san_promo(state: &GameState, from: &str, to: &str, promotion: Option<PieceKind>) -> String {
+    let mv = legal(state, from, to, promotion);
+    san(state, mv).unwrap().to_string()
+}
+
+#[test]
+fn pieces_pawns_and_captures() {
+    let start = GameState::start_position();
+    assert_eq!(san_of(&start, "e2", "e4"), "e4");
+    assert_eq!(san_of(&start, "g1", "f3"), "Nf3");
+
+    let state = play(start, &[("e2", "e4"), ("d7", "d5")]);
+    assert_eq!(san_of(&state, "e4", "d5"), "exd5");
+    assert_eq!(san_of(&state, "f1", "b5"), "Bb5+");
+}
+
+#[test]
+fn promotions_name_the_new_piece() {
+    let state = parse_square_list("Ka1 Pe7 kh7 nd8 w").unwrap();
+    assert_eq!(
+        san_promo(&state, "e7", "e8", Some(PieceKind::Queen)),
+        "e8=Q"
+    );
+    assert_eq!(
+        san_promo(&state, "e7", "e8", Some(PieceKind::Knight)),
+        "e8=N"
+    );
+    assert_eq!(
+        san_promo(&state, "e7", "d8", Some(PieceKind::Rook)),
+        "exd8=R"
+    );
+}
+
+#[test]
+fn en_passant_is_written_as_a_plain_pawn_capture() {
+    let state = play(
+        GameState::start_position(),
+        &[("e2", "e4"), ("a7", "a6"), ("e4", "e5"), ("d7", "d5")],
+    );
+    let mv = legal(&state, "e5", "d6", None);
+    assert!(mv.is_en_passant);
+    assert_eq!(san(&state, mv).unwrap().as_str(), "exd6");
+}
+
+#[test]
+fn castling_both_sides() {
+    let state = play(
+        GameState::start_position(),
+        &[
+            ("e2", "e4"),
+            ("d7", "d5"),
+            ("g1", "f3"),
+            ("d8", "d6"),
+            ("f1", "e2"),
+            ("c8", "e6"),
+            ("a2", "a3"),
+            ("b8", "c6"),
+        ],
+    );
+    assert_eq!(san_of(&state, "e1", "g1"), "O-O");
+    let state = play(state, &[("a3", "a4")]);
+    assert_eq!(san_of(&state, "e8", "c8"), "O-O-O");
+}
+
+#[test]
+fn check_and_mate_suffixes() {
+    // Fool's mate.
+    let state = play(
+        GameState::start_position(),
+        &[("f2", "f3"), ("e7", "e5"), ("g2", "g4")],
+    );
+    assert_eq!(san_of(&state, "d8", "h4"), "Qh4#");
+
+    let state = parse_square_list("Ka1 Qc2 kh8 w").unwrap();
+    assert_eq!(san_of(&state, "c2", "c8"), "Qc8+");
+    let after = state.make_move(legal(&state, "c2", "c8", None)).unwrap();
+    assert_eq!(Suffix::of(&after), Suffix::Check);
+}
+
+#[test]
+fn disambiguation_by_file_rank_or_both() {
+    // Knights on b1 and f3 both reach d2: the file tells them apart.
+    let state = parse_square_list("Ka1 Nb1f3 kh8 w").unwrap();
+    assert_eq!(san_of(&state, "b1", "d2"), "Nbd2");
+    assert_eq!(san_of(&state, "f3", "d2"), "Nfd2");
+
+    // Rooks on e1 and e3 share the file: the rank tells them apart.
+    let state = parse_square_list("Ka1 Re1e3 kh8 w").unwrap();
+    assert_eq!(san_of(&state, "e1", "e2"), "R1e2");
+    assert_eq!(san_of(&state, "e3", "e2"), "R3e2");
+
+    // Three queens reach d5: a8 shares its file with a2 and its rank with d8, so it
+    // needs both; the others need only what sets them apart.
+    let state = parse_square_list("Kh1 Qa2a8d8 kh6 w").unwrap();
+    assert_eq!(san_of(&state, "a8", "d5"), "Qa8d5");
+    assert_eq!(san_of(&state, "d8", "d5"), "Qdd5");
+    assert_eq!(san_of(&state, "a2", "d5"), "Q2d5");
+
+    // A pinned rival does not count, so no disambiguation is needed.
+    let state = parse_square_list("Ke1 Nc3g1 ke8 ba5 w").unwrap();
+    assert_eq!(san_of(&state, "g1", "e2"), "Ne2");
+}
+
+#[test]
+fn disambiguation_is_reported_on_its_own() {
+    let state = parse_square_list("Kh1 Qa2a8d8 kh6 w").unwrap();
+    let kind = |from, to| Disambiguation::of(&state, legal(&state, from, to, None));
+    assert_eq!(kind("a8", "d5"), Disambiguation::Square);
+    assert_eq!(kind("d8", "d5"), Disambiguation::File);
+    assert_eq!(kind("a2", "d5"), Disambiguation::Rank);
+    assert_eq!(kind("a2", "b1"), Disambiguation::None);
+    assert_eq!(kind("h1", "g1"), Disambiguation::None);
+}
+
+#[test]
+fn format_move_writes_into_the_buffer() {
+    let state = parse_square_list("Ka1 Nb1f3 kh8 w").unwrap();
+    let mv = legal(&state, "b1", "d2", None);
+    let mut buf = [0u8; 8];
+    assert_eq!(format_move(&state, mv, &mut buf), "Nbd2");
+    let mut short = [0u8; 2];
+    assert_eq!(format_move(&state, mv, &mut short), "Nb");
+    let illegal = Move::quiet(sq("b1"), sq("b2"));
+    assert_eq!(format_move(&state, illegal, &mut buf), "");
+}
+
+#[test]
+fn illegal_moves_have_no_san() {
+    let start = GameState::start_position();
+    assert!(san(&start, Move::quiet(sq("e2"), sq("e5"))).is_none());
+}
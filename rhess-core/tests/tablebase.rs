@@ -0,0 +1,177 @@
+//! Three-man endgame tables: known results, agreement of every stored entry with the
+//! positions it leads to, table play that mates, and adjudication.
+
+use rhess_core::ai::{AiConfig, MoveOrigin, choose_best_move};
+use rhess_core::editor::parse_square_list;
+use rhess_core::result::GameResult;
+use rhess_core::tablebase::{Probe, Wdl, adjudicate, best_move, probe};
+use rhess_core::{CastlingRights, Color, GameState, Piece, PieceKind};
+
+fn probe_of(squares: &str) -> Probe {
+    probe(&parse_square_list(squares).unwrap()).unwrap()
+}
+
+fn wdl(squares: &str) -> Wdl {
+    probe_of(squares).wdl
+}
+
+/// Kings on `strong_king` and `weak_king`, the extra piece on `piece`, if that is a legal
+/// position with `stm` to move.
+fn position(
+    kind: PieceKind,
+    strong: Color,
+    stm: Color,
+    [strong_king, weak_king, piece]: [u8; 3],
+) -> Option<GameState> {
+    let squares = [strong_king, weak_king, piece];
+    if squares[0] == squares[1] || squares[0] == squares[2] || squares[1] == squares[2] {
+        return None;
+    }
+    if kind == PieceKind::Pawn && !(8..56).contains(&piece) {
+        return None;
+    }
+    let mut state = GameState::start_position();
+    state.board = [None; 64];
+    state.castling = CastlingRights::new();
+    let weak = strong.opposite();
+    state.board[strong_king as usize] = Some(Piece {
+        color: strong,
+        kind: PieceKind::King,
+    });
+    state.board[weak_king as usize] = Some(Piece {
+        color: weak,
+        kind: PieceKind::King,
+    });
+    state.board[piece as usize] = Some(Piece {
+        color: strong,
+        kind,
+    });
+    state.side_to_move = stm;
+    let legal = state.check_integrity().is_ok() && !state.is_in_check(stm.opposite());
+    legal.then_some(state)
+}
+
+#[test]
+fn known_endings() {
+    assert_eq!(probe(&GameState::start_position()), None);
+    assert_eq!(probe(&parse_square_list("Ke1 Ne2 ke8").unwrap()), None);
+
+    // Qb8 mates at once.
+    assert_eq!(
+        probe_of("Kg6 Qb1 kh8"),
+        Probe {
+            wdl: Wdl::Win,
+            plies: 1
+        }
+    );
+    assert_eq!(wdl("Kg6 Qb1 kh8 b"), Wdl::Loss);
+    // Black to move takes the rook.
+    assert_eq!(wdl("Ke1 Re7 kd8 b"), Wdl::Draw);
+    assert_eq!(wdl("Ke1 Re7 kd8"), Wdl::Win);
+    // A king ahead of its pawn on the sixth rank wins with either side to move.
+    assert_eq!(wdl("Ke6 Pe5 ke8"), Wdl::Win);
+    assert_eq!(wdl("Ke6 Pe5 ke8 b"), Wdl::Loss);
+    // The defending king in front of a rook pawn holds.
+    assert_eq!(wdl("Kc1 Pa4 ka8"), Wdl::Draw);
+    // Outside the black king's square the pawn runs home.
+    assert_eq!(wdl("Kh1 Pa5 kh8"), Wdl::Win);
+    // The same endings with colours swapped.
+    assert_eq!(wdl("Ke1 ke3 pe4 b"), Wdl::Win);
+    assert_eq!(wdl("Kh8 kc8 ra1"), Wdl::Loss);
+}
+
+/// Every entry must be one more than the best reply: wins one ply beyond the quickest
+/// loss they can force, draws with no reply that loses. Checked on a sample of
+/// placements in all three endings, for either colour holding the extra piece.
+#[test]
+fn entries_agree_with_their_replies() {
+    for kind in [PieceKind::Queen, PieceKind::Rook, PieceKind::Pawn] {
+        for strong in [Color::White, Color::Black] {
+            for strong_king in [10, 36, 61] {
+                for weak_king in (0..64).step_by(3) {
+                    for piece in (0..64).step_by(5) {
+                        let squares = [strong_king, weak_king, piece];
+                        let Some(state) = position(kind, strong, strong, squares) else {
+                            continue;
+                        };
+                        check_entry(&state);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn check_entry(state: &GameState) {
+    let entry = probe(state).unwrap();
+    let mut quickest = None;
+    for mv in state.generate_legal_moves().iter() {
+        let next = state.make_move(*mv).unwrap();
+        let reply = probe(&next).unwrap_or(Probe {
+            wdl: Wdl::Draw,
+            plies: 0,
+        });
+        assert_ne!(reply.wdl, Wdl::Win, "{state:?} {mv:?}");
+        if reply.wdl == Wdl::Loss {
+            // KPK stops counting at a promotion that wins.
+            let plies = if mv.promotion.is_some() {
+                0
+            } else {
+                reply.plies
+            };
+            quickest = Some(quickest.map_or(plies, |q: u8| q.min(plies)));
+        }
+    }
+    match quickest {
+        Some(plies) => assert_eq!(
+            entry,
+            Probe {
+                wdl: Wdl::Win,
+                plies: plies + 1
+            },
+            "{state:?}"
+        ),
+        None => assert_eq!(entry.wdl, Wdl::Draw, "{state:?}"),
+    }
+}
+
+#[test]
+fn table_moves_mate_within_the_count() {
+    for squares in ["Kd3 Rh7 ke5 b", "Kb1 Qh7 kd4", "Ke6 Pe5 ke8"] {
+        let mut state = parse_square_list(squares).unwrap();
+        let mut plies = 0;
+        while state.result().is_none() {
+            let mv = best_move(&state).unwrap_or_else(|| panic!("{squares}: no table move"));
+            state = state.make_move(mv).unwrap();
+            plies += 1;
+            assert!(plies <= 80, "{squares}: no mate after 80 plies");
+        }
+        assert_eq!(
+            state.result(),
+            Some(GameResult::Checkmate {
+                winner: Color::White
+            }),
+            "{squares}"
+        );
+    }
+}
+
+#[test]
+fn search_plays_table_moves_and_results_adjudicate() {
+    let state = parse_square_list("Kb1 Qh7 kd4").unwrap();
+    let result = choose_best_move(&state, Color::White, AiConfig::default(), || {}).unwrap();
+    assert_eq!(result.origin, MoveOrigin::Tablebase);
+    assert_eq!(Some(result.mv), best_move(&state));
+
+    assert_eq!(
+        adjudicate(&state),
+        Some(GameResult::Tablebase {
+            winner: Some(Color::White)
+        })
+    );
+    assert_eq!(
+        adjudicate(&parse_square_list("Kc1 Pa4 ka8").unwrap()),
+        Some(GameResult::Tablebase { winner: None })
+    );
+    assert_eq!(adjudicate(&GameState::start_position()), None);
+}
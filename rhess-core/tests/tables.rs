@@ -0,0 +1,68 @@
+//! Compile-time lookup tables: leaper attack sets, line masks and mirrored square tables.
+
+mod common;
+
+use common::sq;
+use rhess_core::SquareSet;
+use rhess_core::tables::{
+    FILE_MASKS, KING_ATTACKS, KNIGHT_ATTACKS, PAWN_ATTACKS, RANK_MASKS, mirror_square, mirrored,
+    with_mirrored,
+};
+
+fn set(names: &[&str]) -> u64 {
+    names.iter().fold(0, |bits, name| bits | 1 << sq(name))
+}
+
+#[test]
+fn leaper_attacks_stay_on_the_board_and_are_symmetric() {
+    assert_eq!(KNIGHT_ATTACKS[sq("a1") as usize], set(&["b3", "c2"]));
+    assert_eq!(KNIGHT_ATTACKS[sq("h8") as usize], set(&["g6", "f7"]));
+    assert_eq!(KNIGHT_ATTACKS[sq("d4") as usize].count_ones(), 8);
+    assert_eq!(KING_ATTACKS[sq("a1") as usize], set(&["a2", "b1", "b2"]));
+    assert_eq!(KING_ATTACKS[sq("e4") as usize].count_ones(), 8);
+    assert_eq!(KING_ATTACKS[sq("h5") as usize].count_ones(), 5);
+    for table in [KNIGHT_ATTACKS, KING_ATTACKS] {
+        for from in 0..64u8 {
+            for to in SquareSet(table[from as usize]).iter() {
+                assert!(SquareSet(table[to as usize]).contains(from), "{from} {to}");
+            }
+        }
+    }
+}
+
+#[test]
+fn pawn_attacks_point_forward_for_each_side() {
+    assert_eq!(PAWN_ATTACKS[0][sq("e4") as usize], set(&["d5", "f5"]));
+    assert_eq!(PAWN_ATTACKS[0][sq("a2") as usize], set(&["b3"]));
+    assert_eq!(PAWN_ATTACKS[1][sq("e4") as usize], set(&["d3", "f3"]));
+    assert_eq!(PAWN_ATTACKS[1][sq("h7") as usize], set(&["g6"]));
+    assert_eq!(PAWN_ATTACKS[0][sq("c8") as usize], 0);
+    assert_eq!(PAWN_ATTACKS[1][sq("c1") as usize], 0);
+}
+
+#[test]
+fn file_and_rank_masks_meet_in_a_single_square() {
+    assert_eq!(FILE_MASKS.iter().fold(0, |all, m| all | m), !0);
+    assert_eq!(RANK_MASKS.iter().fold(0, |all, m| all | m), !0);
+    for (file, file_mask) in FILE_MASKS.iter().enumerate() {
+        for (rank, rank_mask) in RANK_MASKS.iter().enumerate() {
+            assert_eq!(file_mask & rank_mask, 1 << (rank * 8 + file));
+        }
+    }
+}
+
+#[test]
+fn mirrored_tables_flip_ranks() {
+    assert_eq!(mirror_square(sq("a1")), sq("a8"));
+    assert_eq!(mirror_square(sq("e2")), sq("e7"));
+    let mut table = [0i16; 64];
+    for (i, value) in table.iter_mut().enumerate() {
+        *value = i as i16;
+    }
+    let black = mirrored(&table);
+    assert_eq!(black[sq("e7") as usize], sq("e2") as i16);
+    assert_eq!(mirrored(&black), table);
+    let both = with_mirrored([table]);
+    assert_eq!(both[0][0], table);
+    assert_eq!(both[1][0], black);
+}
@@ -0,0 +1,76 @@
+//! AI 思考看门狗：TIM4 的 100 Hz 中断独立于主循环给单次搜索计时，超过硬上限即置停止
+//! 标志，搜索在下一个节点检查时收手并交出已完成深度的最佳着法（见
+//! `rhess_core::ai::choose_best_move_until`）。新的剪枝或延伸若有缺陷导致节点预算失效，
+//! 对局也不会卡死在思考中。
+
+use crate::hal;
+use crate::sync::{Countdown, Isr, MainLoop, priority};
+use core::sync::atomic::{AtomicBool, Ordering};
+use cortex_m::peripheral::NVIC;
+use hal::pac::{self, interrupt};
+use hal::prelude::*;
+use hal::timer::{CounterHz, Event};
+
+/// 中断频率与每次递减的毫秒数。
+const TICK_HZ: u32 = 100;
+const TICK_MS: u32 = 1_000 / TICK_HZ;
+/// 单次搜索的硬上限；正常难度下远达不到，只为兜底。
+pub const HARD_CAP_MS: u32 = 30_000;
+
+/// 本次搜索的剩余时间，由 TIM4 中断递减。
+static REMAINING: Countdown = Countdown::new();
+/// 搜索停止标志：倒计时归零后由 TIM4 中断置位，`arm` 清除。
+static STOP: AtomicBool = AtomicBool::new(false);
+
+pub struct AiWatchdog {
+    /// 持有 TIM4 以免被他处重新配置；中断里直接清标志位。
+    _timer: CounterHz<pac::TIM4>,
+}
+
+impl AiWatchdog {
+    pub fn new(tim4: pac::TIM4, nvic: &mut NVIC, rcc: &mut hal::rcc::Rcc) -> Self {
+        let mut timer = tim4.counter_hz(rcc);
+        timer.start(TICK_HZ.Hz()).unwrap();
+        timer.listen(Event::Update);
+        // SAFETY: 在启用中断前设置优先级；处理函数只访问原子量。
+        unsafe {
+            nvic.set_priority(pac::Interrupt::TIM4, priority::AI_WATCHDOG);
+            NVIC::unmask(pac::Interrupt::TIM4);
+        }
+        AiWatchdog { _timer: timer }
+    }
+
+    /// 搜索开始前调用：从硬上限起倒数并清除停止标志。
+    pub fn arm(&mut self, main: &MainLoop) {
+        REMAINING.start(main, HARD_CAP_MS);
+        STOP.store(false, Ordering::Release);
+    }
+
+    /// 搜索结束后调用：停止倒数，停止标志保留到下次 `arm`，供 `fired` 查询。
+    pub fn disarm(&mut self, main: &MainLoop) {
+        REMAINING.stop(main);
+    }
+
+    /// 传给搜索的停止标志。
+    pub fn stop_flag(&self) -> &'static AtomicBool {
+        &STOP
+    }
+
+    /// 上一次搜索是否被看门狗截断。
+    pub fn fired(&self) -> bool {
+        STOP.load(Ordering::Acquire)
+    }
+}
+
+#[interrupt]
+fn TIM4() {
+    // SAFETY: 凭据只在本处理函数内使用。
+    let isr = unsafe { Isr::enter() };
+    // SAFETY: 仅写 SR 清更新标志（写 0 清除），与主循环不共享该寄存器。
+    unsafe { (*pac::TIM4::ptr()).sr().write(|w| w.uif().clear_bit()) };
+    REMAINING.tick(&isr, TICK_MS);
+    // 未启用时倒计时可能停在 0，此时置位无害：`arm` 会先清除。
+    if REMAINING.expired() {
+        STOP.store(true, Ordering::Release);
+    }
+}
@@ -0,0 +1,219 @@
+//! 格子受攻查询：移动光标选择任意格，标出双方所有攻击该格的棋子并在侧栏列出，
+//! 附带“此格安全吗”的判断。既是教学工具，也用于在板上核对 rhess-core 的攻击判定。
+//!
+//! 局面默认为初始局面；长按 KEY2 进入摆局编辑器换局面，长按 KEY1 恢复初始局面，
+//! 也可经 USART1 控制台 `set` 命令载入。每次查询的结果同时经 RTT 以位掩码输出。
+
+use crate::board::Board;
+use crate::board_editor;
+use crate::console::{Command, Console};
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::settings::Settings;
+use crate::ui::hold_bar::HoldBar;
+use crate::ui::theme::Theme;
+use crate::ui::{chessboard, fmt, pieces, text};
+use rhess_core::{Color, GameState, PieceKind, SquareSet};
+use rtt_target::rprintln;
+
+/// 侧栏每行列出的攻击者个数（每个形如 "Nf3 "）。
+const ENTRIES_PER_LINE: usize = 8;
+
+struct Query {
+    state: GameState,
+    cursor: (u8, u8),
+    theme: &'static Theme,
+}
+
+/// 进入查询模式（不返回）。
+pub fn run(board: &mut Board, settings: Settings) -> ! {
+    let mut query = Query {
+        state: GameState::start_position(),
+        cursor: (4, 3),
+        theme: settings.theme(),
+    };
+    let input = InputMapper::new(InputMode::Game);
+    let mut console = Console::new();
+    board.lcd.clear(query.theme.ui_bg);
+    query.render(board);
+    let mut hold_bar = HoldBar::sidebar(&board.lcd);
+
+    loop {
+        let held = input.long_press_held_ms(board);
+        hold_bar.update(&mut board.lcd, query.theme, held);
+        if let Some(Command::SetPosition(state)) = console.poll(&mut board.serial, &board.main) {
+            query.state = state;
+            board.serial.write_bytes(b"ok\r\n");
+            query.render(board);
+        }
+        if let Some(action) = input.poll(board) {
+            let old = query.cursor;
+            let old_marked = query.marked();
+            match action {
+                Action::MoveLeft => query.cursor.0 = query.cursor.0.saturating_sub(1),
+                Action::MoveRight => query.cursor.0 = (query.cursor.0 + 1).min(7),
+                Action::MoveUp => query.cursor.1 = (query.cursor.1 + 1).min(7),
+                Action::MoveDown => query.cursor.1 = query.cursor.1.saturating_sub(1),
+                Action::ToggleSelect => {
+                    query.state = GameState::start_position();
+                    query.render(board);
+                    continue;
+                }
+                Action::SubmitMove => {
+                    query.state = board_editor::run(board, settings);
+                    query.render(board);
+                    continue;
+                }
+                _ => continue,
+            }
+            // 只重绘标记有变化的格子与新旧光标格。
+            for sq in old_marked.union(query.marked()).iter() {
+                query.render_square(board, sq % 8, sq / 8);
+            }
+            query.render_square(board, old.0, old.1);
+            query.render_square(board, query.cursor.0, query.cursor.1);
+            query.render_side_info(board);
+        }
+        board.delay.ms(10);
+    }
+}
+
+impl Query {
+    fn square(&self) -> u8 {
+        self.cursor.1 * 8 + self.cursor.0
+    }
+
+    fn attackers(&self, by: Color) -> SquareSet {
+        self.state.attackers(self.square(), by)
+    }
+
+    /// 当前带标记的格子：双方的全部攻击者。
+    fn marked(&self) -> SquareSet {
+        self.attackers(Color::White)
+            .union(self.attackers(Color::Black))
+    }
+
+    fn render(&self, board: &mut Board) {
+        for rank in 0..8 {
+            for file in 0..8 {
+                self.render_square(board, file, rank);
+            }
+        }
+        self.render_side_info(board);
+    }
+
+    fn render_square(&self, board: &mut Board, file: u8, rank: u8) {
+        let sq = rank * 8 + file;
+        let color = if self.cursor == (file, rank) {
+            self.theme.cursor
+        } else {
+            chessboard::square_color(self.theme, file, rank)
+        };
+        chessboard::draw_square_with_color(&mut board.lcd, file, rank, color);
+        if let Some(piece) = self.state.board[sq as usize] {
+            pieces::draw_piece_on_square(
+                &mut board.lcd,
+                self.theme,
+                piece.kind,
+                piece.color,
+                file,
+                rank,
+            );
+        }
+        // 攻击者标记：白方白底黑边、黑方黑底白边，不依赖主题配色。
+        if self.attackers(Color::White).contains(sq) {
+            chessboard::draw_corner_marker(&mut board.lcd, file, rank, 0xFFFF, 0x0000);
+        } else if self.attackers(Color::Black).contains(sq) {
+            chessboard::draw_corner_marker(&mut board.lcd, file, rank, 0x0000, 0xFFFF);
+        }
+    }
+
+    fn render_side_info(&self, board: &mut Board) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let start_x = chessboard::BOARD_SIZE + 4;
+        let width = board.lcd.width.saturating_sub(start_x);
+        board.lcd.fill_rect(start_x, 0, width, board.lcd.height, bg);
+
+        let x = start_x + 2;
+        let sq = self.square();
+        let name = square_name(sq);
+        text::draw_text_scaled(&mut board.lcd, "Attacks", x, 6, fg, Some(bg), 2);
+        text::draw_text_scaled(&mut board.lcd, "Square:", x, 30, fg, Some(bg), 2);
+        let name = core::str::from_utf8(&name).unwrap_or("");
+        text::draw_text_scaled(&mut board.lcd, name, x + 96, 30, fg, Some(bg), 2);
+
+        let white = self.attackers(Color::White);
+        let black = self.attackers(Color::Black);
+        rprintln!(
+            "attackers of {}: white {:#018x}, black {:#018x}",
+            name,
+            white.0,
+            black.0
+        );
+        self.render_attacker_list(board, x, 60, "White", white);
+        self.render_attacker_list(board, x, 100, "Black", black);
+
+        // 有子的格子：对方攻击数与己方保护数给出判断；空格只列攻击者。
+        if let Some(piece) = self.state.board[sq as usize] {
+            let (own, enemy) = match piece.color {
+                Color::White => (white, black),
+                Color::Black => (black, white),
+            };
+            let (verdict, color) = if enemy.is_empty() {
+                ("Safe", fg)
+            } else if own.is_empty() {
+                ("Hanging", self.theme.ui_alert)
+            } else if enemy.len() > own.len() {
+                ("Outnumbered", self.theme.ui_alert)
+            } else {
+                ("Defended", fg)
+            };
+            text::draw_text_scaled(&mut board.lcd, verdict, x, 144, color, Some(bg), 2);
+        }
+
+        let help = [
+            "KEY1 hold: start position",
+            "KEY2 hold: edit position",
+            "Serial: set <squares>",
+        ];
+        for (i, line) in help.iter().enumerate() {
+            let y = 214 + i as u16 * 16;
+            text::draw_text_scaled(&mut board.lcd, line, x, y, fg, Some(bg), 1);
+        }
+    }
+
+    /// "White 2" 标题行，下面每行最多 `ENTRIES_PER_LINE` 个攻击者，如 "Ng1 Pe2 Pg2"。
+    fn render_attacker_list(&self, board: &mut Board, x: u16, y: u16, label: &str, set: SquareSet) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        text::draw_text_scaled(&mut board.lcd, label, x, y, fg, Some(bg), 1);
+        let mut count_buf = [0u8; 10];
+        let count = fmt::u32_to_str(set.len(), &mut count_buf);
+        text::draw_text_scaled(&mut board.lcd, count, x + 36, y, fg, Some(bg), 1);
+        for (i, sq) in set.iter().enumerate() {
+            let Some(piece) = self.state.board[sq as usize] else {
+                continue;
+            };
+            let [file, rank] = square_name(sq);
+            let entry = [piece_letter(piece.kind), file, rank];
+            let entry = core::str::from_utf8(&entry).unwrap_or("");
+            let row = (i / ENTRIES_PER_LINE) as u16;
+            let col = (i % ENTRIES_PER_LINE) as u16;
+            let (ex, ey) = (x + col * 24, y + 12 + row * 12);
+            text::draw_text_scaled(&mut board.lcd, entry, ex, ey, fg, Some(bg), 1);
+        }
+    }
+}
+
+fn square_name(sq: u8) -> [u8; 2] {
+    [b'a' + sq % 8, b'1' + sq / 8]
+}
+
+fn piece_letter(kind: PieceKind) -> u8 {
+    match kind {
+        PieceKind::King => b'K',
+        PieceKind::Queen => b'Q',
+        PieceKind::Rook => b'R',
+        PieceKind::Bishop => b'B',
+        PieceKind::Knight => b'N',
+        PieceKind::Pawn => b'P',
+    }
+}
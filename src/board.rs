@@ -1,30 +1,66 @@
+use crate::ai_watchdog::AiWatchdog;
+use crate::board_profile::{self, PROFILE};
+use crate::clock::ChessClock;
 use crate::drivers::{
+    backup_sram::BackupSram,
+    battery::Battery,
     button::Buttons,
     delay::Delay,
+    entropy::Entropy,
+    flash::GameFlash,
     lcd::{Lcd, LcdPins},
     led::Leds,
-    serial::SerialPort,
+    power::Power,
+    rtc::WallClock,
+    serial::{AuxSerial, SerialPort},
+    timebase::Timebase,
 };
 use crate::hal;
+use crate::status::StatusQueue;
+use crate::sync::MainLoop;
 use cortex_m::peripheral::Peripherals as CorePeripherals;
 use hal::pac;
 use hal::prelude::*;
 use hal::rcc::Clocks;
+use rtt_target::rprintln;
 
 /// 聚合板级外设初始化，基于 stm32f4xx-hal。
 pub struct Board {
     pub clocks: Clocks,
     pub delay: Delay,
+    pub timebase: Timebase,
+    /// 对局双方的棋钟（TIM2 中断递减，见 `crate::clock`）。
+    pub chess_clock: ChessClock,
+    /// AI 单次思考的硬上限（TIM4 中断计时，见 `crate::ai_watchdog`）。
+    pub ai_watchdog: AiWatchdog,
+    pub rtc: WallClock,
     pub leds: Leds,
     pub buttons: Buttons,
     pub serial: SerialPort,
+    /// 第二串口（赛事广播）；开局时由 main 取走交给广播观察者。
+    pub aux_serial: Option<AuxSerial>,
     pub lcd: Lcd,
+    pub power: Power,
+    /// 对局存档区（见 `crate::game::archive`）。
+    pub flash: GameFlash,
+    /// 电池保持的 AI 思考检查点（见 `crate::game::checkpoint`）。
+    pub backup: BackupSram,
+    /// 随机数来源（见 `crate::drivers::entropy`）。
+    pub entropy: Entropy,
+    /// 供电电压监测（见 `crate::drivers::battery`），读数经 `entropy` 的 ADC1 取得。
+    pub battery: Battery,
+    /// 主循环凭据：只允许主循环执行的共享状态操作需要它（见 `crate::sync`）。
+    pub main: MainLoop,
+    /// 状态消息队列：各处推送，由当前界面在状态行显示（见 `crate::status`）。
+    pub status: StatusQueue,
+    #[cfg(feature = "input-log")]
+    pub input_log: crate::input_log::InputLog,
 }
 
 impl Board {
     pub fn new() -> Self {
         let dp = pac::Peripherals::take().expect("pac already taken");
-        let cp = CorePeripherals::take().expect("core already taken");
+        let mut cp = CorePeripherals::take().expect("core already taken");
 
         let rcc = dp.RCC.constrain();
         // 外部 25MHz 晶振 → 168MHz SYSCLK，对齐参考 C 示例与板卡硬件。
@@ -37,6 +73,19 @@ impl Board {
         let clocks = rcc.clocks;
 
         let mut delay = Delay::new(cp.SYST, &clocks);
+        // TIM5 自由运行计数 + TIM3 1 kHz 节拍中断，供帧调度与计时使用。
+        let timebase = Timebase::new(dp.TIM5, dp.TIM3, &mut cp.NVIC, &mut rcc);
+        // TIM2 100 Hz 中断：棋钟倒计时。
+        let chess_clock = ChessClock::new(dp.TIM2, &mut cp.NVIC, &mut rcc);
+        // TIM4 100 Hz 中断：AI 思考看门狗。
+        let ai_watchdog = AiWatchdog::new(dp.TIM4, &mut cp.NVIC, &mut rcc);
+        // RTC：LSE 32.768 kHz，提供墙上时间与对局起止时间戳。
+        let mut pwr = dp.PWR;
+        let rtc = WallClock::new(dp.RTC, &mut rcc, &mut pwr);
+        // 备份 SRAM：电池保持，存放 AI 思考中途的检查点。
+        let backup = BackupSram::new(&mut rcc, &mut pwr);
+        // Flash 末尾两个扇区存放最近对局。
+        let flash = GameFlash::new(dp.FLASH);
 
         let gpioa = dp.GPIOA.split(&mut rcc);
         let gpiob = dp.GPIOB.split(&mut rcc);
@@ -46,21 +95,28 @@ impl Board {
         let gpioe = dp.GPIOE.split(&mut rcc);
         let gpiog = dp.GPIOG.split(&mut rcc);
 
-        // LEDs: PC0, PF10, PB0, PB1（低电平点亮，初始化时关闭）
-        let mut leds = Leds::new(
-            gpioc.pc0.into_push_pull_output(),
-            gpiof.pf10.into_push_pull_output(),
-            gpiob.pb0.into_push_pull_output(),
-            gpiob.pb1.into_push_pull_output(),
+        // LED 与按键引脚随板型而定（见 board_profile），LED 初始化时关闭。
+        rprintln!(
+            "board profile: {}{}",
+            PROFILE.name,
+            if PROFILE.touch {
+                " (touch panel not driven)"
+            } else {
+                ""
+            }
         );
+        let pins = board_profile::profile_pins!(gpioa, gpiob, gpioc, gpioe, gpiof);
+        let mut leds = Leds::new(pins.leds, PROFILE.leds_active_low);
         leds.all_off();
+        let mut buttons = Buttons::new(pins.keys);
+        // 按键电平变化走 EXTI 中断；低功耗：STOP 模式由同几条线的 EXTI 事件唤醒。
+        let syscfg = dp.SYSCFG.constrain(&mut rcc);
+        buttons.listen(&syscfg, &dp.EXTI, &mut cp.NVIC);
+        let power = Power::new(pwr, syscfg, dp.EXTI, cp.SCB, buttons.wake_lines());
 
-        // 按键：PE2/PE3/PE4，PA0，使用上拉输入
-        let pe2 = gpioe.pe2.into_pull_up_input();
-        let pe3 = gpioe.pe3.into_pull_up_input();
-        let pe4 = gpioe.pe4.into_pull_up_input();
-        let pa0 = gpioa.pa0.into_pull_up_input();
-        let buttons = Buttons::new(pe2, pe3, pe4, pa0);
+        // 熵源：ADC1 采样悬空的 PC1/PC2 与片内温度传感器。
+        let floating = (gpioc.pc1.into_analog(), gpioc.pc2.into_analog());
+        let entropy = Entropy::new(dp.ADC1, floating, &mut rcc, &timebase);
 
         // LCD pins拆出后传入
         let lcd_pins = LcdPins {
@@ -87,24 +143,63 @@ impl Board {
             pg12: gpiog.pg12,
         };
 
-        // 串口：USART1 TX=PA9, RX=PA10，115200 8N1
+        // 串口：USART1 TX=PA9, RX=PA10，115200 8N1，接收走中断与环形队列
         let mut tx = gpioa.pa9.into_alternate::<7>();
         tx.set_speed(hal::gpio::Speed::VeryHigh);
         let mut rx = gpioa.pa10.into_alternate::<7>();
         rx.set_speed(hal::gpio::Speed::VeryHigh);
-        let serial = SerialPort::new(dp.USART1, tx, rx, &mut rcc, 115_200.bps());
+        let serial = SerialPort::new(dp.USART1, tx, rx, &mut cp.NVIC, &mut rcc, 115_200.bps());
+        // 广播串口：USART3 TX=PB10，波特率在开局时按设置调整。
+        let aux_tx = gpiob.pb10.into_alternate::<7>();
+        let aux_serial = AuxSerial::new(dp.USART3, aux_tx, &mut rcc, 115_200.bps());
 
-        // LCD：FSMC 16bit 总线 + SSD1963 初始化（480x272）。
-        let mut lcd = Lcd::new(dp.FSMC, lcd_pins);
+        // LCD：FSMC 16bit 总线 + SSD1963 初始化，面板尺寸随板型。
+        let mut lcd = Lcd::new(dp.FSMC, lcd_pins, &PROFILE.panel);
+        // 可选的离屏缓冲：棋盘格画好后经 DMA2 一次送屏。
+        #[cfg(feature = "framebuffer")]
+        lcd.enable_framebuffer(dp.DMA2);
         lcd.init(&mut delay);
+        let timing = lcd.calibrate_write_timing();
+        rprintln!(
+            "lcd write timing: ADDSET={} DATAST={}",
+            timing.addset,
+            timing.datast
+        );
+
+        #[cfg(feature = "input-log")]
+        let input_log = crate::input_log::InputLog::new(timebase.now_ms());
 
         Self {
             clocks,
             delay,
+            timebase,
+            chess_clock,
+            ai_watchdog,
+            rtc,
             leds,
             buttons,
             serial,
+            aux_serial: Some(aux_serial),
             lcd,
+            power,
+            flash,
+            backup,
+            entropy,
+            battery: Battery::new(),
+            main: MainLoop::take().expect("main loop token already taken"),
+            status: StatusQueue::new(),
+            #[cfg(feature = "input-log")]
+            input_log,
         }
     }
+
+    /// 整机休眠：关 LED、LCD 休眠关背光后进入 STOP，任一按键唤醒并恢复显示。
+    /// 内存中的对局/菜单状态与屏幕内容都原样保留；返回时唤醒键已松开。
+    pub fn sleep(&mut self) {
+        self.leds.all_off();
+        self.lcd.suspend(&mut self.delay);
+        self.power.stop_until_key();
+        self.lcd.resume(&mut self.delay);
+        self.buttons.wait_release(&self.main, &mut self.delay);
+    }
 }
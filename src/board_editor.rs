@@ -0,0 +1,180 @@
+//! 摆局编辑器：在空棋盘上逐格摆放棋子，校验通过后以该局面开局（白方先走）。
+
+use crate::board::Board;
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::settings::Settings;
+use crate::ui::hold_bar::HoldBar;
+use crate::ui::theme::Theme;
+use crate::ui::{chessboard, pieces, text};
+use rhess_core::editor::{BoardEditor, SetupError};
+use rhess_core::{Color, GameState, Piece, PieceKind};
+
+/// KEY1 长按时光标格依次轮换的棋子：空 → 白 K Q R B N P → 黑 k q r b n p → 空。
+const CYCLE: [PieceKind; 6] = [
+    PieceKind::King,
+    PieceKind::Queen,
+    PieceKind::Rook,
+    PieceKind::Bishop,
+    PieceKind::Knight,
+    PieceKind::Pawn,
+];
+
+struct Editor {
+    squares: [Option<Piece>; 64],
+    cursor: (u8, u8),
+    theme: &'static Theme,
+    error: Option<SetupError>,
+}
+
+/// 运行编辑器直到摆出合法局面并确认，返回该局面。
+pub fn run(board: &mut Board, settings: Settings) -> GameState {
+    let mut editor = Editor {
+        squares: [None; 64],
+        cursor: (4, 0),
+        theme: settings.theme(),
+        error: None,
+    };
+    let input = InputMapper::new(InputMode::Editor);
+    board.lcd.clear(editor.theme.ui_bg);
+    editor.render(board);
+    let mut hold_bar = HoldBar::sidebar(&board.lcd);
+
+    loop {
+        let held = input.long_press_held_ms(board);
+        hold_bar.update(&mut board.lcd, editor.theme, held);
+        if let Some(action) = input.poll(board) {
+            let old = editor.cursor;
+            match action {
+                Action::MoveLeft => editor.cursor.0 = editor.cursor.0.saturating_sub(1),
+                Action::MoveRight => editor.cursor.0 = (editor.cursor.0 + 1).min(7),
+                Action::MoveUp => editor.cursor.1 = (editor.cursor.1 + 1).min(7),
+                Action::MoveDown => editor.cursor.1 = editor.cursor.1.saturating_sub(1),
+                Action::EditorCycle => editor.cycle_piece(),
+                Action::EditorDone => match editor.finish() {
+                    Ok(state) => {
+                        board.lcd.clear(editor.theme.ui_bg);
+                        return state;
+                    }
+                    Err(err) => {
+                        editor.error = Some(err);
+                        editor.render_side_info(board);
+                        continue;
+                    }
+                },
+                _ => continue,
+            }
+            if editor.error.take().is_some() {
+                editor.render_side_info(board);
+            }
+            editor.render_square(board, old.0, old.1);
+            editor.render_square(board, editor.cursor.0, editor.cursor.1);
+        }
+        board.delay.ms(10);
+    }
+}
+
+impl Editor {
+    fn cursor_index(&self) -> usize {
+        (self.cursor.1 * 8 + self.cursor.0) as usize
+    }
+
+    fn cycle_piece(&mut self) {
+        let idx = self.cursor_index();
+        self.squares[idx] = match self.squares[idx] {
+            None => Some(Piece {
+                color: Color::White,
+                kind: CYCLE[0],
+            }),
+            Some(piece) => {
+                let pos = CYCLE.iter().position(|&k| k == piece.kind).unwrap_or(0);
+                match (piece.color, CYCLE.get(pos + 1)) {
+                    (color, Some(&kind)) => Some(Piece { color, kind }),
+                    (Color::White, None) => Some(Piece {
+                        color: Color::Black,
+                        kind: CYCLE[0],
+                    }),
+                    (Color::Black, None) => None,
+                }
+            }
+        };
+    }
+
+    fn finish(&self) -> Result<GameState, SetupError> {
+        let mut editor = BoardEditor::new();
+        for (sq, piece) in self.squares.iter().enumerate() {
+            if let Some(piece) = piece {
+                editor.place(sq as u8, *piece);
+            }
+        }
+        editor.set_side_to_move(Color::White);
+        editor.finish()
+    }
+
+    fn render(&self, board: &mut Board) {
+        for rank in 0..8 {
+            for file in 0..8 {
+                self.render_square(board, file, rank);
+            }
+        }
+        self.render_side_info(board);
+    }
+
+    fn render_square(&self, board: &mut Board, file: u8, rank: u8) {
+        let color = if self.cursor == (file, rank) {
+            self.theme.cursor
+        } else {
+            chessboard::square_color(self.theme, file, rank)
+        };
+        chessboard::draw_square_with_color(&mut board.lcd, file, rank, color);
+        if let Some(piece) = self.squares[(rank * 8 + file) as usize] {
+            pieces::draw_piece_on_square(
+                &mut board.lcd,
+                self.theme,
+                piece.kind,
+                piece.color,
+                file,
+                rank,
+            );
+        }
+    }
+
+    fn render_side_info(&self, board: &mut Board) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let start_x = chessboard::BOARD_SIZE + 4;
+        let width = board.lcd.width.saturating_sub(start_x);
+        board.lcd.fill_rect(start_x, 0, width, board.lcd.height, bg);
+
+        let x = start_x + 2;
+        text::draw_text_scaled(&mut board.lcd, "Board editor", x, 6, fg, Some(bg), 2);
+        text::draw_text_scaled(&mut board.lcd, "White to move", x, 30, fg, Some(bg), 1);
+
+        if let Some(err) = self.error {
+            let msg = match err {
+                SetupError::KingCount => "Need one king each",
+                SetupError::PawnOnBackRank => "Pawn on back rank",
+                SetupError::OpponentInCheck => "Black is in check",
+                SetupError::BadToken => "Invalid position",
+            };
+            text::draw_text_scaled(&mut board.lcd, msg, x, 80, self.theme.ui_alert, Some(bg), 1);
+        }
+
+        text::draw_text_scaled(
+            &mut board.lcd,
+            "KEY1 hold: cycle piece",
+            x,
+            230,
+            fg,
+            Some(bg),
+            1,
+        );
+        text::draw_text_scaled(
+            &mut board.lcd,
+            "KEY2 hold: start game",
+            x,
+            246,
+            fg,
+            Some(bg),
+            1,
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! 板型配置：同为 STM32F407ZGT6 的国产开发板在 LED/按键引脚、面板尺寸、有无触摸上各有出入。
+//! 每种板型对应一个 cargo feature，引脚表与参数集中在这里，`Board::new` 只读取选中的那一份；
+//! 不选任何板型 feature 时为本项目开发所用的板子。
+//!
+//! FSMC 屏幕接线（NE4、A10 作 RS、PG6 背光）、串口与熵源引脚各板型相同，仍写在 `board` 中。
+//! 新增板型：在 Cargo.toml 加一个 `board-*` feature，再仿照下面的模块写出 `PROFILE`
+//! 与 `profile_pins!`。
+
+use crate::drivers::lcd::Panel;
+use crate::hal;
+use hal::gpio::{AnyPin, Input, Output, PushPull};
+
+#[cfg(all(feature = "board-explorer", feature = "board-7inch"))]
+compile_error!("select at most one board-* feature");
+
+/// 板型参数。
+pub struct Profile {
+    /// 启动时经 RTT 打印。
+    pub name: &'static str,
+    /// LED 低电平点亮。
+    pub leds_active_low: bool,
+    pub panel: Panel,
+    /// 面板带触摸控制器；触摸尚未驱动，只在启动日志中注明。
+    pub touch: bool,
+}
+
+/// `profile_pins!` 从各 GPIO 端口取出的板型相关引脚，已配置好输入/输出模式。
+pub struct ProfilePins {
+    /// LED1..LED4；板上不足四个时其余为 None。
+    pub leds: [Option<AnyPin<Output<PushPull>>>; 4],
+    /// KEY1..KEY4 的引脚及是否低电平有效（低电平有效配上拉，否则配下拉）。
+    pub keys: [(AnyPin<Input>, bool); 4],
+}
+
+/// 4.3 寸 480x272 面板；消隐参数沿用参考 C 工程。
+const PANEL_480X272: Panel = Panel {
+    width: 480,
+    height: 272,
+    h_back_porch: 46,
+    h_front_porch: 210,
+    v_back_porch: 23,
+    v_front_porch: 22,
+};
+
+/// 本项目开发所用的板子：四个低电平点亮的 LED（PC0/PF10/PB0/PB1），
+/// 四个低电平有效的按键（PE2/PE3/PE4/PA0），4.3 寸无触摸面板。
+#[cfg(not(any(feature = "board-explorer", feature = "board-7inch")))]
+mod selected {
+    use super::*;
+
+    pub static PROFILE: Profile = Profile {
+        name: "default (4 LEDs, 480x272)",
+        leds_active_low: true,
+        panel: PANEL_480X272,
+        touch: false,
+    };
+
+    macro_rules! profile_pins {
+        ($gpioa:ident, $gpiob:ident, $gpioc:ident, $gpioe:ident, $gpiof:ident) => {
+            $crate::board_profile::ProfilePins {
+                leds: [
+                    Some($gpioc.pc0.into_push_pull_output().erase()),
+                    Some($gpiof.pf10.into_push_pull_output().erase()),
+                    Some($gpiob.pb0.into_push_pull_output().erase()),
+                    Some($gpiob.pb1.into_push_pull_output().erase()),
+                ],
+                keys: [
+                    ($gpioe.pe2.into_pull_up_input().erase(), true),
+                    ($gpioe.pe3.into_pull_up_input().erase(), true),
+                    ($gpioe.pe4.into_pull_up_input().erase(), true),
+                    ($gpioa.pa0.into_pull_up_input().erase(), true),
+                ],
+            }
+        };
+    }
+    pub(crate) use profile_pins;
+}
+
+/// 探索者式布线：只有 PF9/PF10 两个 LED；按键同在 PE2/PE3/PE4/PA0，
+/// 但 PA0 是高电平有效的 WK_UP 键。
+#[cfg(feature = "board-explorer")]
+mod selected {
+    use super::*;
+
+    pub static PROFILE: Profile = Profile {
+        name: "explorer (2 LEDs, WK_UP active-high)",
+        leds_active_low: true,
+        panel: PANEL_480X272,
+        touch: false,
+    };
+
+    macro_rules! profile_pins {
+        ($gpioa:ident, $gpiob:ident, $gpioc:ident, $gpioe:ident, $gpiof:ident) => {
+            $crate::board_profile::ProfilePins {
+                leds: [
+                    Some($gpiof.pf9.into_push_pull_output().erase()),
+                    Some($gpiof.pf10.into_push_pull_output().erase()),
+                    None,
+                    None,
+                ],
+                keys: [
+                    ($gpioe.pe2.into_pull_up_input().erase(), true),
+                    ($gpioe.pe3.into_pull_up_input().erase(), true),
+                    ($gpioe.pe4.into_pull_up_input().erase(), true),
+                    ($gpioa.pa0.into_pull_down_input().erase(), false),
+                ],
+            }
+        };
+    }
+    pub(crate) use profile_pins;
+}
+
+/// 与默认板相同的 LED 与按键，换装 7 寸 800x480 触摸面板。界面仍按 480x272 布局，
+/// 画在面板左上角。面板时序沿用 4.3 寸面板的前后沿（像素时钟与同步脉宽也在 `Lcd::init`
+/// 中按 4.3 寸面板设定），尚未对照 800x480 面板的数据手册或在实物上验证。
+#[cfg(feature = "board-7inch")]
+mod selected {
+    use super::*;
+
+    pub static PROFILE: Profile = Profile {
+        name: "7-inch (4 LEDs, 800x480 touch)",
+        leds_active_low: true,
+        panel: Panel {
+            width: 800,
+            height: 480,
+            ..PANEL_480X272
+        },
+        touch: true,
+    };
+
+    macro_rules! profile_pins {
+        ($gpioa:ident, $gpiob:ident, $gpioc:ident, $gpioe:ident, $gpiof:ident) => {
+            $crate::board_profile::ProfilePins {
+                leds: [
+                    Some($gpioc.pc0.into_push_pull_output().erase()),
+                    Some($gpiof.pf10.into_push_pull_output().erase()),
+                    Some($gpiob.pb0.into_push_pull_output().erase()),
+                    Some($gpiob.pb1.into_push_pull_output().erase()),
+                ],
+                keys: [
+                    ($gpioe.pe2.into_pull_up_input().erase(), true),
+                    ($gpioe.pe3.into_pull_up_input().erase(), true),
+                    ($gpioe.pe4.into_pull_up_input().erase(), true),
+                    ($gpioa.pa0.into_pull_up_input().erase(), true),
+                ],
+            }
+        };
+    }
+    pub(crate) use profile_pins;
+}
+
+pub(crate) use selected::{PROFILE, profile_pins};
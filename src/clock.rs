@@ -0,0 +1,126 @@
+//! 棋钟：双方各一个倒计时，由 TIM2 的 100 Hz 中断递减正在走的一方（见 `crate::sync` 的
+//! 上下文分工）。主循环只在轮到的一方变化时切换走钟并加秒，不靠延时或逐帧累计，AI 搜索或
+//! 整屏重画占住主循环时钟照样走。STOP 休眠期间 TIM2 随高速时钟一起停下，双方用时不变。
+
+use crate::hal;
+use crate::sync::{Countdown, Isr, MainLoop, priority};
+use cortex_m::peripheral::NVIC;
+use hal::pac::{self, interrupt};
+use hal::prelude::*;
+use hal::timer::{CounterHz, Event};
+use rhess_core::Color;
+
+/// 中断频率与每次递减的毫秒数；显示只到秒，10 ms 的分辨率足够。
+const TICK_HZ: u32 = 100;
+const TICK_MS: u32 = 1_000 / TICK_HZ;
+
+/// 白/黑剩余用时，由 TIM2 中断递减。
+static REMAINING: [Countdown; 2] = [Countdown::new(), Countdown::new()];
+
+/// 时限：每方基本用时加每步加秒，在开局向导的时钟一步中选择，随对局设置保存。
+pub use rhess_core::time_control::TimeControl;
+
+/// 每方的基本用时与每步加秒（毫秒）；不限时为 None。
+fn budget_ms(control: TimeControl) -> Option<(u32, u32)> {
+    control
+        .seconds()
+        .map(|(base, increment)| (base * 1_000, increment * 1_000))
+}
+
+pub struct ChessClock {
+    /// 持有 TIM2 以免被他处重新配置；中断里直接清标志位。
+    _timer: CounterHz<pac::TIM2>,
+    control: TimeControl,
+    /// 正在走钟的一方；None 表示双方都停着。
+    running: Option<Color>,
+}
+
+impl ChessClock {
+    pub fn new(tim2: pac::TIM2, nvic: &mut NVIC, rcc: &mut hal::rcc::Rcc) -> Self {
+        let mut timer = tim2.counter_hz(rcc);
+        timer.start(TICK_HZ.Hz()).unwrap();
+        timer.listen(Event::Update);
+        // SAFETY: 在启用中断前设置优先级；处理函数只访问原子量。
+        unsafe {
+            nvic.set_priority(pac::Interrupt::TIM2, priority::CHESS_CLOCK);
+            NVIC::unmask(pac::Interrupt::TIM2);
+        }
+        ChessClock {
+            _timer: timer,
+            control: TimeControl::Untimed,
+            running: None,
+        }
+    }
+
+    /// 停钟并按时限重置双方用时，开新局时调用。
+    pub fn reset(&mut self, main: &MainLoop, control: TimeControl) {
+        let base_ms = budget_ms(control).map_or(0, |(base_ms, _)| base_ms);
+        for countdown in &REMAINING {
+            countdown.stop_at(main, base_ms);
+        }
+        self.control = control;
+        self.running = None;
+    }
+
+    pub fn is_timed(&self) -> bool {
+        self.control != TimeControl::Untimed
+    }
+
+    /// 让 `side` 的钟走，None 时双方都停。走钟从一方直接换到另一方，即前者走完了一步，
+    /// 按时限给它加秒；暂停后恢复不加。
+    pub fn run(&mut self, main: &MainLoop, side: Option<Color>) {
+        if side == self.running {
+            return;
+        }
+        if let Some(prev) = self.running {
+            let countdown = &REMAINING[index(prev)];
+            countdown.stop(main);
+            if side == Some(prev.opposite())
+                && let Some((_, increment_ms)) = budget_ms(self.control)
+            {
+                countdown.stop_at(main, countdown.remaining_ms() + increment_ms);
+            }
+        }
+        if let Some(next) = side {
+            REMAINING[index(next)].resume(main);
+        }
+        self.running = side;
+    }
+
+    /// 正在走钟的一方。
+    pub fn running(&self) -> Option<Color> {
+        self.running
+    }
+
+    pub fn remaining_ms(&self, side: Color) -> u32 {
+        REMAINING[index(side)].remaining_ms()
+    }
+
+    /// 用时耗尽的一方；不限时为 None。
+    pub fn flagged(&self) -> Option<Color> {
+        if !self.is_timed() {
+            return None;
+        }
+        [Color::White, Color::Black]
+            .into_iter()
+            .find(|&side| REMAINING[index(side)].expired())
+    }
+}
+
+fn index(side: Color) -> usize {
+    match side {
+        Color::White => 0,
+        Color::Black => 1,
+    }
+}
+
+#[interrupt]
+fn TIM2() {
+    // SAFETY: 凭据只在本处理函数内使用。
+    let isr = unsafe { Isr::enter() };
+    // SAFETY: 仅写 SR 清更新标志（写 0 清除），与主循环不共享该寄存器。
+    unsafe { (*pac::TIM2::ptr()).sr().write(|w| w.uif().clear_bit()) };
+    for countdown in &REMAINING {
+        countdown.tick(&isr, TICK_MS);
+    }
+}
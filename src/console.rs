@@ -0,0 +1,131 @@
+//! USART1 文本控制台：按行（`\r` 或 `\n` 结束）解析命令。行由串口驱动的接收队列拼好
+//! （见 `SerialPort::read_line`），整段粘贴也不会丢字。
+//!
+//! 命令：
+//! - `set <棋子><格子>... [w|b]`：摆放局面，大写白方、小写黑方，如 `set Kc1 Qd8 Pa2b2 ke8 pe7 b`
+//! - `bench`：对局中在板上测搜索开销，机动性评估关/开各测一轮（见 `search_bench`）
+//! - `rxstat`：打印接收端丢字统计（队列满/硬件溢出/线路错误），由控制台直接应答
+//! - `actions`（`input-log` 特性）：打印最近录制的输入动作
+//! - `moves?`：列出当前局面的全部合法着法，坐标与 SAN 两种写法并列
+//! - `arrow <起点> <终点> [颜色]`、`mark <格子> [颜色]`：在棋盘上画批注箭头/方格标记，
+//!   颜色为 green、red、blue、yellow（默认 green），如 `arrow e2 e4 green`、`mark d5 red`
+//! - `clear-marks`：撤下全部批注
+//! - 其余的行按着法解析（见 `rhess_core::notation`）：`e2e4`、`E2-E4`、`Nf3`、`exd5`、
+//!   `e8=Q`、`O-O`、`0-0-0` 等写法均可，轮到人类走棋时直接落子
+
+use crate::drivers::serial::{LineTooLong, SerialPort};
+use crate::sync::MainLoop;
+use crate::ui::annotations::{Arrow, MarkColor};
+use core::fmt::Write;
+use rhess_core::editor::parse_square_list;
+use rhess_core::notation::MoveText;
+use rhess_core::{ChessError, GameState};
+
+pub enum Command {
+    /// 用摆好的局面重新开局。
+    SetPosition(GameState),
+    /// 运行搜索基准并打印结果。
+    Bench,
+    /// 走一步键入的着法，对局中按当前局面解析。
+    PlayMove(MoveText),
+    /// 打印当前局面的合法着法。
+    ListMoves,
+    /// 在棋盘上画一支批注箭头。
+    Arrow(Arrow),
+    /// 给一格加批注标记。
+    Mark(u8, MarkColor),
+    /// 撤下全部批注。
+    ClearMarks,
+    /// 打印内存中录制的输入动作。
+    #[cfg(feature = "input-log")]
+    DumpInputLog,
+}
+
+pub struct Console;
+
+impl Console {
+    pub fn new() -> Self {
+        Console
+    }
+
+    /// 读取已到达的行并解析，返回命令；错误信息与查询结果直接回显到串口。
+    pub fn poll(&mut self, serial: &mut SerialPort, main: &MainLoop) -> Option<Command> {
+        loop {
+            let parsed = match serial.read_line(main)? {
+                Ok(line) if line.trim_ascii() == b"rxstat" => {
+                    let stats = serial.rx_stats();
+                    let _ = write!(
+                        serial,
+                        "rx dropped {} overruns {} errors {}\r\n",
+                        stats.dropped, stats.overruns, stats.line_errors
+                    );
+                    continue;
+                }
+                Ok(line) => parse_line(line),
+                Err(LineTooLong) => Err("line too long"),
+            };
+            match parsed {
+                Ok(cmd) => return Some(cmd),
+                Err(msg) => {
+                    serial.write_bytes(b"error: ");
+                    serial.write_bytes(msg.as_bytes());
+                    serial.write_bytes(b"\r\n");
+                }
+            }
+        }
+    }
+}
+
+fn parse_line(line: &[u8]) -> Result<Command, &'static str> {
+    let text = core::str::from_utf8(line).map_err(|_| "invalid utf-8")?;
+    let text = text.trim();
+    let (name, args) = text.split_once(' ').unwrap_or((text, ""));
+    match name {
+        "set" => parse_square_list(args)
+            .map(Command::SetPosition)
+            .map_err(|err| ChessError::from(err).message()),
+        "bench" => Ok(Command::Bench),
+        "moves?" => Ok(Command::ListMoves),
+        "arrow" => {
+            let mut words = args.split_whitespace();
+            let (Some(from), Some(to), color, None) =
+                (words.next(), words.next(), words.next(), words.next())
+            else {
+                return Err("usage: arrow e2 e4 [green|red|blue|yellow]");
+            };
+            Ok(Command::Arrow(Arrow {
+                from: parse_square(from)?,
+                to: parse_square(to)?,
+                color: parse_color(color)?,
+            }))
+        }
+        "mark" => {
+            let mut words = args.split_whitespace();
+            let (Some(square), color, None) = (words.next(), words.next(), words.next()) else {
+                return Err("usage: mark d5 [green|red|blue|yellow]");
+            };
+            Ok(Command::Mark(parse_square(square)?, parse_color(color)?))
+        }
+        "clear-marks" => Ok(Command::ClearMarks),
+        #[cfg(feature = "input-log")]
+        "actions" => Ok(Command::DumpInputLog),
+        _ => MoveText::parse(text)
+            .map(Command::PlayMove)
+            .map_err(|_| "unknown command or move, try e2e4, Nf3 or O-O"),
+    }
+}
+
+/// 坐标写法的格子（`e4`），返回 `rank * 8 + file`。
+fn parse_square(text: &str) -> Result<u8, &'static str> {
+    match *text.as_bytes() {
+        [file @ b'a'..=b'h', rank @ b'1'..=b'8'] => Ok((rank - b'1') * 8 + (file - b'a')),
+        _ => Err("bad square, try e4"),
+    }
+}
+
+/// 批注颜色，省略时为绿色。
+fn parse_color(name: Option<&str>) -> Result<MarkColor, &'static str> {
+    name.map_or(Ok(MarkColor::Green), |name| {
+        MarkColor::parse(name).ok_or("unknown color, try green, red, blue or yellow")
+    })
+}
@@ -0,0 +1,310 @@
+//! 名局演示：内置几盘经典对局（坐标记法着法串 + 逐步解说），自动播放并可暂停、单步前后翻看。
+//! 着法记入 `History`，后退时借助其重放得到局面，侧栏沿用着法列表控件；适合展会展示。
+
+use crate::board::Board;
+use crate::game::history::History;
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::settings::{Labels, Settings};
+use crate::ui::hold_bar::HoldBar;
+use crate::ui::theme::Theme;
+use crate::ui::{chessboard, fmt, movelist, pieces, text};
+use rhess_core::{GameState, Move, PieceKind};
+
+/// 自动播放时每步间隔。
+const STEP_MS: u32 = 2_000;
+const PANEL_X: u16 = chessboard::BOARD_SIZE + 4;
+const CAPTION_Y: u16 = 56;
+const MOVE_LIST_Y: u16 = 72;
+const MOVE_LIST_ROWS: usize = 11;
+
+struct FamousGame {
+    title: &'static str,
+    players: &'static str,
+    /// 自起始局面的着法，坐标记法、空格分隔（升变附小写字母，如 "e7e8q"）。
+    moves: &'static str,
+    /// (半回合序号, 解说)：走完该步后显示。
+    captions: &'static [(usize, &'static str)],
+}
+
+const GAMES: [FamousGame; 3] = [
+    FamousGame {
+        title: "Opera Game",
+        players: "Morphy - Duke & Count, 1858",
+        moves: "e2e4 e7e5 g1f3 d7d6 d2d4 c8g4 d4e5 g4f3 d1f3 d6e5 f1c4 g8f6 f3b3 d8e7 \
+                b1c3 c7c6 c1g5 b7b5 c3b5 c6b5 c4b5 b8d7 e1c1 a8d8 d1d7 d8d7 h1d1 e7e6 \
+                b5d7 f6d7 b3b8 d7b8 d1d8",
+        captions: &[
+            (5, "Bg4 pins the knight"),
+            (12, "Qb3 hits f7 and b7"),
+            (18, "Knight sacrifice opens lines"),
+            (22, "Castles into the attack"),
+            (24, "Rxd7 keeps the pin alive"),
+            (30, "Queen sacrifice!"),
+            (32, "Rd8 mate with the last pieces"),
+        ],
+    },
+    FamousGame {
+        title: "Immortal Game",
+        players: "Anderssen - Kieseritzky, 1851",
+        moves: "e2e4 e7e5 f2f4 e5f4 f1c4 d8h4 e1f1 b7b5 c4b5 g8f6 g1f3 h4h6 d2d3 f6h5 \
+                f3h4 h6g5 h4f5 c7c6 g2g4 h5f6 h1g1 c6b5 h2h4 g5g6 h4h5 g6g5 d1f3 f6g8 \
+                c1f4 g5f6 b1c3 f8c5 c3d5 f6b2 f4d6 c5g1 e4e5 b2a1 f1e2 b8a6 f5g7 e8d8 \
+                f3f6 g8f6 d6e7",
+        captions: &[
+            (2, "King's Gambit"),
+            (5, "Qh4+ costs White castling"),
+            (7, "Bryan Countergambit"),
+            (20, "Rg1 leaves the bishop hanging"),
+            (34, "Bd6! offers both rooks"),
+            (37, "Black has won two rooks"),
+            (42, "Queen sacrifice!"),
+            (44, "Mate with minor pieces only"),
+        ],
+    },
+    FamousGame {
+        title: "Reti-Tartakower",
+        players: "Reti - Tartakower, 1910",
+        moves: "e2e4 c7c6 d2d4 d7d5 b1c3 d5e4 c3e4 g8f6 d1d3 e7e5 d4e5 d8a5 c1d2 a5e5 \
+                e1c1 f6e4 d3d8 e8d8 d2g5 d8c7 g5d8",
+        captions: &[
+            (8, "Qd3 lines up on the d-file"),
+            (14, "Castles, offering the knight"),
+            (15, "Black grabs the knight"),
+            (16, "Qd8+!! queen sacrifice"),
+            (18, "Double check"),
+            (20, "Bd8 mate"),
+        ],
+    },
+];
+
+struct Demo {
+    game: usize,
+    state: GameState,
+    history: History,
+    playing: bool,
+    last_step_ms: u32,
+    theme: &'static Theme,
+    labels: Labels,
+}
+
+/// 进入演示模式（不返回）：KEY3 播放/暂停，KEY1/KEY4 后退/前进一步，KEY1 长按换下一盘。
+/// 减少动态效果时不自动播放，每盘都从暂停开始，由按键单步翻看。
+pub fn run(board: &mut Board, settings: Settings) -> ! {
+    let start = GameState::start_position();
+    let mut demo = Demo {
+        game: 0,
+        state: start,
+        history: History::new(start),
+        playing: board.timebase.motion_enabled(),
+        last_step_ms: board.timebase.now_ms(),
+        theme: settings.theme(),
+        labels: settings.labels,
+    };
+    let input = InputMapper::new(InputMode::Demo);
+    board.lcd.clear(demo.theme.ui_bg);
+    demo.render(board);
+    let mut hold_bar = HoldBar::sidebar(&board.lcd);
+
+    loop {
+        let held = input.long_press_held_ms(board);
+        hold_bar.update(&mut board.lcd, demo.theme, held);
+        let changed = match input.poll(board) {
+            Some(Action::ReviewBack) => {
+                demo.playing = false;
+                demo.step_back()
+            }
+            Some(Action::ReviewForward) => {
+                demo.playing = false;
+                demo.step_forward()
+            }
+            Some(Action::DemoPlayPause) => {
+                demo.playing = !demo.playing;
+                demo.last_step_ms = board.timebase.now_ms();
+                true
+            }
+            Some(Action::DemoNextGame) => {
+                let autoplay = board.timebase.motion_enabled();
+                demo.load((demo.game + 1) % GAMES.len(), autoplay);
+                demo.last_step_ms = board.timebase.now_ms();
+                true
+            }
+            _ if demo.playing && board.timebase.elapsed_ms(demo.last_step_ms) >= STEP_MS => {
+                demo.last_step_ms = board.timebase.now_ms();
+                // 播完后自动暂停，停在终局。
+                if !demo.step_forward() {
+                    demo.playing = false;
+                }
+                true
+            }
+            _ => false,
+        };
+        if changed {
+            demo.render(board);
+        }
+        board.delay.ms(10);
+    }
+}
+
+impl Demo {
+    fn current(&self) -> &'static FamousGame {
+        &GAMES[self.game]
+    }
+
+    fn load(&mut self, game: usize, autoplay: bool) {
+        self.game = game;
+        self.state = GameState::start_position();
+        self.history.reset(self.state);
+        self.playing = autoplay;
+    }
+
+    /// 走出下一步；已到终局或着法串有误时返回 false。
+    fn step_forward(&mut self) -> bool {
+        let ply = self.history.len();
+        let Some(mv) = self
+            .current()
+            .moves
+            .split_whitespace()
+            .nth(ply)
+            .and_then(|token| resolve(&self.state, token))
+        else {
+            return false;
+        };
+        let Some(next) = self.state.make_move(mv) else {
+            return false;
+        };
+        self.history.push(&self.state, mv);
+        self.state = next;
+        true
+    }
+
+    fn step_back(&mut self) -> bool {
+        let Some(ply) = self.history.len().checked_sub(1) else {
+            return false;
+        };
+        self.history.truncate(ply);
+        self.state = self.history.replay(ply);
+        true
+    }
+
+    fn caption(&self) -> Option<&'static str> {
+        let ply = self.history.len().checked_sub(1)?;
+        self.current()
+            .captions
+            .iter()
+            .find(|(at, _)| *at == ply)
+            .map(|(_, caption)| *caption)
+    }
+
+    fn render(&self, board: &mut Board) {
+        let last_move = self
+            .history
+            .len()
+            .checked_sub(1)
+            .and_then(|ply| self.history.get(ply));
+        for rank in 0..8 {
+            for file in 0..8 {
+                let idx = rank * 8 + file;
+                let color = if last_move.is_some_and(|mv| mv.from == idx || mv.to == idx) {
+                    self.theme.last_move
+                } else {
+                    chessboard::square_color(self.theme, file, rank)
+                };
+                chessboard::draw_square_with_color(&mut board.lcd, file, rank, color);
+                chessboard::draw_coordinates(
+                    &mut board.lcd,
+                    self.theme,
+                    file,
+                    rank,
+                    self.labels.rank_label(rank),
+                    (b'a' + file) as char,
+                );
+                if let Some(piece) = self.state.board[idx as usize] {
+                    pieces::draw_piece_on_square(
+                        &mut board.lcd,
+                        self.theme,
+                        piece.kind,
+                        piece.color,
+                        file,
+                        rank,
+                    );
+                }
+            }
+        }
+        self.render_side_info(board);
+    }
+
+    fn render_side_info(&self, board: &mut Board) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let width = board.lcd.width.saturating_sub(PANEL_X);
+        board.lcd.fill_rect(PANEL_X, 0, width, board.lcd.height, bg);
+
+        let x = PANEL_X + 2;
+        let game = self.current();
+        text::draw_text_scaled(&mut board.lcd, game.title, x, 6, fg, Some(bg), 2);
+        text::draw_text_scaled(&mut board.lcd, game.players, x, 26, fg, Some(bg), 1);
+
+        let mut ply_buf = [0u8; 10];
+        let mut len_buf = [0u8; 10];
+        let total = game.moves.split_whitespace().count();
+        let status = if self.playing { "Playing" } else { "Paused" };
+        let mut px = x;
+        for part in [
+            "Ply ",
+            fmt::u32_to_str(self.history.len() as u32, &mut ply_buf),
+            "/",
+            fmt::u32_to_str(total as u32, &mut len_buf),
+            "  ",
+            status,
+        ] {
+            text::draw_text_scaled(&mut board.lcd, part, px, 40, fg, Some(bg), 1);
+            px += part.len() as u16 * 6;
+        }
+        if let Some(caption) = self.caption() {
+            let color = self.theme.cursor;
+            text::draw_text_scaled(&mut board.lcd, caption, x, CAPTION_Y, color, Some(bg), 1);
+        }
+
+        movelist::render(
+            &mut board.lcd,
+            self.theme,
+            &self.history,
+            x,
+            MOVE_LIST_Y,
+            MOVE_LIST_ROWS,
+            None,
+        );
+
+        let help = ["KEY1/KEY4 step  KEY3 play/pause", "KEY1 hold: next game"];
+        for (i, line) in help.iter().enumerate() {
+            let y = 230 + i as u16 * 16;
+            text::draw_text_scaled(&mut board.lcd, line, x, y, fg, Some(bg), 1);
+        }
+    }
+}
+
+/// 在当前局面的合法着法中查找坐标记法 `token`（如 "e2e4"、"e7e8q"）。
+fn resolve(state: &GameState, token: &str) -> Option<Move> {
+    let bytes = token.as_bytes();
+    if bytes.len() < 4 {
+        return None;
+    }
+    let square = |file: u8, rank: u8| -> Option<u8> {
+        ((b'a'..=b'h').contains(&file) && (b'1'..=b'8').contains(&rank))
+            .then(|| (rank - b'1') * 8 + (file - b'a'))
+    };
+    let from = square(bytes[0], bytes[1])?;
+    let to = square(bytes[2], bytes[3])?;
+    let promotion = match bytes.get(4) {
+        None => None,
+        Some(b'n') => Some(PieceKind::Knight),
+        Some(b'b') => Some(PieceKind::Bishop),
+        Some(b'r') => Some(PieceKind::Rook),
+        Some(b'q') => Some(PieceKind::Queen),
+        Some(_) => return None,
+    };
+    let legal = state.generate_legal_moves();
+    legal.moves[..legal.len]
+        .iter()
+        .find(|mv| mv.from == from && mv.to == to && mv.promotion == promotion)
+        .copied()
+}
@@ -0,0 +1,45 @@
+//! 备份 SRAM：0x4002_4000 起 4 KB，位于备份域。开启备份稳压器（BRE）后由 VBAT 纽扣电池
+//! 供电，主电源断开时内容保留；复位与 STOP 也不影响。无电池时断电即丢失，读出方须自行校验。
+//! 这里只提供整块读取与按偏移写入，记录格式见 `game::checkpoint` 与末尾的 `game::playout_tally`。
+
+use crate::hal;
+use hal::pac;
+
+const BASE: usize = 0x4002_4000;
+pub const SIZE: usize = 4096;
+/// 等待备份稳压器就绪的最大轮询次数；超时（如芯片未接 VBAT 引脚）也照常使用，只是断电不保留。
+const REGULATOR_READY_POLLS: u32 = 100_000;
+
+pub struct BackupSram {
+    _private: (),
+}
+
+impl BackupSram {
+    /// 开启备份 SRAM 时钟与备份稳压器。须在 `WallClock::new` 之后调用：那里已打开 PWR 时钟
+    /// 并解除备份域写保护（DBP）。
+    pub fn new(rcc: &mut pac::RCC, pwr: &mut pac::PWR) -> Self {
+        rcc.ahb1enr().modify(|_, w| w.bkpsramen().set_bit());
+        pwr.cr().modify(|_, w| w.dbp().set_bit());
+        pwr.csr().modify(|_, w| w.bre().set_bit());
+        for _ in 0..REGULATOR_READY_POLLS {
+            if pwr.csr().read().brr().bit_is_set() {
+                break;
+            }
+        }
+        BackupSram { _private: () }
+    }
+
+    pub fn read(&self) -> &[u8; SIZE] {
+        // SAFETY: 时钟已开启，区域只经由本结构体（唯一实例）访问，写入需要 &mut self。
+        unsafe { &*(BASE as *const [u8; SIZE]) }
+    }
+
+    /// 从 `offset` 起写入 `bytes`；越界部分被截掉。
+    pub fn write(&mut self, offset: usize, bytes: &[u8]) {
+        let len = bytes.len().min(SIZE.saturating_sub(offset));
+        for (i, &b) in bytes[..len].iter().enumerate() {
+            // SAFETY: 地址在 4 KB 区域内，逐字节易失写入。
+            unsafe { core::ptr::write_volatile((BASE + offset + i) as *mut u8, b) };
+        }
+    }
+}
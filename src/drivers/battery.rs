@@ -0,0 +1,66 @@
+//! 供电监测：板子由电池组经 LDO 稳压供电时，电池将耗尽、压差不足后 VDD 随之跌落到 3.3 V 以下。
+//! 每 `SAMPLE_MS` 经熵源的 ADC1 读一次 VDDA（见 `Entropy::supply_mv`），取最近几次的平均：
+//! 低于 `LOW_MV` 判为电量低，回升到 `RECOVER_MV` 以上才解除，避免在门限附近反复切换。
+//! USB 供电时 VDD 稳定在 3.3 V，不会误报。
+
+use crate::drivers::entropy::Entropy;
+
+const SAMPLE_MS: u32 = 500;
+/// 平均的采样数；凑满之前不作判断。
+const AVERAGE_OF: usize = 8;
+const LOW_MV: u32 = 3_150;
+const RECOVER_MV: u32 = 3_250;
+
+pub struct Battery {
+    samples: [u16; AVERAGE_OF],
+    count: usize,
+    next: usize,
+    sampled_at: Option<u32>,
+    low: bool,
+}
+
+impl Battery {
+    pub const fn new() -> Self {
+        Battery {
+            samples: [0; AVERAGE_OF],
+            count: 0,
+            next: 0,
+            sampled_at: None,
+            low: false,
+        }
+    }
+
+    /// 到采样间隔则采样一次；本次采样使电量由正常转为低时返回 true。
+    pub fn update(&mut self, entropy: &mut Entropy, now_ms: u32) -> bool {
+        if self
+            .sampled_at
+            .is_some_and(|at| now_ms.wrapping_sub(at) < SAMPLE_MS)
+        {
+            return false;
+        }
+        self.sampled_at = Some(now_ms);
+        self.samples[self.next] = entropy.supply_mv().min(u16::MAX as u32) as u16;
+        self.next = (self.next + 1) % AVERAGE_OF;
+        self.count = (self.count + 1).min(AVERAGE_OF);
+        let Some(mv) = self.millivolts() else {
+            return false;
+        };
+        let was_low = self.low;
+        self.low = if was_low {
+            mv < RECOVER_MV
+        } else {
+            mv < LOW_MV
+        };
+        self.low && !was_low
+    }
+
+    /// 最近几次采样的平均电压；采样数不足时为 None。
+    pub fn millivolts(&self) -> Option<u32> {
+        (self.count == AVERAGE_OF)
+            .then(|| self.samples.iter().map(|&mv| mv as u32).sum::<u32>() / AVERAGE_OF as u32)
+    }
+
+    pub fn is_low(&self) -> bool {
+        self.low
+    }
+}
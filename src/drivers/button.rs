@@ -1,103 +1,356 @@
+//! 板载按键：非阻塞的短按/长按识别。按键所在的 EXTI 线按下、松开都进中断，中断只读引脚，
+//! 给电平变化打上时间戳入队；主循环轮询时先按时间顺序回放这些变化，再读一次引脚补上当前
+//! 电平，凭时基毫秒数判断按住多久。AI 搜索等占满一帧时按下又松开的短按因此不会丢；
+//! 按住期间主循环照常运行，界面可据 [`Buttons::held_ms`] 画出长按进度。
+
 use crate::drivers::delay::Delay;
+use crate::drivers::exti::{self, KeyLine};
+use crate::drivers::timebase;
 use crate::hal;
+use crate::sync::{Isr, MainLoop, WordQueue, priority};
+use core::sync::atomic::{AtomicU8, AtomicU32, Ordering};
+use cortex_m::peripheral::NVIC;
 use embedded_hal::digital::InputPin;
-use hal::gpio::{
-    Input,
-    gpioa::PA0,
-    gpioe::{PE2, PE3, PE4},
-};
+use hal::gpio::{AnyPin, Input, PinExt};
+use hal::pac::{self, interrupt};
 
 /// 长按识别阈值（毫秒）。
 pub const LONG_PRESS_MS: u32 = 500;
 
-// 轻量防抖与轮询步进，保持阻塞时间可控。
+// 防抖：按下不足该时长即松开视为抖动；等待全部松开时的轮询步进。
 const DEBOUNCE_MS: u32 = 20;
 const POLL_INTERVAL_MS: u32 = 10;
+// 自动连发间隔：按住超过长按阈值后，每隔该时长重复上报一次短按。
+const REPEAT_INTERVAL_MS: u32 = 120;
+// 电平变化队列容量：一次按键连抖动约十来次变化，够一整帧里按好几下。
+const EDGE_QUEUE_LEN: usize = 32;
+// 回放产生、尚未被取走的事件数上限。
+const QUEUED_EVENTS: usize = 8;
+
+/// 中断记下的电平变化：毫秒时间戳的低 29 位 << 3 | 键序号 << 1 | 是否按下。
+static EDGES: WordQueue<EDGE_QUEUE_LEN> = WordQueue::new();
+/// 各键的 EXTI 线（见 `pack_line`），`listen` 时写入，中断只读；0 表示未配置。
+static LINES: [AtomicU32; 4] = [const { AtomicU32::new(0) }; 4];
+/// 中断最近一次入队的各键电平，第 i 位为 KEY(i+1) 按下；只由中断写。
+static LAST_LEVELS: AtomicU8 = AtomicU8::new(0);
 
-/// 板载四个按键（上拉，按下为低电平）。
+/// 板载四个按键 KEY1..KEY4，引脚与有效电平由板型配置给出（见 `crate::board_profile`）。
+///
+/// KEY3/KEY4 没有长按功能，按住后自动连发短按，便于连续移动光标。
 pub struct Buttons {
-    key1: PE2<Input>,
-    key2: PE3<Input>,
-    key3: PE4<Input>,
-    key4: PA0<Input>,
-    key1_long_latched: bool,
-    key2_long_latched: bool,
-    key3_long_latched: bool,
-    key4_long_latched: bool,
+    /// 依次对应 KEY1..KEY4。
+    keys: [AnyPin<Input>; 4],
+    /// 按下为低电平（上拉）；否则按下为高电平（下拉）。
+    active_low: [bool; 4],
+    states: [KeyState; 4],
+    /// 回放电平变化时产生、尚未被取走的事件（键序号, 种类），按发生先后排列。
+    queued: [(usize, PressKind); QUEUED_EVENTS],
+    queued_len: usize,
+}
+
+/// 单个按键的按住状态。
+#[derive(Clone, Copy, Default)]
+struct KeyState {
+    /// 按下时刻（ms）；松开时为 None。
+    down_since: Option<u32>,
+    /// 本次按住已上报长按（或连发）的最近时刻；松开前不再报短按。
+    fired_at: Option<u32>,
+    /// 本次按住已作为组合键的一部分触发，松开前不再上报任何事件。
+    chorded: bool,
+    /// 最近一次喂给状态机的时刻；回放的变化早于它时按它计，按住时长不会倒退。
+    sampled_ms: u32,
+}
+
+impl KeyState {
+    /// 在 `at_ms` 时刻的电平喂给状态机：KEY1/KEY2 为长按键，KEY3/KEY4 连发。
+    fn feed(&mut self, index: usize, down: bool, at_ms: u32) -> Option<PressKind> {
+        let at_ms = if (at_ms.wrapping_sub(self.sampled_ms) as i32) < 0 {
+            self.sampled_ms
+        } else {
+            at_ms
+        };
+        self.sampled_ms = at_ms;
+        match index {
+            0 | 1 => self.poll(down, at_ms),
+            _ => self.poll_repeating(down, at_ms),
+        }
+    }
+
+    /// 长按键：松开时报短按，按满阈值报一次长按，之后等待松开。
+    fn poll(&mut self, down: bool, now_ms: u32) -> Option<PressKind> {
+        if self.swallow_chord(down) {
+            return None;
+        }
+        let Some(since) = self.down_since else {
+            if down {
+                self.down_since = Some(now_ms);
+            }
+            return None;
+        };
+        let held = now_ms.wrapping_sub(since);
+        if !down {
+            let fired = self.fired_at.is_some();
+            self.release();
+            return (!fired && held >= DEBOUNCE_MS).then_some(PressKind::Short);
+        }
+        if self.fired_at.is_none() && held >= LONG_PRESS_MS {
+            self.fired_at = Some(now_ms);
+            return Some(PressKind::Long);
+        }
+        None
+    }
+
+    /// 连发键：按满阈值立即报一次短按，此后保持按下则按间隔重复。
+    fn poll_repeating(&mut self, down: bool, now_ms: u32) -> Option<PressKind> {
+        if self.swallow_chord(down) {
+            return None;
+        }
+        if let Some(last) = self.fired_at
+            && down
+        {
+            if now_ms.wrapping_sub(last) < REPEAT_INTERVAL_MS {
+                return None;
+            }
+            self.fired_at = Some(now_ms);
+            return Some(PressKind::Short);
+        }
+        match self.poll(down, now_ms) {
+            Some(PressKind::Long) => Some(PressKind::Short),
+            other => other,
+        }
+    }
+
+    /// 组合键按住期间吞掉一切事件，松开后复位。
+    fn swallow_chord(&mut self, down: bool) -> bool {
+        if self.chorded && !down {
+            self.release();
+            return true;
+        }
+        self.chorded
+    }
+
+    /// 松开后复位，保留采样时刻。
+    fn release(&mut self) {
+        *self = KeyState {
+            sampled_ms: self.sampled_ms,
+            ..KeyState::default()
+        };
+    }
+
+    /// 正在等待长按的按住时长；未按下或已触发长按时为 None。
+    fn pending_ms(&self, now_ms: u32) -> Option<u32> {
+        match (self.down_since, self.fired_at) {
+            (Some(since), None) => Some(now_ms.wrapping_sub(since)),
+            _ => None,
+        }
+    }
 }
 
 impl Buttons {
-    pub fn new(key1: PE2<Input>, key2: PE3<Input>, key3: PE4<Input>, key4: PA0<Input>) -> Self {
+    /// `keys` 为 KEY1..KEY4 的引脚及各自是否低电平有效。
+    pub fn new(keys: [(AnyPin<Input>, bool); 4]) -> Self {
         Self {
-            key1,
-            key2,
-            key3,
-            key4,
-            key1_long_latched: false,
-            key2_long_latched: false,
-            key3_long_latched: false,
-            key4_long_latched: false,
+            active_low: keys.each_ref().map(|(_, active_low)| *active_low),
+            keys: keys.map(|(pin, _)| pin),
+            states: [KeyState::default(); 4],
+            queued: [(0, PressKind::Short); QUEUED_EVENTS],
+            queued_len: 0,
         }
     }
 
+    /// 开启按键电平变化中断（按下、松开两个方向都触发），此后按键边沿由中断入队。
+    pub fn listen(&mut self, syscfg: &pac::SYSCFG, exti: &pac::EXTI, nvic: &mut NVIC) {
+        let lines = self.wake_lines();
+        for (slot, line) in LINES.iter().zip(&lines) {
+            slot.store(pack_line(line), Ordering::Release);
+        }
+        exti::enable_key_interrupts(syscfg, exti, &lines);
+        for line in &lines {
+            // SAFETY: 在启用中断前设置优先级；处理函数只访问原子量、EXTI 挂起位与 GPIO 输入寄存器。
+            unsafe {
+                nvic.set_priority(line.interrupt(), priority::KEYS);
+                NVIC::unmask(line.interrupt());
+            }
+        }
+    }
+
+    /// 按键唤醒所用的 EXTI 线（见 `drivers::power`）。
+    pub fn wake_lines(&self) -> [KeyLine; 4] {
+        core::array::from_fn(|i| KeyLine {
+            port: self.keys[i].port_id(),
+            pin: self.keys[i].pin_id(),
+            falling: self.active_low[i],
+        })
+    }
+
     #[inline]
-    pub fn key1_press(&mut self, delay: &mut Delay) -> Option<PressKind> {
-        detect_press(&mut self.key1, &mut self.key1_long_latched, delay)
+    pub fn key1_press(&mut self, main: &MainLoop, now_ms: u32) -> Option<PressKind> {
+        self.press(0, main, now_ms)
     }
 
     #[inline]
-    pub fn key2_press(&mut self, delay: &mut Delay) -> Option<PressKind> {
-        detect_press(&mut self.key2, &mut self.key2_long_latched, delay)
+    pub fn key2_press(&mut self, main: &MainLoop, now_ms: u32) -> Option<PressKind> {
+        self.press(1, main, now_ms)
     }
 
     #[inline]
-    pub fn key3_press(&mut self, delay: &mut Delay) -> Option<PressKind> {
-        detect_press(&mut self.key3, &mut self.key3_long_latched, delay)
+    pub fn key3_press(&mut self, main: &MainLoop, now_ms: u32) -> Option<PressKind> {
+        self.press(2, main, now_ms)
     }
 
     #[inline]
-    pub fn key4_press(&mut self, delay: &mut Delay) -> Option<PressKind> {
-        detect_press(&mut self.key4, &mut self.key4_long_latched, delay)
+    pub fn key4_press(&mut self, main: &MainLoop, now_ms: u32) -> Option<PressKind> {
+        self.press(3, main, now_ms)
+    }
+
+    /// 先回放中断记下的电平变化，本键有积压的事件就先报最早的一个，否则按当前电平轮询。
+    fn press(&mut self, index: usize, main: &MainLoop, now_ms: u32) -> Option<PressKind> {
+        self.replay_edges(main, now_ms);
+        if let Some(kind) = self.take_queued(index) {
+            return Some(kind);
+        }
+        let down = self.is_down(index);
+        self.states[index].feed(index, down, now_ms)
+    }
+
+    /// 按时间顺序把中断记下的电平变化喂给各键的状态机，产生的事件留待各键取走。
+    fn replay_edges(&mut self, main: &MainLoop, now_ms: u32) {
+        while let Some(word) = EDGES.pop(main) {
+            let index = (word >> 1 & 0b11) as usize;
+            let down = word & 1 != 0;
+            // 时间戳只存了毫秒数的低 29 位，按与现在的差还原。
+            let age = (now_ms << 3).wrapping_sub(word & !0b111) >> 3;
+            if let Some(kind) = self.states[index].feed(index, down, now_ms.wrapping_sub(age)) {
+                self.queue(index, kind);
+            }
+        }
+    }
+
+    /// 积压满时丢掉新事件：来不及处理的连按本就只会被当作一次。
+    fn queue(&mut self, index: usize, kind: PressKind) {
+        if self.queued_len < QUEUED_EVENTS {
+            self.queued[self.queued_len] = (index, kind);
+            self.queued_len += 1;
+        }
+    }
+
+    fn take_queued(&mut self, index: usize) -> Option<PressKind> {
+        let pending = &mut self.queued[..self.queued_len];
+        let pos = pending.iter().position(|&(key, _)| key == index)?;
+        let kind = pending[pos].1;
+        pending.copy_within(pos + 1.., pos);
+        self.queued_len -= 1;
+        Some(kind)
+    }
+
+    /// KEY1 与 KEY4 同时按下（帧耗时叠加层）时返回 true，每次按住只报一次；
+    /// 两键本次按住的短按、长按与连发一律作废。
+    pub fn key1_key4_chord(&mut self, now_ms: u32) -> bool {
+        self.chord([0, 3], now_ms)
+    }
+
+    /// KEY2 与 KEY3 同时按下（屏幕控制台），规则同 [`key1_key4_chord`](Self::key1_key4_chord)。
+    pub fn key2_key3_chord(&mut self, now_ms: u32) -> bool {
+        self.chord([1, 2], now_ms)
+    }
+
+    fn chord(&mut self, keys: [usize; 2], now_ms: u32) -> bool {
+        if !keys.iter().all(|&i| self.is_down(i)) {
+            return false;
+        }
+        if keys.iter().any(|&i| self.states[i].chorded) {
+            return false;
+        }
+        for i in keys {
+            let state = &mut self.states[i];
+            state.down_since.get_or_insert(now_ms);
+            state.fired_at = Some(now_ms);
+            state.chorded = true;
+        }
+        true
+    }
+
+    /// KEY1..KEY4 各自朝长按阈值已按住的毫秒数（以上次轮询时的状态计）；
+    /// 未按下、已触发长按或连发时为 None。
+    pub fn held_ms(&self, now_ms: u32) -> [Option<u32>; 4] {
+        self.states.map(|state| state.pending_ms(now_ms))
+    }
+
+    /// 等待四个按键全部松开（含防抖），如从睡眠唤醒后吞掉唤醒用的那次按键。
+    pub fn wait_release(&mut self, main: &MainLoop, delay: &mut Delay) {
+        loop {
+            while self.any_down() {
+                delay.ms(POLL_INTERVAL_MS);
+            }
+            delay.ms(DEBOUNCE_MS);
+            if !self.any_down() {
+                // 睡眠期间关掉了松开沿，中断记下的电平可能还停在按下：软件触发一次按键线，
+                // 让中断按当前电平补记，再连同积压的变化一起丢掉。
+                let mask = line_mask(&configured_lines());
+                // SAFETY: SWIER 写 1 只挂起这几条按键线，处理函数即 `on_key_edge`。
+                unsafe { (*pac::EXTI::ptr()).swier().write(|w| w.bits(mask)) };
+                cortex_m::asm::dsb();
+                cortex_m::asm::isb();
+                while EDGES.pop(main).is_some() {}
+                self.states = [KeyState::default(); 4];
+                self.queued_len = 0;
+                return;
+            }
+        }
+    }
+
+    fn is_down(&mut self, index: usize) -> bool {
+        let pin = &mut self.keys[index];
+        let level = if self.active_low[index] {
+            pin.is_low()
+        } else {
+            pin.is_high()
+        };
+        level.unwrap_or(false)
+    }
+
+    fn any_down(&mut self) -> bool {
+        (0..self.keys.len()).any(|i| self.is_down(i))
     }
 
     #[inline]
-    pub fn key1_pressed(&mut self, delay: &mut Delay) -> bool {
-        matches!(self.key1_press(delay), Some(PressKind::Short))
+    pub fn key1_pressed(&mut self, main: &MainLoop, now_ms: u32) -> bool {
+        matches!(self.key1_press(main, now_ms), Some(PressKind::Short))
     }
 
     #[inline]
-    pub fn key2_pressed(&mut self, delay: &mut Delay) -> bool {
-        matches!(self.key2_press(delay), Some(PressKind::Short))
+    pub fn key2_pressed(&mut self, main: &MainLoop, now_ms: u32) -> bool {
+        matches!(self.key2_press(main, now_ms), Some(PressKind::Short))
     }
 
     #[inline]
-    pub fn key3_pressed(&mut self, delay: &mut Delay) -> bool {
-        matches!(self.key3_press(delay), Some(PressKind::Short))
+    pub fn key3_pressed(&mut self, main: &MainLoop, now_ms: u32) -> bool {
+        matches!(self.key3_press(main, now_ms), Some(PressKind::Short))
     }
 
     #[inline]
-    pub fn key4_pressed(&mut self, delay: &mut Delay) -> bool {
-        matches!(self.key4_press(delay), Some(PressKind::Short))
+    pub fn key4_pressed(&mut self, main: &MainLoop, now_ms: u32) -> bool {
+        matches!(self.key4_press(main, now_ms), Some(PressKind::Short))
     }
 
     #[inline]
-    pub fn key1_long_pressed(&mut self, delay: &mut Delay) -> bool {
-        matches!(self.key1_press(delay), Some(PressKind::Long))
+    pub fn key1_long_pressed(&mut self, main: &MainLoop, now_ms: u32) -> bool {
+        matches!(self.key1_press(main, now_ms), Some(PressKind::Long))
     }
 
     #[inline]
-    pub fn key2_long_pressed(&mut self, delay: &mut Delay) -> bool {
-        matches!(self.key2_press(delay), Some(PressKind::Long))
+    pub fn key2_long_pressed(&mut self, main: &MainLoop, now_ms: u32) -> bool {
+        matches!(self.key2_press(main, now_ms), Some(PressKind::Long))
     }
 
     #[inline]
-    pub fn key3_long_pressed(&mut self, delay: &mut Delay) -> bool {
-        matches!(self.key3_press(delay), Some(PressKind::Long))
+    pub fn key3_long_pressed(&mut self, main: &MainLoop, now_ms: u32) -> bool {
+        matches!(self.key3_press(main, now_ms), Some(PressKind::Long))
     }
 
     #[inline]
-    pub fn key4_long_pressed(&mut self, delay: &mut Delay) -> bool {
-        matches!(self.key4_press(delay), Some(PressKind::Long))
+    pub fn key4_long_pressed(&mut self, main: &MainLoop, now_ms: u32) -> bool {
+        matches!(self.key4_press(main, now_ms), Some(PressKind::Long))
     }
 }
 
@@ -107,42 +360,62 @@ pub enum PressKind {
     Long,
 }
 
-fn detect_press<P: InputPin>(
-    pin: &mut P,
-    long_latched: &mut bool,
-    delay: &mut Delay,
-) -> Option<PressKind> {
-    // 若已报告长按且仍保持按下，不重复触发，等待松手后复位。
-    if *long_latched && is_low(pin) {
-        return None;
-    }
+/// 把按键 EXTI 线压成一个字存进 `LINES`：端口号 | 引脚号 << 8 | 下降沿 << 16 | 有效位。
+fn pack_line(line: &KeyLine) -> u32 {
+    line.port as u32 | (line.pin as u32) << 8 | (line.falling as u32) << 16 | 1 << 24
+}
 
-    if !is_low(pin) {
-        *long_latched = false;
-        return None;
-    }
+fn unpack_line(word: u32) -> Option<KeyLine> {
+    (word & 1 << 24 != 0).then_some(KeyLine {
+        port: word as u8,
+        pin: (word >> 8) as u8,
+        falling: word & 1 << 16 != 0,
+    })
+}
 
-    // 简单防抖。
-    delay.ms(DEBOUNCE_MS);
-    if !is_low(pin) {
-        return None;
-    }
+fn configured_lines() -> [Option<KeyLine>; 4] {
+    LINES
+        .each_ref()
+        .map(|slot| unpack_line(slot.load(Ordering::Acquire)))
+}
 
-    let mut held_ms = 0;
-    while is_low(pin) {
-        if held_ms >= LONG_PRESS_MS {
-            *long_latched = true;
-            return Some(PressKind::Long);
-        }
+fn line_mask(lines: &[Option<KeyLine>]) -> u32 {
+    lines
+        .iter()
+        .flatten()
+        .fold(0, |mask, line| mask | 1 << line.pin)
+}
 
-        delay.ms(POLL_INTERVAL_MS);
-        held_ms += POLL_INTERVAL_MS;
+/// 按键线的 EXTI 中断：先清挂起位再读引脚，读后的变化会再次挂起；
+/// 各键电平与上次入队的不同就连同时刻入队。队列满时只丢这次变化，主循环读引脚时会补上。
+fn on_key_edge(isr: &Isr) {
+    let lines = configured_lines();
+    let mask = line_mask(&lines);
+    // SAFETY: PR 写 1 只清这几条线的挂起位，其余线不受影响。
+    unsafe { (*pac::EXTI::ptr()).pr().write(|w| w.bits(mask)) };
+    let now_ms = timebase::uptime_ms();
+    let mut levels = LAST_LEVELS.load(Ordering::Relaxed);
+    for (i, line) in lines.iter().enumerate() {
+        let Some(line) = line else { continue };
+        let down = line.is_down();
+        if down != (levels & 1 << i != 0) {
+            levels ^= 1 << i;
+            EDGES.push(isr, now_ms << 3 | (i as u32) << 1 | down as u32);
+        }
     }
-
-    Some(PressKind::Short)
+    LAST_LEVELS.store(levels, Ordering::Relaxed);
 }
 
-#[inline]
-fn is_low<P: InputPin>(pin: &mut P) -> bool {
-    pin.is_low().unwrap_or(false)
+/// 各按键线可能落在的 EXTI 中断，同一优先级，互不抢占。
+macro_rules! key_edge_handlers {
+    ($($name:ident),*) => {$(
+        #[interrupt]
+        fn $name() {
+            // SAFETY: 凭据只在本处理函数内使用。
+            let isr = unsafe { Isr::enter() };
+            on_key_edge(&isr);
+        }
+    )*};
 }
+
+key_edge_handlers!(EXTI0, EXTI1, EXTI2, EXTI3, EXTI4, EXTI9_5, EXTI15_10);
@@ -0,0 +1,90 @@
+//! 熵源：ADC1 以最短采样时间读取悬空引脚（PC1/PC2，板上未接外设）与片内温度传感器，
+//! 取最低几位噪声，连同 SysTick 与 TIM5 计数的时序抖动混入一个 4 字的小熵池。
+//! 需要“随机”的功能（马步训练出题、AI 开局选线）从这里取数，复位后不会重复同一序列。
+//!
+//! 这不是密码学意义的随机数：只保证每次上电、每局之间足够不同。
+
+use crate::drivers::timebase::Timebase;
+use crate::hal;
+use cortex_m::peripheral::SYST;
+use hal::adc::config::{AdcConfig, SampleTime};
+use hal::adc::{Adc, Temperature};
+use hal::gpio::{Analog, PC1, PC2};
+use hal::pac;
+
+/// 上电时收集的采样轮数；每轮读三个通道。
+const BOOT_ROUNDS: u32 = 64;
+/// 每次取数前补充的采样轮数。
+const DRAW_ROUNDS: u32 = 2;
+/// 每个 ADC 读数保留的低位（噪声主要在这几位）。
+const NOISE_MASK: u16 = 0x000F;
+
+pub struct Entropy {
+    adc: Adc<pac::ADC1>,
+    floating: (PC1<Analog>, PC2<Analog>),
+    pool: [u32; 4],
+    cursor: usize,
+}
+
+impl Entropy {
+    pub fn new(
+        adc1: pac::ADC1,
+        floating: (PC1<Analog>, PC2<Analog>),
+        rcc: &mut hal::rcc::Rcc,
+        timebase: &Timebase,
+    ) -> Self {
+        let mut adc = Adc::new(adc1, true, AdcConfig::default(), rcc);
+        adc.enable_temperature_and_vref();
+        let mut entropy = Entropy {
+            adc,
+            floating,
+            pool: [0x6A09_E667, 0xBB67_AE85, 0x3C6E_F372, 0xA54F_F53A],
+            cursor: 0,
+        };
+        entropy.harvest(BOOT_ROUNDS, timebase);
+        entropy
+    }
+
+    /// 采样 `rounds` 轮噪声混入熵池。
+    pub fn harvest(&mut self, rounds: u32, timebase: &Timebase) {
+        for _ in 0..rounds {
+            let a = self.adc.convert(&self.floating.0, SampleTime::Cycles_3) & NOISE_MASK;
+            let b = self.adc.convert(&self.floating.1, SampleTime::Cycles_3) & NOISE_MASK;
+            let t = self.adc.convert(&Temperature, SampleTime::Cycles_3) & NOISE_MASK;
+            let noise = (a as u32) | (b as u32) << 4 | (t as u32) << 8;
+            // SysTick 只在延时期间计数，TIM5 始终运行，两者的抖动一并混入。
+            let jitter = SYST::get_current() ^ timebase.now_ticks().rotate_left(12);
+            self.mix(noise ^ jitter.rotate_left(16));
+        }
+    }
+
+    /// 供电电压 VDDA（毫伏）：读片内参考电压 VREFINT，按出厂校准值换算。ADC1 归熵源所有，
+    /// 供电监测（见 `drivers::battery`）经这里读取。
+    pub fn supply_mv(&mut self) -> u32 {
+        self.adc.calibrate();
+        self.adc.reference_voltage()
+    }
+
+    /// 取一个 32 位随机数；每次先补充少量新采样。
+    pub fn next_u32(&mut self, timebase: &Timebase) -> u32 {
+        self.harvest(DRAW_ROUNDS, timebase);
+        let out = scramble(self.pool[self.cursor] ^ self.pool[(self.cursor + 2) & 3]);
+        self.mix(out);
+        out
+    }
+
+    fn mix(&mut self, value: u32) {
+        let next = (self.cursor + 1) & 3;
+        self.pool[next] = scramble(self.pool[next] ^ self.pool[self.cursor].rotate_left(7) ^ value);
+        self.cursor = next;
+    }
+}
+
+/// 32 位整数散列（lowbias32），把少量输入位扩散到整个字。
+fn scramble(mut x: u32) -> u32 {
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7FEB_352D);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846C_A68B);
+    x ^ x >> 16
+}
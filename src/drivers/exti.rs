@@ -1,4 +1,5 @@
-//! 按键外部中断配置（PE2 -> EXTI2, PA0 -> EXTI0），参考实验2。
+//! 按键外部中断与唤醒事件配置。中断部分（PE2 -> EXTI2, PA0 -> EXTI0）参考实验2；
+//! 按键电平变化中断与唤醒事件按板型配置的按键引脚设置（见 [`KeyLine`]）。
 
 use crate::hal;
 use hal::pac;
@@ -25,3 +26,111 @@ pub fn configure_exti_for_keys(syscfg: &mut pac::SYSCFG, exti: &mut pac::EXTI) {
 pub fn clear_key_pending(exti: &mut pac::EXTI) {
     exti.pr().write(|w| w.pr0().bit(true).pr2().bit(true));
 }
+
+/// 按键所在的 EXTI 线：端口号（0 为 GPIOA）、引脚号（即线号），按下是否为下降沿。
+#[derive(Clone, Copy)]
+pub struct KeyLine {
+    pub port: u8,
+    pub pin: u8,
+    pub falling: bool,
+}
+
+impl KeyLine {
+    /// 本线所属的 NVIC 中断：线 0~4 各有一个，5~9、10~15 分别共用一个。
+    pub fn interrupt(self) -> pac::Interrupt {
+        match self.pin {
+            0 => pac::Interrupt::EXTI0,
+            1 => pac::Interrupt::EXTI1,
+            2 => pac::Interrupt::EXTI2,
+            3 => pac::Interrupt::EXTI3,
+            4 => pac::Interrupt::EXTI4,
+            5..=9 => pac::Interrupt::EXTI9_5,
+            _ => pac::Interrupt::EXTI15_10,
+        }
+    }
+
+    /// 中断里读引脚用：端口输入数据寄存器中本键的电平是否为按下。
+    pub fn is_down(self) -> bool {
+        // SAFETY: 只读 IDR，不影响其他代码对该端口的使用。
+        let bits = unsafe {
+            match self.port {
+                0 => (*pac::GPIOA::ptr()).idr().read().bits(),
+                1 => (*pac::GPIOB::ptr()).idr().read().bits(),
+                2 => (*pac::GPIOC::ptr()).idr().read().bits(),
+                3 => (*pac::GPIOD::ptr()).idr().read().bits(),
+                4 => (*pac::GPIOE::ptr()).idr().read().bits(),
+                5 => (*pac::GPIOF::ptr()).idr().read().bits(),
+                6 => (*pac::GPIOG::ptr()).idr().read().bits(),
+                7 => (*pac::GPIOH::ptr()).idr().read().bits(),
+                _ => (*pac::GPIOI::ptr()).idr().read().bits(),
+            }
+        };
+        let high = bits & 1 << self.pin != 0;
+        high != self.falling
+    }
+}
+
+/// 把本线接到按键所在的端口。
+fn select_port(syscfg: &pac::SYSCFG, key: KeyLine) {
+    // 每个 EXTICR 寄存器管 4 条线，每线 4 位端口选择。
+    let shift = (key.pin % 4) * 4;
+    let select = |bits: u32| bits & !(0xF << shift) | (key.port as u32) << shift;
+    // SAFETY: 只改写本线的端口选择位，端口号来自实际引脚。
+    unsafe {
+        match key.pin / 4 {
+            0 => syscfg.exticr1().modify(|r, w| w.bits(select(r.bits()))),
+            1 => syscfg.exticr2().modify(|r, w| w.bits(select(r.bits()))),
+            2 => syscfg.exticr3().modify(|r, w| w.bits(select(r.bits()))),
+            _ => syscfg.exticr4().modify(|r, w| w.bits(select(r.bits()))),
+        };
+    }
+}
+
+/// 把按键所在的 EXTI 线配置为双沿中断，按下与松开都进中断（见 `drivers::button`）。
+/// 只配置 EXTI，NVIC 由调用方在设好优先级后开启。
+pub fn enable_key_interrupts(syscfg: &pac::SYSCFG, exti: &pac::EXTI, keys: &[KeyLine]) {
+    let bits = keys.iter().fold(0u32, |bits, key| bits | 1 << key.pin);
+    for &key in keys {
+        select_port(syscfg, key);
+    }
+    // SAFETY: 只置位/清除这几条线的触发沿、挂起与中断屏蔽位；PR 写 1 清除。
+    unsafe {
+        exti.rtsr().modify(|r, w| w.bits(r.bits() | bits));
+        exti.ftsr().modify(|r, w| w.bits(r.bits() | bits));
+        exti.pr().write(|w| w.bits(bits));
+        exti.imr().modify(|r, w| w.bits(r.bits() | bits));
+    }
+}
+
+/// 把按键所在的 EXTI 线配置为按下沿（低电平有效的键为下降沿）唤醒事件。
+/// 只开事件屏蔽位（EMR），供 STOP 模式下的 WFE 唤醒使用；睡眠期间关掉松开沿，
+/// 松开或抖动不会把刚睡下的板子唤醒。
+pub fn enable_key_wake_events(syscfg: &pac::SYSCFG, exti: &pac::EXTI, keys: &[KeyLine]) {
+    for &key in keys {
+        select_port(syscfg, key);
+        let bit = 1 << key.pin;
+        // SAFETY: 只改写单条线的触发沿与事件屏蔽位。
+        unsafe {
+            if key.falling {
+                exti.ftsr().modify(|r, w| w.bits(r.bits() | bit));
+                exti.rtsr().modify(|r, w| w.bits(r.bits() & !bit));
+            } else {
+                exti.rtsr().modify(|r, w| w.bits(r.bits() | bit));
+                exti.ftsr().modify(|r, w| w.bits(r.bits() & !bit));
+            }
+            exti.emr().modify(|r, w| w.bits(r.bits() | bit));
+        }
+    }
+}
+
+/// 关闭按键唤醒事件、恢复双沿触发并清 pending，运行中按键只产生电平变化中断。
+pub fn disable_key_wake_events(exti: &pac::EXTI, keys: &[KeyLine]) {
+    let bits = keys.iter().fold(0u32, |bits, key| bits | 1 << key.pin);
+    // SAFETY: 只改写这几条线的事件屏蔽位与触发沿；PR 写 1 清除。
+    unsafe {
+        exti.emr().modify(|r, w| w.bits(r.bits() & !bits));
+        exti.rtsr().modify(|r, w| w.bits(r.bits() | bits));
+        exti.ftsr().modify(|r, w| w.bits(r.bits() | bits));
+        exti.pr().write(|w| w.bits(bits));
+    }
+}
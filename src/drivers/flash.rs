@@ -0,0 +1,89 @@
+//! 片上 Flash 存档区：最后两个 128 KB 扇区（10、11，0x080C_0000 起）留作对局存档，
+//! 其前的扇区 9（0x080A_0000 起）存放外观包，memory.x 相应把程序区缩短到 640K。这里只提供
+//! 按区内偏移读取、编程与整扇区擦除，存档格式与轮转规则见 `game::archive`，外观包见
+//! `crate::packs`。
+
+use crate::hal;
+use hal::flash::{Error, FlashExt, LockedFlash};
+use hal::pac;
+
+/// 片上 Flash 的起始地址。
+const FLASH_ADDRESS: usize = 0x0800_0000;
+/// 存档区相对 Flash 起始的偏移。
+const REGION_OFFSET: usize = 0xC_0000;
+/// 存档区第一个扇区的编号（F407：0–3 为 16 KB，4 为 64 KB，5–11 为 128 KB）。
+const FIRST_SECTOR: u8 = 10;
+pub const SECTOR_SIZE: usize = 128 * 1024;
+pub const SECTOR_COUNT: usize = 2;
+/// 外观包区的起始地址、扇区编号与大小。
+const PACK_ADDRESS: usize = 0x080A_0000;
+const PACK_SECTOR: u8 = 9;
+pub const PACK_AREA_SIZE: usize = 128 * 1024;
+
+pub struct GameFlash {
+    flash: LockedFlash,
+}
+
+impl GameFlash {
+    pub fn new(flash: pac::FLASH) -> Self {
+        GameFlash {
+            flash: LockedFlash::new(flash),
+        }
+    }
+
+    /// 存档区内 `offset` 起 `len` 字节（内存映射，直接读取）。
+    pub fn read(&self, offset: usize, len: usize) -> &[u8] {
+        &self.flash.read()[REGION_OFFSET + offset..][..len]
+    }
+
+    /// 整个外观包区（内存映射）。外观包的配色与位图直接在 Flash 中引用，因此返回 `'static`；
+    /// 区内只在 `erase_packs` 时改写已有内容，调用方须先丢弃引用（见 `crate::packs`）。
+    pub fn packs(&self) -> &'static [u8] {
+        // SAFETY: 地址与长度正是片上 Flash 的扇区 9，始终映射可读；memory.x 不把它分给程序。
+        unsafe { core::slice::from_raw_parts(PACK_ADDRESS as *const u8, PACK_AREA_SIZE) }
+    }
+
+    /// 擦除外观包区，停顿与存档区擦除相同。
+    pub fn erase_packs(&mut self) -> Result<(), Error> {
+        let result = self.flash.unlocked().erase(PACK_SECTOR);
+        flush_data_cache();
+        result
+    }
+
+    /// 在外观包区 `offset` 处编程；目标字节须已擦除（0xFF）。
+    pub fn program_packs(&mut self, offset: usize, bytes: &[u8]) -> Result<(), Error> {
+        let result = self
+            .flash
+            .unlocked()
+            .program(PACK_ADDRESS - FLASH_ADDRESS + offset, bytes.iter());
+        flush_data_cache();
+        result
+    }
+
+    /// 擦除存档区第 `index` 个扇区。擦除期间 CPU 取指会停顿约 1–2 秒。
+    pub fn erase_sector(&mut self, index: usize) -> Result<(), Error> {
+        let result = self.flash.unlocked().erase(FIRST_SECTOR + index as u8);
+        flush_data_cache();
+        result
+    }
+
+    /// 在存档区 `offset` 处编程；目标字节须已擦除（0xFF）。
+    pub fn program(&mut self, offset: usize, bytes: &[u8]) -> Result<(), Error> {
+        let result = self
+            .flash
+            .unlocked()
+            .program(REGION_OFFSET + offset, bytes.iter());
+        flush_data_cache();
+        result
+    }
+}
+
+/// 擦写后复位 Flash 数据缓存，避免随后读到擦写前缓存的旧内容（RM0090 3.5.2）。
+fn flush_data_cache() {
+    // SAFETY: 只改 ACR 的数据缓存位；等待周期等其余字段在时钟初始化后不再变动。
+    let acr = unsafe { &*pac::FLASH::ptr() }.acr();
+    acr.modify(|_, w| w.dcen().clear_bit());
+    acr.modify(|_, w| w.dcrst().set_bit());
+    acr.modify(|_, w| w.dcrst().clear_bit());
+    acr.modify(|_, w| w.dcen().set_bit());
+}
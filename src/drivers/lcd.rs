@@ -1,4 +1,14 @@
-//! SSD1963 LCD 驱动（480x272，FSMC 16bit 并口），参考实验5 C 代码。
+//! SSD1963 LCD 驱动（FSMC 16bit 并口，面板尺寸与时序见 [`Panel`]），参考实验5 C 代码。
+//!
+//! 总线访问集中在模块私有的 `DisplayHandle`：每次 设窗口 + 写像素 的事务，以及运行期的寄存器命令
+//! （调背光、休眠与唤醒），都在临界区内完成，寄存器号与其参数之间不会插进别的总线访问。
+//! `Lcd` 归 `Board` 所有，负责初始化与校准。
+//! `Lcd` 的绘制还受当前裁剪矩形约束（见 [`ClipRect`]），面板内的控件可借此限定绘制范围。
+//! 启用 `framebuffer` 特性时，[`Lcd::offscreen`] 内的绘制先写进 SRAM 中的离屏缓冲，结束后
+//! 经 DMA2 送到屏幕。整块棋盘（272×272×2 字节）放不进 128K SRAM，CCM 又不在 DMA 总线上，
+//! 缓冲因此按格存放，最多 `FRAME_SQUARES` 格：[`Lcd::begin_batch`] 与 [`Lcd::end_batch`]
+//! 之间画的各格先全部画进缓冲，结束时一并经 DMA 连续送屏，同一帧里变化的格子（光标、
+//! 上一步高亮、走子）一起出现，不再逐格可见；一帧超过容量时先送出已画好的部分再接着画。
 
 use crate::hal;
 use core::convert::Infallible;
@@ -6,13 +16,89 @@ use embedded_graphics_core::Pixel;
 use embedded_graphics_core::geometry::Size;
 use embedded_graphics_core::pixelcolor::Rgb565;
 use embedded_graphics_core::prelude::*;
+use embedded_graphics_core::primitives::Rectangle;
 use hal::gpio::{Input, Output, PushPull, Speed, gpiod, gpioe, gpiog};
 use hal::pac;
 
 const LCD_BASE: u32 = 0x6C00_0000 | 0x0000_07FE;
 const SSD1963_ID: u16 = 0x1963;
-const WIDTH: u16 = 480;
-const HEIGHT: u16 = 272;
+/// 背光 PWM 占空比（见 `set_brightness`）：上电时的亮度。
+pub const BRIGHTNESS_FULL: u8 = 0xFE;
+
+/// 面板分辨率与行/场消隐（像素时钟周期数），由板型配置给出（见 `crate::board_profile`）。
+pub struct Panel {
+    pub width: u16,
+    pub height: u16,
+    pub h_back_porch: u16,
+    pub h_front_porch: u16,
+    pub v_back_porch: u16,
+    pub v_front_porch: u16,
+}
+
+/// FSMC 写时序（HCLK 周期数）：地址建立 ADDSET 与数据保持 DATAST。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WriteTiming {
+    pub addset: u8,
+    pub datast: u8,
+}
+
+/// 保守的默认写时序，所有批次面板都能稳定工作。
+const DEFAULT_WRITE_TIMING: WriteTiming = WriteTiming {
+    addset: 9,
+    datast: 8,
+};
+
+/// 校准候选：按总周期数由快到慢排列，末项即默认值。
+const WRITE_TIMING_CANDIDATES: [WriteTiming; 9] = [
+    WriteTiming {
+        addset: 0,
+        datast: 1,
+    },
+    WriteTiming {
+        addset: 0,
+        datast: 2,
+    },
+    WriteTiming {
+        addset: 1,
+        datast: 2,
+    },
+    WriteTiming {
+        addset: 1,
+        datast: 3,
+    },
+    WriteTiming {
+        addset: 2,
+        datast: 3,
+    },
+    WriteTiming {
+        addset: 2,
+        datast: 4,
+    },
+    WriteTiming {
+        addset: 3,
+        datast: 5,
+    },
+    WriteTiming {
+        addset: 5,
+        datast: 6,
+    },
+    DEFAULT_WRITE_TIMING,
+];
+
+/// 校准用 GRAM 区域：首行前 64 像素（随后整屏清除，不影响显示）。
+const PROBE_LEN: usize = 64;
+/// 每个候选重复写读的轮数。
+const PROBE_ROUNDS: usize = 4;
+/// 大块填充/位图按此行数分段加锁，限制关中断的最长时间。
+const ROWS_PER_LOCK: u16 = 16;
+/// 同色填充每轮循环连写的像素数。
+const FILL_UNROLL: u32 = 8;
+/// 离屏缓冲一次最多攒下的棋盘格数。
+#[cfg(feature = "framebuffer")]
+const FRAME_SQUARES: usize = 16;
+/// 离屏缓冲容量（像素）：`FRAME_SQUARES` 个棋盘格（34×34），约 36 KB。
+#[cfg(feature = "framebuffer")]
+const FRAME_PIXELS: usize = FRAME_SQUARES * 34 * 34;
 
 #[repr(C)]
 struct Regs {
@@ -45,16 +131,121 @@ pub struct LcdPins {
     pub pg12: gpiog::PG12<Input>,
 }
 
-pub struct Lcd {
+/// LCD 总线句柄，只归 `Lcd` 所有，不交给别的上下文（中断里不绘制）。
+///
+/// 绘制方法以事务为单位进入临界区，寄存器号与其参数之间不会插进别的总线访问。
+struct DisplayHandle {
     regs: *mut Regs,
+    width: u16,
+    height: u16,
+}
+
+/// 裁剪矩形（左上角 + 宽高，单位像素）；宽或高为 0 表示空区域。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ClipRect {
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+impl ClipRect {
+    pub const fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        ClipRect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// 两矩形的交集；不相交时返回空区域。
+    pub fn intersect(self, other: ClipRect) -> ClipRect {
+        let x0 = self.x.max(other.x);
+        let y0 = self.y.max(other.y);
+        let x1 = self.right().min(other.right());
+        let y1 = self.bottom().min(other.bottom());
+        ClipRect {
+            x: x0,
+            y: y0,
+            width: x1.saturating_sub(x0 as u32) as u16,
+            height: y1.saturating_sub(y0 as u32) as u16,
+        }
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    pub fn contains(self, x: u16, y: u16) -> bool {
+        x >= self.x && y >= self.y && (x as u32) < self.right() && (y as u32) < self.bottom()
+    }
+
+    /// 第 `y` 行是否落在矩形内（逐行绘制时可整行跳过）。
+    pub fn contains_row(self, y: u16) -> bool {
+        y >= self.y && (y as u32) < self.bottom()
+    }
+
+    // 右/下边界（不含），用 u32 避免 x + width 溢出。
+    fn right(self) -> u32 {
+        self.x as u32 + self.width as u32
+    }
+
+    fn bottom(self) -> u32 {
+        self.y as u32 + self.height as u32
+    }
+}
+
+pub struct Lcd {
+    bus: DisplayHandle,
+    /// 当前裁剪矩形，始终位于屏幕范围内。
+    clip: ClipRect,
     pub width: u16,
     pub height: u16,
     backlight: gpiog::PG6<Output<PushPull>>,
+    panel: &'static Panel,
     _fsmc: pac::FSMC,
+    /// 离屏缓冲；`enable_framebuffer` 之前为 None，`offscreen` 直接绘制。
+    #[cfg(feature = "framebuffer")]
+    frame: Option<Framebuffer>,
+}
+
+/// 离屏缓冲与送屏用的 DMA2。`region` 为正在捕获的屏幕区域，从缓冲的 `used` 处起按其宽度
+/// 行优先存放；`queued` 为已画好、等待送屏的区域及其在缓冲中的起点。
+#[cfg(feature = "framebuffer")]
+struct Framebuffer {
+    pixels: &'static mut [u16; FRAME_PIXELS],
+    region: Option<ClipRect>,
+    queued: [(ClipRect, usize); FRAME_SQUARES],
+    queued_len: usize,
+    used: usize,
+    /// `begin_batch` 之后为 true：画好的区域先排队，`end_batch` 时一并送屏。
+    batching: bool,
+    dma: pac::DMA2,
+}
+
+#[cfg(feature = "framebuffer")]
+impl Framebuffer {
+    /// 正在捕获时把 `r`（已落在捕获区域内）逐行交给 `row` 写入缓冲，返回 true；
+    /// 未捕获时返回 false，由调用方直接画到屏幕。
+    fn capture(&mut self, r: ClipRect, mut row: impl FnMut(usize, &mut [u16])) -> bool {
+        let Some(region) = self.region else {
+            return false;
+        };
+        let stride = region.width as usize;
+        for i in 0..r.height as usize {
+            let start = self.used
+                + (r.y - region.y) as usize * stride
+                + i * stride
+                + (r.x - region.x) as usize;
+            row(i, &mut self.pixels[start..start + r.width as usize]);
+        }
+        true
+    }
 }
 
 impl Lcd {
-    pub fn new(fsmc: pac::FSMC, pins: LcdPins) -> Self {
+    pub fn new(fsmc: pac::FSMC, pins: LcdPins, panel: &'static Panel) -> Self {
         // GPIO 复用为 FSMC AF12，设置为高速（对齐参考 C 工程）
         let mut pd0 = pins.pd0.into_alternate::<12>();
         pd0.set_speed(Speed::VeryHigh);
@@ -122,14 +313,8 @@ impl Lcd {
             w.datlat().bits(0);
             w.accmod().bits(0)
         });
-        // 写时序（较快）
-        f.bwtr4().write(|w| unsafe {
-            w.addset().bits(9);
-            w.addhld().bits(0);
-            w.datast().bits(8);
-            w.busturn().bits(0);
-            w.accmod().bits(0)
-        });
+        // 写时序：先用保守值，init 后可通过 calibrate_write_timing 提速
+        apply_write_timing(DEFAULT_WRITE_TIMING);
         // 基本控制
         f.bcr4().write(|w| unsafe {
             w.muxen().clear_bit(); // 地址数据不复用
@@ -150,11 +335,19 @@ impl Lcd {
         });
 
         Lcd {
-            regs: LCD_BASE as *mut Regs,
-            width: WIDTH,
-            height: HEIGHT,
+            bus: DisplayHandle {
+                regs: LCD_BASE as *mut Regs,
+                width: panel.width,
+                height: panel.height,
+            },
+            width: panel.width,
+            height: panel.height,
+            clip: ClipRect::new(0, 0, panel.width, panel.height),
             backlight,
+            panel,
             _fsmc: fsmc,
+            #[cfg(feature = "framebuffer")]
+            frame: None,
         }
     }
 
@@ -191,43 +384,38 @@ impl Lcd {
         self.write_reg(0x00B0);
         self.write_data(0x0020); // 24位接口
         self.write_data(0x0000); // TFT
-        self.write_data((WIDTH - 1) >> 8);
-        self.write_data((WIDTH - 1) & 0xFF);
-        self.write_data((HEIGHT - 1) >> 8);
-        self.write_data((HEIGHT - 1) & 0xFF);
+        let panel = self.panel;
+        self.write_data((panel.width - 1) >> 8);
+        self.write_data((panel.width - 1) & 0xFF);
+        self.write_data((panel.height - 1) >> 8);
+        self.write_data((panel.height - 1) & 0xFF);
         self.write_data(0x0000);
 
-        let ht = WIDTH + 46 + 210;
+        let ht = panel.width + panel.h_back_porch + panel.h_front_porch;
         self.write_reg(0x00B4); // 水平
         self.write_data((ht - 1) >> 8);
         self.write_data((ht - 1) & 0xFF);
-        self.write_data(46 >> 8);
-        self.write_data(46 & 0xFF);
+        self.write_data(panel.h_back_porch >> 8);
+        self.write_data(panel.h_back_porch & 0xFF);
         self.write_data(0); // 脉宽
         self.write_data(0x00);
         self.write_data(0x00);
         self.write_data(0x00);
 
-        let vt = HEIGHT + 23 + 22;
+        let vt = panel.height + panel.v_back_porch + panel.v_front_porch;
         self.write_reg(0x00B6); // 垂直
         self.write_data((vt - 1) >> 8);
         self.write_data((vt - 1) & 0xFF);
-        self.write_data(23 >> 8);
-        self.write_data(23 & 0xFF);
-        self.write_data(22 - 1);
+        self.write_data(panel.v_back_porch >> 8);
+        self.write_data(panel.v_back_porch & 0xFF);
+        self.write_data(panel.v_front_porch - 1);
         self.write_data(0x00);
         self.write_data(0x00);
 
         // 背光 PWM
         self.write_reg(0x00D0);
         self.write_data(0x000D);
-        self.write_reg(0x00BE);
-        self.write_data(0x0006);
-        self.write_data(0x00FE);
-        self.write_data(0x0001);
-        self.write_data(0x00F0);
-        self.write_data(0x0000);
-        self.write_data(0x0000);
+        self.set_brightness(BRIGHTNESS_FULL);
 
         self.write_reg(0x00B8);
         self.write_data(0x0003);
@@ -244,41 +432,360 @@ impl Lcd {
         self.write_reg(0x0029); // 显示 ON
     }
 
-    pub fn clear(&mut self, color: u16) {
-        self.set_window(0, 0, self.width - 1, self.height - 1);
+    /// 背光亮度：设定控制器 PWM 输出（背光驱动的调光输入）的占空比，0xFF 为常亮。
+    pub fn set_brightness(&mut self, duty: u8) {
+        self.locked(|bus| {
+            bus.write_reg(0x00BE);
+            bus.write_data(0x0006); // PWM 频率
+            bus.write_data(duty as u16);
+            bus.write_data(0x0001); // 由主机设定占空比，不用 DBC
+            bus.write_data(0x00F0);
+            bus.write_data(0x0000);
+            bus.write_data(0x0000);
+        });
+    }
+
+    /// 休眠：关背光与显示，控制器进入 sleep（GRAM 内容保留，PLL 继续运行）。
+    pub fn suspend(&mut self, delay: &mut crate::drivers::delay::Delay) {
+        self.backlight.set_low();
+        self.locked(|bus| {
+            bus.write_reg(0x0028); // 显示 OFF
+            bus.write_reg(0x0010); // enter_sleep_mode
+        });
+        delay.ms(5);
+    }
+
+    /// 唤醒：退出 sleep 后重新打开显示与背光，画面即休眠前的内容。
+    pub fn resume(&mut self, delay: &mut crate::drivers::delay::Delay) {
+        self.locked(|bus| bus.write_reg(0x0011)); // exit_sleep_mode
+        delay.ms(5);
+        self.locked(|bus| bus.write_reg(0x0029)); // 显示 ON
+        self.backlight.set_high();
+    }
+
+    /// 开机校准写时序：从最快候选开始，向 GRAM 写入测试图样再用宽松读时序读回，
+    /// 取最快的稳定配置并再退一档留余量。读不到控制器 ID 或读回不可靠时保持默认值。
+    /// 会覆盖首行像素，调用后应清屏。
+    pub fn calibrate_write_timing(&mut self) -> WriteTiming {
+        if self.read_id() != SSD1963_ID {
+            return DEFAULT_WRITE_TIMING;
+        }
+        // 先在默认时序下确定读回是否带一个空读字，并确认读回本身可用。
+        apply_write_timing(DEFAULT_WRITE_TIMING);
+        let Some(skip) = (0..=1).find(|&skip| self.probe_pattern(0, skip)) else {
+            return DEFAULT_WRITE_TIMING;
+        };
+
+        let fastest = WRITE_TIMING_CANDIDATES.iter().position(|&timing| {
+            apply_write_timing(timing);
+            (0..PROBE_ROUNDS).all(|round| self.probe_pattern(round, skip))
+        });
+        let chosen = match fastest {
+            Some(idx) => WRITE_TIMING_CANDIDATES[(idx + 1).min(WRITE_TIMING_CANDIDATES.len() - 1)],
+            None => DEFAULT_WRITE_TIMING,
+        };
+        apply_write_timing(chosen);
+        chosen
+    }
+
+    /// 写入第 `round` 组图样并读回比较；`skip` 为读回前需丢弃的空读字数。
+    fn probe_pattern(&mut self, round: usize, skip: usize) -> bool {
+        let pattern = |i: usize| -> u16 {
+            // 相邻像素交替取值的两组图样
+            let odd = i & 1 == 1;
+            match round % 4 {
+                0 => 1u16.rotate_left(i as u32), // 走位 1
+                1 => [0xAAAA, 0x5555][odd as usize],
+                2 => [0xFFFF, 0x0000][odd as usize],
+                _ => (i as u16).wrapping_mul(0x9E37) ^ 0x5A5A,
+            }
+        };
+        let xe = PROBE_LEN as u16 - 1;
+        self.set_window(0, 0, xe, 0);
         self.write_reg(0x002C);
-        for _ in 0..(self.width as u32 * self.height as u32) {
-            self.write_data(color);
+        for i in 0..PROBE_LEN {
+            self.write_data(pattern(i));
+        }
+        self.set_window(0, 0, xe, 0);
+        self.write_reg(0x002E); // read_memory_start
+        for _ in 0..skip {
+            let _ = self.read_data();
         }
+        (0..PROBE_LEN).all(|i| self.read_data() == pattern(i))
     }
 
-    /// 单像素绘制（范围外将被忽略）。
+    /// 整屏清除（不受裁剪矩形限制）。
+    pub fn clear(&mut self, color: u16) {
+        self.fill_rect_fast(0, 0, self.width, self.height, color);
+    }
+
+    /// 不经裁剪矩形的同色填充，整块只设一次窗口（见 `DisplayHandle::fill_rect_streamed`）。
+    /// 用于整屏清除等调用方已知范围的大块填充。
+    pub fn fill_rect_fast(&mut self, x: u16, y: u16, width: u16, height: u16, color: u16) {
+        self.bus.fill_rect_streamed(x, y, width, height, color);
+    }
+
+    /// 当前裁剪矩形。
+    pub fn clip(&self) -> ClipRect {
+        self.clip
+    }
+
+    /// 设置裁剪矩形（与屏幕求交），返回原先的矩形以便调用方恢复。
+    pub fn set_clip(&mut self, rect: ClipRect) -> ClipRect {
+        let screen = ClipRect::new(0, 0, self.width, self.height);
+        core::mem::replace(&mut self.clip, rect.intersect(screen))
+    }
+
+    /// 在 `rect` 与当前裁剪矩形的交集内执行绘制，结束后恢复原裁剪矩形。
+    pub fn with_clip<R>(&mut self, rect: ClipRect, draw: impl FnOnce(&mut Lcd) -> R) -> R {
+        let prev = self.clip;
+        self.clip = prev.intersect(rect);
+        let result = draw(self);
+        self.clip = prev;
+        result
+    }
+
+    /// 启用离屏缓冲：从 RAM 取一格大小的缓冲，送屏占用 DMA2 数据流 0。
+    #[cfg(feature = "framebuffer")]
+    pub fn enable_framebuffer(&mut self, dma2: pac::DMA2) {
+        // SAFETY: 只在初始化时置位 DMA2 时钟，与其他外设的时钟位互不影响。
+        unsafe {
+            let rcc = &*pac::RCC::ptr();
+            rcc.ahb1enr().modify(|_, w| w.dma2en().set_bit());
+        }
+        let pixels = cortex_m::singleton!(: [u16; FRAME_PIXELS] = [0; FRAME_PIXELS])
+            .expect("framebuffer already taken");
+        self.frame = Some(Framebuffer {
+            pixels,
+            region: None,
+            queued: [(ClipRect::new(0, 0, 0, 0), 0); FRAME_SQUARES],
+            queued_len: 0,
+            used: 0,
+            batching: false,
+            dma: dma2,
+        });
+    }
+
+    /// 开始攒一帧：此后 `offscreen` 画好的区域先留在缓冲里，到 `end_batch` 再一并送屏。
+    /// 未启用离屏缓冲时什么也不做。
+    pub fn begin_batch(&mut self) {
+        #[cfg(feature = "framebuffer")]
+        if let Some(frame) = self.frame.as_mut() {
+            frame.batching = true;
+        }
+    }
+
+    /// 结束一帧：把攒下的区域依次经 DMA 送屏。
+    pub fn end_batch(&mut self) {
+        #[cfg(feature = "framebuffer")]
+        {
+            self.flush_queued();
+            if let Some(frame) = self.frame.as_mut() {
+                frame.batching = false;
+            }
+        }
+    }
+
+    /// 送出缓冲里排队的全部区域并腾空缓冲。
+    #[cfg(feature = "framebuffer")]
+    fn flush_queued(&mut self) {
+        let Some(frame) = self.frame.as_mut() else {
+            return;
+        };
+        for &(r, start) in &frame.queued[..frame.queued_len] {
+            let len = r.width as usize * r.height as usize;
+            self.bus
+                .blit_dma(&frame.dma, r, &frame.pixels[start..start + len]);
+        }
+        frame.queued_len = 0;
+        frame.used = 0;
+    }
+
+    /// 在 `rect`（与当前裁剪矩形求交）内执行绘制。启用离屏缓冲时先画进缓冲，区域内的各层
+    /// 同时出现：`begin_batch` 与 `end_batch` 之间留到 `end_batch` 与同一帧的其他区域一并
+    /// 送屏（缓冲已满时先送出排队的区域），否则画完立即经 DMA 送屏。`draw` 须铺满整个区域，
+    /// 否则缓冲里的旧像素会一并送出。未启用、已在捕获中或区域超出缓冲容量时，等同于 `with_clip`。
+    pub fn offscreen<R>(&mut self, rect: ClipRect, draw: impl FnOnce(&mut Lcd) -> R) -> R {
+        #[cfg(feature = "framebuffer")]
+        {
+            let r = rect.intersect(self.clip);
+            let len = r.width as usize * r.height as usize;
+            let fits = !r.is_empty() && len <= FRAME_PIXELS;
+            let full = self
+                .frame
+                .as_ref()
+                .is_some_and(|f| f.queued_len == FRAME_SQUARES || f.used + len > FRAME_PIXELS);
+            if fits && full {
+                self.flush_queued();
+            }
+            if let Some(frame) = self.frame.as_mut().filter(|f| fits && f.region.is_none()) {
+                frame.region = Some(r);
+                let result = self.with_clip(r, draw);
+                if let Some(frame) = self.frame.as_mut() {
+                    frame.region = None;
+                    frame.queued[frame.queued_len] = (r, frame.used);
+                    frame.queued_len += 1;
+                    frame.used += len;
+                    if !frame.batching {
+                        self.flush_queued();
+                    }
+                }
+                return result;
+            }
+        }
+        self.with_clip(rect, draw)
+    }
+
+    /// 单像素绘制（裁剪矩形外将被忽略）。
     pub fn draw_pixel(&mut self, x: u16, y: u16, color: u16) {
-        if x >= self.width || y >= self.height {
+        if !self.clip.contains(x, y) {
             return;
         }
-        self.set_window(x, y, x, y);
-        self.write_reg(0x002C);
-        self.write_data(color);
+        #[cfg(feature = "framebuffer")]
+        if let Some(frame) = self.frame.as_mut()
+            && frame.capture(ClipRect::new(x, y, 1, 1), |_, px| px[0] = color)
+        {
+            return;
+        }
+        self.bus.draw_pixel(x, y, color);
     }
 
-    /// 填充指定矩形区域为单一颜色（坐标超出屏幕时自动裁剪）。
+    /// 填充指定矩形区域为单一颜色（按裁剪矩形裁剪）。
     pub fn fill_rect(&mut self, x: u16, y: u16, width: u16, height: u16, color: u16) {
+        let r = ClipRect::new(x, y, width, height).intersect(self.clip);
+        if r.is_empty() {
+            return;
+        }
+        #[cfg(feature = "framebuffer")]
+        if let Some(frame) = self.frame.as_mut()
+            && frame.capture(r, |_, line| line.fill(color))
+        {
+            return;
+        }
+        self.bus.fill_rect(r.x, r.y, r.width, r.height, color);
+    }
+
+    /// 绘制一块 RGB565 位图（行优先数据），按裁剪矩形裁掉四边超出的部分。
+    pub fn blit_bitmap(&mut self, x: u16, y: u16, width: u16, height: u16, pixels: &[u16]) {
+        let full = ClipRect::new(x, y, width, height);
+        let r = full.intersect(self.clip);
+        if r.is_empty() || pixels.len() < width as usize * height as usize {
+            return;
+        }
+        #[cfg(feature = "framebuffer")]
+        if let Some(frame) = self.frame.as_mut() {
+            let (skip_x, skip_y) = ((r.x - x) as usize, (r.y - y) as usize);
+            let captured = frame.capture(r, |row, line| {
+                let start = (skip_y + row) * width as usize + skip_x;
+                line.copy_from_slice(&pixels[start..start + line.len()]);
+            });
+            if captured {
+                return;
+            }
+        }
+        if r == full {
+            self.bus.blit_bitmap(x, y, width, height, pixels);
+            return;
+        }
+        // 左/上被裁掉时源数据不再连续，逐行取可见片段。
+        let (skip_x, skip_y) = ((r.x - x) as usize, (r.y - y) as usize);
+        for row in 0..r.height as usize {
+            let start = (skip_y + row) * width as usize + skip_x;
+            let line = &pixels[start..start + r.width as usize];
+            self.bus
+                .blit_bitmap(r.x, r.y + row as u16, r.width, 1, line);
+        }
+    }
+
+    /// 在一个临界区内完成一串运行期的寄存器命令。
+    fn locked(&self, commands: impl FnOnce(&DisplayHandle)) {
+        cortex_m::interrupt::free(|_| commands(&self.bus));
+    }
+
+    // 以下裸访问不加锁，仅用于初始化与校准；运行期的寄存器命令经 `locked` 发出。
+    fn set_window(&mut self, xs: u16, ys: u16, xe: u16, ye: u16) {
+        self.bus.set_window(xs, ys, xe, ye);
+    }
+
+    fn write_reg(&self, reg: u16) {
+        self.bus.write_reg(reg);
+    }
+
+    fn write_data(&self, data: u16) {
+        self.bus.write_data(data);
+    }
+
+    fn read_data(&self) -> u16 {
+        self.bus.read_data()
+    }
+
+    fn read_id(&self) -> u16 {
+        self.write_reg(0x0000);
+        let _ = self.read_data();
+        let id_high = self.read_data();
+        let id_low = self.read_data();
+        (id_high << 8) | (id_low & 0xFF)
+    }
+}
+
+impl DisplayHandle {
+    /// 单像素绘制（范围外将被忽略）。
+    pub fn draw_pixel(&self, x: u16, y: u16, color: u16) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        cortex_m::interrupt::free(|_| {
+            self.set_window(x, y, x, y);
+            self.write_reg(0x002C);
+            self.write_data(color);
+        });
+    }
+
+    /// 填充指定矩形区域为单一颜色（坐标超出屏幕时自动裁剪）。每段设一次窗口后连续写满，
+    /// 不逐像素设窗口。
+    pub fn fill_rect(&self, x: u16, y: u16, width: u16, height: u16, color: u16) {
         if width == 0 || height == 0 || x >= self.width || y >= self.height {
             return;
         }
         let xe = x.saturating_add(width - 1).min(self.width - 1);
         let ye = y.saturating_add(height - 1).min(self.height - 1);
-        self.set_window(x, y, xe, ye);
-        self.write_reg(0x002C);
-        let pixels = (xe - x + 1) as u32 * (ye - y + 1) as u32;
-        for _ in 0..pixels {
-            self.write_data(color);
+        let mut band_y = y;
+        while band_y <= ye {
+            let band_ye = band_y.saturating_add(ROWS_PER_LOCK - 1).min(ye);
+            let pixels = (xe - x + 1) as u32 * (band_ye - band_y + 1) as u32;
+            cortex_m::interrupt::free(|_| {
+                self.set_window(x, band_y, xe, band_ye);
+                self.write_reg(0x002C);
+                self.write_repeated(color, pixels);
+            });
+            band_y = band_ye + 1;
+        }
+    }
+
+    /// 与 [`fill_rect`](Self::fill_rect) 相同，但整块只设一次窗口、发一次写命令：之后仍按
+    /// `ROWS_PER_LOCK` 行一段进入临界区，只是各段直接接着写像素，不再重设窗口。
+    /// 段与段之间若有别的上下文改了窗口，后面的像素会写错位置；只供独占 LCD 的 `Lcd` 使用。
+    pub fn fill_rect_streamed(&self, x: u16, y: u16, width: u16, height: u16, color: u16) {
+        if width == 0 || height == 0 || x >= self.width || y >= self.height {
+            return;
+        }
+        let xe = x.saturating_add(width - 1).min(self.width - 1);
+        let ye = y.saturating_add(height - 1).min(self.height - 1);
+        let row = (xe - x + 1) as u32;
+        let mut rows = (ye - y + 1) as u32;
+        cortex_m::interrupt::free(|_| {
+            self.set_window(x, y, xe, ye);
+            self.write_reg(0x002C);
+        });
+        while rows > 0 {
+            let band = rows.min(ROWS_PER_LOCK as u32);
+            cortex_m::interrupt::free(|_| self.write_repeated(color, row * band));
+            rows -= band;
         }
     }
 
     /// 绘制一块 RGB565 位图（行优先数据）。
-    pub fn blit_bitmap(&mut self, x: u16, y: u16, width: u16, height: u16, pixels: &[u16]) {
+    pub fn blit_bitmap(&self, x: u16, y: u16, width: u16, height: u16, pixels: &[u16]) {
         if width == 0 || height == 0 || x >= self.width || y >= self.height {
             return;
         }
@@ -288,18 +795,90 @@ impl Lcd {
             return;
         }
 
-        self.set_window(x, y, x + draw_w - 1, y + draw_h - 1);
-        self.write_reg(0x002C);
-        for row in 0..draw_h as usize {
-            let start = row * width as usize;
-            let end = start + draw_w as usize;
-            for &px in &pixels[start..end] {
-                self.write_data(px);
+        let mut band = 0;
+        while band < draw_h {
+            let rows = ROWS_PER_LOCK.min(draw_h - band);
+            cortex_m::interrupt::free(|_| {
+                self.set_window(x, y + band, x + draw_w - 1, y + band + rows - 1);
+                self.write_reg(0x002C);
+                for row in band as usize..(band + rows) as usize {
+                    let start = row * width as usize;
+                    let end = start + draw_w as usize;
+                    for &px in &pixels[start..end] {
+                        self.write_data(px);
+                    }
+                }
+            });
+            band += rows;
+        }
+    }
+
+    /// 把离屏缓冲送到屏幕区域 `r`：设好窗口后由 DMA2 数据流 0 以存储器到存储器方式把像素
+    /// 逐个写进数据口（外设端为缓冲、地址递增，存储器端为固定的数据口）。整个事务在临界区内
+    /// 完成，一格约千余像素，关中断时间与同样大小的 `blit_bitmap` 一段相当。
+    #[cfg(feature = "framebuffer")]
+    fn blit_dma(&self, dma: &pac::DMA2, r: ClipRect, pixels: &[u16]) {
+        let stream = dma.st(0);
+        let data_ptr = (self.regs as *mut u16).wrapping_add(1);
+        cortex_m::interrupt::free(|_| {
+            self.set_window(r.x, r.y, r.x + r.width - 1, r.y + r.height - 1);
+            self.write_reg(0x002C);
+            // SAFETY: 数据流 0 只供这里使用且此前已停下；地址分别指向静态缓冲与 FSMC 数据口，
+            // 传输在本临界区内等到完成，缓冲在此期间不会被改写。
+            unsafe {
+                stream.par().write(|w| w.pa().bits(pixels.as_ptr() as u32));
+                stream.m0ar().write(|w| w.m0a().bits(data_ptr as u32));
+                stream.ndtr().write(|w| w.ndt().bits(pixels.len() as u16));
             }
+            // 存储器到存储器只能走 FIFO，不能用直接模式。
+            stream.fcr().write(|w| w.dmdis().set_bit().fth().full());
+            stream.cr().write(|w| {
+                w.dir().memory_to_memory();
+                w.pinc().incremented();
+                w.minc().fixed();
+                w.psize().bits16();
+                w.msize().bits16();
+                w.en().enabled()
+            });
+            while dma.lisr().read().tcif0().is_not_complete()
+                && dma.lisr().read().teif0().is_no_error()
+            {}
+            dma.lifcr().write(|w| {
+                w.ctcif0()
+                    .clear()
+                    .cteif0()
+                    .clear()
+                    .chtif0()
+                    .clear()
+                    .cfeif0()
+                    .clear()
+            });
+        });
+    }
+
+    /// 向已打开的 GRAM 写窗口连写 `count` 个同色像素。循环展开成每轮 `FILL_UNROLL` 次写，
+    /// 省下大部分计数与跳转，FSMC 写周期得以紧密相接。
+    fn write_repeated(&self, color: u16, count: u32) {
+        let data_ptr = (self.regs as *mut u16).wrapping_add(1);
+        for _ in 0..count / FILL_UNROLL {
+            // SAFETY: `regs` 指向 FSMC 映射的 LCD 数据口，写入只推进 GRAM 地址。
+            unsafe {
+                core::ptr::write_volatile(data_ptr, color);
+                core::ptr::write_volatile(data_ptr, color);
+                core::ptr::write_volatile(data_ptr, color);
+                core::ptr::write_volatile(data_ptr, color);
+                core::ptr::write_volatile(data_ptr, color);
+                core::ptr::write_volatile(data_ptr, color);
+                core::ptr::write_volatile(data_ptr, color);
+                core::ptr::write_volatile(data_ptr, color);
+            }
+        }
+        for _ in 0..count % FILL_UNROLL {
+            self.write_data(color);
         }
     }
 
-    pub fn set_window(&mut self, xs: u16, ys: u16, xe: u16, ye: u16) {
+    fn set_window(&self, xs: u16, ys: u16, xe: u16, ye: u16) {
         self.write_reg(0x002A);
         self.write_data(xs >> 8);
         self.write_data(xs & 0xFF);
@@ -327,19 +906,6 @@ impl Lcd {
         let data_ptr = (self.regs as *mut u16).wrapping_add(1);
         unsafe { core::ptr::read_volatile(data_ptr) }
     }
-
-    fn write_reg_data(&self, reg: u16, data: u16) {
-        self.write_reg(reg);
-        self.write_data(data);
-    }
-
-    fn read_id(&self) -> u16 {
-        self.write_reg(0x0000);
-        let _ = self.read_data();
-        let id_high = self.read_data();
-        let id_low = self.read_data();
-        (id_high << 8) | (id_low & 0xFF)
-    }
 }
 
 impl OriginDimensions for Lcd {
@@ -361,12 +927,18 @@ impl DrawTarget for Lcd {
                 continue;
             }
             let (x, y) = (point.x as u16, point.y as u16);
-            if x >= self.width || y >= self.height {
-                continue;
-            }
-            self.set_window(x, y, x, y);
-            self.write_reg(0x002C);
-            self.write_data(color.into_storage());
+            self.draw_pixel(x, y, color.into_storage());
+        }
+        Ok(())
+    }
+
+    /// 实心矩形走按窗口填充（受裁剪矩形约束），不逐像素经过 `draw_iter`。
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        if let Some(bottom_right) = area.bottom_right() {
+            let (x, y) = (area.top_left.x as u16, area.top_left.y as u16);
+            let (width, height) = (bottom_right.x as u16 - x + 1, bottom_right.y as u16 - y + 1);
+            self.fill_rect(x, y, width, height, color.into_storage());
         }
         Ok(())
     }
@@ -376,3 +948,14 @@ impl DrawTarget for Lcd {
         Ok(())
     }
 }
+
+fn apply_write_timing(timing: WriteTiming) {
+    let f = unsafe { &*pac::FSMC::ptr() };
+    f.bwtr4().write(|w| unsafe {
+        w.addset().bits(timing.addset);
+        w.addhld().bits(0);
+        w.datast().bits(timing.datast);
+        w.busturn().bits(0);
+        w.accmod().bits(0)
+    });
+}
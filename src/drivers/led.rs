@@ -1,40 +1,34 @@
 use crate::hal;
-use hal::gpio::{Output, PushPull, gpiob::PB0, gpiob::PB1, gpioc::PC0, gpiof::PF10};
+use hal::gpio::{AnyPin, Output, PinState, PushPull};
 
-/// 板载四个 LED（低电平点亮）。
+/// 板载 LED，最多四个（LED1..LED4），引脚与点亮电平由板型配置给出（见 `crate::board_profile`）。
+/// 板上不足四个时缺的位置为 None，对它们的操作被忽略。
 pub struct Leds {
-    pub led1: PC0<Output<PushPull>>,
-    pub led2: PF10<Output<PushPull>>,
-    pub led3: PB0<Output<PushPull>>,
-    pub led4: PB1<Output<PushPull>>,
+    pins: [Option<AnyPin<Output<PushPull>>>; 4],
+    active_low: bool,
 }
 
 impl Leds {
-    pub fn new(
-        led1: PC0<Output<PushPull>>,
-        led2: PF10<Output<PushPull>>,
-        led3: PB0<Output<PushPull>>,
-        led4: PB1<Output<PushPull>>,
-    ) -> Self {
-        Self {
-            led1,
-            led2,
-            led3,
-            led4,
-        }
+    pub fn new(pins: [Option<AnyPin<Output<PushPull>>>; 4], active_low: bool) -> Self {
+        Self { pins, active_low }
     }
 
     pub fn all_off(&mut self) {
-        self.led1.set_high();
-        self.led2.set_high();
-        self.led3.set_high();
-        self.led4.set_high();
+        for index in 0..self.pins.len() {
+            self.set(index, false);
+        }
+    }
+
+    /// 按编号（0..4 对应 LED1..LED4）点亮或熄灭单个 LED。
+    pub fn set(&mut self, index: usize, on: bool) {
+        if let Some(Some(pin)) = self.pins.get_mut(index) {
+            pin.set_state(PinState::from(on != self.active_low));
+        }
     }
 
     pub fn all_toggle(&mut self) {
-        self.led1.toggle();
-        self.led2.toggle();
-        self.led3.toggle();
-        self.led4.toggle();
+        for pin in self.pins.iter_mut().flatten() {
+            pin.toggle();
+        }
     }
 }
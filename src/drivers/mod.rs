@@ -1,6 +1,14 @@
+pub mod backup_sram;
+pub mod battery;
 pub mod button;
 pub mod delay;
+pub mod entropy;
 pub mod exti;
+pub mod flash;
 pub mod lcd;
 pub mod led;
+pub mod power;
+pub mod rtc;
 pub mod serial;
+pub mod stack;
+pub mod timebase;
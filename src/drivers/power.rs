@@ -0,0 +1,71 @@
+//! 低功耗：STOP 模式与按键唤醒。
+//!
+//! STOP 期间 HSE/PLL 与全部高速时钟停止，SRAM 与寄存器内容保留；TIM3/TIM5 时基、TIM2 棋钟与 TIM4 看门狗
+//! 随之暂停，因此运行毫秒数、对局用时与双方棋钟都不会把睡眠时间算进去。RTC 由 LSE 驱动，墙上时间照常走。
+//! 唤醒源只有按键的 EXTI 事件（不进中断），醒来后系统先跑在 HSI 上，由 [`Power`] 恢复 168 MHz。
+
+use crate::drivers::exti::{self, KeyLine};
+use crate::hal;
+use cortex_m::peripheral::SCB;
+use hal::pac;
+use hal::syscfg::SysCfg;
+
+pub struct Power {
+    pwr: pac::PWR,
+    syscfg: SysCfg,
+    exti: pac::EXTI,
+    scb: SCB,
+    /// 唤醒用的按键 EXTI 线（见 `Buttons::wake_lines`）。
+    wake_lines: [KeyLine; 4],
+}
+
+impl Power {
+    pub fn new(
+        pwr: pac::PWR,
+        syscfg: SysCfg,
+        exti: pac::EXTI,
+        scb: SCB,
+        wake_lines: [KeyLine; 4],
+    ) -> Self {
+        Power {
+            pwr,
+            syscfg,
+            exti,
+            scb,
+            wake_lines,
+        }
+    }
+
+    /// 进入 STOP，直到任一按键按下；返回前已恢复 HSE + PLL 系统时钟。
+    /// 调用前应先让外设进入休眠（见 `Board::sleep`）。
+    pub fn stop_until_key(&mut self) {
+        exti::enable_key_wake_events(&self.syscfg, &self.exti, &self.wake_lines);
+        // 调压器低功耗，掉电深睡眠（PDDS）保持关闭，即 STOP 而非 STANDBY。
+        self.pwr
+            .cr()
+            .modify(|_, w| w.pdds().clear_bit().lpds().set_bit());
+        self.scb.set_sleepdeep();
+        // 关中断期间进入：睡前挂起的 TIM3 节拍留到恢复时钟后再处理，不会把 WFE 提前唤醒。
+        cortex_m::interrupt::free(|_| {
+            // 先清掉事件寄存器中可能残留的事件，第二次 WFE 才真正睡眠。
+            cortex_m::asm::sev();
+            cortex_m::asm::wfe();
+            cortex_m::asm::wfe();
+            self.scb.clear_sleepdeep();
+            restore_system_clock();
+        });
+        exti::disable_key_wake_events(&self.exti, &self.wake_lines);
+    }
+}
+
+/// STOP 唤醒后系统时钟回落到 HSI；PLL 配置寄存器保留，只需重新开启 HSE、PLL 并切换。
+fn restore_system_clock() {
+    // SAFETY: 仅在关中断时调用，此时没有其他代码访问 RCC。
+    let rcc = unsafe { &*pac::RCC::ptr() };
+    rcc.cr().modify(|_, w| w.hseon().set_bit());
+    while rcc.cr().read().hserdy().bit_is_clear() {}
+    rcc.cr().modify(|_, w| w.pllon().set_bit());
+    while rcc.cr().read().pllrdy().bit_is_clear() {}
+    rcc.cfgr().modify(|_, w| w.sw().pll());
+    while !rcc.cfgr().read().sws().is_pll() {}
+}
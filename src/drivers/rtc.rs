@@ -0,0 +1,56 @@
+//! 片上 RTC：外部 32.768 kHz LSE 驱动的日历时钟，有纽扣电池时断电后继续走时。
+
+use crate::hal;
+use hal::pac;
+use hal::rtc::Rtc;
+
+/// 日历时间（24 小时制）。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl DateTime {
+    /// 当日零点起的秒数，用于计算同一天内的时间差。
+    pub fn seconds_of_day(&self) -> u32 {
+        self.hour as u32 * 3600 + self.minute as u32 * 60 + self.second as u32
+    }
+}
+
+pub struct WallClock {
+    rtc: Rtc,
+}
+
+impl WallClock {
+    /// 启用 LSE 与 RTC；若备份域已在运行（电池保持），保留原有时间。
+    pub fn new(rtc: pac::RTC, rcc: &mut pac::RCC, pwr: &mut pac::PWR) -> Self {
+        Self {
+            rtc: Rtc::new(rtc, rcc, pwr),
+        }
+    }
+
+    pub fn now(&mut self) -> DateTime {
+        let dt = self.rtc.get_datetime();
+        DateTime {
+            year: dt.year() as u16,
+            month: u8::from(dt.month()),
+            day: dt.day(),
+            hour: dt.hour(),
+            minute: dt.minute(),
+            second: dt.second(),
+        }
+    }
+
+    /// 设置时、分（秒清零），日期保持不变。
+    pub fn set_time_of_day(&mut self, hour: u8, minute: u8) {
+        // 参数已由调用方限定在 0..24 / 0..60，hal 仅在越界时返回错误。
+        let _ = self.rtc.set_hours(hour % 24);
+        let _ = self.rtc.set_minutes(minute % 60);
+        let _ = self.rtc.set_seconds(0);
+    }
+}
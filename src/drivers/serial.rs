@@ -1,15 +1,52 @@
+//! 串口：USART1 是调试/控制台口，发送为阻塞式，接收由中断搬进环形队列，主循环按行
+//! 非阻塞取走，整段粘贴也不会在帧间丢字；USART3 只发送，用于赛事广播。
+//! USART1 收到的命令行与发出的文字同时记入 [`SerialPort::log`]，供屏幕上的控制台视图显示。
+
 use crate::hal;
+use crate::line_log::LineLog;
+use crate::sync::{ByteQueue, Isr, MainLoop, TickCounter, priority};
 use core::fmt;
 
-use hal::pac;
+use cortex_m::peripheral::NVIC;
+use hal::pac::{self, interrupt};
 use hal::prelude::*;
-use hal::serial::{CommonPins, Rx, Serial, Tx, config::Config};
+use hal::serial::{CommonPins, Rx, RxListen, Serial, Tx, config::Config};
 use hal::time::Bps;
 use nb::block;
 
+/// 接收队列容量：主循环一帧（约 30 ms）在 115200 波特下最多到达约 350 字节，
+/// 加上 AI 思考时的较长停顿，512 字节足够容纳一整条粘贴的命令。
+const RX_QUEUE_LEN: usize = 512;
+/// 单行上限（不含行尾）；超长的行整行丢弃。
+pub const LINE_CAP: usize = 128;
+
+static RX_QUEUE: ByteQueue<RX_QUEUE_LEN> = ByteQueue::new();
+/// 硬件溢出（ORE）：中断还没读走上一个字节，下一个已经到达。
+static RX_OVERRUNS: TickCounter = TickCounter::new();
+/// 帧错误或噪声，该字节被丢弃。
+static RX_LINE_ERRORS: TickCounter = TickCounter::new();
+
+/// 接收端的丢字统计，分别对应队列满、硬件溢出和线路错误三个环节。
+#[derive(Clone, Copy)]
+pub struct RxStats {
+    pub dropped: u32,
+    pub overruns: u32,
+    pub line_errors: u32,
+}
+
+/// [`SerialPort::read_line`] 遇到超过 [`LINE_CAP`] 的行，已整行丢弃。
+pub struct LineTooLong;
+
 pub struct SerialPort {
     pub tx: Tx<pac::USART1>,
-    pub rx: Rx<pac::USART1>,
+    /// 持有接收端以免被他处配置；数据由 USART1 中断读出。
+    _rx: Rx<pac::USART1>,
+    line: [u8; LINE_CAP],
+    line_len: usize,
+    /// 本行超长，丢弃到行尾。
+    line_overflow: bool,
+    /// 最近的收发行，输入行带 "> " 前缀。
+    pub log: LineLog,
 }
 
 impl SerialPort {
@@ -17,16 +54,65 @@ impl SerialPort {
         usart1: pac::USART1,
         tx_pin: impl Into<<pac::USART1 as CommonPins>::Tx<hal::gpio::PushPull>>,
         rx_pin: impl Into<<pac::USART1 as CommonPins>::Rx<hal::gpio::PushPull>>,
+        nvic: &mut NVIC,
         rcc: &mut hal::rcc::Rcc,
         baud: Bps,
     ) -> Self {
         let config = Config::default().baudrate(baud);
         let serial = Serial::new(usart1, (tx_pin, rx_pin), config, rcc).unwrap();
-        let (tx, rx) = serial.split();
-        Self { tx, rx }
+        let (tx, mut rx) = serial.split();
+        rx.listen();
+        // SAFETY: 在启用中断前设置优先级；处理函数只读 USART1 的 SR/DR 并写原子量。
+        unsafe {
+            nvic.set_priority(pac::Interrupt::USART1, priority::SERIAL_RX);
+            NVIC::unmask(pac::Interrupt::USART1);
+        }
+        Self {
+            tx,
+            _rx: rx,
+            line: [0; LINE_CAP],
+            line_len: 0,
+            line_overflow: false,
+            log: LineLog::new(),
+        }
+    }
+
+    /// 非阻塞读行：取走已到达的字节，凑满一行（`\r` 或 `\n` 结束）时返回该行，不含行尾；
+    /// 空行跳过，未读完的半行留到下次继续拼接。
+    pub fn read_line(&mut self, main: &MainLoop) -> Option<Result<&[u8], LineTooLong>> {
+        while let Some(byte) = RX_QUEUE.pop(main) {
+            match byte {
+                b'\r' | b'\n' => {
+                    let len = core::mem::take(&mut self.line_len);
+                    if core::mem::take(&mut self.line_overflow) {
+                        return Some(Err(LineTooLong));
+                    }
+                    if len > 0 {
+                        self.log.push_line(&[b"> ", &self.line[..len]]);
+                        return Some(Ok(&self.line[..len]));
+                    }
+                }
+                _ if self.line_len < LINE_CAP => {
+                    self.line[self.line_len] = byte;
+                    self.line_len += 1;
+                }
+                _ => self.line_overflow = true,
+            }
+        }
+        None
+    }
+
+    /// 上电以来的接收丢字统计。
+    pub fn rx_stats(&self) -> RxStats {
+        RxStats {
+            dropped: RX_QUEUE.dropped(),
+            overruns: RX_OVERRUNS.get(),
+            line_errors: RX_LINE_ERRORS.get(),
+        }
     }
 
     pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.log.write(bytes);
         for b in bytes {
             let _ = block!(self.tx.write(*b));
         }
@@ -39,3 +125,66 @@ impl fmt::Write for SerialPort {
         Ok(())
     }
 }
+
+#[interrupt]
+fn USART1() {
+    // SAFETY: 凭据只在本处理函数内使用。
+    let isr = unsafe { Isr::enter() };
+    // SAFETY: 只读 SR 与 DR；先读 SR 再读 DR 会同时清除 RXNE 与 ORE/FE/NF 标志。
+    // 主循环只经 `Tx` 写 DR 与查询发送标志，不读接收数据。
+    let usart = unsafe { &*pac::USART1::ptr() };
+    let sr = usart.sr().read();
+    let byte = usart.dr().read().dr().bits() as u8;
+    if sr.ore().bit_is_set() {
+        RX_OVERRUNS.tick(&isr);
+    }
+    if sr.fe().bit_is_set() || sr.nf().bit_is_set() {
+        RX_LINE_ERRORS.tick(&isr);
+    } else if sr.rxne().bit_is_set() {
+        RX_QUEUE.push(&isr, byte);
+    }
+}
+
+/// 第二串口：USART3 只发送（TX=PB10，接板上扩展口），用于对外广播对局事件，
+/// 与 USART1 的调试/控制台分开，外接显示屏或记录设备时互不干扰。
+pub struct AuxSerial {
+    tx: Tx<pac::USART3>,
+    pclk1_hz: u32,
+}
+
+impl AuxSerial {
+    pub fn new(
+        usart3: pac::USART3,
+        tx_pin: impl Into<<pac::USART3 as CommonPins>::Tx<hal::gpio::PushPull>>,
+        rcc: &mut hal::rcc::Rcc,
+        baud: Bps,
+    ) -> Self {
+        let config = Config::default().baudrate(baud);
+        let tx = Serial::tx(usart3, tx_pin, config, rcc).unwrap();
+        Self {
+            tx,
+            pclk1_hz: rcc.clocks.pclk1().raw(),
+        }
+    }
+
+    /// 改波特率：等当前字节发完后直接改写 BRR（16 倍过采样时 BRR = PCLK1 / 波特率）。
+    pub fn set_baud(&mut self, baud: u32) {
+        let _ = block!(self.tx.flush());
+        let brr = ((self.pclk1_hz + baud / 2) / baud.max(1)).min(u16::MAX as u32) as u16;
+        // SAFETY: 只改写 USART3 的 BRR，发送已结束；该外设只由本结构持有。
+        unsafe { (*pac::USART3::ptr()).brr().write(|w| w.bits(brr)) };
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            let _ = block!(self.tx.write(*b));
+        }
+    }
+}
+
+impl fmt::Write for AuxSerial {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_bytes(s.as_bytes());
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+//! 栈用量估计：上电时把栈底（`_stack_end`，即 .bss 之后）到当前栈指针下方的空闲 RAM
+//! 填上标记字，之后从栈底向上数仍保持标记的字，即运行至今栈从未触及的字节数（低水位）。
+//! 栈向下生长，最深处的写入会破坏标记；只读不写的局部变量可能漏计，结果略偏乐观。
+//!
+//! 栈底最低的 `GUARD_BYTES` 字节是守卫区：正常运行时栈不应深到这里，其中的标记被改写
+//! 说明栈已用尽、再往下就要写进 .bss 的静态数据（见 `stack_guard`）。上电时先对守卫区做
+//! 一次读写自检，再统一填充标记。
+
+use core::ptr;
+
+/// 标记字：不像地址也不像小整数，被栈上数据恰好写成同值的机会很小。
+const PAINT: u32 = 0xC5AC_CE55;
+/// 填充止于当前栈指针下方这么多字节，给 `paint` 自身（调试构建不内联）的调用帧留余量。
+const SP_MARGIN: usize = 256;
+/// 守卫区大小。搜索上下文按层分配，越界多在整帧上发生，1 KiB 足以在改写静态数据前察觉。
+pub const GUARD_BYTES: usize = 1024;
+
+unsafe extern "C" {
+    /// cortex-m-rt 链接脚本提供：栈的最低地址。
+    static _stack_end: u32;
+}
+
+fn bottom() -> usize {
+    // 只取链接符号的地址，不读取其内容。
+    ptr::addr_of!(_stack_end) as usize
+}
+
+/// 守卫区 RAM 自检：依次写入两种互补图样（与地址异或，兼查地址线粘连）并读回比对。
+/// 会破坏守卫区内容，须在 `paint` 之前调用。
+pub fn self_test() -> bool {
+    let guard = bottom()..bottom() + GUARD_BYTES;
+    [0x5555_5555u32, 0xAAAA_AAAA].iter().all(|&pattern| {
+        for addr in guard.clone().step_by(4) {
+            // SAFETY: 守卫区位于栈底，远在当前栈指针之下，未被任何调用帧使用。
+            unsafe { ptr::write_volatile(addr as *mut u32, pattern ^ addr as u32) };
+        }
+        guard
+            .clone()
+            .step_by(4)
+            // SAFETY: 同上，只读回刚写入的字。
+            .all(|addr| unsafe { ptr::read_volatile(addr as *const u32) } == pattern ^ addr as u32)
+    })
+}
+
+/// 守卫区的标记是否完好。只读 `GUARD_BYTES / 4` 个字，可以每帧调用。
+pub fn guard_intact() -> bool {
+    (bottom()..bottom() + GUARD_BYTES)
+        .step_by(4)
+        // SAFETY: 守卫区是有效 RAM；读取不影响栈上数据。
+        .all(|addr| unsafe { ptr::read_volatile(addr as *const u32) } == PAINT)
+}
+
+/// 填充空闲栈区。须在 `main` 开头、调用链最浅时执行一次。
+pub fn paint() {
+    let top = (cortex_m::register::msp::read() as usize).saturating_sub(SP_MARGIN) & !3;
+    for addr in (bottom()..top).step_by(4) {
+        // SAFETY: 栈底与栈指针下方余量之间的 RAM 尚未被任何调用帧使用。
+        unsafe { ptr::write_volatile(addr as *mut u32, PAINT) };
+    }
+}
+
+/// 栈底以上仍保持标记的字节数。只从栈底扫描到第一个被改写的字，耗时与剩余空间成正比。
+pub fn free_bytes() -> u32 {
+    let mut addr = bottom();
+    // SAFETY: 扫描范围在栈底与当前栈指针之间，均为有效 RAM。
+    while addr < cortex_m::register::msp::read() as usize
+        && unsafe { ptr::read_volatile(addr as *const u32) } == PAINT
+    {
+        addr += 4;
+    }
+    (addr - bottom()) as u32
+}
@@ -0,0 +1,99 @@
+//! 时基：TIM5（32 位）自由运行的 10 kHz 计数提供高分辨率时间戳，约 119 小时回绕一次；
+//! TIM3 产生 1 kHz 中断，累加毫秒节拍并递减各倒计时（见 `crate::sync` 的上下文分工）。
+
+use crate::hal;
+use crate::sync::{Countdown, Isr, TickCounter, priority};
+use cortex_m::peripheral::NVIC;
+use hal::pac::{self, interrupt};
+use hal::prelude::*;
+use hal::timer::{Counter, CounterHz, Event};
+
+/// 计数频率：0.1 ms 分辨率，APB1 定时器时钟 84 MHz 可整除。
+const TICK_HZ: u32 = 10_000;
+
+/// 1 kHz 中断累加的毫秒数。
+static UPTIME_MS: TickCounter = TickCounter::new();
+
+/// 界面提示用倒计时（如 AI 着法说明的停留时间），由 1 kHz 中断递减。
+pub static UI_COUNTDOWN: Countdown = Countdown::new();
+
+pub struct Timebase {
+    counter: Counter<pac::TIM5, TICK_HZ>,
+    /// 持有 TIM3 以免被他处重新配置；中断里直接清标志位。
+    _millis: CounterHz<pac::TIM3>,
+    /// 减少动态效果：所有闪烁、流水灯等定时动画的总开关（见 `blink_lit`）。
+    reduced_motion: bool,
+}
+
+impl Timebase {
+    pub fn new(tim5: pac::TIM5, tim3: pac::TIM3, nvic: &mut NVIC, rcc: &mut hal::rcc::Rcc) -> Self {
+        let mut counter = tim5.counter::<TICK_HZ>(rcc);
+        // 自动重装载取最大值，让计数器自由运行。
+        counter.start((u32::MAX / TICK_HZ).secs()).unwrap();
+
+        let mut millis = tim3.counter_hz(rcc);
+        millis.start(1.kHz()).unwrap();
+        millis.listen(Event::Update);
+        // SAFETY: 在启用中断前设置优先级；处理函数只访问原子量。
+        unsafe {
+            nvic.set_priority(pac::Interrupt::TIM3, priority::TIMEBASE);
+            NVIC::unmask(pac::Interrupt::TIM3);
+        }
+        Self {
+            counter,
+            _millis: millis,
+            reduced_motion: false,
+        }
+    }
+
+    /// 上电以来的原始计数（0.1 ms），差值请用 `wrapping_sub`。
+    #[inline]
+    pub fn now_ticks(&self) -> u32 {
+        self.counter.now().ticks()
+    }
+
+    /// 上电以来的毫秒数（1 kHz 中断节拍）。
+    #[inline]
+    pub fn now_ms(&self) -> u32 {
+        UPTIME_MS.get()
+    }
+
+    /// 自 `since_ms`（取自 `now_ms`）起经过的毫秒数。
+    #[inline]
+    pub fn elapsed_ms(&self, since_ms: u32) -> u32 {
+        self.now_ms().wrapping_sub(since_ms)
+    }
+
+    /// 打开/关闭减少动态效果（设置页的 Motion 项）。
+    pub fn set_reduced_motion(&mut self, reduced: bool) {
+        self.reduced_motion = reduced;
+    }
+
+    /// 是否允许定时动画；为 false 时各处改用静态显示。
+    #[inline]
+    pub fn motion_enabled(&self) -> bool {
+        !self.reduced_motion
+    }
+
+    /// 闪烁相位：自 `since_ms` 起亮、暗各 `half_ms` 交替。减少动态效果时恒为亮，
+    /// 闪烁的光标与指示灯因此退化为常亮。
+    pub fn blink_lit(&self, since_ms: u32, half_ms: u32) -> bool {
+        self.reduced_motion || (self.elapsed_ms(since_ms) / half_ms.max(1)) & 1 == 0
+    }
+}
+
+/// 上电以来的毫秒数，供其他中断处理函数打时间戳；主循环请用 [`Timebase::now_ms`]。
+#[inline]
+pub fn uptime_ms() -> u32 {
+    UPTIME_MS.get()
+}
+
+#[interrupt]
+fn TIM3() {
+    // SAFETY: 凭据只在本处理函数内使用。
+    let isr = unsafe { Isr::enter() };
+    // SAFETY: 仅写 SR 清更新标志（写 0 清除），与主循环不共享该寄存器。
+    unsafe { (*pac::TIM3::ptr()).sr().write(|w| w.uif().clear_bit()) };
+    UPTIME_MS.tick(&isr);
+    UI_COUNTDOWN.tick(&isr, 1);
+}
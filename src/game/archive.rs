@@ -0,0 +1,278 @@
+//! 最近对局存档：下完的对局写入 Flash 存档区（见 `drivers::flash`），可在启动菜单的
+//! "Recent Games" 中浏览，以复盘方式打开或经 USART1 导出。
+//!
+//! 存档区按 2 KB 分槽，每局占一槽，序号递增依次写入；写到一个扇区的开头时先擦除该扇区，
+//! 两个扇区轮流擦写以均摊磨损，最旧的一批对局随之被覆盖。每槽先写正文、最后写头部，
+//! 写入中途断电的槽因魔数或校验不符被忽略，不会被当成对局读出。
+//!
+//! 槽格式（小端）：
+//! - 0 魔数 `RHG1`，4 序号 u32，8 校验 u32（FNV-1a，覆盖第 12 字节起的正文）
+//! - 12 半回合数 u16，14 结果码（`GameResult::code`，含结束原因），15 计分标记（0 不计分，1/2 人类执白/执黑对 AI）
+//! - 16 开始时间、23 结束时间（年 u16、月、日、时、分、秒），30 赛后等级分 u16（不计分为 0）
+//! - 32 时长秒数 u32，36 起始局面（`rhess_core::record`，40 字节）
+//! - 76 起每个半回合 2 字节着法码
+//! - 着法之后（仅 `RHG2`）13 字节对局设置（`rhess_core::record::GameSetup`）：随机种子 u32、
+//!   AI 搜索深度（位 4-6 为时限，位 7 为相近着法随机选择）、节点预算 u32（0 为不限）、标志（位 0 自适应预算、位 1 机动性评估、位 2/3 白/黑方
+//!   由 AI 执子、位 4-7 白/黑方评估风格）、固件版本（主、次、修订）
+//!
+//! 等级分历史就是计分对局槽头里的赛后等级分，不另设存储：当前等级分取自仍在存档中的
+//! 最新计分对局，全部被轮转覆盖后回到初始分。旧格式的保留字节为 0，读出即不计分。
+//!
+//! 魔数 `RHG1` 的旧槽没有对局设置，照常读出，只是导出时不带设置标签。
+
+use super::history::History;
+use crate::drivers::flash::{GameFlash, SECTOR_COUNT, SECTOR_SIZE};
+use crate::drivers::rtc::DateTime;
+use crate::hal::flash::Error;
+use crate::new_game::CASUAL_VARIETY_MARGIN;
+use rhess_core::Color;
+use rhess_core::rating::INITIAL_RATING;
+use rhess_core::record::{self, GameSetup, POSITION_LEN, SETUP_LEN};
+use rhess_core::result::GameResult;
+
+const SLOT_SIZE: usize = 2048;
+const SLOTS_PER_SECTOR: usize = SECTOR_SIZE / SLOT_SIZE;
+const SLOT_COUNT: usize = SLOTS_PER_SECTOR * SECTOR_COUNT;
+const MAGIC: [u8; 4] = *b"RHG2";
+/// 不带对局设置的旧格式。
+const MAGIC_V1: [u8; 4] = *b"RHG1";
+/// 头部（魔数、序号、校验）长度；校验覆盖其后的全部正文。
+const HEADER_LEN: usize = 12;
+const POSITION_OFFSET: usize = 36;
+const MOVES_OFFSET: usize = POSITION_OFFSET + POSITION_LEN;
+/// 单槽可存的半回合数上限。
+const MAX_SAVED_PLIES: usize = (SLOT_SIZE - MOVES_OFFSET - SETUP_LEN) / 2;
+
+/// 对 AI 的计分对局：人类执哪方，以及赛后的等级分。
+#[derive(Clone, Copy)]
+pub struct Rated {
+    pub human: Color,
+    pub rating: u16,
+}
+
+impl Rated {
+    fn code(self) -> u8 {
+        match self.human {
+            Color::White => 1,
+            Color::Black => 2,
+        }
+    }
+
+    fn decode(code: u8, rating: u16) -> Option<Self> {
+        let human = match code {
+            1 => Color::White,
+            2 => Color::Black,
+            _ => return None,
+        };
+        Some(Rated { human, rating })
+    }
+}
+
+/// 本固件的版本号（取自 Cargo.toml），记入新对局的设置。
+pub fn firmware_version() -> [u8; 3] {
+    let part = |s: &str| s.parse().unwrap_or(0);
+    [
+        part(env!("CARGO_PKG_VERSION_MAJOR")),
+        part(env!("CARGO_PKG_VERSION_MINOR")),
+        part(env!("CARGO_PKG_VERSION_PATCH")),
+    ]
+}
+
+/// 存档目录中的一局：槽头信息，着法由 [`load`] 读出。
+#[derive(Clone, Copy)]
+pub struct SavedGame {
+    slot: usize,
+    pub seq: u32,
+    pub plies: u16,
+    pub result: GameResult,
+    pub started_at: DateTime,
+    pub ended_at: DateTime,
+    pub seconds: u32,
+    pub rated: Option<Rated>,
+    /// 旧格式存档没有对局设置。
+    pub setup: Option<GameSetup>,
+}
+
+/// 一局的结局：结果与原因、起止时间、用时与计分。
+pub struct Ending {
+    pub result: GameResult,
+    pub started_at: DateTime,
+    pub ended_at: DateTime,
+    pub seconds: u32,
+    pub rated: Option<Rated>,
+}
+
+/// 保存一局，返回其序号。
+pub fn save(
+    flash: &mut GameFlash,
+    history: &History,
+    ending: &Ending,
+    setup: &GameSetup,
+) -> Result<u32, Error> {
+    let newest = (0..SLOT_COUNT)
+        .filter_map(|slot| read_header(flash, slot))
+        .max_by_key(|game| game.seq);
+    let (mut slot, seq) = newest.map_or((0, 1), |g| ((g.slot + 1) % SLOT_COUNT, g.seq + 1));
+    // 进入新扇区时整扇区擦除；扇区内跳过写坏（非空白）的槽。
+    loop {
+        if slot % SLOTS_PER_SECTOR == 0 && !is_blank(flash, slot, SLOTS_PER_SECTOR) {
+            flash.erase_sector(slot / SLOTS_PER_SECTOR)?;
+        }
+        if is_blank(flash, slot, 1) {
+            break;
+        }
+        slot = (slot + 1) % SLOT_COUNT;
+    }
+
+    let plies = history.len().min(MAX_SAVED_PLIES);
+    let setup_at = MOVES_OFFSET + plies * 2;
+    let len = setup_at + SETUP_LEN;
+    let mut buf = [0xFFu8; SLOT_SIZE];
+    buf[12..14].copy_from_slice(&(plies as u16).to_le_bytes());
+    let rated = ending.rated;
+    buf[14] = ending.result.code();
+    buf[15] = rated.map_or(0, Rated::code);
+    buf[16..23].copy_from_slice(&encode_time(ending.started_at));
+    buf[23..30].copy_from_slice(&encode_time(ending.ended_at));
+    buf[30..32].copy_from_slice(&rated.map_or(0, |r| r.rating).to_le_bytes());
+    buf[32..36].copy_from_slice(&ending.seconds.to_le_bytes());
+    buf[POSITION_OFFSET..MOVES_OFFSET].copy_from_slice(&record::encode_position(history.start()));
+    for ply in 0..plies {
+        let code = history.get(ply).map_or(0, record::encode_move);
+        let at = MOVES_OFFSET + ply * 2;
+        buf[at..at + 2].copy_from_slice(&code.to_le_bytes());
+    }
+    buf[setup_at..len].copy_from_slice(&setup.encode());
+    buf[0..4].copy_from_slice(&MAGIC);
+    buf[4..8].copy_from_slice(&seq.to_le_bytes());
+    let checksum = fnv1a(&buf[HEADER_LEN..len]);
+    buf[8..12].copy_from_slice(&checksum.to_le_bytes());
+
+    let base = slot * SLOT_SIZE;
+    flash.program(base + HEADER_LEN, &buf[HEADER_LEN..len])?;
+    flash.program(base, &buf[..HEADER_LEN])?;
+    Ok(seq)
+}
+
+/// 最近的若干局，按从新到旧填入 `out`（其余位置为 None），返回局数。
+pub fn recent(flash: &GameFlash, out: &mut [Option<SavedGame>]) -> usize {
+    out.fill(None);
+    for game in (0..SLOT_COUNT).filter_map(|slot| read_header(flash, slot)) {
+        // 插入排序：保持按序号降序，超出容量的最旧一局被挤掉。
+        let Some(pos) = out.iter().position(|g| g.is_none_or(|g| g.seq < game.seq)) else {
+            continue;
+        };
+        out[pos..].rotate_right(1);
+        out[pos] = Some(game);
+    }
+    out.iter().flatten().count()
+}
+
+/// 全部计分对局，按从旧到新填入 `out`；超出容量时保留最新的，返回局数。
+pub fn rated_games(flash: &GameFlash, out: &mut [Option<SavedGame>]) -> usize {
+    let mut all = [None; SLOT_COUNT];
+    recent(flash, &mut all);
+    out.fill(None);
+    let rated = all.iter().flatten().filter(|g| g.rated.is_some());
+    // `all` 从新到旧，逆序填入 `out` 的末尾，再把空位挪到后面。
+    let mut count = 0;
+    for (slot, game) in out.iter_mut().rev().zip(rated) {
+        *slot = Some(*game);
+        count += 1;
+    }
+    out.rotate_left(out.len() - count);
+    count
+}
+
+/// 当前等级分：最新一局计分对局的赛后等级分，尚无计分对局时为初始分。
+pub fn current_rating(flash: &GameFlash) -> u16 {
+    let mut latest = [None];
+    rated_games(flash, &mut latest);
+    latest[0]
+        .and_then(|g| g.rated)
+        .map_or(INITIAL_RATING, |r| r.rating)
+}
+
+/// 读出一局的着法；槽已被覆盖或记录不可重放时返回 None。
+pub fn load(flash: &GameFlash, game: &SavedGame) -> Option<History> {
+    let current = read_header(flash, game.slot).filter(|g| g.seq == game.seq)?;
+    let bytes = flash.read(current.slot * SLOT_SIZE, SLOT_SIZE);
+    let position: &[u8; POSITION_LEN] = bytes[POSITION_OFFSET..MOVES_OFFSET].try_into().ok()?;
+    let start = record::decode_position(position)?;
+    let mut history = History::new(start);
+    let mut state = start;
+    for ply in 0..current.plies as usize {
+        let at = MOVES_OFFSET + ply * 2;
+        let mv = record::decode_move(&state, u16::from_le_bytes([bytes[at], bytes[at + 1]]))?;
+        history.push(&state, mv);
+        state = state.make_move(mv)?;
+    }
+    Some(history)
+}
+
+/// 读取并校验槽头；空槽、写坏的槽返回 None。
+fn read_header(flash: &GameFlash, slot: usize) -> Option<SavedGame> {
+    let bytes = flash.read(slot * SLOT_SIZE, SLOT_SIZE);
+    let has_setup = match bytes[0..4].try_into() {
+        Ok(MAGIC) => true,
+        Ok(MAGIC_V1) => false,
+        _ => return None,
+    };
+    let word =
+        |at: usize| u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]);
+    let plies = u16::from_le_bytes([bytes[12], bytes[13]]);
+    if plies as usize > MAX_SAVED_PLIES {
+        return None;
+    }
+    let setup_at = MOVES_OFFSET + plies as usize * 2;
+    let len = setup_at + if has_setup { SETUP_LEN } else { 0 };
+    if word(8) != fnv1a(&bytes[HEADER_LEN..len]) {
+        return None;
+    }
+    Some(SavedGame {
+        slot,
+        seq: word(4),
+        plies,
+        result: GameResult::from_code(bytes[14])?,
+        started_at: decode_time(&bytes[16..23]),
+        ended_at: decode_time(&bytes[23..30]),
+        seconds: word(32),
+        rated: Rated::decode(bytes[15], u16::from_le_bytes([bytes[30], bytes[31]])),
+        // 旧格式没有设置，切片为空。
+        setup: bytes[setup_at..len]
+            .try_into()
+            .ok()
+            .map(|setup| GameSetup::decode(setup, CASUAL_VARIETY_MARGIN)),
+    })
+}
+
+/// 从 `slot` 起 `count` 个槽是否全为擦除态（0xFF）。
+fn is_blank(flash: &GameFlash, slot: usize, count: usize) -> bool {
+    flash
+        .read(slot * SLOT_SIZE, count * SLOT_SIZE)
+        .iter()
+        .all(|&b| b == 0xFF)
+}
+
+fn encode_time(t: DateTime) -> [u8; 7] {
+    let [y0, y1] = t.year.to_le_bytes();
+    [y0, y1, t.month, t.day, t.hour, t.minute, t.second]
+}
+
+fn decode_time(bytes: &[u8]) -> DateTime {
+    DateTime {
+        year: u16::from_le_bytes([bytes[0], bytes[1]]),
+        month: bytes[2],
+        day: bytes[3],
+        hour: bytes[4],
+        minute: bytes[5],
+        second: bytes[6],
+    }
+}
+
+/// 32 位 FNV-1a 散列，用作槽校验。
+pub(crate) fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811C_9DC5, |hash, &b| {
+        (hash ^ b as u32).wrapping_mul(0x0100_0193)
+    })
+}
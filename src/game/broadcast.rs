@@ -0,0 +1,125 @@
+//! 赛事广播：作为对局观察者，把落子、将军、计时与结果以 JSON 行（每行一个对象）
+//! 经第二串口（USART3）输出，供外接大屏或赛事记录设备解析。不依赖 GPS 或墙上时钟，
+//! 时间戳 `t` 为上电以来的毫秒数，接收端按差值计时。发送为阻塞式，9600 波特时
+//! 一行落子事件约占 100 ms，需要流畅操作时宜选较高波特率。离开开局库事件的 `ply` 为
+//! 离开书的那一着是第几个半回合（自 1 起）。结果事件的 `reason` 取自
+//! `GameResult::key`（checkmate、stalemate、insufficient_material 等）。
+//!
+//! ```text
+//! {"event":"move","t":51230,"uci":"e2e4","fen":"rnbqkbnr/... b KQkq e3 0 1"}
+//! {"event":"check","t":80311,"side":"b"}
+//! {"event":"book_exit","t":90412,"ply":9}
+//! {"event":"clock","t":81000,"turn":"b"}
+//! {"event":"result","t":95102,"result":"1-0","reason":"checkmate"}
+//! ```
+
+use super::observer::{GameObserver, square_name};
+use crate::drivers::serial::AuxSerial;
+use core::fmt::Write;
+use rhess_core::result::GameResult;
+use rhess_core::{Color, GameState, Move, PieceKind, fen};
+
+/// 计时事件的间隔。
+const CLOCK_EVENT_MS: u32 = 1_000;
+
+pub struct JsonBroadcast {
+    port: AuxSerial,
+    /// 最近一次时钟回调的时间，落子等事件沿用它作时间戳。
+    now_ms: u32,
+    last_clock_ms: Option<u32>,
+    turn: Color,
+}
+
+impl JsonBroadcast {
+    /// 以 `baud` 接管广播串口，从 `start` 局面开始跟踪行棋方。
+    pub fn new(mut port: AuxSerial, baud: u32, start: &GameState) -> Self {
+        port.set_baud(baud);
+        JsonBroadcast {
+            port,
+            now_ms: 0,
+            last_clock_ms: None,
+            turn: start.side_to_move,
+        }
+    }
+
+    /// 对局结束后交还广播串口，下一局再接管。
+    pub fn into_port(self) -> AuxSerial {
+        self.port
+    }
+}
+
+impl GameObserver for JsonBroadcast {
+    fn on_move(&mut self, mv: Move, state: &GameState) {
+        self.turn = state.side_to_move;
+        let (from, to) = (square_name(mv.from), square_name(mv.to));
+        let promotion = match mv.promotion {
+            Some(PieceKind::Queen) => "q",
+            Some(PieceKind::Rook) => "r",
+            Some(PieceKind::Bishop) => "b",
+            Some(PieceKind::Knight) => "n",
+            _ => "",
+        };
+        let _ = write!(
+            self.port,
+            "{{\"event\":\"move\",\"t\":{},\"uci\":\"{}{}{}{}{}\",\"fen\":\"{}\"}}\r\n",
+            self.now_ms,
+            from[0] as char,
+            from[1] as char,
+            to[0] as char,
+            to[1] as char,
+            promotion,
+            fen::fen(state),
+        );
+    }
+
+    fn on_check(&mut self, color: Color) {
+        let _ = write!(
+            self.port,
+            "{{\"event\":\"check\",\"t\":{},\"side\":\"{}\"}}\r\n",
+            self.now_ms,
+            side_letter(color),
+        );
+    }
+
+    fn on_book_exit(&mut self, ply: usize) {
+        let _ = write!(
+            self.port,
+            "{{\"event\":\"book_exit\",\"t\":{},\"ply\":{}}}\r\n",
+            self.now_ms,
+            ply + 1,
+        );
+    }
+
+    fn on_game_end(&mut self, result: GameResult) {
+        let _ = write!(
+            self.port,
+            "{{\"event\":\"result\",\"t\":{},\"result\":\"{}\",\"reason\":\"{}\"}}\r\n",
+            self.now_ms,
+            result.pgn(),
+            result.key(),
+        );
+    }
+
+    fn on_clock_tick(&mut self, now_ms: u32) {
+        self.now_ms = now_ms;
+        let due = self
+            .last_clock_ms
+            .is_none_or(|last| now_ms.wrapping_sub(last) >= CLOCK_EVENT_MS);
+        if due {
+            self.last_clock_ms = Some(now_ms);
+            let _ = write!(
+                self.port,
+                "{{\"event\":\"clock\",\"t\":{},\"turn\":\"{}\"}}\r\n",
+                now_ms,
+                side_letter(self.turn),
+            );
+        }
+    }
+}
+
+fn side_letter(color: Color) -> char {
+    match color {
+        Color::White => 'w',
+        Color::Black => 'b',
+    }
+}
@@ -0,0 +1,118 @@
+//! AI 思考检查点：长时间搜索期间把对局记录与已完成深度的最佳着法写入备份 SRAM
+//! （见 `drivers::backup_sram`），中途复位或断电（有纽扣电池时）后上电直接续局，
+//! 不必回到菜单重下。搜索结束、着法走出后即清除，检查点只覆盖 AI 思考这一段。
+//!
+//! 记录格式（小端）：
+//! - 0 魔数 `RHC1`，4 校验 u32（FNV-1a，覆盖第 8 字节起的正文）
+//! - 8 半回合数 u16，10 保留（写 0），11 AI 落子间隔（百毫秒）
+//! - 12 最佳着法码 u16，14 其搜索深度（0 表示尚无完成的深度），15 保留（写 0）
+//! - 16 对局设置（`rhess_core::record::GameSetup`，13 字节，与存档相同：种子、AI 执子、强度与风格）
+//! - 29 起始局面（`rhess_core::record`，40 字节），69 起每个半回合 2 字节着法码
+//!
+//! 续局按对局设置与落子间隔恢复开局向导的选择，而不是回到默认强度。
+//!
+//! 写入时先抹掉魔数、再写正文、最后写回魔数，写到一半复位的记录不会被当成检查点读出。
+
+use super::archive::fnv1a;
+use super::history::History;
+use super::playout_tally;
+use crate::drivers::backup_sram::BackupSram;
+use crate::new_game::CASUAL_VARIETY_MARGIN;
+use rhess_core::Move;
+use rhess_core::record::{self, GameSetup, POSITION_LEN, SETUP_LEN};
+
+/// `RHC1` 的旧检查点没有对局设置，不再读出。
+const MAGIC: [u8; 4] = *b"RHC2";
+const HEADER_LEN: usize = 8;
+const SETUP_OFFSET: usize = 16;
+const POSITION_OFFSET: usize = SETUP_OFFSET + SETUP_LEN;
+const MOVES_OFFSET: usize = POSITION_OFFSET + POSITION_LEN;
+/// 可存的半回合数上限；备份 SRAM 末尾留给快速对弈的比分。
+const MAX_PLIES: usize = (playout_tally::OFFSET - MOVES_OFFSET) / 2;
+
+/// 读出的检查点：复位前的对局、其设置与落子间隔，以及搜索已完成的最佳着法（着法, 深度）。
+pub struct Checkpoint {
+    pub history: History,
+    pub setup: GameSetup,
+    pub ai_delay_ms: u32,
+    pub best: Option<(Move, u8)>,
+}
+
+/// 写入检查点；对局超出容量时清除旧检查点而不是写入残缺记录。
+pub fn save(
+    backup: &mut BackupSram,
+    history: &History,
+    setup: &GameSetup,
+    ai_delay_ms: u32,
+    best: Option<(Move, u8)>,
+) {
+    clear(backup);
+    let plies = history.len();
+    if plies > MAX_PLIES {
+        return;
+    }
+    // 搜索中途调用，栈上不放整块缓冲：各字段直接写入备份 SRAM，校验从写好的正文算出。
+    let [p0, p1] = (plies as u16).to_le_bytes();
+    let [m0, m1] = best
+        .map_or(0, |(mv, _)| record::encode_move(mv))
+        .to_le_bytes();
+    let depth = best.map_or(0, |(_, depth)| depth);
+    let delay = (ai_delay_ms / 100).min(u8::MAX as u32) as u8;
+    backup.write(8, &[p0, p1, 0, delay, m0, m1, depth, 0]);
+    backup.write(SETUP_OFFSET, &setup.encode());
+    backup.write(POSITION_OFFSET, &record::encode_position(history.start()));
+    for ply in 0..plies {
+        let code = history.get(ply).map_or(0, record::encode_move);
+        backup.write(MOVES_OFFSET + ply * 2, &code.to_le_bytes());
+    }
+    let len = MOVES_OFFSET + plies * 2;
+    let checksum = fnv1a(&backup.read()[HEADER_LEN..len]);
+    backup.write(4, &checksum.to_le_bytes());
+    backup.write(0, &MAGIC);
+}
+
+/// 读出并校验检查点；没有、写坏或无法重放时返回 None。
+pub fn load(backup: &BackupSram) -> Option<Checkpoint> {
+    let bytes = backup.read();
+    if bytes[0..4] != MAGIC {
+        return None;
+    }
+    let plies = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    if plies > MAX_PLIES {
+        return None;
+    }
+    let len = MOVES_OFFSET + plies * 2;
+    let checksum = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+    if checksum != fnv1a(&bytes[HEADER_LEN..len]) {
+        return None;
+    }
+    let setup: &[u8; SETUP_LEN] = bytes[SETUP_OFFSET..POSITION_OFFSET].try_into().ok()?;
+    let position: &[u8; POSITION_LEN] = bytes[POSITION_OFFSET..MOVES_OFFSET].try_into().ok()?;
+    let start = record::decode_position(position)?;
+    let mut history = History::new(start);
+    let mut state = start;
+    for ply in 0..plies {
+        let at = MOVES_OFFSET + ply * 2;
+        let mv = record::decode_move(&state, u16::from_le_bytes([bytes[at], bytes[at + 1]]))?;
+        history.push(&state, mv);
+        state = state.make_move(mv)?;
+    }
+    // 着法码按重放后的局面解码，非法则视为没有可用的着法，续局后重新搜索。
+    let best = match bytes[14] {
+        0 => None,
+        depth => record::decode_move(&state, u16::from_le_bytes([bytes[12], bytes[13]]))
+            .filter(|&mv| state.is_move_legal(mv))
+            .map(|mv| (mv, depth)),
+    };
+    Some(Checkpoint {
+        history,
+        setup: GameSetup::decode(setup, CASUAL_VARIETY_MARGIN),
+        ai_delay_ms: bytes[11] as u32 * 100,
+        best,
+    })
+}
+
+/// 作废检查点（只抹掉魔数）。
+pub fn clear(backup: &mut BackupSram) {
+    backup.write(0, &[0; 4]);
+}
@@ -0,0 +1,109 @@
+//! 终局结算画面：结束原因与胜负横幅、终局缩略图、步数与用时，以及 新对局/复盘/导出/主菜单 选项。
+
+use crate::drivers::lcd::Lcd;
+use crate::ui::theme::Theme;
+use crate::ui::{chessboard, fmt, text};
+use rhess_core::result::GameResult;
+use rhess_core::{Color, GameState};
+
+/// 终局缩略图每格像素。
+const THUMB_CELL: u16 = 16;
+const THUMB_X: u16 = 16;
+const THUMB_Y: u16 = 76;
+/// 标题默认 4 倍字号，较长的原因（如 "Insufficient material"）放不下时改用 3 倍。
+const TITLE_MAX_WIDTH: u16 = 448;
+
+pub struct EndSummary<'s> {
+    pub result: GameResult,
+    pub state: &'s GameState,
+    /// 全部半回合数。
+    pub plies: usize,
+    pub seconds: u32,
+    /// 计分对局的等级分（赛前, 赛后）；不计分为 None。
+    pub rating_change: Option<(u16, u16)>,
+    /// 人机对局中人执的一方，胜负横幅据此写 "You win"/"You lose"，执白执黑一样；
+    /// 人人对局与电脑对电脑为 None，写胜方颜色。
+    pub human: Option<Color>,
+}
+
+pub fn render(lcd: &mut Lcd, theme: &Theme, summary: &EndSummary) {
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    lcd.clear(bg);
+
+    let title = summary.result.reason();
+    let banner = match (summary.result.winner(), summary.human) {
+        (None, _) => "Draw",
+        (Some(winner), Some(human)) if winner == human => "You win",
+        (Some(_), Some(_)) => "You lose",
+        (Some(Color::White), None) => "White wins",
+        (Some(Color::Black), None) => "Black wins",
+    };
+    let scale = if title.len() as u16 * 24 <= TITLE_MAX_WIDTH {
+        4
+    } else {
+        3
+    };
+    text::draw_text_scaled(lcd, title, 16, 10, theme.ui_alert, Some(bg), scale);
+    text::draw_text_scaled(lcd, banner, 16, 46, fg, Some(bg), 2);
+
+    chessboard::draw_thumbnail(
+        lcd,
+        theme,
+        &summary.state.board,
+        THUMB_X,
+        THUMB_Y,
+        THUMB_CELL,
+    );
+
+    let x = THUMB_X + THUMB_CELL * 8 + 24;
+    let mut buf = [0u8; 10];
+    let moves = (summary.plies as u32).div_ceil(2);
+    text::draw_text_scaled(lcd, "Moves:", x, THUMB_Y, fg, Some(bg), 2);
+    text::draw_text_scaled(
+        lcd,
+        fmt::u32_to_str(moves, &mut buf),
+        x + 84,
+        THUMB_Y,
+        fg,
+        Some(bg),
+        2,
+    );
+    let mut hms_buf = [0u8; 8];
+    text::draw_text_scaled(lcd, "Time:", x, THUMB_Y + 24, fg, Some(bg), 2);
+    text::draw_text_scaled(
+        lcd,
+        fmt::format_mm_ss(summary.seconds, &mut hms_buf),
+        x + 84,
+        THUMB_Y + 24,
+        fg,
+        Some(bg),
+        2,
+    );
+
+    if let Some((before, after)) = summary.rating_change {
+        let mut rating_buf = [0u8; 10];
+        let mut delta_buf = [0u8; 11];
+        let y = THUMB_Y + 48;
+        let rating = fmt::u32_to_str(after as u32, &mut rating_buf);
+        let delta = fmt::i32_to_str(after as i32 - before as i32, &mut delta_buf);
+        text::draw_text_scaled(lcd, "Rating:", x, y, fg, Some(bg), 2);
+        text::draw_text_scaled(lcd, rating, x + 96, y, fg, Some(bg), 2);
+        // 非负变化补上 "+" 号。
+        let sign = if after >= before { "+" } else { "" };
+        let dx = x + 96 + (rating.len() as u16 + 1) * 12;
+        text::draw_text_scaled(lcd, sign, dx, y, theme.ui_alert, Some(bg), 2);
+        let dx = dx + sign.len() as u16 * 12;
+        text::draw_text_scaled(lcd, delta, dx, y, theme.ui_alert, Some(bg), 2);
+    }
+
+    let options = [
+        "KEY1 New game",
+        "KEY2 Review",
+        "KEY3 Export (UART)",
+        "KEY4 Main menu",
+    ];
+    for (i, label) in options.iter().enumerate() {
+        let y = THUMB_Y + 76 + i as u16 * 20;
+        text::draw_text_scaled(lcd, label, x, y, fg, Some(bg), 2);
+    }
+}
@@ -0,0 +1,494 @@
+//! 对局着法记录：起始局面 + 固定容量着法数组（附复盘标注），供终局导出与复盘使用。
+//! 复盘中试走的着法记为变着：每条变着从主线某一步分出，代替主线该步及其后的着法；
+//! 变着只挂在主线上，在变着中途改走别的着法会截去原变着的余下部分，而不再分支。
+
+use core::fmt::{self, Write};
+use rhess_core::book;
+use rhess_core::repetition::Repetitions;
+use rhess_core::san::{Disambiguation, Suffix};
+use rhess_core::{Color, GameState, Move, PieceKind};
+
+/// 记录上限（半回合）；超出后不再记录，对局本身不受影响。
+const MAX_PLIES: usize = 512;
+/// 变着条数与每条变着的半回合数上限。
+const MAX_VARIATIONS: usize = 4;
+const MAX_VARIATION_PLIES: usize = 16;
+
+/// 复盘时给着法加的标注符号。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Annotation {
+    /// `!` 好棋
+    Good,
+    /// `?` 错着
+    Mistake,
+    /// `!?` 有趣的一着
+    Interesting,
+    /// `??` 败着
+    Blunder,
+}
+
+impl Annotation {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Annotation::Good => "!",
+            Annotation::Mistake => "?",
+            Annotation::Interesting => "!?",
+            Annotation::Blunder => "??",
+        }
+    }
+
+    /// 按键轮换顺序：无 → ! → ? → !? → ?? → 无。
+    fn cycle(current: Option<Self>) -> Option<Self> {
+        match current {
+            None => Some(Annotation::Good),
+            Some(Annotation::Good) => Some(Annotation::Mistake),
+            Some(Annotation::Mistake) => Some(Annotation::Interesting),
+            Some(Annotation::Interesting) => Some(Annotation::Blunder),
+            Some(Annotation::Blunder) => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Record {
+    mv: Move,
+    /// 走子方的棋子类型（升变前），用于按棋子名显示着法。
+    piece: PieceKind,
+    /// 是否吃子（含吃过路兵）。
+    capture: bool,
+    /// 同类棋子也能走到目标格时，SAN 在棋子名后补的起点列/行。
+    disambiguation: Disambiguation,
+    /// 走后是否将军/将死，显示为 `+`/`#`。
+    suffix: Suffix,
+    annotation: Option<Annotation>,
+}
+
+impl Record {
+    const EMPTY: Record = Record {
+        mv: Move::quiet(0, 0),
+        piece: PieceKind::Pawn,
+        capture: false,
+        disambiguation: Disambiguation::None,
+        suffix: Suffix::None,
+        annotation: None,
+    };
+
+    /// 在 `before` 局面上走出的 `mv`。
+    fn new(before: &GameState, mv: Move) -> Self {
+        Record {
+            mv,
+            piece: before.board[mv.from as usize].map_or(PieceKind::Pawn, |p| p.kind),
+            capture: mv.is_en_passant || before.board[mv.to as usize].is_some(),
+            disambiguation: Disambiguation::of(before, mv),
+            suffix: before
+                .make_move(mv)
+                .map_or(Suffix::None, |after| Suffix::of(&after)),
+            annotation: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Variation {
+    /// 代替主线第 `branch` 个半回合（从 0 计）起的着法。
+    branch: usize,
+    records: [Record; MAX_VARIATION_PLIES],
+    len: usize,
+}
+
+/// 着法树中的一条线：主线或第几条变着。变着中的半回合序号与主线一样从起始局面计，
+/// 前 `branch` 个半回合即主线的着法。
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Line {
+    #[default]
+    Main,
+    Variation(usize),
+}
+
+/// 试走的着法无法记入着法树。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VariationError {
+    /// 变着条数已满。
+    NoFreeSlot,
+    /// 变着已达长度上限。
+    TooLong,
+}
+
+impl VariationError {
+    pub fn message(self) -> &'static str {
+        match self {
+            VariationError::NoFreeSlot => "Too many variations",
+            VariationError::TooLong => "Variation too long",
+        }
+    }
+}
+
+pub struct History {
+    start: GameState,
+    records: [Record; MAX_PLIES],
+    len: usize,
+    variations: [Variation; MAX_VARIATIONS],
+    variation_count: usize,
+}
+
+impl History {
+    pub fn new(start: GameState) -> Self {
+        History {
+            start,
+            records: [Record::EMPTY; MAX_PLIES],
+            len: 0,
+            variations: [Variation {
+                branch: 0,
+                records: [Record::EMPTY; MAX_VARIATION_PLIES],
+                len: 0,
+            }; MAX_VARIATIONS],
+            variation_count: 0,
+        }
+    }
+
+    /// 记录在 `before` 局面上走出的 `mv`。
+    pub fn push(&mut self, before: &GameState, mv: Move) {
+        if self.len < MAX_PLIES {
+            self.records[self.len] = Record::new(before, mv);
+            self.len += 1;
+        }
+    }
+
+    /// 从新的起始局面重新记录。
+    pub fn reset(&mut self, start: GameState) {
+        self.start = start;
+        self.len = 0;
+        self.variation_count = 0;
+    }
+
+    /// 只保留前 `len` 个半回合；从被截去的着法分出的变着一并删除。
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+        let mut kept = 0;
+        for i in 0..self.variation_count {
+            if self.variations[i].branch < self.len {
+                self.variations[kept] = self.variations[i];
+                kept += 1;
+            }
+        }
+        self.variation_count = kept;
+    }
+
+    /// 在 `line` 上走过 `ply` 个半回合后的局面 `before` 中试走 `mv`，返回记下这一着的线。
+    /// 与该线的下一着相同则沿用原线；在主线上改走时，从同一步分出且首着相同的变着直接沿用，
+    /// 否则新开一条变着；在变着中途改走时截去原变着的余下部分再记入。
+    pub fn try_move(
+        &mut self,
+        line: Line,
+        ply: usize,
+        before: &GameState,
+        mv: Move,
+    ) -> Result<Line, VariationError> {
+        let line = self.normalize(line, ply);
+        if self.line_move(line, ply) == Some(mv) {
+            return Ok(line);
+        }
+        let index = match line {
+            Line::Variation(index) => index,
+            Line::Main => {
+                let existing = self.variations[..self.variation_count]
+                    .iter()
+                    .position(|v| v.branch == ply && v.records[0].mv == mv);
+                if let Some(index) = existing {
+                    return Ok(Line::Variation(index));
+                }
+                if self.variation_count == MAX_VARIATIONS {
+                    return Err(VariationError::NoFreeSlot);
+                }
+                let index = self.variation_count;
+                self.variation_count += 1;
+                self.variations[index].branch = ply;
+                self.variations[index].len = 0;
+                index
+            }
+        };
+        let variation = &mut self.variations[index];
+        let offset = ply - variation.branch;
+        if offset == MAX_VARIATION_PLIES {
+            return Err(VariationError::TooLong);
+        }
+        variation.records[offset] = Record::new(before, mv);
+        variation.len = offset + 1;
+        Ok(Line::Variation(index))
+    }
+
+    /// 走过 `ply` 个半回合时所在的线：尚未走到变着的分支点时即为主线。
+    pub fn normalize(&self, line: Line, ply: usize) -> Line {
+        match line {
+            Line::Variation(index) if ply > self.variations[index].branch => line,
+            _ => Line::Main,
+        }
+    }
+
+    /// `line` 的总半回合数（变着含分支点之前的主线着法）。
+    pub fn line_len(&self, line: Line) -> usize {
+        match line {
+            Line::Main => self.len,
+            Line::Variation(index) => {
+                let variation = &self.variations[index];
+                variation.branch + variation.len
+            }
+        }
+    }
+
+    /// 变着的分支点：它代替的主线着法序号。
+    pub fn branch(&self, line: Line) -> Option<usize> {
+        match line {
+            Line::Main => None,
+            Line::Variation(index) => Some(self.variations[index].branch),
+        }
+    }
+
+    /// `line` 上第 `ply` 个半回合的着法。
+    pub fn line_move(&self, line: Line, ply: usize) -> Option<Move> {
+        self.record(line, ply).map(|r| r.mv)
+    }
+
+    fn record(&self, line: Line, ply: usize) -> Option<&Record> {
+        match line {
+            Line::Variation(index) if ply >= self.variations[index].branch => {
+                let variation = &self.variations[index];
+                variation.records[..variation.len].get(ply - variation.branch)
+            }
+            _ => self.records[..self.len].get(ply),
+        }
+    }
+
+    fn record_mut(&mut self, line: Line, ply: usize) -> Option<&mut Record> {
+        match line {
+            Line::Variation(index) if ply >= self.variations[index].branch => {
+                let variation = &mut self.variations[index];
+                variation.records[..variation.len].get_mut(ply - variation.branch)
+            }
+            _ => self.records[..self.len].get_mut(ply),
+        }
+    }
+
+    /// 第 `ply` 个半回合（从 0 计）的着法。
+    pub fn get(&self, ply: usize) -> Option<Move> {
+        self.records[..self.len].get(ply).map(|r| r.mv)
+    }
+
+    /// `line` 上第 `ply` 个半回合走动的棋子。
+    pub fn piece(&self, line: Line, ply: usize) -> Option<PieceKind> {
+        self.record(line, ply).map(|r| r.piece)
+    }
+
+    /// 轮换 `line` 上第 `ply` 个半回合的标注，返回新的标注。
+    pub fn cycle_annotation(&mut self, line: Line, ply: usize) -> Option<Annotation> {
+        let record = self.record_mut(line, ply)?;
+        record.annotation = Annotation::cycle(record.annotation);
+        record.annotation
+    }
+
+    /// `line` 上第 `ply` 个半回合的坐标记法加标注，如 "e7e8q!?"。
+    pub fn ply_text<'b>(&self, line: Line, ply: usize, buf: &'b mut [u8; 8]) -> &'b str {
+        let Some(record) = self.record(line, ply) else {
+            return "";
+        };
+        let mv = record.mv;
+        buf[..4].copy_from_slice(&[
+            b'a' + mv.from % 8,
+            b'1' + mv.from / 8,
+            b'a' + mv.to % 8,
+            b'1' + mv.to / 8,
+        ]);
+        let mut len = 4;
+        if let Some(kind) = mv.promotion {
+            buf[len] = promotion_letter(kind);
+            len += 1;
+        }
+        if let Some(annotation) = record.annotation {
+            let symbol = annotation.symbol().as_bytes();
+            buf[len..len + symbol.len()].copy_from_slice(symbol);
+            len += symbol.len();
+        }
+        core::str::from_utf8(&buf[..len]).unwrap_or("")
+    }
+
+    /// 第 `ply` 个半回合的标准代数记法（SAN）加标注，如 "Nf3"、"Nbd2"、"exd8=Q+!?"、"O-O#"。
+    /// 由记录拼出，不必重放局面。
+    pub fn san_text<'b>(&self, ply: usize, buf: &'b mut [u8; 12]) -> &'b str {
+        let Some(record) = self.records[..self.len].get(ply) else {
+            return "";
+        };
+        let mv = record.mv;
+        let mut len = 0;
+        let mut put = |bytes: &[u8]| {
+            buf[len..len + bytes.len()].copy_from_slice(bytes);
+            len += bytes.len();
+        };
+        if mv.is_castling {
+            put(if mv.to % 8 == 6 { b"O-O" } else { b"O-O-O" });
+        } else {
+            match piece_letter(record.piece) {
+                Some(letter) => {
+                    put(&[letter]);
+                    let (bytes, n) = record.disambiguation.bytes(mv.from);
+                    put(&bytes[..n]);
+                }
+                // 兵吃子写出发的列。
+                None if record.capture => put(&[b'a' + mv.from % 8]),
+                None => {}
+            }
+            if record.capture {
+                put(b"x");
+            }
+            put(&[b'a' + mv.to % 8, b'1' + mv.to / 8]);
+            if let Some(kind) = mv.promotion {
+                put(&[b'=', promotion_letter(kind).to_ascii_uppercase()]);
+            }
+        }
+        put(record.suffix.as_str().as_bytes());
+        if let Some(annotation) = record.annotation {
+            put(annotation.symbol().as_bytes());
+        }
+        core::str::from_utf8(&buf[..len]).unwrap_or("")
+    }
+
+    /// 起始局面（着法编号与先走方由它决定）。
+    pub fn start(&self) -> &GameState {
+        &self.start
+    }
+
+    /// 从起始局面重放前 `ply` 个半回合，得到当时的局面。
+    pub fn replay(&self, ply: usize) -> GameState {
+        self.replay_line(Line::Main, ply)
+    }
+
+    /// 沿 `line` 重放前 `ply` 个半回合。
+    pub fn replay_line(&self, line: Line, ply: usize) -> GameState {
+        let mut state = self.start;
+        for i in 0..ply.min(self.line_len(line)) {
+            match self.line_move(line, i).and_then(|mv| state.make_move(mv)) {
+                Some(next) => state = next,
+                None => break,
+            }
+        }
+        state
+    }
+
+    /// 重放主线，记下每一着之前的局面，供三次重复判和。
+    pub fn repetitions(&self) -> Repetitions {
+        let mut repetitions = Repetitions::new();
+        let mut state = self.start;
+        for record in &self.records[..self.len] {
+            let Some(next) = state.make_move(record.mv) else {
+                break;
+            };
+            repetitions.record(&state);
+            state = next;
+        }
+        repetitions
+    }
+
+    /// 从起始局面逐步重放，返回最后一个通过完整性检查的 (半回合数, 局面)，作为损坏后的恢复点；
+    /// 起始局面本身损坏时返回 None。
+    pub fn last_consistent(&self) -> Option<(usize, GameState)> {
+        self.start.check_integrity().ok()?;
+        let mut good = (0, self.start);
+        for record in &self.records[..self.len] {
+            match good.1.make_move(record.mv) {
+                Some(next) if next.check_integrity().is_ok() => good = (good.0 + 1, next),
+                _ => break,
+            }
+        }
+        Some(good)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 主线离开开局库的半回合序号（见 `book::book_exit`）；不是从标准起始局面开始的
+    /// 对局没有开局库可言，为 None。
+    pub fn book_exit(&self) -> Option<usize> {
+        if self.start != GameState::start_position() {
+            return None;
+        }
+        book::book_exit((0..self.len).filter_map(|ply| self.get(ply)))
+    }
+
+    /// 以坐标记法输出，如 "1. e2e4! e7e5 2. g1f3"；黑方先行的局面以 "1... " 开头。
+    /// 离开开局库的一着后加注释 "{out of book}"。
+    /// 变着按 PGN 的写法放在所代替的主线着法之后的括号里，如 "2. g1f3 (2. f2f4 e5f4) 2... b8c6"。
+    pub fn write_to(&self, out: &mut impl Write, result: &str) -> fmt::Result {
+        let mut buf = [0u8; 8];
+        let mut resumed = true;
+        let book_exit = self.book_exit();
+        for i in 0..self.len {
+            self.write_number(out, i, resumed)?;
+            resumed = false;
+            out.write_str(self.ply_text(Line::Main, i, &mut buf))?;
+            out.write_char(' ')?;
+            if book_exit == Some(i) {
+                out.write_str("{out of book} ")?;
+                resumed = true;
+            }
+            for index in 0..self.variation_count {
+                if self.variations[index].branch == i {
+                    self.write_variation(out, index)?;
+                    resumed = true;
+                }
+            }
+        }
+        writeln!(out, "{}", result)
+    }
+
+    /// 第 `index` 条变着，如 "(2. f2f4 e5f4) "。
+    fn write_variation(&self, out: &mut impl Write, index: usize) -> fmt::Result {
+        let line = Line::Variation(index);
+        let branch = self.variations[index].branch;
+        let mut buf = [0u8; 8];
+        out.write_char('(')?;
+        for ply in branch..self.line_len(line) {
+            if ply > branch {
+                out.write_char(' ')?;
+            }
+            self.write_number(out, ply, ply == branch)?;
+            out.write_str(self.ply_text(line, ply, &mut buf))?;
+        }
+        out.write_str(") ")
+    }
+
+    /// 第 `ply` 个半回合前的回合编号：白方着法前为 "12. "；黑方着法只在 `resumed`
+    /// （着法正文或括号开头、变着之后）时写作 "12... "。
+    fn write_number(&self, out: &mut impl Write, ply: usize, resumed: bool) -> fmt::Result {
+        let first_move = self.start.fullmove_number as usize;
+        let ply = ply + (self.start.side_to_move == Color::Black) as usize;
+        let number = first_move + ply / 2;
+        if ply & 1 == 0 {
+            write!(out, "{}. ", number)
+        } else if resumed {
+            write!(out, "{}... ", number)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// 代数记法的棋子字母，兵省略。
+fn piece_letter(kind: PieceKind) -> Option<u8> {
+    match kind {
+        PieceKind::King => Some(b'K'),
+        PieceKind::Queen => Some(b'Q'),
+        PieceKind::Rook => Some(b'R'),
+        PieceKind::Bishop => Some(b'B'),
+        PieceKind::Knight => Some(b'N'),
+        PieceKind::Pawn => None,
+    }
+}
+
+fn promotion_letter(kind: PieceKind) -> u8 {
+    match kind {
+        PieceKind::Knight => b'n',
+        PieceKind::Bishop => b'b',
+        PieceKind::Rook => b'r',
+        _ => b'q',
+    }
+}
@@ -0,0 +1,2589 @@
+pub mod archive;
+pub mod broadcast;
+pub mod checkpoint;
+mod end_screen;
+pub mod history;
+pub mod observer;
+pub mod pgn;
+pub mod playout_tally;
+mod scheduler;
+mod status_leds;
+
+use crate::board::Board;
+use crate::clock::TimeControl;
+use crate::console::{Command, Console};
+use crate::drivers::lcd::{self, ClipRect};
+use crate::drivers::rtc::DateTime;
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::settings::{Labels, PromotionOrder, Settings};
+use crate::stack_guard;
+use crate::start_menu_crab::{self, CRAB_SMALL_W, THINKING_STRIDE};
+use crate::ui::animation::Animation;
+use crate::ui::annotations::{self, Annotations};
+use crate::ui::chessboard::{self, SquareOverlay};
+use crate::ui::console_view::ConsoleView;
+use crate::ui::fmt::{self, i32_to_str, u32_to_str};
+use crate::ui::frame_overlay::FrameOverlay;
+use crate::ui::hold_bar::HoldBar;
+use crate::ui::invalidation::{Invalidation, Region};
+use crate::ui::square_cache::{CornerLabel, SquareCache, SquareLook};
+use crate::ui::theme::Theme;
+use crate::ui::{movelist, pieces, text};
+use archive::{Ending, Rated, SavedGame};
+use checkpoint::Checkpoint;
+use core::cell::Cell;
+use end_screen::EndSummary;
+use history::{History, Line};
+use observer::Observers;
+use rhess_core::ai::{
+    AiConfig, MoveOrigin, SearchResult, SearchStats, TranspositionTable, Variety,
+    choose_best_move_until, explain_move, static_eval,
+};
+use rhess_core::fen;
+use rhess_core::integrity::IntegrityError;
+use rhess_core::notation::{self, MoveText};
+use rhess_core::phase;
+use rhess_core::profile::EvalProfile;
+use rhess_core::rating;
+use rhess_core::record::GameSetup;
+use rhess_core::repetition::Repetitions;
+use rhess_core::result::{FIFTY_MOVE_PLIES, GameResult};
+use rhess_core::san;
+use rhess_core::tablebase;
+use rhess_core::{ChessError, Color, Destinations, GameState, Move, Piece, PieceKind, SquareSet};
+use rtt_target::rprintln;
+use scheduler::{Phase, Scheduler};
+use status_leds::StatusLeds;
+
+const RIGHT_X: u16 = chessboard::BOARD_SIZE;
+const RIGHT_MARGIN: u16 = 4;
+// 续局与复盘后开新局时 AI 落子前的默认等待；新对局由开局向导选择。
+const AI_MOVE_MIN_DELAY_MS: u32 = 1_000;
+// AI 思考中写检查点的最短间隔；续局时检查点着法至少要搜到这一深度才直接走出，否则重新搜索。
+const CHECKPOINT_INTERVAL_MS: u32 = 500;
+const RESUME_MIN_DEPTH: u8 = 3;
+// 每帧最多合并的输入事件数，连发时光标可一次跨多格而只重绘一次。
+const MAX_COALESCED_ACTIONS: usize = 4;
+// 结算画面 LED 流水灯步进间隔。
+const END_LED_STEP_MS: u32 = 300;
+// 五十步规则（`FIFTY_MOVE_PLIES` 个未吃子、未动兵的半回合判和）的计数达到 80 起警示。
+const FIFTY_MOVE_WARN: u16 = 80;
+// 复盘时侧栏列出的最近着法数。
+const REVIEW_LIST_LEN: usize = 5;
+// 侧栏着法列表的起始行与可见行数（止于 AI 说明行之上）。
+const MOVE_LIST_Y: u16 = 82;
+const MOVE_LIST_ROWS: usize = 11;
+// 侧栏状态行（见 `crate::status`）的位置，以及 AI 着法说明与操作提示的停留时间。
+const STATUS_Y: u16 = 222;
+const AI_NOTE_MS: u32 = 3_000;
+const NOTICE_MS: u32 = 2_000;
+// AI 着法说明之后的搜索开销（深度、节点数、用时）的停留时间。
+const AI_STATS_MS: u32 = 2_500;
+// 无障碍模式下光标格亮/暗各持续的时间，以及选中棋子的边框宽度。
+const CURSOR_BLINK_MS: u32 = 400;
+const SELECTED_BORDER: u16 = 3;
+// FEN 面板每行字数（2 倍字号每字 12 像素，侧栏约 200 像素宽）。
+const FEN_CHARS_PER_LINE: usize = 16;
+// 低电量暂停时的背光 PWM 占空比。
+const PAUSED_BRIGHTNESS: u8 = 0x30;
+// 限时对局侧栏两个棋钟的水平间距，以及剩余用时转为警示色的秒数。
+const CLOCK_STRIDE: u16 = 100;
+const LOW_TIME_S: u32 = 10;
+// 运子教学叠加层中走法范围方格的边框宽度。
+const TUTOR_BORDER: u16 = 2;
+// 被将军的王所在方格的警示色边框宽度。
+const CHECK_BORDER: u16 = 2;
+// 选中无合法着法的己方棋子后，"无着"角标停留多久再自动取消选择。
+const NO_MOVES_MS: u32 = 800;
+
+pub struct Game<'a> {
+    state: GameState,
+    cursor: (u8, u8),     // (file, rank_from_bottom)
+    selected: Option<u8>, // 0..63
+    promotion: Option<PromotionPrompt>,
+    last_move: Option<(u8, u8)>,
+    ai_sides: [bool; 2], // 白/黑是否由 AI 控制
+    /// 自动翻转棋盘：让 `perspective` 一方坐在屏幕下方（见 `sync_orientation`）。
+    auto_flip: bool,
+    /// 棋盘当前是否翻转（黑方在下）。
+    flipped: bool,
+    theme: &'static Theme,
+    /// 坐标与棋子名风格（英文/中文）。
+    labels: Labels,
+    /// 升变菜单的棋子顺序（KEY1~KEY4）。
+    promotion_order: PromotionOrder,
+    /// 摸子走子规则，仅在人人对局中生效（见 `touch_locked`）。
+    touch_move: bool,
+    /// AI 落子后把光标移到其落点，被将军时移到己方王（见 `snap_cursor`）。
+    snap_cursor: bool,
+    /// 无障碍显示：光标闪烁、选中棋子加边框。
+    blink_cursor: bool,
+    /// 闪烁相位：光标格当前是否亮起，以及本轮闪烁的起点（光标移动时重置为亮）。
+    cursor_lit: bool,
+    blink_since_ms: u32,
+    /// 本帧待重绘的方格与侧栏区域，由输入/AI/时钟阶段登记，渲染阶段统一 `flush`。
+    dirty: Invalidation,
+    /// 各格上次画出的样子，`render_square` 据此跳过没有变化的格子。
+    square_cache: SquareCache,
+    /// 选中棋子的落点提示，每次 `flush` 前按 `move_hints` 重算一次，免得逐格生成着法。
+    hints: Destinations,
+    /// 显示的局面中被将军的王所在格，与 `hints` 一同在 `flush` 前刷新。
+    checked_king: Option<u8>,
+    ai_wait_since: Option<u32>, // AI 回合开始时刻（ms），用于非阻塞的最短思考间隔
+    /// AI 回合开始后至少等待这么久再搜索，便于看清对局。
+    ai_delay_ms: u32,
+    /// 双方棋钟的时限，重新开局时沿用。
+    time_control: TimeControl,
+    /// 快速对弈的重画间隔（半回合），见 `Players::playout`；按任意键后变回 None。
+    playout: Option<u16>,
+    /// 本轮快速对弈的累计结果：白胜、和、黑胜。
+    playout_games: [u32; 3],
+    observers: Observers<'a>,
+    clock: GameClock,
+    history: History,
+    /// 主线上走过的局面，判三次重复用；与 `history` 同步增减。
+    repetitions: Repetitions,
+    /// 将死后显示结算画面；选择复盘时暂时关闭以查看棋盘。
+    end_screen: bool,
+    /// 结算画面选了回到主菜单：本帧结束后退出帧循环。
+    leave: bool,
+    /// 复盘中显示的历史局面；None 表示显示当前局面。
+    review: Option<ReviewView>,
+    end_led_step: u8,
+    end_led_last_ms: u32,
+    console: Console,
+    input: InputMapper,
+    status_leds: StatusLeds,
+    /// AI 思考期间登记的预走 (from, to)，AI 落子后若仍合法立即走出。
+    premove: Option<(u8, u8)>,
+    /// 完整性检查发现的局面损坏；置位后停止走子，侧栏提示恢复或重开。
+    corrupt: Option<IntegrityError>,
+    /// 电脑对电脑对局的低电量暂停状态。
+    battery_pause: BatteryPause,
+    /// AI 最近一步的半回合序号与来源（开局库/查表/搜索深度），显示在着法列表该步旁。
+    ai_origin: Option<(usize, SearchResult)>,
+    /// 主线离开开局库的半回合序号；找到后不再重算，只在截断历史或重新开局时清除。
+    book_exit: Option<usize>,
+    /// 离开开局库的提示尚待显示在状态行（落子时拿不到 `board`，留给时钟阶段）。
+    book_exit_notice: bool,
+    /// 运子教学叠加层：选中棋子的基本走法与当前合法落点，下一次按键时撤下。
+    tutor: Option<TutorOverlay>,
+    /// 经串口画在棋盘上的批注箭头与方格标记，直到 `clear-marks` 或重新摆局面。
+    annotations: Annotations,
+    /// 选中的己方棋子无合法着法：(方格, 选中时刻)，方格角标提示"无着"，到时自动取消选择。
+    no_moves: Option<(u8, u32)>,
+    /// 侧栏以大字显示当前局面的 FEN（代替着法列表），下一次按键时撤下。
+    fen_panel: bool,
+    /// 本局计分后的等级分变化（赛前, 赛后），显示在结算画面。
+    rating_change: Option<(u16, u16)>,
+    /// 按住 KEY1/KEY2 等待长按时的进度条。
+    hold_bar: HoldBar,
+    /// KEY1+KEY4 开关的帧耗时调试叠加层。
+    frame_overlay: FrameOverlay,
+    /// KEY2+KEY3 开关的屏幕控制台，显示在着法列表的位置。
+    console_view: ConsoleView,
+    /// AI 搜索期间在侧栏右上角横行的小螃蟹。
+    thinking: Animation,
+    /// 本局的随机种子与 AI 设置，随存档保存并写入 PGN 标签；旧格式存档复盘时未知。
+    setup: Option<GameSetup>,
+}
+
+/// 选中棋子在空棋盘上的走法范围（描边）与当前局面下的合法落点（角标），
+/// 让初学者区分"这个子怎么走"与"现在能走到哪"。
+#[derive(Clone, Copy)]
+struct TutorOverlay {
+    pattern: SquareSet,
+    legal: SquareSet,
+}
+
+/// 电池将耗尽时暂停电脑对电脑对局：在两步之间停下而不是打断搜索，先写检查点、调暗背光、
+/// 熄灭 LED，等玩家按键继续。电量回升后再次跌落才会重新暂停，因此电量仍低时也可以继续下完。
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BatteryPause {
+    Running,
+    /// 电量刚转低，下一步开始思考前暂停。
+    Pending,
+    Paused,
+}
+
+/// 复盘时的显示局面：着法树中 `line` 上第 `ply` 个半回合之后。
+struct ReviewView {
+    line: Line,
+    ply: usize,
+    state: GameState,
+    last_move: Option<(u8, u8)>,
+    /// 试走中：按键移动光标、选子、提交，着法记为变着。
+    trying: bool,
+}
+
+/// 对局双方与 AI 设置，由开局向导选定；续局取检查点中的设置，复盘沿用默认 AI 设置。
+#[derive(Clone, Copy)]
+pub struct Players {
+    /// 白/黑是否由 AI 控制。
+    pub ai_sides: [bool; 2],
+    pub ai: AiConfig,
+    /// 白/黑 AI 的评估风格。
+    pub profiles: [EvalProfile; 2],
+    /// AI 回合开始后至少等待这么久再落子。
+    pub ai_delay_ms: u32,
+    /// 双方棋钟的时限。
+    pub time_control: TimeControl,
+    /// 快速对弈（仅电脑对电脑）：每隔这么多半回合才重画，不等待，一局接一局。
+    pub playout: Option<u16>,
+}
+
+impl Players {
+    /// 默认 AI 强度与落子间隔。
+    pub fn with_defaults(ai_sides: [bool; 2]) -> Self {
+        Players {
+            ai_sides,
+            ai: AiConfig::default(),
+            profiles: [EvalProfile::BALANCED; 2],
+            ai_delay_ms: AI_MOVE_MIN_DELAY_MS,
+            time_control: TimeControl::Untimed,
+            playout: None,
+        }
+    }
+}
+
+/// 对局时长与起止时间戳（RTC 墙上时间），结束后时长停止走动。
+struct GameClock {
+    started_at: DateTime,
+    start_ms: u32,
+    ended_at: Option<DateTime>,
+    /// 对局已分出结果（含结束原因），时钟阶段据此结束计时。
+    result: Option<GameResult>,
+    /// 结束时冻结的时长（秒）。
+    final_seconds: Option<u32>,
+    /// 侧边栏当前显示的时钟，变化时才重绘。
+    shown: Option<ClockFace>,
+}
+
+/// 侧栏时钟显示的内容：限时对局为双方剩余秒数与走钟的一方，不限时为对局时长。
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ClockFace {
+    Elapsed(u32),
+    Countdown {
+        seconds: [u32; 2],
+        running: Option<Color>,
+    },
+}
+
+impl GameClock {
+    fn start(board: &mut Board) -> Self {
+        GameClock {
+            started_at: board.rtc.now(),
+            start_ms: board.timebase.now_ms(),
+            ended_at: None,
+            result: None,
+            final_seconds: None,
+            shown: None,
+        }
+    }
+
+    /// 存档对局的时钟：已结束，时长与起止时间取自存档。
+    fn finished(saved: &SavedGame) -> Self {
+        GameClock {
+            started_at: saved.started_at,
+            start_ms: 0,
+            ended_at: Some(saved.ended_at),
+            result: Some(saved.result),
+            final_seconds: Some(saved.seconds),
+            shown: None,
+        }
+    }
+}
+
+/// 操作前记下的棋盘状态，操作后由 `invalidate_since` 对比出要重绘的方格与侧栏。
+struct BoardMarks {
+    /// 显示中的局面（复盘时为复盘局面），重画时与之逐格比较（见 `GameState::diff`）。
+    position: GameState,
+    /// 高亮类标记覆盖的方格（见 `marked_squares`）。
+    marked: u64,
+    /// 侧栏内容随之变化的状态：半回合数、升变菜单、FEN 面板。
+    panel: (usize, Option<u8>, bool),
+}
+
+#[derive(Clone, Copy)]
+struct PromotionPrompt {
+    from: u8,
+    to: u8,
+    color: Color,
+    moves: [Option<Move>; 4], // 按设置的升变顺序排列，依次对应 KEY1~KEY4
+}
+
+impl<'a> Game<'a> {
+    pub fn run(
+        board: &mut Board,
+        initial: GameState,
+        players: Players,
+        settings: Settings,
+        observers: Observers<'a>,
+    ) {
+        Game::new(board, initial, players, settings, observers).main_loop(board)
+    }
+
+    /// 打开一局存档：停在终局进入复盘，长按 KEY2 到结算画面，可从那里导出、以人人模式开新局
+    /// 或回到主菜单。
+    pub fn review_saved(
+        board: &mut Board,
+        saved: &SavedGame,
+        history: History,
+        settings: Settings,
+        observers: Observers<'a>,
+    ) {
+        let start = *history.start();
+        let players = Players::with_defaults([false, false]);
+        let mut game = Game::new(board, start, players, settings, observers);
+        game.state = history.replay(history.len());
+        game.last_move = history
+            .len()
+            .checked_sub(1)
+            .and_then(|prev| history.get(prev))
+            .map(|mv| (mv.from, mv.to));
+        game.book_exit = history.book_exit();
+        game.repetitions = history.repetitions();
+        game.history = history;
+        game.clock = GameClock::finished(saved);
+        game.setup = saved.setup;
+        game.review_goto(Line::Main, game.history.len());
+        game.main_loop(board)
+    }
+
+    /// 上电时发现 AI 思考检查点：恢复对局记录与开局向导选定的设置（强度、风格、随机种子、
+    /// 时限与落子间隔；双方用时按时限重新计起），检查点着法够深就直接走出，否则 AI 重新搜索。对局时长从续局时重新计起。
+    pub fn resume(
+        board: &mut Board,
+        checkpoint: Checkpoint,
+        settings: Settings,
+        observers: Observers<'a>,
+    ) {
+        let history = checkpoint.history;
+        let setup = checkpoint.setup;
+        let players = Players {
+            ai: setup.ai,
+            profiles: setup.profiles,
+            ai_delay_ms: checkpoint.ai_delay_ms,
+            time_control: setup.time_control,
+            ..Players::with_defaults(setup.ai_sides)
+        };
+        let mut game = Game::new(board, *history.start(), players, settings, observers);
+        // 沿用原来的种子，开局库与相近着法的随机选择照原局继续。
+        game.setup = Some(setup);
+        let plies = history.len();
+        game.state = history.replay(plies);
+        game.last_move = plies
+            .checked_sub(1)
+            .and_then(|prev| history.get(prev))
+            .map(|mv| (mv.from, mv.to));
+        game.book_exit = history.book_exit();
+        game.repetitions = history.repetitions();
+        game.history = history;
+        checkpoint::clear(&mut board.backup);
+        let best = checkpoint
+            .best
+            .filter(|&(_, depth)| depth >= RESUME_MIN_DEPTH && game.is_ai_turn());
+        match best {
+            Some((mv, depth)) if game.apply_move(mv) => {
+                let origin = SearchResult {
+                    mv,
+                    origin: MoveOrigin::Search,
+                    depth,
+                    nodes: 0,
+                    stats: SearchStats::default(),
+                };
+                game.ai_origin = Some((plies, origin));
+                game.snap_cursor();
+                board.status.push("Resumed: AI move played", NOTICE_MS);
+            }
+            _ => board.status.push("Resumed game", NOTICE_MS),
+        }
+        rprintln!("resumed game at ply {} from checkpoint", plies);
+        game.main_loop(board)
+    }
+
+    fn new(
+        board: &mut Board,
+        initial: GameState,
+        players: Players,
+        settings: Settings,
+        observers: Observers<'a>,
+    ) -> Self {
+        let Players {
+            ai_sides,
+            ai,
+            profiles,
+            ai_delay_ms,
+            time_control,
+            playout,
+        } = players;
+        board.chess_clock.reset(&board.main, time_control);
+        let mut game = Game {
+            state: initial,
+            cursor: (0, 0),
+            selected: None,
+            promotion: None,
+            last_move: None,
+            ai_sides,
+            auto_flip: settings.auto_flip,
+            flipped: false,
+            theme: settings.theme(),
+            labels: settings.labels,
+            promotion_order: settings.promotion_order,
+            touch_move: settings.touch_move && ai_sides == [false, false],
+            snap_cursor: settings.snap_cursor,
+            blink_cursor: settings.blink_cursor,
+            cursor_lit: true,
+            blink_since_ms: board.timebase.now_ms(),
+            dirty: Invalidation::new(),
+            square_cache: SquareCache::new(),
+            hints: Destinations::default(),
+            checked_king: None,
+            ai_wait_since: None,
+            ai_delay_ms,
+            time_control,
+            playout,
+            playout_games: [0; 3],
+            observers,
+            clock: GameClock::start(board),
+            history: History::new(initial),
+            repetitions: Repetitions::new(),
+            end_screen: false,
+            leave: false,
+            review: None,
+            end_led_step: 0,
+            end_led_last_ms: 0,
+            console: Console::new(),
+            input: InputMapper::new(InputMode::Game),
+            status_leds: StatusLeds::new(),
+            premove: None,
+            corrupt: None,
+            battery_pause: BatteryPause::Running,
+            ai_origin: None,
+            book_exit: None,
+            book_exit_notice: false,
+            tutor: None,
+            annotations: Annotations::new(),
+            no_moves: None,
+            fen_panel: false,
+            rating_change: None,
+            hold_bar: HoldBar::sidebar(&board.lcd),
+            frame_overlay: FrameOverlay::sidebar(&board.lcd),
+            console_view: ConsoleView::new(RIGHT_X + RIGHT_MARGIN + 2, MOVE_LIST_Y),
+            thinking: Animation::new(
+                &start_menu_crab::THINKING,
+                board.lcd.width - CRAB_SMALL_W - THINKING_STRIDE - 4,
+                2,
+                settings.theme().ui_bg,
+            ),
+            setup: Some(Self::fresh_setup(
+                board,
+                ai_sides,
+                ai,
+                profiles,
+                time_control,
+            )),
+        };
+        game.cursor = game.home_cursor();
+        game.flipped = game.faces_black();
+        game
+    }
+
+    /// 帧循环，直到在结算画面选择回到主菜单。
+    fn main_loop(&mut self, board: &mut Board) {
+        self.status_leds.show_position(&self.state);
+        self.clear_screen(board);
+        self.render_full(board);
+
+        // AI 搜索表跨回合保留，重复出现的局面可直接查表作答。放在帧循环这里而不是 Game 中，
+        // 搜索回调才能同时借用整个 Game。
+        let mut search_table = TranspositionTable::new();
+        let mut scheduler = Scheduler::new(&board.timebase);
+        loop {
+            scheduler.begin_frame(&board.timebase);
+            self.handle_input(board, &mut search_table);
+            scheduler.mark(Phase::Input, &board.timebase);
+            self.ai_slice(board, &mut search_table);
+            scheduler.mark(Phase::Ai, &board.timebase);
+            // 时钟阶段：更新对局时长并向观察者广播时间。
+            self.tick_clock(board);
+            scheduler.mark(Phase::Clock, &board.timebase);
+            // 快速对弈时一帧就是一步棋：失效区域攒到每第 N 个半回合才重画，也不按帧预算休眠。
+            match self.playout {
+                None => {
+                    self.render_frame(board, &scheduler);
+                    scheduler.mark(Phase::Render, &board.timebase);
+                    scheduler.end_frame(&board.timebase, &mut board.delay);
+                }
+                Some(every) if self.history.len().is_multiple_of(every as usize) => {
+                    self.render_frame(board, &scheduler);
+                }
+                Some(_) => {}
+            }
+            if self.leave {
+                break;
+            }
+        }
+        // 结算画面的流水灯与状态行不带回菜单。
+        board.leds.all_off();
+        board.status.clear(&board.main);
+    }
+
+    /// 渲染阶段：重画失效区域，再叠加帧统计与串口日志。
+    fn render_frame(&mut self, board: &mut Board, scheduler: &Scheduler) {
+        self.render_pending(board);
+        if self.shows_frame_overlay() {
+            let now_ms = board.timebase.now_ms();
+            let stats = scheduler.stats();
+            self.frame_overlay
+                .update(&mut board.lcd, self.theme, now_ms, stats);
+        }
+        if self.shows_console() {
+            self.console_view
+                .update(&mut board.lcd, self.theme, &board.serial.log);
+        }
+    }
+
+    fn tick_clock(&mut self, board: &mut Board) {
+        let now_ms = board.timebase.now_ms();
+        self.observers.notify_clock_tick(now_ms);
+        if board.battery.update(&mut board.entropy, now_ms) {
+            rprintln!(
+                "low battery: {} mV",
+                board.battery.millivolts().unwrap_or(0)
+            );
+            board.status.push("Low battery", NOTICE_MS);
+            if self.ai_sides == [true, true] && self.clock.result.is_none() {
+                self.battery_pause = BatteryPause::Pending;
+            }
+        }
+        if self.book_exit_notice {
+            self.book_exit_notice = false;
+            board.status.push("Out of book", NOTICE_MS);
+        }
+        // 搜索最深的调用链刚在本帧的 AI 阶段结束，此时检查栈守卫区最及时。
+        stack_guard::check(board);
+        if self.corrupt.is_none()
+            && let Err(err) = self.state.check_integrity()
+        {
+            self.report_corruption(board, err);
+        }
+        self.run_chess_clock(board);
+        if self.clock.result.is_some() && self.clock.ended_at.is_none() {
+            self.finish_clock(board);
+            if self.playout.is_some() {
+                self.next_playout_game(board);
+                return;
+            }
+            self.end_screen = true;
+            self.end_led_last_ms = now_ms;
+            self.dirty.all();
+        }
+        // 低电量暂停时 LED 保持熄灭。
+        if self.battery_pause == BatteryPause::Paused {
+            return;
+        }
+        // 结算画面：LED 缓慢流水（减少动态效果时全亮）；其余时间 LED 指示行棋方与将军/思考状态。
+        if !self.end_screen {
+            self.status_leds.update(&mut board.leds, &board.timebase);
+        } else if !board.timebase.motion_enabled() {
+            (0..4).for_each(|i| board.leds.set(i, true));
+        } else if now_ms.wrapping_sub(self.end_led_last_ms) >= END_LED_STEP_MS {
+            self.end_led_last_ms = now_ms;
+            Self::advance_led_spinner(board, &mut self.end_led_step);
+        }
+    }
+
+    /// 棋钟：轮到谁走谁的钟走，终局、复盘、局面损坏与低电量暂停时停钟。用时耗尽的一方
+    /// 判负；AI 的搜索在 AI 阶段内完成，超时在它落子后的这里才判出，仍算它超时。
+    fn run_chess_clock(&mut self, board: &mut Board) {
+        if !board.chess_clock.is_timed() {
+            return;
+        }
+        if self.clock.result.is_none()
+            && let Some(flagged) = board.chess_clock.flagged()
+        {
+            self.clock.result = Some(GameResult::FlagFall {
+                winner: flagged.opposite(),
+            });
+            self.promotion = None;
+            self.premove = None;
+        }
+        let running = (self.clock.result.is_none()
+            && self.review.is_none()
+            && self.corrupt.is_none()
+            && self.battery_pause != BatteryPause::Paused)
+            .then_some(self.state.side_to_move);
+        board.chess_clock.run(&board.main, running);
+    }
+
+    /// 侧栏时钟此刻应有的内容。
+    fn clock_face(&self, board: &Board) -> ClockFace {
+        let clock = &board.chess_clock;
+        if !clock.is_timed() {
+            return ClockFace::Elapsed(self.elapsed_seconds(board));
+        }
+        // 向上取整：显示 0:00 时恰好超时。
+        let seconds = |side| clock.remaining_ms(side).div_ceil(1000);
+        ClockFace::Countdown {
+            seconds: [seconds(Color::White), seconds(Color::Black)],
+            running: clock.running(),
+        }
+    }
+
+    /// 局面损坏：记录原因并切换到恢复提示（快速对弈随之停下），而不是在无法到达的局面上继续走子。
+    fn report_corruption(&mut self, board: &mut Board, err: IntegrityError) {
+        rprintln!("position integrity check failed: {:?}", err);
+        board.serial.write_bytes(b"error: position corrupt\r\n");
+        board.status.push("Position corrupt", NOTICE_MS);
+        self.corrupt = Some(err);
+        self.playout = None;
+        self.selected = None;
+        self.promotion = None;
+        self.premove = None;
+        self.dirty.all();
+    }
+
+    /// 恢复提示输入：KEY1 回到历史中最后一个完好的局面，KEY4 重新开局。
+    fn handle_recovery(&mut self, board: &mut Board) {
+        match self.input.poll(board) {
+            Some(Action::RestoreCheckpoint) => self.restore_checkpoint(board),
+            Some(Action::NewGame) => self.new_game(board),
+            _ => {}
+        }
+    }
+
+    /// 截断历史到最后一个完好的局面继续对局；起始局面本身损坏时只能重新开局。
+    fn restore_checkpoint(&mut self, board: &mut Board) {
+        let Some((ply, state)) = self.history.last_consistent() else {
+            self.new_game(board);
+            return;
+        };
+        self.history.truncate(ply);
+        self.repetitions = self.history.repetitions();
+        self.ai_origin = self.ai_origin.filter(|(origin_ply, _)| *origin_ply < ply);
+        self.book_exit = self.book_exit.filter(|&exit| exit < ply);
+        self.state = state;
+        self.last_move = ply
+            .checked_sub(1)
+            .and_then(|prev| self.history.get(prev))
+            .map(|mv| (mv.from, mv.to));
+        self.clock.result = state
+            .result_with(&self.repetitions)
+            .or_else(|| tablebase::adjudicate(&state));
+        self.status_leds.show_position(&self.state);
+        self.corrupt = None;
+        self.ai_wait_since = None;
+        board
+            .status
+            .push_fmt(format_args!("Restored move {}", ply), NOTICE_MS);
+        self.clear_screen(board);
+        self.dirty.all();
+    }
+
+    /// 结算画面输入：新对局 / 复盘 / 经 USART1 导出着法 / 回到主菜单。
+    fn handle_end_screen(&mut self, board: &mut Board) {
+        match self.input.poll(board) {
+            Some(Action::NewGame) => self.new_game(board),
+            Some(Action::MainMenu) => self.leave = true,
+            Some(Action::Review) => {
+                self.end_screen = false;
+                self.review_goto(Line::Main, self.history.len());
+                board.leds.all_off();
+                self.clear_screen(board);
+            }
+            Some(Action::Export) => {
+                let Some(result) = self.clock.result else {
+                    return;
+                };
+                let _ = pgn::write(
+                    &mut board.serial,
+                    &self.history,
+                    self.setup.as_ref(),
+                    &self.clock.started_at,
+                    result,
+                );
+                rprintln!("exported {} plies over USART1", self.history.len());
+            }
+            _ => {}
+        }
+    }
+
+    /// 复盘输入：KEY1/KEY4 沿当前线逐步后退/前进，KEY3 跳到终局（在变着中则回到主线的分支点），
+    /// KEY2 试走，长按 KEY2 回到结算画面。
+    fn handle_review(&mut self, board: &mut Board) {
+        let Some((line, ply)) = self.review.as_ref().map(|r| (r.line, r.ply)) else {
+            return;
+        };
+        match self.input.poll(board) {
+            Some(Action::ReviewBack) if ply > 0 => self.review_goto(line, ply - 1),
+            Some(Action::ReviewForward) if ply < self.history.line_len(line) => {
+                self.review_goto(line, ply + 1)
+            }
+            Some(Action::ReviewLatest) => match self.history.branch(line) {
+                Some(branch) => self.review_goto(Line::Main, branch),
+                None => self.review_goto(Line::Main, self.history.len()),
+            },
+            // 标注刚走出的那一着（第 ply 个半回合之前的一步）。
+            Some(Action::ReviewAnnotate) if ply > 0 => {
+                self.history.cycle_annotation(line, ply - 1);
+                self.dirty.all();
+            }
+            Some(Action::ReviewTry) => self.set_review_trying(true),
+            Some(Action::ReviewExit) => {
+                self.review = None;
+                self.end_screen = true;
+                self.dirty.all();
+            }
+            _ => {}
+        }
+    }
+
+    /// 复盘试走：与对局相同的键位移动光标、选子、提交，提交的着法记入着法树并前进一步，
+    /// 可接着替双方走下去。未选子时长按 KEY2 回到逐步回看。
+    fn handle_review_try(&mut self, board: &mut Board) {
+        let Some(action) = self.input.poll(board) else {
+            return;
+        };
+        let Some(view) = self.review.as_ref().map(|r| r.state) else {
+            return;
+        };
+        match action {
+            Action::MoveLeft | Action::MoveRight | Action::MoveUp | Action::MoveDown => {
+                let before = self.cursor;
+                self.cursor = self.stepped_cursor(action);
+                if self.cursor != before {
+                    self.dirty.square(before.0, before.1);
+                    self.dirty.square(self.cursor.0, self.cursor.1);
+                }
+            }
+            Action::ToggleSelect => {
+                let before = self.board_marks();
+                let idx = Self::index(self.cursor.0, self.cursor.1);
+                let own = view.board[idx as usize].is_some_and(|p| p.color == view.side_to_move);
+                self.selected = (self.selected != Some(idx) && own).then_some(idx);
+                self.invalidate_since(&before);
+            }
+            Action::SubmitMove => match self.selected {
+                Some(src) => self.try_review_move(board, src),
+                None => self.set_review_trying(false),
+            },
+            _ => {}
+        }
+    }
+
+    fn set_review_trying(&mut self, trying: bool) {
+        if let Some(review) = self.review.as_mut() {
+            review.trying = trying;
+        }
+        self.selected = None;
+        self.dirty.all();
+    }
+
+    /// 在复盘局面上走出选中棋子到光标格的一着；试走不弹升变菜单，一律升后。
+    fn try_review_move(&mut self, board: &mut Board, src: u8) {
+        let Some((line, ply, view)) = self.review.as_ref().map(|r| (r.line, r.ply, r.state)) else {
+            return;
+        };
+        let dst = Self::index(self.cursor.0, self.cursor.1);
+        if src == dst {
+            return;
+        }
+        let mv = view.generate_legal_moves().iter().copied().find(|mv| {
+            mv.from == src && mv.to == dst && mv.promotion.is_none_or(|k| k == PieceKind::Queen)
+        });
+        let Some(mv) = mv else {
+            let err = view
+                .check_move(Move::quiet(src, dst))
+                .err()
+                .unwrap_or(ChessError::IllegalMove);
+            board.status.push(err.message(), NOTICE_MS);
+            return;
+        };
+        match self.history.try_move(line, ply, &view, mv) {
+            Ok(line) => {
+                self.selected = None;
+                self.review_goto(line, ply + 1);
+            }
+            Err(err) => board.status.push(err.message(), NOTICE_MS),
+        }
+    }
+
+    fn review_goto(&mut self, line: Line, ply: usize) {
+        let line = self.history.normalize(line, ply);
+        let last_move = ply
+            .checked_sub(1)
+            .and_then(|prev| self.history.line_move(line, prev))
+            .map(|mv| (mv.from, mv.to));
+        let state = self.history.replay_line(line, ply);
+        let trying = self.review.as_ref().is_some_and(|r| r.trying);
+        self.status_leds.show_position(&state);
+        self.review = Some(ReviewView {
+            line,
+            ply,
+            state,
+            last_move,
+            trying,
+        });
+        self.dirty.all();
+    }
+
+    /// 当前显示的局面：复盘时为历史局面，否则为实际局面。
+    fn view(&self) -> &GameState {
+        self.review.as_ref().map_or(&self.state, |r| &r.state)
+    }
+
+    /// 对局以和棋结束，且显示的正是终局局面（未在复盘别处或试走）。
+    fn showing_draw(&self) -> bool {
+        self.clock
+            .result
+            .is_some_and(|result| result.winner().is_none())
+            && self
+                .review
+                .as_ref()
+                .is_none_or(|r| !r.trying && r.line == Line::Main && r.ply == self.history.len())
+    }
+
+    fn input_mode(&self) -> InputMode {
+        if self.corrupt.is_some() {
+            InputMode::Recovery
+        } else if self.battery_pause == BatteryPause::Paused {
+            InputMode::Paused
+        } else if self.end_screen {
+            InputMode::Summary
+        } else if let Some(review) = &self.review {
+            if review.trying {
+                InputMode::Game
+            } else {
+                InputMode::Review
+            }
+        } else if self.promotion.is_some() {
+            InputMode::Promotion
+        } else {
+            InputMode::Game
+        }
+    }
+
+    /// 以相同模式重新开局。
+    fn new_game(&mut self, board: &mut Board) {
+        self.start_from(board, GameState::start_position());
+    }
+
+    /// 从给定局面开局（控制台 `set` 摆局也走这里）。
+    fn start_from(&mut self, board: &mut Board, state: GameState) {
+        self.state = state;
+        self.cursor = self.home_cursor();
+        self.selected = None;
+        self.promotion = None;
+        self.last_move = None;
+        self.ai_wait_since = None;
+        self.clock = GameClock::start(board);
+        board.chess_clock.reset(&board.main, self.time_control);
+        let (ai, profiles) = self.setup.map_or_else(
+            || (AiConfig::default(), [EvalProfile::BALANCED; 2]),
+            |setup| (setup.ai, setup.profiles),
+        );
+        self.setup = Some(Self::fresh_setup(
+            board,
+            self.ai_sides,
+            ai,
+            profiles,
+            self.time_control,
+        ));
+        // 摆出的局面可能已是终局（无着可走、子力不足、已满五十步或残局库可判），交给时钟阶段处理。
+        self.repetitions.clear();
+        self.clock.result = self
+            .state
+            .result_with(&self.repetitions)
+            .or_else(|| tablebase::adjudicate(&self.state));
+        self.history.reset(state);
+        self.status_leds.show_position(&self.state);
+        board.status.clear(&board.main);
+        self.premove = None;
+        self.corrupt = None;
+        self.end_battery_pause(board);
+        self.ai_origin = None;
+        self.book_exit = None;
+        self.book_exit_notice = false;
+        self.tutor = None;
+        self.annotations.clear();
+        self.fen_panel = false;
+        self.rating_change = None;
+        self.end_screen = false;
+        self.review = None;
+        board.leds.all_off();
+        self.clear_screen(board);
+        self.dirty.all();
+    }
+
+    /// 新对局的设置：重新抽取随机种子，`ai` 即 run_ai 使用的搜索限制，
+    /// `profiles` 为白/黑 AI 的评估风格，`time_control` 为双方棋钟的时限。
+    fn fresh_setup(
+        board: &mut Board,
+        ai_sides: [bool; 2],
+        ai: AiConfig,
+        profiles: [EvalProfile; 2],
+        time_control: TimeControl,
+    ) -> GameSetup {
+        GameSetup {
+            seed: board.entropy.next_u32(&board.timebase),
+            ai_sides,
+            ai,
+            profiles,
+            time_control,
+            version: archive::firmware_version(),
+        }
+    }
+
+    /// 对局结束：冻结时长，打印起止时间戳并把对局存入 Flash 存档区。
+    fn finish_clock(&mut self, board: &mut Board) {
+        let ended_at = board.rtc.now();
+        let seconds = board.timebase.elapsed_ms(self.clock.start_ms) / 1000;
+        self.clock.ended_at = Some(ended_at);
+        self.clock.final_seconds = Some(seconds);
+        let (start, end) = (self.clock.started_at, ended_at);
+        rprintln!(
+            "game started {}-{:02}-{:02} {:02}:{:02}:{:02}, ended {}-{:02}-{:02} {:02}:{:02}:{:02}",
+            start.year,
+            start.month,
+            start.day,
+            start.hour,
+            start.minute,
+            start.second,
+            end.year,
+            end.month,
+            end.day,
+            end.hour,
+            end.minute,
+            end.second
+        );
+        // 摆出的局面一步未走就结束时不值得保存；快速对弈的对局改经 USART1 导出，免得挤掉
+        // 存档里的其他对局与等级分记录，也免得频繁擦写 Flash。
+        if self.history.len() == 0 || self.playout.is_some() {
+            return;
+        }
+        // 没有设置的只有旧存档的复盘，它本就在存档中。
+        let Some(setup) = self.setup else {
+            return;
+        };
+        let Some(result) = self.clock.result else {
+            return;
+        };
+        let before = archive::current_rating(&board.flash);
+        let rated = self.rated_side().map(|human| {
+            // 与 run_ai 相同的搜索限制决定 AI 的名义等级分。
+            let engine = rating::engine_rating(&setup.ai);
+            let rating = rating::updated(before, engine, result.score_for(human));
+            Rated { human, rating }
+        });
+        match archive::save(
+            &mut board.flash,
+            &self.history,
+            &Ending {
+                result,
+                started_at: start,
+                ended_at: end,
+                seconds,
+                rated,
+            },
+            &setup,
+        ) {
+            Ok(seq) => {
+                rprintln!("saved game #{}", seq);
+                board.status.push("Game saved", NOTICE_MS);
+                if let Some(rated) = rated {
+                    rprintln!("rating {} -> {}", before, rated.rating);
+                    self.rating_change = Some((before, rated.rating));
+                }
+            }
+            Err(err) => {
+                rprintln!("saving game failed: {:?}", err);
+                board.status.push("Save failed", NOTICE_MS);
+            }
+        }
+    }
+
+    /// 快速对弈的一局结束：经 USART1 导出 PGN，在 RTT 记下本轮比分并计入备份 SRAM 中的
+    /// 总比分（见 `playout_tally`），立即以同样设置开下一局
+    /// （与结算画面的新对局相同，重新抽取种子）。
+    fn next_playout_game(&mut self, board: &mut Board) {
+        let Some(result) = self.clock.result else {
+            return;
+        };
+        let _ = pgn::write(
+            &mut board.serial,
+            &self.history,
+            self.setup.as_ref(),
+            &self.clock.started_at,
+            result,
+        );
+        let slot = match result.winner() {
+            Some(Color::White) => 0,
+            None => 1,
+            Some(Color::Black) => 2,
+        };
+        self.playout_games[slot] += 1;
+        playout_tally::record(&mut board.backup, slot);
+        let [white, draws, black] = self.playout_games;
+        rprintln!(
+            "playout game {}: {} in {} plies ({}); +{} ={} -{}",
+            white + draws + black,
+            result.pgn(),
+            self.history.len(),
+            result.reason(),
+            white,
+            draws,
+            black
+        );
+        self.new_game(board);
+        board.status.push_fmt(
+            format_args!("Playout {}-{}-{}", white, draws, black),
+            NOTICE_MS,
+        );
+    }
+
+    /// 快速对弈中按任意键：回到正常速度继续本局，结束后照常进入结算画面并存档。
+    fn stop_playout(&mut self, board: &mut Board) {
+        self.playout = None;
+        rprintln!("playout stopped at ply {}", self.history.len());
+        board.status.push("Fast playout off", NOTICE_MS);
+        self.dirty.all();
+    }
+
+    /// 计分对局：人对 AI 且从标准开局下起，返回人类一方；其余对局不影响等级分。
+    fn rated_side(&self) -> Option<Color> {
+        let human = match self.ai_sides {
+            [false, true] => Color::White,
+            [true, false] => Color::Black,
+            _ => return None,
+        };
+        (*self.history.start() == GameState::start_position()).then_some(human)
+    }
+
+    fn elapsed_seconds(&self, board: &Board) -> u32 {
+        self.clock
+            .final_seconds
+            .unwrap_or_else(|| board.timebase.elapsed_ms(self.clock.start_ms) / 1000)
+    }
+
+    fn handle_input(&mut self, board: &mut Board, table: &mut TranspositionTable) {
+        if self.shows_frame_overlay() && board.buttons.key1_key4_chord(board.timebase.now_ms()) {
+            self.frame_overlay.toggle(&mut board.lcd, self.theme);
+        }
+        if self.shows_frame_overlay() && board.buttons.key2_key3_chord(board.timebase.now_ms()) {
+            self.console_view.toggle();
+            self.dirty.all();
+        }
+        match self.console.poll(&mut board.serial, &board.main) {
+            Some(Command::SetPosition(state)) => {
+                self.start_from(board, state);
+                board.status.push("Position set", NOTICE_MS);
+                board.serial.write_bytes(b"ok\r\n");
+                return;
+            }
+            #[cfg(feature = "input-log")]
+            Some(Command::DumpInputLog) => {
+                board.input_log.dump(&mut board.serial);
+                return;
+            }
+            Some(Command::Bench) => {
+                board.status.push("Benchmarking...", NOTICE_MS);
+                self.render_pending(board);
+                crate::search_bench::run(board, table);
+                return;
+            }
+            Some(Command::PlayMove(text)) => {
+                match self.play_typed_move(text) {
+                    Ok(()) => board.serial.write_bytes(b"ok\r\n"),
+                    Err(msg) => {
+                        board.serial.write_bytes(b"error: ");
+                        board.serial.write_bytes(msg.as_bytes());
+                        board.serial.write_bytes(b"\r\n");
+                    }
+                }
+                return;
+            }
+            Some(Command::ListMoves) => {
+                self.list_moves(board);
+                return;
+            }
+            Some(Command::Arrow(arrow)) => {
+                match self.annotations.add_arrow(arrow) {
+                    Ok(()) => {
+                        self.dirty.all();
+                        board.serial.write_bytes(b"ok\r\n");
+                    }
+                    Err(msg) => {
+                        board.serial.write_bytes(b"error: ");
+                        board.serial.write_bytes(msg.as_bytes());
+                        board.serial.write_bytes(b"\r\n");
+                    }
+                }
+                return;
+            }
+            Some(Command::Mark(square, color)) => {
+                self.annotations.set_mark(square, color);
+                self.dirty.square(square % 8, square / 8);
+                board.serial.write_bytes(b"ok\r\n");
+                return;
+            }
+            Some(Command::ClearMarks) => {
+                self.annotations.clear();
+                self.dirty.all();
+                board.serial.write_bytes(b"ok\r\n");
+                return;
+            }
+            None => {}
+        }
+        let mode = self.input_mode();
+        self.input.set_mode(mode);
+        if self.playout.is_some() {
+            if self.input.poll(board).is_some() {
+                self.stop_playout(board);
+            }
+            return;
+        }
+        match mode {
+            InputMode::Summary => self.handle_end_screen(board),
+            InputMode::Review => self.handle_review(board),
+            InputMode::Promotion => self.handle_promotion(board),
+            InputMode::Recovery => self.handle_recovery(board),
+            InputMode::Paused => self.handle_paused(board),
+            _ if self.review.is_some() => self.handle_review_try(board),
+            _ => self.handle_board_input(board),
+        }
+    }
+
+    fn handle_board_input(&mut self, board: &mut Board) {
+        // AI 回合中，若对面是人类则可登记预走；终局后双方都可浏览棋盘。
+        let premoving = self.is_ai_turn() && self.clock.result.is_none();
+        if premoving && !self.can_premove() {
+            return;
+        }
+
+        // 先收集本帧的输入，渲染阶段统一绘制：只重绘状态真正变了的方格，
+        // 连发导航即只有起止两格；走子或侧栏提示变化时才连带侧栏。
+        let before = self.board_marks();
+        let mut full_redraw = false;
+        for _ in 0..MAX_COALESCED_ACTIONS {
+            let Some(action) = self.input.poll(board) else {
+                break;
+            };
+            let showed_tutor = self.tutor.take().is_some();
+            let showed_fen = core::mem::take(&mut self.fen_panel);
+            full_redraw |= showed_tutor || showed_fen;
+            let on_selected = self.selected == Some(Self::index(self.cursor.0, self.cursor.1));
+            match action {
+                // 在选中的棋子上长按 KEY2（原地提交没有意义）：显示运子教学叠加层。
+                Action::SubmitMove if on_selected && !showed_tutor => {
+                    self.show_tutor(board);
+                    full_redraw = true;
+                }
+                // 未选子时长按 KEY2：侧栏显示 FEN，并经 USART1 输出。
+                Action::SubmitMove if self.selected.is_none() && !showed_fen => {
+                    self.show_fen(board);
+                    full_redraw = true;
+                }
+                Action::MoveLeft | Action::MoveRight | Action::MoveUp | Action::MoveDown => {
+                    self.cursor = self.stepped_cursor(action);
+                }
+                Action::ToggleSelect if premoving => {
+                    self.toggle_premove_select();
+                    full_redraw = true;
+                }
+                Action::ToggleSelect => {
+                    self.toggle_select(board);
+                    full_redraw = true;
+                }
+                Action::SubmitMove if premoving => {
+                    self.set_premove();
+                    full_redraw = true;
+                }
+                Action::SubmitMove => {
+                    self.try_submit_move(board);
+                    full_redraw = true;
+                }
+                _ => {}
+            }
+            if full_redraw {
+                break;
+            }
+        }
+
+        self.invalidate_since(&before);
+    }
+
+    fn board_marks(&self) -> BoardMarks {
+        BoardMarks {
+            position: *self.view(),
+            marked: self.marked_squares(),
+            panel: (
+                self.history.len(),
+                self.promotion.map(|prompt| prompt.to),
+                self.fen_panel,
+            ),
+        }
+    }
+
+    /// 光标、选中、上一步、预走、升变候选与运子教学覆盖的方格，即 `square_look` 中
+    /// 除棋子与批注以外决定方格样子的状态。
+    fn marked_squares(&self) -> u64 {
+        let bit = |sq: u8| 1u64 << sq;
+        let pair = |squares: Option<(u8, u8)>| squares.map_or(0, |(from, to)| bit(from) | bit(to));
+        let last_move = self.review.as_ref().map_or(self.last_move, |r| r.last_move);
+        let promotion = self.promotion.map_or(0, |prompt| {
+            // 候选沿升变格所在的一列排开。
+            bit(prompt.from) | 0x0101_0101_0101_0101 << (prompt.to % 8)
+        });
+        let tutor = self
+            .tutor
+            .map_or(0, |tutor| tutor.pattern.0 | tutor.legal.0);
+        let hints = self.move_hints().all().0;
+        let check = self.view().checked_king().map_or(0, bit);
+        bit(Self::index(self.cursor.0, self.cursor.1))
+            | self.selected.map_or(0, bit)
+            | pair(last_move)
+            | pair(self.premove)
+            | promotion
+            | tutor
+            | hints
+            | check
+    }
+
+    /// 选中的是行棋方的棋子时，它在当前局面下的落点；复盘、升变菜单与运子教学叠加层
+    /// （它自己标出合法落点）显示期间不提示。
+    fn move_hints(&self) -> Destinations {
+        match self.selected {
+            Some(sq)
+                if self.review.is_none() && self.promotion.is_none() && self.tutor.is_none() =>
+            {
+                self.state.legal_destinations(sq)
+            }
+            _ => Destinations::default(),
+        }
+    }
+
+    /// 与操作前的 `before` 对比，登记变了的方格：前后任一时刻带标记的格子与棋子有变化的格子。
+    /// 局面、升变菜单或 FEN 面板变了再连带侧栏。
+    fn invalidate_since(&mut self, before: &BoardMarks) {
+        let after = self.board_marks();
+        let changed = before.position.diff(&after.position).squares();
+        self.dirty
+            .squares_in(before.marked | after.marked | changed.0);
+        if before.panel != after.panel {
+            self.invalidate_side_panel();
+        }
+    }
+
+    /// 侧栏重画会先整块刷底色，其中的时钟与叠加层随之一并重画。
+    fn invalidate_side_panel(&mut self) {
+        self.dirty.region(Region::SidePanel);
+        self.dirty.region(Region::Clock);
+        self.dirty.region(Region::Overlays);
+    }
+
+    /// 方向动作移动一格后的光标位置，到棋盘边缘不动。
+    fn stepped_cursor(&self, action: Action) -> (u8, u8) {
+        let (file, rank) = self.cursor;
+        // 棋盘翻转时，屏幕上的左右上下对应逻辑上的右左下上。
+        let action = match action {
+            Action::MoveLeft if self.flipped => Action::MoveRight,
+            Action::MoveRight if self.flipped => Action::MoveLeft,
+            Action::MoveUp if self.flipped => Action::MoveDown,
+            Action::MoveDown if self.flipped => Action::MoveUp,
+            other => other,
+        };
+        match action {
+            Action::MoveLeft => (file.saturating_sub(1), rank),
+            Action::MoveRight => ((file + 1).min(7), rank),
+            Action::MoveUp => (file, (rank + 1).min(7)),
+            Action::MoveDown => (file, rank.saturating_sub(1)),
+            _ => self.cursor,
+        }
+    }
+
+    /// 渲染阶段：登记时钟、闪烁与状态行的变化，再统一重绘本帧的失效区域。
+    fn render_pending(&mut self, board: &mut Board) {
+        // 输入或走子改动了方格时，光标闪烁从亮相位重新开始。
+        if self.dirty.has_squares() {
+            self.cursor_lit = true;
+            self.blink_since_ms = board.timebase.now_ms();
+        }
+        self.sync_orientation();
+        if self.clock.shown != Some(self.clock_face(board)) {
+            self.dirty.region(Region::Clock);
+        }
+        self.update_blink(board);
+        self.expire_no_moves(board);
+        if board.status.update(&board.main) {
+            self.dirty.region(Region::StatusLine);
+        }
+        self.flush(board);
+        if !self.end_screen {
+            let held = self.input.long_press_held_ms(board);
+            self.hold_bar.update(&mut board.lcd, self.theme, held);
+        }
+    }
+
+    /// 恰好重绘已登记的失效集合。整屏失效时交给 `render`（含结算画面）；结算画面显示期间
+    /// 其余的局部失效都被它盖住，直接丢弃。
+    fn flush(&mut self, board: &mut Board) {
+        let dirty = self.dirty.take();
+        if dirty.is_clean() {
+            return;
+        }
+        self.hints = self.move_hints();
+        self.checked_king = self.view().checked_king();
+        if dirty.is_all() {
+            self.render_full(board);
+        } else if !self.end_screen {
+            // 本帧变化的格子先全部画进离屏缓冲，再一并送屏（见 `Lcd::begin_batch`）。
+            board.lcd.begin_batch();
+            for (file, rank) in dirty.squares() {
+                self.render_square(board, file, rank);
+            }
+            board.lcd.end_batch();
+            if dirty.contains(Region::SidePanel) {
+                self.render_side_info(board);
+            } else if dirty.contains(Region::StatusLine) {
+                self.render_status_line(board);
+            }
+            if dirty.contains(Region::Clock) {
+                self.render_clock(board);
+            }
+        }
+        if dirty.contains(Region::Overlays) {
+            self.hold_bar.invalidate();
+            self.frame_overlay.invalidate();
+            self.console_view.invalidate();
+            self.thinking.invalidate();
+        }
+    }
+
+    /// 对局中与复盘试走时显示光标。
+    fn shows_cursor(&self) -> bool {
+        self.review.as_ref().is_none_or(|r| r.trying)
+    }
+
+    /// 调试叠加层只在对局画面显示：复盘与结算画面的侧栏底部另有用途。
+    fn shows_frame_overlay(&self) -> bool {
+        !self.end_screen && self.review.is_none()
+    }
+
+    /// 屏幕控制台占用着法列表的位置，侧栏被提示、复盘或 FEN 面板占用时不画。
+    fn shows_console(&self) -> bool {
+        self.console_view.enabled()
+            && self.shows_frame_overlay()
+            && self.corrupt.is_none()
+            && self.battery_pause != BatteryPause::Paused
+            && self.promotion.is_none()
+            && !self.fen_panel
+    }
+
+    /// 无障碍模式的定时重绘：闪烁相位翻转时只标记光标格。
+    fn update_blink(&mut self, board: &mut Board) {
+        if !self.blink_cursor || self.end_screen || !self.shows_cursor() {
+            return;
+        }
+        let lit = board
+            .timebase
+            .blink_lit(self.blink_since_ms, CURSOR_BLINK_MS);
+        if lit != self.cursor_lit {
+            self.cursor_lit = lit;
+            self.dirty.square(self.cursor.0, self.cursor.1);
+        }
+    }
+
+    fn show_tutor(&mut self, board: &mut Board) {
+        let Some(sq) = self.selected else {
+            return;
+        };
+        let Some(piece) = self.state.board[sq as usize] else {
+            return;
+        };
+        self.tutor = Some(TutorOverlay {
+            pattern: piece.movement_pattern(sq),
+            legal: self.legal_targets(sq),
+        });
+        board.status.push("Outline: moves  Mark: legal", AI_NOTE_MS);
+    }
+
+    /// 控制台键入的着法：只在轮到人类、棋盘正常对局时接受，落子与按键提交走同一条路径。
+    fn play_typed_move(&mut self, text: MoveText) -> Result<(), &'static str> {
+        if self.input_mode() != InputMode::Game
+            || self.review.is_some()
+            || self.clock.result.is_some()
+        {
+            return Err("no move expected now");
+        }
+        if self.is_ai_turn() {
+            return Err("not your turn");
+        }
+        let mv = text.resolve(&self.state).map_err(ChessError::message)?;
+        if self.touch_locked() && self.selected != Some(mv.from) {
+            return Err("touch-move: move the touched piece");
+        }
+        let before = self.board_marks();
+        if !self.apply_move(mv) {
+            return Err(ChessError::IllegalMove.message());
+        }
+        self.selected = None;
+        self.tutor = None;
+        self.fen_panel = false;
+        self.invalidate_since(&before);
+        Ok(())
+    }
+
+    /// `moves?`：每行五步，坐标写法在前、SAN 在后，如 `g1f3 Nf3`。
+    fn list_moves(&self, board: &mut Board) {
+        let moves = self.state.generate_legal_moves();
+        let mut count_buf = [0u8; 10];
+        board
+            .serial
+            .write_bytes(u32_to_str(moves.len as u32, &mut count_buf).as_bytes());
+        board.serial.write_bytes(b" legal moves\r\n");
+        for (i, mv) in moves.iter().enumerate() {
+            board
+                .serial
+                .write_bytes(notation::uci(*mv).as_str().as_bytes());
+            board.serial.write_bytes(b" ");
+            if let Some(san) = san::san(&self.state, *mv) {
+                board.serial.write_bytes(san.as_str().as_bytes());
+            }
+            let line_end = i % 5 == 4 || i + 1 == moves.len;
+            board
+                .serial
+                .write_bytes(if line_end { b"\r\n" } else { b"  " });
+        }
+    }
+
+    fn show_fen(&mut self, board: &mut Board) {
+        let fen = fen::fen(&self.state);
+        board.serial.write_bytes(b"fen ");
+        board.serial.write_bytes(fen.as_str().as_bytes());
+        board.serial.write_bytes(b"\r\n");
+        self.fen_panel = true;
+    }
+
+    fn toggle_select(&mut self, board: &mut Board) {
+        if self.touch_locked() {
+            board.status.push("Touch-move: move this piece", NOTICE_MS);
+            return;
+        }
+        let idx = Self::index(self.cursor.0, self.cursor.1);
+        if self.selected == Some(idx) {
+            self.selected = None;
+            return;
+        }
+        if let Some(piece) = self.state.board[idx as usize] {
+            self.selected = Some(idx);
+            if self.touch_locked() {
+                board.status.push("Touched: must move", NOTICE_MS);
+            }
+            // 无处可走的己方棋子：不必让玩家逐格试探落点，标出后稍候自动放下。
+            if piece.color == self.state.side_to_move && self.legal_targets(idx).is_empty() {
+                self.no_moves = Some((idx, board.timebase.now_ms()));
+            }
+        }
+    }
+
+    /// 当前局面下从 `sq` 出发的合法落点。
+    fn legal_targets(&self, sq: u8) -> SquareSet {
+        self.state.legal_destinations(sq).all()
+    }
+
+    /// 摸子走子：选中的是行棋方且有合法着法的棋子时，选择锁定，直到用它走出一步。
+    /// 没有合法着法的棋子（或对方棋子）仍可随意取消，免得把局面卡死。
+    fn touch_locked(&self) -> bool {
+        self.touch_move
+            && self.selected.is_some_and(|sq| {
+                self.state.board[sq as usize].is_some_and(|p| p.color == self.state.side_to_move)
+                    && !self.legal_targets(sq).is_empty()
+            })
+    }
+
+    /// "无着"提示到时：仍选着该子则取消选择并标记该格重绘；选择已被改动则直接丢弃提示。
+    fn expire_no_moves(&mut self, board: &mut Board) {
+        let Some((sq, since)) = self.no_moves else {
+            return;
+        };
+        if self.selected != Some(sq) {
+            self.no_moves = None;
+        } else if board.timebase.now_ms().wrapping_sub(since) >= NO_MOVES_MS {
+            self.no_moves = None;
+            self.selected = None;
+            self.dirty.square(sq % 8, sq / 8);
+        }
+    }
+
+    /// 预走选子：只能选人类一方（即 AI 的对手）的棋子；已有预走时再按一次取消预走。
+    fn toggle_premove_select(&mut self) {
+        if self.premove.take().is_some() {
+            self.selected = None;
+            return;
+        }
+        let idx = Self::index(self.cursor.0, self.cursor.1);
+        if self.selected == Some(idx) {
+            self.selected = None;
+            return;
+        }
+        let human = self.state.side_to_move.opposite();
+        if self.state.board[idx as usize].is_some_and(|p| p.color == human) {
+            self.selected = Some(idx);
+        }
+    }
+
+    /// 登记预走；合法性留到 AI 落子后按新局面检查。
+    fn set_premove(&mut self) {
+        let Some(src) = self.selected else {
+            return;
+        };
+        let dst = Self::index(self.cursor.0, self.cursor.1);
+        if src != dst {
+            self.premove = Some((src, dst));
+            self.selected = None;
+        }
+    }
+
+    /// AI 落子后执行预走：仍合法则立即走出（升变默认为后），否则丢弃。
+    fn play_premove(&mut self, board: &mut Board) {
+        let Some((src, dst)) = self.premove.take() else {
+            return;
+        };
+        self.selected = None;
+        if self.is_ai_turn() || self.clock.result.is_some() {
+            return;
+        }
+        // 预走的升变一律升后，不受菜单顺序影响。
+        let queen = self.promotion_order.slot(PieceKind::Queen);
+        let mv = self
+            .find_moves(src, dst)
+            .and_then(|(normal, promos)| normal.or(queen.and_then(|i| promos[i])));
+        match mv {
+            Some(mv) => {
+                self.apply_move(mv);
+                rprintln!("premove played: {} -> {}", src, dst);
+            }
+            None => {
+                rprintln!("premove dropped: {} -> {}", src, dst);
+                board.status.push("Premove dropped", NOTICE_MS);
+            }
+        }
+    }
+
+    fn can_premove(&self) -> bool {
+        !self.ai_sides[Self::color_index(self.state.side_to_move.opposite())]
+    }
+
+    fn try_submit_move(&mut self, board: &mut Board) {
+        let Some(src) = self.selected else {
+            return;
+        };
+        let dst = Self::index(self.cursor.0, self.cursor.1);
+        if src == dst {
+            return;
+        }
+        let move_set = self.find_moves(src, dst);
+        if move_set.is_none() {
+            rprintln!("非法走子: {} -> {}", src, dst);
+            let err = self
+                .state
+                .check_move(Move::quiet(src, dst))
+                .err()
+                .unwrap_or(ChessError::IllegalMove);
+            board.status.push(err.message(), NOTICE_MS);
+            return;
+        }
+        let (normal, promo_moves) = move_set.unwrap();
+
+        // 若存在升变选项且当前为玩家回合，进入升变选择
+        if promo_moves.iter().any(|m| m.is_some()) && self.is_human_turn() {
+            self.promotion = Some(PromotionPrompt {
+                from: src,
+                to: dst,
+                color: self.state.side_to_move,
+                moves: promo_moves,
+            });
+            self.selected = None;
+            return;
+        }
+
+        // 落子在本帧渲染，AI 方从下一帧开始思考，保证先显示玩家落子。
+        if let Some(mv) = normal.or_else(|| promo_moves.iter().flatten().next().copied()) {
+            if self.apply_move(mv) {
+                self.selected = None;
+            }
+        }
+    }
+
+    /// 落子并通知观察者；非法着法返回 false，局面不变。
+    fn apply_move(&mut self, mv: Move) -> bool {
+        let Some(next) = self.state.make_move(mv) else {
+            return false;
+        };
+        // 三子残局直接按残局库判定胜负。
+        self.repetitions.record(&self.state);
+        let result = next
+            .result_with(&self.repetitions)
+            .or_else(|| tablebase::adjudicate(&next));
+        self.observers.notify_move(&self.state, mv, &next, result);
+        self.history.push(&self.state, mv);
+        if self.book_exit.is_none()
+            && let Some(ply) = self.history.book_exit()
+        {
+            self.book_exit = Some(ply);
+            self.book_exit_notice = true;
+            self.observers.notify_book_exit(ply);
+        }
+        self.clock.result = result;
+        self.state = next;
+        self.tutor = None;
+        self.fen_panel = false;
+        self.last_move = Some((mv.from, mv.to));
+        self.status_leds.show_position(&self.state);
+        true
+    }
+
+    /// 整屏绘制：开局时与整屏失效时调用；结算画面显示期间画结算画面。
+    fn render_full(&mut self, board: &mut Board) {
+        if self.end_screen
+            && let Some(result) = self.clock.result
+        {
+            let summary = EndSummary {
+                result,
+                state: &self.state,
+                plies: self.history.len(),
+                seconds: self.elapsed_seconds(board),
+                rating_change: self.rating_change,
+                human: self.human_side(),
+            };
+            end_screen::render(&mut board.lcd, self.theme, &summary);
+            self.square_cache.invalidate();
+            return;
+        }
+        board.lcd.begin_batch();
+        for rank in 0..8 {
+            for file in 0..8 {
+                self.render_square(board, file, rank);
+            }
+        }
+        board.lcd.end_batch();
+        self.render_side_info(board);
+        self.render_clock(board);
+    }
+
+    /// 清屏；棋盘上画过的格子随之作废。
+    fn clear_screen(&mut self, board: &mut Board) {
+        board.lcd.clear(self.theme.ui_bg);
+        self.square_cache.invalidate();
+    }
+
+    /// 侧边栏时钟：不限时为对局时长 "Time: HH:MM:SS"；限时对局为双方剩余用时
+    /// "W 04:59  B 05:00"，走钟的一方反色，不足 `LOW_TIME_S` 秒时用警示色。
+    fn render_clock(&mut self, board: &mut Board) {
+        let face = self.clock_face(board);
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let x = RIGHT_X + RIGHT_MARGIN + 2;
+        let y = 46;
+        let mut buf = [0u8; 8];
+        match face {
+            ClockFace::Elapsed(seconds) => {
+                let hms = fmt::format_hms(seconds, &mut buf);
+                text::draw_text_scaled(&mut board.lcd, "Time:", x, y, fg, Some(bg), 2);
+                text::draw_text_scaled(&mut board.lcd, hms, x + 64, y, fg, Some(bg), 2);
+            }
+            ClockFace::Countdown { seconds, running } => {
+                for (i, (side, label)) in [(Color::White, "W"), (Color::Black, "B")]
+                    .into_iter()
+                    .enumerate()
+                {
+                    let left = seconds[i];
+                    let ink = if left < LOW_TIME_S {
+                        self.theme.ui_alert
+                    } else {
+                        fg
+                    };
+                    let (ink, paper) = if running == Some(side) {
+                        (bg, ink)
+                    } else {
+                        (ink, bg)
+                    };
+                    let cx = x + i as u16 * CLOCK_STRIDE;
+                    text::draw_text_scaled(&mut board.lcd, label, cx, y, fg, Some(bg), 2);
+                    let mm_ss = fmt::format_mm_ss(left, &mut buf);
+                    text::draw_text_scaled(&mut board.lcd, mm_ss, cx + 24, y, ink, Some(paper), 2);
+                }
+            }
+        }
+        self.clock.shown = Some(face);
+    }
+
+    /// 重画一格；与上次画出的样子相同时什么也不做（见 `SquareCache`）。
+    fn render_square(&mut self, board: &mut Board, file: u8, rank: u8) {
+        let idx = Self::index(file, rank);
+        let look = self.square_look(file, rank);
+        if !self.square_cache.update(idx, look) {
+            return;
+        }
+        let theme = self.theme;
+        // 以下按屏幕位置绘制；坐标标注仍写本格的逻辑列与横排。
+        let (file_label, rank_label) = ((b'a' + file) as char, self.labels.rank_label(rank));
+        let (file, rank) = self.screen_square(file, rank);
+        // 一格的各层先画进离屏缓冲（启用 `framebuffer` 特性时），与同一帧的其他格一起送屏。
+        let rect = chessboard::square_rect(file, rank);
+        board.lcd.offscreen(rect, |lcd| {
+            chessboard::draw_square_with_color(lcd, file, rank, look.fill);
+            if let Some(hint) = look.hint {
+                chessboard::draw_overlay(lcd, theme, file, rank, hint);
+            }
+            chessboard::draw_coordinates(lcd, theme, file, rank, rank_label, file_label);
+            if look.selected {
+                chessboard::draw_square_border(
+                    lcd,
+                    file,
+                    rank,
+                    SELECTED_BORDER,
+                    theme.selected_piece,
+                );
+            }
+            if look.tutor_pattern {
+                chessboard::draw_square_border(lcd, file, rank, TUTOR_BORDER, theme.premove);
+            }
+            if look.check {
+                chessboard::draw_square_border(lcd, file, rank, CHECK_BORDER, theme.ui_alert);
+            }
+            if let Some(color) = look.mark {
+                chessboard::draw_square_border(lcd, file, rank, annotations::MARK_BORDER, color);
+            }
+            if let Some(piece) = look.piece {
+                pieces::draw_piece_on_square_custom(
+                    lcd,
+                    theme,
+                    piece.kind,
+                    piece.color,
+                    file,
+                    rank,
+                    look.piece_color,
+                );
+            }
+            if look.tutor_legal {
+                chessboard::draw_corner_marker(lcd, file, rank, theme.promotion, 0x0000);
+            }
+            if look.arrows != 0 {
+                self.annotations
+                    .draw_arrows(lcd, file, rank, look.arrows, self.flipped);
+            }
+            let corner = match look.corner {
+                Some(CornerLabel::PromotionSlot(slot)) => {
+                    Some((b'1' + slot, theme.ui_bg, theme.ui_fg))
+                }
+                Some(CornerLabel::NoMoves) => Some((b'x', theme.ui_fg, theme.ui_bg)),
+                None => None,
+            };
+            if let Some((label, fg, bg)) = corner {
+                chessboard::draw_corner_label(lcd, file, rank, char::from(label), fg, bg);
+            }
+        });
+    }
+
+    /// 一格此刻应有的样子：底色高亮、棋子、边框与角标。
+    fn square_look(&self, file: u8, rank: u8) -> SquareLook {
+        let idx = Self::index(file, rank);
+        let promo_slot = self.promotion_slot(idx);
+        let is_promo_from = self.promotion.map_or(false, |p| p.from == idx);
+        // 复盘时只在试走中显示光标
+        // 闪烁的暗相位按非光标格着色，露出底下的上一步/预走等高亮。
+        let is_cursor = self.shows_cursor()
+            && self.cursor == (file, rank)
+            && (self.cursor_lit || !self.blink_cursor);
+        let last_move = self.review.as_ref().map_or(self.last_move, |r| r.last_move);
+        let is_last_move = last_move.map_or(false, |(from, to)| from == idx || to == idx);
+        let is_premove = self
+            .premove
+            .is_some_and(|(from, to)| from == idx || to == idx);
+        let theme = self.theme;
+        let arrows = self.annotations.arrows_through(idx);
+        let fill = if is_cursor {
+            theme.cursor
+        } else if is_premove {
+            theme.premove
+        } else if is_last_move {
+            theme.last_move
+        } else if promo_slot.is_some() {
+            theme.promotion
+        } else {
+            chessboard::square_color(theme, file, rank)
+        };
+        let mut look = SquareLook {
+            fill,
+            selected: self.blink_cursor && self.selected == Some(idx),
+            tutor_pattern: self.tutor.is_some_and(|tutor| tutor.pattern.contains(idx)),
+            check: self.checked_king == Some(idx),
+            mark: self
+                .annotations
+                .mark(idx)
+                .map(annotations::MarkColor::rgb565),
+            piece: None,
+            piece_color: None,
+            tutor_legal: false,
+            hint: None,
+            corner: None,
+            arrows,
+            arrow_colors: self.annotations.colors_of(arrows),
+        };
+
+        // 升变叠加层盖住这几格原有的棋子，选定或取消后即恢复。
+        if let (Some(prompt), Some(slot)) = (self.promotion, promo_slot) {
+            look.piece = Some(Piece {
+                kind: self.promotion_order.pieces()[slot],
+                color: prompt.color,
+            });
+            look.corner = Some(CornerLabel::PromotionSlot(slot as u8));
+            return look;
+        }
+        if is_promo_from {
+            // 避免在原位重复显示
+            return look;
+        }
+
+        look.piece = self.view().board[idx as usize];
+        if look.piece.is_some() && self.selected == Some(idx) {
+            look.piece_color = Some(theme.selected_piece);
+        }
+        look.tutor_legal = self.tutor.is_some_and(|tutor| tutor.legal.contains(idx));
+        look.hint = if self.hints.captures.contains(idx) {
+            Some(SquareOverlay::Capture)
+        } else if self.hints.quiet.contains(idx) {
+            Some(SquareOverlay::Move)
+        } else {
+            None
+        };
+        if self.selected == Some(idx) && self.no_moves.is_some_and(|(sq, _)| sq == idx) {
+            look.corner = Some(CornerLabel::NoMoves);
+        }
+        look
+    }
+
+    fn render_side_info(&self, board: &mut Board) {
+        let start_x = RIGHT_X + RIGHT_MARGIN;
+        let width = board.lcd.width.saturating_sub(start_x);
+        // 侧栏内容一律裁剪在面板内，过长的文字不会画进棋盘。
+        let panel = ClipRect::new(start_x, 0, width, board.lcd.height);
+        let prev_clip = board.lcd.set_clip(panel);
+        self.render_side_panel(board, start_x, width);
+        board.lcd.set_clip(prev_clip);
+    }
+
+    fn render_side_panel(&self, board: &mut Board, start_x: u16, width: u16) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        // 右侧信息区域
+        board.lcd.fill_rect(start_x, 0, width, board.lcd.height, bg);
+
+        // 和棋终局（含三次重复、五十步）在行棋方处显示 "Draw"。
+        let (side, side_fg) = if self.showing_draw() {
+            ("Draw", self.theme.ui_alert)
+        } else {
+            match self.view().side_to_move {
+                Color::White => ("White", fg),
+                Color::Black => ("Black", fg),
+            }
+        };
+        let text_x = start_x + 2;
+        let text_y = 6;
+        text::draw_text_scaled(&mut board.lcd, "Side:", text_x, text_y, fg, Some(bg), 2);
+        text::draw_text_scaled(
+            &mut board.lcd,
+            side,
+            text_x + 64,
+            text_y,
+            side_fg,
+            Some(bg),
+            2,
+        );
+        // 行棋方后以小字分两行显示对局阶段（开局/中局/残局，与 AI 估值和开局库同一划分），
+        // 被将军时在其下提示，棋盘上的王格同时加警示色边框。
+        let phase_label = phase::Phase::of(self.view()).label();
+        text::draw_text_scaled(
+            &mut board.lcd,
+            phase_label,
+            text_x + 136,
+            text_y,
+            fg,
+            Some(bg),
+            1,
+        );
+        if !self.showing_draw() && self.view().checked_king().is_some() {
+            let alert = self.theme.ui_alert;
+            let (check_x, check_y) = (text_x + 136, text_y + 10);
+            text::draw_text_scaled(
+                &mut board.lcd,
+                "Check!",
+                check_x,
+                check_y,
+                alert,
+                Some(bg),
+                1,
+            );
+        }
+
+        let focus = self.perspective();
+        let diff = self.material_diff(focus);
+        let mut buf = [0u8; 11];
+        let diff_str = i32_to_str(diff, &mut buf);
+
+        text::draw_text_scaled(&mut board.lcd, "Mat:", text_x, text_y + 20, fg, Some(bg), 2);
+        text::draw_text_scaled(
+            &mut board.lcd,
+            diff_str,
+            text_x + 64,
+            text_y + 20,
+            fg,
+            Some(bg),
+            2,
+        );
+
+        // 同一行右侧以小字显示静态评估（兵为单位），如 "Ev +0.35"。
+        let mut eval_buf = [0u8; 12];
+        let eval_str = fmt::format_centipawns(static_eval(self.view(), focus), &mut eval_buf);
+        let eval_y = text_y + 24;
+        text::draw_text_scaled(&mut board.lcd, "Ev", text_x + 112, eval_y, fg, Some(bg), 1);
+        text::draw_text_scaled(
+            &mut board.lcd,
+            eval_str,
+            text_x + 130,
+            eval_y,
+            fg,
+            Some(bg),
+            1,
+        );
+
+        self.render_move_counters(board, text_x, text_y + 60);
+        self.render_status_line(board);
+
+        if let Some(err) = self.corrupt {
+            self.render_recovery_prompt(board, text_x, err);
+        } else if self.battery_pause == BatteryPause::Paused {
+            self.render_battery_prompt(board, text_x);
+        } else if let Some(prompt) = self.promotion {
+            self.render_promotion_menu(board, start_x, prompt);
+        } else if let Some(review) = &self.review {
+            self.render_review_info(board, text_x, review);
+        } else if self.fen_panel {
+            self.render_fen(board, text_x);
+        } else if !self.console_view.enabled() {
+            let mut tag_buf = [0u8; 5];
+            let tag = self
+                .ai_origin
+                .filter(|(ply, _)| *ply < self.history.len())
+                .map(|(ply, result)| (ply, origin_tag(result, &mut tag_buf)));
+            movelist::render(
+                &mut board.lcd,
+                self.theme,
+                &self.history,
+                text_x,
+                MOVE_LIST_Y,
+                MOVE_LIST_ROWS,
+                tag,
+            );
+        }
+    }
+
+    /// FEN 面板：按列宽折行的大字 FEN，便于照着抄到手机或电脑上的引擎。
+    fn render_fen(&self, board: &mut Board, x: u16) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let fen = fen::fen(&self.state);
+        text::draw_text_scaled(
+            &mut board.lcd,
+            "FEN (also on UART)",
+            x,
+            MOVE_LIST_Y,
+            fg,
+            Some(bg),
+            1,
+        );
+        let mut y = MOVE_LIST_Y + 14;
+        for line in fen.as_str().as_bytes().chunks(FEN_CHARS_PER_LINE) {
+            let line = core::str::from_utf8(line).unwrap_or("");
+            text::draw_text_scaled(&mut board.lcd, line, x, y, fg, Some(bg), 2);
+            y += 18;
+        }
+    }
+
+    /// 回合数与五十步规则进度 "Move: 12  HM: 37/100"（半回合计数满 100 判和）。
+    fn render_move_counters(&self, board: &mut Board, x: u16, y: u16) {
+        let state = self.view();
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let mut move_buf = [0u8; 10];
+        let mut hm_buf = [0u8; 10];
+        let move_str = u32_to_str(state.fullmove_number as u32, &mut move_buf);
+        text::draw_text_scaled(&mut board.lcd, "Move:", x, y, fg, Some(bg), 1);
+        text::draw_text_scaled(&mut board.lcd, move_str, x + 36, y, fg, Some(bg), 1);
+
+        // 接近 100 时改用警示色提醒。
+        let halfmoves = state.halfmove_clock.min(FIFTY_MOVE_PLIES);
+        let hm_color = if halfmoves >= FIFTY_MOVE_WARN {
+            self.theme.ui_alert
+        } else {
+            fg
+        };
+        let hm_str = u32_to_str(halfmoves as u32, &mut hm_buf);
+        let mut px = x + 84;
+        for part in ["HM: ", hm_str, "/100"] {
+            text::draw_text_scaled(&mut board.lcd, part, px, y, hm_color, Some(bg), 1);
+            px += part.len() as u16 * 6;
+        }
+    }
+
+    /// 状态行：显示状态消息队列的当前消息，没有消息时清空该行。
+    fn render_status_line(&self, board: &mut Board) {
+        let x = RIGHT_X + RIGHT_MARGIN + 2;
+        let width = board.lcd.width.saturating_sub(x);
+        let bg = self.theme.ui_bg;
+        board.lcd.fill_rect(x, STATUS_Y, width, 8, bg);
+        if self.review.as_ref().is_some_and(|r| !r.trying) {
+            return;
+        }
+        if let Some(message) = board.status.current().or_else(|| self.turn_prompt()) {
+            let fg = self.theme.cursor;
+            text::draw_text_scaled(&mut board.lcd, message, x, STATUS_Y, fg, Some(bg), 1);
+        }
+    }
+
+    /// 状态行没有消息时的回合提示：人机对局轮到人时为 "Your move"（执白执黑一样），
+    /// 人人对局为轮到的一方；AI 回合、复盘与终局不提示。
+    fn turn_prompt(&self) -> Option<&'static str> {
+        if self.clock.result.is_some() || self.review.is_some() || self.is_ai_turn() {
+            return None;
+        }
+        Some(match (self.human_side(), self.state.side_to_move) {
+            (Some(_), _) => "Your move",
+            (None, Color::White) => "White to move",
+            (None, Color::Black) => "Black to move",
+        })
+    }
+
+    /// 低电量暂停时代替着法列表：当前电压与继续/休眠选项。
+    fn render_battery_prompt(&self, board: &mut Board, x: u16) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let y = 80;
+        let alert = self.theme.ui_alert;
+        text::draw_text_scaled(&mut board.lcd, "Low battery", x, y, alert, Some(bg), 2);
+        text::draw_text_scaled(
+            &mut board.lcd,
+            "Match paused, saved",
+            x,
+            y + 22,
+            fg,
+            Some(bg),
+            1,
+        );
+        if let Some(mv) = board.battery.millivolts() {
+            let mut mv_buf = [0u8; 10];
+            let mut px = x;
+            for part in ["Supply ", u32_to_str(mv, &mut mv_buf), " mV"] {
+                text::draw_text_scaled(&mut board.lcd, part, px, y + 36, fg, Some(bg), 1);
+                px += part.len() as u16 * 6;
+            }
+        }
+        text::draw_text_scaled(&mut board.lcd, "KEY1 resume", x, y + 58, fg, Some(bg), 1);
+        text::draw_text_scaled(&mut board.lcd, "KEY4 sleep", x, y + 72, fg, Some(bg), 1);
+    }
+
+    /// 局面损坏时代替着法列表：原因与恢复选项。
+    fn render_recovery_prompt(&self, board: &mut Board, x: u16, err: IntegrityError) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let y = 80;
+        let alert = self.theme.ui_alert;
+        text::draw_text_scaled(&mut board.lcd, "Position corrupt", x, y, alert, Some(bg), 1);
+        text::draw_text_scaled(&mut board.lcd, err.message(), x, y + 14, fg, Some(bg), 1);
+        let mut ply_buf = [0u8; 10];
+        let ply = self.history.last_consistent().map(|(ply, _)| ply);
+        let restore_y = y + 36;
+        match ply {
+            Some(ply) => {
+                let ply_str = u32_to_str(ply as u32, &mut ply_buf);
+                let mut px = x;
+                for part in ["KEY1 restore ply ", ply_str] {
+                    text::draw_text_scaled(&mut board.lcd, part, px, restore_y, fg, Some(bg), 1);
+                    px += part.len() as u16 * 6;
+                }
+            }
+            None => {
+                let msg = "No safe checkpoint";
+                text::draw_text_scaled(&mut board.lcd, msg, x, restore_y, fg, Some(bg), 1);
+            }
+        }
+        text::draw_text_scaled(&mut board.lcd, "KEY4 new game", x, y + 50, fg, Some(bg), 1);
+    }
+
+    /// 复盘进度 "Review 12/40"、当前线上的最近几步与按键提示；变着中的着法加括号。
+    fn render_review_info(&self, board: &mut Board, x: u16, review: &ReviewView) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let (line, ply) = (review.line, review.ply);
+        let mut ply_buf = [0u8; 10];
+        let mut len_buf = [0u8; 10];
+        let y = 80;
+        text::draw_text_scaled(&mut board.lcd, "Review", x, y, fg, Some(bg), 2);
+        let ply_str = u32_to_str(ply as u32, &mut ply_buf);
+        let len_str = u32_to_str(self.history.line_len(line) as u32, &mut len_buf);
+        let mut px = x + 84;
+        for part in [ply_str, "/", len_str] {
+            text::draw_text_scaled(&mut board.lcd, part, px, y, fg, Some(bg), 2);
+            px += part.len() as u16 * 12;
+        }
+        // 最近几步着法（含标注），刚走出的一步高亮。
+        let branch = self.history.branch(line);
+        let mut move_buf = [0u8; 8];
+        let first = ply.saturating_sub(REVIEW_LIST_LEN);
+        for (row, i) in (first..ply).enumerate() {
+            let color = if i + 1 == ply { self.theme.cursor } else { fg };
+            let in_variation = branch.is_some_and(|b| i >= b);
+            let mv_text = self.history.ply_text(line, i, &mut move_buf);
+            let row_y = y + 24 + row as u16 * 20;
+            let mut text_x = x;
+            if in_variation {
+                text::draw_text_scaled(&mut board.lcd, "(", text_x, row_y, color, Some(bg), 2);
+                text_x += 12;
+            }
+            // 中文模式在着法前标出棋子名，如 "马g1f3"。
+            if self.labels == Labels::Chinese {
+                if let Some(kind) = self.history.piece(line, i) {
+                    let name = self.labels.piece_name(kind);
+                    text::draw_text_scaled(&mut board.lcd, name, text_x, row_y, color, Some(bg), 2);
+                    text_x += 14;
+                }
+            }
+            text::draw_text_scaled(&mut board.lcd, mv_text, text_x, row_y, color, Some(bg), 2);
+            if in_variation {
+                text_x += mv_text.len() as u16 * 12;
+                text::draw_text_scaled(&mut board.lcd, ")", text_x, row_y, color, Some(bg), 2);
+            }
+        }
+        let hints = if review.trying {
+            [
+                "Keys move  KEY1 hold: pick",
+                "KEY2 hold: play move",
+                "KEY2 hold, none picked: done",
+            ]
+        } else if branch.is_some() {
+            [
+                "KEY1/KEY4 step  KEY3 main",
+                "KEY1 hold: mark ! ? !? ??",
+                "KEY2 try  KEY2 hold: summary",
+            ]
+        } else {
+            [
+                "KEY1/KEY4 step  KEY3 end",
+                "KEY1 hold: mark ! ? !? ??",
+                "KEY2 try  KEY2 hold: summary",
+            ]
+        };
+        for (hint, hint_y) in hints.into_iter().zip([214, 230, 246]) {
+            text::draw_text_scaled(&mut board.lcd, hint, x, hint_y, fg, Some(bg), 1);
+        }
+    }
+
+    fn render_promotion_menu(&self, board: &mut Board, start_x: u16, prompt: PromotionPrompt) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let x = start_x + 2;
+        let mut y = 80;
+        text::draw_text_scaled(&mut board.lcd, "Promote (KEY1-4)", x, y, fg, Some(bg), 2);
+        y += 24;
+        let order = self.promotion_order.pieces();
+        for (num, kind) in ["1", "2", "3", "4"].into_iter().zip(order) {
+            let label = self.labels.piece_name(kind);
+            text::draw_text_scaled(&mut board.lcd, num, x, y, fg, Some(bg), 2);
+            text::draw_text_scaled(&mut board.lcd, label, x + 20, y, fg, Some(bg), 2);
+            // Place icon slightly above text baseline for better alignment.
+            pieces::draw_piece_icon(
+                &mut board.lcd,
+                self.theme,
+                kind,
+                prompt.color,
+                x + 90,
+                y - 2,
+                None,
+            );
+            y += 28;
+        }
+    }
+
+    /// 升变时棋盘上的候选格：升变格及其向棋盘内侧的三格依次放 KEY1~KEY4 对应的棋子，
+    /// 返回该格的候选序号。
+    fn promotion_slot(&self, idx: u8) -> Option<usize> {
+        let prompt = self.promotion?;
+        if idx % 8 != prompt.to % 8 {
+            return None;
+        }
+        let (rank, to_rank) = ((idx / 8) as usize, (prompt.to / 8) as usize);
+        let slot = if to_rank == 7 {
+            7 - rank
+        } else {
+            rank.checked_sub(to_rank)?
+        };
+        (slot < 4).then_some(slot)
+    }
+
+    fn handle_promotion(&mut self, board: &mut Board) {
+        let Some(prompt) = self.promotion else {
+            return;
+        };
+
+        if let Some(Action::Promote(choice)) = self.input.poll(board) {
+            let before = self.board_marks();
+            if let Some(mv) = prompt.moves.get(choice.index()).and_then(|m| *m) {
+                self.apply_move(mv);
+            }
+            self.promotion = None;
+            self.selected = None;
+            self.invalidate_since(&before);
+        }
+    }
+
+    fn find_moves(&self, src: u8, dst: u8) -> Option<(Option<Move>, [Option<Move>; 4])> {
+        let mut normal = None;
+        let mut promos: [Option<Move>; 4] = [None, None, None, None];
+        let mut found = false;
+        let moves = self.state.generate_legal_moves();
+        for mv in moves.iter().copied() {
+            if mv.from == src && mv.to == dst {
+                found = true;
+                if let Some(kind) = mv.promotion {
+                    if let Some(i) = self.promotion_order.slot(kind) {
+                        promos[i] = Some(mv);
+                    }
+                } else {
+                    normal = Some(mv);
+                }
+            }
+        }
+        if found { Some((normal, promos)) } else { None }
+    }
+
+    fn index(file: u8, rank_from_bottom: u8) -> u8 {
+        rank_from_bottom * 8 + file
+    }
+
+    /// AI 阶段：回合开始后至少等待 `ai_delay_ms` 再搜索，等待期间不阻塞帧循环。
+    fn ai_slice(&mut self, board: &mut Board, table: &mut TranspositionTable) {
+        if !self.is_ai_turn()
+            || self.promotion.is_some()
+            || self.clock.result.is_some()
+            || self.corrupt.is_some()
+            || self.battery_pause == BatteryPause::Paused
+        {
+            self.ai_wait_since = None;
+            return;
+        }
+        if self.battery_pause == BatteryPause::Pending {
+            self.pause_for_battery(board);
+            return;
+        }
+        let since = *self
+            .ai_wait_since
+            .get_or_insert_with(|| board.timebase.now_ms());
+        if self.playout.is_none() && board.timebase.elapsed_ms(since) < self.ai_delay_ms {
+            return;
+        }
+        self.ai_wait_since = None;
+        self.run_ai(board, table);
+    }
+
+    /// 低电量暂停：对局写入检查点（电池彻底耗尽后上电即从这里续局），调暗背光、熄灭 LED。
+    fn pause_for_battery(&mut self, board: &mut Board) {
+        rprintln!("match paused on low battery at ply {}", self.history.len());
+        self.save_checkpoint(board, None);
+        board.lcd.set_brightness(PAUSED_BRIGHTNESS);
+        board.leds.all_off();
+        self.battery_pause = BatteryPause::Paused;
+        // 快速对弈随之回到正常速度，暂停提示才会画出并接收按键。
+        self.playout = None;
+        self.dirty.all();
+    }
+
+    /// 低电量暂停提示输入：KEY1 继续对局，KEY4 整机休眠（唤醒后仍停在暂停提示）。
+    fn handle_paused(&mut self, board: &mut Board) {
+        match self.input.poll(board) {
+            Some(Action::ResumeMatch) => {
+                if board.battery.is_low() {
+                    board.status.push("Battery still low", NOTICE_MS);
+                }
+                self.end_battery_pause(board);
+                self.dirty.all();
+            }
+            Some(Action::Sleep) => board.sleep(),
+            _ => {}
+        }
+    }
+
+    /// 撤销低电量暂停：清除暂停时写的检查点，恢复背光亮度。
+    fn end_battery_pause(&mut self, board: &mut Board) {
+        if self.battery_pause == BatteryPause::Paused {
+            checkpoint::clear(&mut board.backup);
+            board.lcd.set_brightness(lcd::BRIGHTNESS_FULL);
+        }
+        self.battery_pause = BatteryPause::Running;
+    }
+
+    /// 写入检查点：对局记录连同本局设置与落子间隔，续局时照原样接着下。只有旧存档的复盘
+    /// 没有设置，那里 AI 不走子，按默认设置记。
+    fn save_checkpoint(&self, board: &mut Board, best: Option<(Move, u8)>) {
+        let setup = self.setup.unwrap_or(GameSetup {
+            seed: 0,
+            ai_sides: self.ai_sides,
+            ai: AiConfig::default(),
+            profiles: [EvalProfile::BALANCED; 2],
+            time_control: self.time_control,
+            version: archive::firmware_version(),
+        });
+        checkpoint::save(
+            &mut board.backup,
+            &self.history,
+            &setup,
+            self.ai_delay_ms,
+            best,
+        );
+    }
+
+    fn run_ai(&mut self, board: &mut Board, table: &mut TranspositionTable) {
+        // 开局库按本局种子与半回合序号选线：每局开局不尽相同，同一种子则可复现。
+        let ply_salt = (self.history.len() as u32).wrapping_mul(0x9E37_79B9);
+        // 评估风格按行棋方取，电脑对电脑时双方可以不同。
+        let side = Self::color_index(self.state.side_to_move);
+        let setup_ai = self.setup.map_or_else(AiConfig::default, |setup| setup.ai);
+        let cfg = AiConfig {
+            book_seed: self.setup.map(|setup| setup.seed ^ ply_salt),
+            // 相近着法的随机选择同样取本局种子，同一种子可复现整局。
+            variety: setup_ai.variety.map(|variety| Variety {
+                seed: self.setup.map_or(0, |setup| setup.seed),
+                ..variety
+            }),
+            profile: self
+                .setup
+                .map_or(EvalProfile::BALANCED, |setup| setup.profiles[side]),
+            ..setup_ai
+        };
+        // 搜索期间阻塞主循环：回调里刷新 LED 让思考灯保持闪烁，并继续接收预走输入。
+        self.status_leds.set_thinking(true);
+        self.thinking.restart(&board.timebase);
+        // 快速对弈不画思考中的螃蟹，棋盘之外的屏幕内容一概不动。
+        let animate = self.playout.is_none();
+        let state = self.state;
+        let ai_color = state.side_to_move;
+        // 思考期间把对局与已完成深度的最佳着法定时写入检查点，中途复位后可续局。
+        self.save_checkpoint(board, None);
+        let best = Cell::new(None);
+        let mut checkpointed = None;
+        let search_start_ms = board.timebase.now_ms();
+        let mut checkpoint_ms = search_start_ms;
+        // 看门狗在中断里独立计时，搜索超过硬上限即收手，走出已完成深度的最佳着法。
+        board.ai_watchdog.arm(&board.main);
+        let stop = board.ai_watchdog.stop_flag();
+        let mut tick = || {
+            self.status_leds.update(&mut board.leds, &board.timebase);
+            if self.can_premove() {
+                self.input.set_mode(InputMode::Game);
+                self.handle_board_input(board);
+                self.render_pending(board);
+            }
+            if animate {
+                self.thinking.update(&mut board.lcd, &board.timebase);
+            }
+            if best.get() != checkpointed
+                && board.timebase.elapsed_ms(checkpoint_ms) >= CHECKPOINT_INTERVAL_MS
+            {
+                checkpointed = best.get();
+                checkpoint_ms = board.timebase.now_ms();
+                self.save_checkpoint(board, checkpointed);
+            }
+        };
+        let report = |r: SearchResult| best.set(Some((r.mv, r.depth)));
+        let result = choose_best_move_until(&state, ai_color, cfg, table, &mut tick, report, stop);
+        let search_ms = board.timebase.elapsed_ms(search_start_ms);
+        board.ai_watchdog.disarm(&board.main);
+        if board.ai_watchdog.fired() {
+            // 记下触发的局面，便于离线复现搜索卡住的问题。
+            rprintln!(
+                "AI search cut off after {} ms: {}",
+                search_ms,
+                fen::fen(&state).as_str()
+            );
+            board.status.push("AI timed out", NOTICE_MS);
+        }
+        checkpoint::clear(&mut board.backup);
+        self.status_leds.set_thinking(false);
+        if animate {
+            self.thinking.clear(&mut board.lcd);
+        }
+        let before = self.board_marks();
+        if let Some(result) = result {
+            let mv = result.mv;
+            let reason = explain_move(&self.state, mv);
+            board
+                .status
+                .push_fmt(format_args!("AI: {}", reason.label()), AI_NOTE_MS);
+            push_search_stats(board, result, search_ms);
+            self.ai_origin = Some((self.history.len(), result));
+            self.apply_move(mv);
+            self.play_premove(board);
+            self.snap_cursor();
+        }
+        self.invalidate_since(&before);
+    }
+
+    /// AI 落子后轮到人类时，把光标移到 AI 的落点；被将军时移到己方王，省去应着前的移动。
+    /// 人类已选中棋子（AI 思考期间登记预走）时保持不动。
+    fn snap_cursor(&mut self) {
+        if !self.snap_cursor
+            || self.selected.is_some()
+            || self.is_ai_turn()
+            || self.clock.result.is_some()
+        {
+            return;
+        }
+        let target = self
+            .state
+            .checked_king()
+            .or_else(|| self.last_move.map(|(_, to)| to));
+        if let Some(sq) = target {
+            self.cursor = (sq % 8, sq / 8);
+        }
+    }
+
+    fn advance_led_spinner(board: &mut Board, step: &mut u8) {
+        board.leds.all_off();
+        board.leds.set(*step as usize % 4, true);
+        *step = step.wrapping_add(1);
+    }
+
+    fn material_scores(&self) -> (u32, u32) {
+        let mut white = 0u32;
+        let mut black = 0u32;
+        for piece in self.view().board.iter().flatten() {
+            let v = material_value(piece.kind);
+            match piece.color {
+                Color::White => white += v,
+                Color::Black => black += v,
+            }
+        }
+        (white, black)
+    }
+
+    fn material_diff(&self, player: Color) -> i32 {
+        let (white, black) = self.material_scores();
+        match player {
+            Color::White => white as i32 - black as i32,
+            Color::Black => black as i32 - white as i32,
+        }
+    }
+
+    /// 唯一由人执子的一方；人人对局与电脑对电脑为 None。
+    fn human_side(&self) -> Option<Color> {
+        match self.ai_sides {
+            [false, true] => Some(Color::White),
+            [true, false] => Some(Color::Black),
+            _ => None,
+        }
+    }
+
+    /// 侧栏优势与评估、光标起点所站的一方：人机对局不论执白执黑都是人这一方；人人对局
+    /// 开启自动翻转时为行棋方，否则与电脑对电脑一样站白方。
+    fn perspective(&self) -> Color {
+        match self.human_side() {
+            Some(side) => side,
+            None if self.auto_flip && self.ai_sides == [false, false] => self.state.side_to_move,
+            None => Color::White,
+        }
+    }
+
+    /// 开启自动翻转且所站一方为黑时，棋盘翻转成黑方在下。
+    fn faces_black(&self) -> bool {
+        self.auto_flip && self.perspective() == Color::Black
+    }
+
+    /// 朝向变了（人人对局每走一步）就翻转棋盘；各格的屏幕位置全变，整屏重画。
+    fn sync_orientation(&mut self) {
+        let flipped = self.faces_black();
+        if flipped != self.flipped {
+            self.flipped = flipped;
+            self.square_cache.invalidate();
+            self.dirty.all();
+        }
+    }
+
+    /// 逻辑方格 (file, rank) 在屏幕上的位置，同样以 (列, 自下而上的横排) 表示。
+    fn screen_square(&self, file: u8, rank: u8) -> (u8, u8) {
+        if self.flipped {
+            (7 - file, 7 - rank)
+        } else {
+            (file, rank)
+        }
+    }
+
+    /// 开局时光标所在格：所站一方的王前兵格（e2 或 e7），执黑也从自己这边开始。
+    fn home_cursor(&self) -> (u8, u8) {
+        match self.perspective() {
+            Color::White => (4, 1),
+            Color::Black => (4, 6),
+        }
+    }
+
+    fn is_human_turn(&self) -> bool {
+        !self.is_ai_turn()
+    }
+
+    fn is_ai_turn(&self) -> bool {
+        self.ai_sides[Self::color_index(self.state.side_to_move)]
+    }
+
+    const fn color_index(color: Color) -> usize {
+        match color {
+            Color::White => 0,
+            Color::Black => 1,
+        }
+    }
+}
+
+fn material_value(kind: PieceKind) -> u32 {
+    match kind {
+        PieceKind::Pawn => 1,
+        PieceKind::Knight => 2,
+        PieceKind::Bishop => 3,
+        PieceKind::Rook => 5,
+        PieceKind::Queen => 9,
+        PieceKind::King => 0,
+    }
+}
+
+/// AI 着法来源标签："book"、查表 "TT d6"、搜索 "d4"（搜索深度不超过 8，一位数字即可）、
+/// 残局库 "TB"。AI 落子后在状态行接着显示搜索开销，如 "d6 18.4k nodes 0.9s"；查表作答
+/// 只报深度，开局库与残局库着法不报。
+fn push_search_stats(board: &mut Board, result: SearchResult, search_ms: u32) {
+    match result.origin {
+        MoveOrigin::Book | MoveOrigin::Tablebase => {}
+        MoveOrigin::TableHit => board
+            .status
+            .push_fmt(format_args!("d{} from table", result.depth), AI_STATS_MS),
+        MoveOrigin::Search => {
+            let (mut nodes_buf, mut time_buf) = ([0u8; 8], [0u8; 8]);
+            let nodes = fmt::format_count(result.nodes, &mut nodes_buf);
+            let time = fmt::format_seconds_tenths(search_ms, &mut time_buf);
+            board.status.push_fmt(
+                format_args!("d{} {} nodes {}", result.depth, nodes, time),
+                AI_STATS_MS,
+            );
+        }
+    }
+}
+
+fn origin_tag(result: SearchResult, buf: &mut [u8; 5]) -> &str {
+    let depth = b'0' + result.depth.min(9);
+    let tag: &[u8] = match result.origin {
+        MoveOrigin::Book => b"book",
+        MoveOrigin::TableHit => &[b'T', b'T', b' ', b'd', depth],
+        MoveOrigin::Search => &[b'd', depth],
+        MoveOrigin::Tablebase => b"TB",
+    };
+    buf[..tag.len()].copy_from_slice(tag);
+    core::str::from_utf8(&buf[..tag.len()]).unwrap_or("")
+}
@@ -0,0 +1,134 @@
+//! 对局事件钩子：PGN 输出、声音、统计、串口镜像等外部功能实现 `GameObserver`，
+//! 注册到 `Observers` 后由 `Game` 在落子/计时时统一通知，无需改动对局逻辑本身。
+
+use rhess_core::result::GameResult;
+use rhess_core::{Color, GameState, Move, Piece};
+use rtt_target::rprintln;
+
+/// 同时挂载的观察者上限（无堆分配，固定数组存放）。
+pub const MAX_OBSERVERS: usize = 4;
+
+/// 对局事件回调，全部带空默认实现，按需覆盖。
+pub trait GameObserver {
+    /// 一步棋已落下，`state` 为落子后的局面。
+    fn on_move(&mut self, _mv: Move, _state: &GameState) {}
+    /// 本步吃子（含吃过路兵），在 `on_move` 之前触发。
+    fn on_capture(&mut self, _mv: Move, _captured: Piece) {}
+    /// 落子后 `color` 一方被将军。
+    fn on_check(&mut self, _color: Color) {}
+    /// 对局离开开局库：第 `ply` 个半回合（自 0 起）不再是任何开局线的续着，每局只触发一次。
+    fn on_book_exit(&mut self, _ply: usize) {}
+    /// 对局结束，`result` 含胜负与结束原因。
+    fn on_game_end(&mut self, _result: GameResult) {}
+    /// 每帧时钟阶段调用，`now_ms` 为时基当前毫秒数。
+    fn on_clock_tick(&mut self, _now_ms: u32) {}
+}
+
+/// 观察者注册表。
+pub struct Observers<'a> {
+    slots: [Option<&'a mut dyn GameObserver>; MAX_OBSERVERS],
+}
+
+impl<'a> Observers<'a> {
+    pub fn new() -> Self {
+        Observers {
+            slots: [None, None, None, None],
+        }
+    }
+
+    /// 注册观察者；已满时原样返回。
+    pub fn register(
+        &mut self,
+        observer: &'a mut dyn GameObserver,
+    ) -> Result<(), &'a mut dyn GameObserver> {
+        match self.slots.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => {
+                *slot = Some(observer);
+                Ok(())
+            }
+            None => Err(observer),
+        }
+    }
+
+    fn for_each(&mut self, mut f: impl FnMut(&mut dyn GameObserver)) {
+        for observer in self.slots.iter_mut().flatten() {
+            f(&mut **observer);
+        }
+    }
+
+    /// 通知一步棋：`before` 为落子前局面，`after` 为落子后局面，`result` 为对局因这一步
+    /// 结束时的结果（含重复、五十步与残局库判定，由 `Game` 算出）。
+    pub(super) fn notify_move(
+        &mut self,
+        before: &GameState,
+        mv: Move,
+        after: &GameState,
+        result: Option<GameResult>,
+    ) {
+        if let Some(captured) = captured_piece(before, mv) {
+            self.for_each(|o| o.on_capture(mv, captured));
+        }
+        self.for_each(|o| o.on_move(mv, after));
+
+        let side = after.side_to_move;
+        let in_check = after.is_in_check(side);
+        if in_check {
+            self.for_each(|o| o.on_check(side));
+        }
+        if let Some(result) = result {
+            self.for_each(|o| o.on_game_end(result));
+        }
+    }
+
+    pub(super) fn notify_book_exit(&mut self, ply: usize) {
+        self.for_each(|o| o.on_book_exit(ply));
+    }
+
+    pub(super) fn notify_clock_tick(&mut self, now_ms: u32) {
+        self.for_each(|o| o.on_clock_tick(now_ms));
+    }
+}
+
+impl Default for Observers<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn captured_piece(before: &GameState, mv: Move) -> Option<Piece> {
+    if mv.is_en_passant {
+        // 被吃的兵与起点同一横排、与终点同一纵线。
+        let square = (mv.from & !7) | (mv.to & 7);
+        return before.board[square as usize];
+    }
+    before.board[mv.to as usize]
+}
+
+/// 通过 RTT 打印每步棋与对局结果，便于调试时跟踪进程。
+pub struct RttLog;
+
+impl GameObserver for RttLog {
+    fn on_move(&mut self, mv: Move, state: &GameState) {
+        let (from, to) = (square_name(mv.from), square_name(mv.to));
+        rprintln!(
+            "move {}: {}{}{}{}",
+            state.fullmove_number,
+            from[0] as char,
+            from[1] as char,
+            to[0] as char,
+            to[1] as char
+        );
+    }
+
+    fn on_book_exit(&mut self, ply: usize) {
+        rprintln!("out of book at ply {}", ply + 1);
+    }
+
+    fn on_game_end(&mut self, result: GameResult) {
+        rprintln!("game over: {} ({})", result.pgn(), result.key());
+    }
+}
+
+pub(crate) fn square_name(square: u8) -> [u8; 2] {
+    [b'a' + (square & 7), b'1' + (square >> 3)]
+}
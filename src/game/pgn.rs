@@ -0,0 +1,89 @@
+//! PGN 导出：标准七项标签与 Termination、TimeControl、非标准起始局面的 SetUp/FEN，以及复现对局用的 `Rhess*` 标签
+//! （固件版本、随机种子、AI 搜索设置），后接 `History::write_to` 的着法正文。
+//! 旧格式存档没有对局设置，只输出标准标签。
+
+use super::history::History;
+use crate::drivers::rtc::DateTime;
+use core::fmt::{self, Write};
+use rhess_core::record::GameSetup;
+use rhess_core::result::GameResult;
+use rhess_core::{GameState, fen, rating};
+
+/// 输出完整的一局 PGN：标签、空行、着法与结果。
+pub fn write(
+    out: &mut impl Write,
+    history: &History,
+    setup: Option<&GameSetup>,
+    started_at: &DateTime,
+    result: GameResult,
+) -> fmt::Result {
+    writeln!(out, "[Event \"rhess game\"]")?;
+    writeln!(out, "[Site \"rhess\"]")?;
+    writeln!(
+        out,
+        "[Date \"{}.{:02}.{:02}\"]",
+        started_at.year, started_at.month, started_at.day
+    )?;
+    writeln!(out, "[Round \"-\"]")?;
+    let player = |ai: Option<bool>| match ai {
+        Some(true) => "rhess",
+        Some(false) => "Human",
+        None => "?",
+    };
+    writeln!(out, "[White \"{}\"]", player(setup.map(|s| s.ai_sides[0])))?;
+    writeln!(out, "[Black \"{}\"]", player(setup.map(|s| s.ai_sides[1])))?;
+    writeln!(out, "[Result \"{}\"]", result.pgn())?;
+    writeln!(out, "[Termination \"{}\"]", result.termination())?;
+    // 基本秒数+每步加秒，不限时为 "-"；旧格式存档不知道时限，不写。
+    match setup.map(|s| s.time_control.seconds()) {
+        Some(Some((base, increment))) => writeln!(out, "[TimeControl \"{}+{}\"]", base, increment)?,
+        Some(None) => writeln!(out, "[TimeControl \"-\"]")?,
+        None => {}
+    }
+    let start = history.start();
+    if *start != GameState::start_position() {
+        writeln!(out, "[SetUp \"1\"]")?;
+        writeln!(out, "[FEN \"{}\"]", fen::fen(start).as_str())?;
+    }
+    if let Some(setup) = setup {
+        write_setup(out, setup)?;
+    }
+    writeln!(out)?;
+    history.write_to(out, result.pgn())
+}
+
+/// 复现对局所需的设置。开局库总是启用，每步按种子与半回合序号选线。
+fn write_setup(out: &mut impl Write, setup: &GameSetup) -> fmt::Result {
+    let [major, minor, patch] = setup.version;
+    writeln!(out, "[RhessVersion \"{}.{}.{}\"]", major, minor, patch)?;
+    writeln!(out, "[RhessSeed \"{:08x}\"]", setup.seed)?;
+    if setup.ai_sides == [false, false] {
+        return Ok(());
+    }
+    let ai = &setup.ai;
+    writeln!(out, "[RhessDepth \"{}\"]", ai.max_depth)?;
+    match ai.node_limit {
+        Some(nodes) => writeln!(out, "[RhessNodes \"{}\"]", nodes)?,
+        None => writeln!(out, "[RhessNodes \"unlimited\"]")?,
+    }
+    let budget = if ai.adaptive_budget {
+        "adaptive"
+    } else {
+        "fixed"
+    };
+    writeln!(out, "[RhessBudget \"{}\"]", budget)?;
+    let mobility = if ai.mobility { "on" } else { "off" };
+    writeln!(out, "[RhessMobility \"{}\"]", mobility)?;
+    // 在与最佳着相差多少厘兵内随机选择；种子即 RhessSeed。
+    match ai.variety {
+        Some(variety) => writeln!(out, "[RhessVariety \"{}\"]", variety.margin)?,
+        None => writeln!(out, "[RhessVariety \"off\"]")?,
+    }
+    for (side, tag) in ["RhessWhiteStyle", "RhessBlackStyle"].iter().enumerate() {
+        if setup.ai_sides[side] {
+            writeln!(out, "[{} \"{}\"]", tag, setup.profiles[side].name)?;
+        }
+    }
+    writeln!(out, "[RhessStrength \"{}\"]", rating::engine_rating(ai))?;
+    writeln!(out, "[RhessBook \"on\"]")
+}
@@ -0,0 +1,35 @@
+//! 快速对弈的累计比分：白胜、和、黑胜，记在备份 SRAM 末尾（检查点之后），战绩页读出显示。
+//! 快速对弈的对局只经 USART1 输出 PGN、不进 Flash 存档，这是它们在板上留下的唯一记录；
+//! 与检查点一样复位不丢失，无纽扣电池时断电即清零。
+//!
+//! 记录格式（小端）：0 魔数 `RHT1`，4 白胜 u32，8 和 u32，12 黑胜 u32。
+
+use crate::drivers::backup_sram::{self, BackupSram};
+
+const MAGIC: [u8; 4] = *b"RHT1";
+const LEN: usize = 16;
+/// 记录在备份 SRAM 中的起点；检查点只用到这里之前。
+pub const OFFSET: usize = backup_sram::SIZE - LEN;
+
+/// 读出累计比分（白胜、和、黑胜）；没有记录时全为 0。
+pub fn load(backup: &BackupSram) -> [u32; 3] {
+    let bytes = &backup.read()[OFFSET..];
+    if bytes[0..4] != MAGIC {
+        return [0; 3];
+    }
+    let count =
+        |at: usize| u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]);
+    [count(4), count(8), count(12)]
+}
+
+/// 记一局：`slot` 为 0 白胜、1 和、2 黑胜。
+pub fn record(backup: &mut BackupSram, slot: usize) {
+    let mut tally = load(backup);
+    tally[slot] = tally[slot].saturating_add(1);
+    let mut out = [0; LEN];
+    out[0..4].copy_from_slice(&MAGIC);
+    for (i, count) in tally.iter().enumerate() {
+        out[4 + i * 4..8 + i * 4].copy_from_slice(&count.to_le_bytes());
+    }
+    backup.write(OFFSET, &out);
+}
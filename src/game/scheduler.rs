@@ -0,0 +1,111 @@
+//! 帧调度：每帧依次执行 输入 → AI 片段 → 时钟 → 渲染，目标 30 ms 一帧。
+//! 各阶段耗时分别累计，超出预算时通过 RTT 打印分项，便于定位卡顿来源。
+
+use crate::drivers::delay::Delay;
+use crate::drivers::timebase::Timebase;
+use crate::ui::frame_overlay::FrameStats;
+use rtt_target::rprintln;
+
+/// 单帧目标时长（毫秒）。
+pub const FRAME_BUDGET_MS: u32 = 30;
+
+#[derive(Clone, Copy)]
+pub enum Phase {
+    Input,
+    Ai,
+    Clock,
+    Render,
+}
+
+impl Phase {
+    const COUNT: usize = 4;
+
+    const fn index(self) -> usize {
+        match self {
+            Phase::Input => 0,
+            Phase::Ai => 1,
+            Phase::Clock => 2,
+            Phase::Render => 3,
+        }
+    }
+}
+
+pub struct Scheduler {
+    frame_start_ms: u32,
+    last_mark_ms: u32,
+    phase_ms: [u32; Phase::COUNT],
+    frames: u32,
+    overruns: u32,
+    worst_ms: u32,
+    /// 上一个完整帧的总耗时与其中渲染阶段的耗时。
+    last_ms: u32,
+    last_render_ms: u32,
+}
+
+impl Scheduler {
+    pub fn new(timebase: &Timebase) -> Self {
+        let now = timebase.now_ms();
+        Scheduler {
+            frame_start_ms: now,
+            last_mark_ms: now,
+            phase_ms: [0; Phase::COUNT],
+            frames: 0,
+            overruns: 0,
+            worst_ms: 0,
+            last_ms: 0,
+            last_render_ms: 0,
+        }
+    }
+
+    pub fn begin_frame(&mut self, timebase: &Timebase) {
+        let now = timebase.now_ms();
+        self.frame_start_ms = now;
+        self.last_mark_ms = now;
+        self.phase_ms = [0; Phase::COUNT];
+    }
+
+    /// 将上一次标记以来的耗时记入 `phase`。
+    pub fn mark(&mut self, phase: Phase, timebase: &Timebase) {
+        let now = timebase.now_ms();
+        self.phase_ms[phase.index()] += now.wrapping_sub(self.last_mark_ms);
+        self.last_mark_ms = now;
+    }
+
+    /// 供调试叠加层显示的计数（见 `ui::frame_overlay`）。
+    pub fn stats(&self) -> FrameStats {
+        FrameStats {
+            frame_ms: self.last_ms,
+            render_ms: self.last_render_ms,
+            worst_ms: self.worst_ms,
+        }
+    }
+
+    /// 结束本帧：未用完的预算用于休眠，超时则记录并立即进入下一帧。
+    pub fn end_frame(&mut self, timebase: &Timebase, delay: &mut Delay) {
+        let elapsed = timebase.elapsed_ms(self.frame_start_ms);
+        self.frames = self.frames.wrapping_add(1);
+        if elapsed > self.worst_ms {
+            self.worst_ms = elapsed;
+        }
+        self.last_ms = elapsed;
+        self.last_render_ms = self.phase_ms[Phase::Render.index()];
+        if elapsed <= FRAME_BUDGET_MS {
+            delay.ms(FRAME_BUDGET_MS - elapsed);
+            return;
+        }
+
+        self.overruns = self.overruns.wrapping_add(1);
+        let [input, ai, clock, render] = self.phase_ms;
+        rprintln!(
+            "frame {} overrun: {} ms (input {} / ai {} / clock {} / render {}), {} overruns, worst {} ms",
+            self.frames,
+            elapsed,
+            input,
+            ai,
+            clock,
+            render,
+            self.overruns,
+            self.worst_ms
+        );
+    }
+}
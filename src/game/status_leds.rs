@@ -0,0 +1,72 @@
+//! LED 状态指示：LED1 白方走棋、LED4 黑方走棋常亮；LED2 被将军、LED3 AI 思考中闪烁。
+//! 各灯只登记图案，由 `update` 按当前时间统一输出，不看屏幕也能读出局面状态。
+
+use crate::drivers::led::Leds;
+use crate::drivers::timebase::Timebase;
+use rhess_core::{Color, GameState};
+
+pub const LED_WHITE: usize = 0;
+pub const LED_CHECK: usize = 1;
+pub const LED_THINKING: usize = 2;
+pub const LED_BLACK: usize = 3;
+
+/// 思考指示灯闪烁周期。
+const THINKING_BLINK_MS: u32 = 200;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Pattern {
+    Off,
+    On,
+    /// 以 `period_ms` 为周期亮灭各半；减少动态效果时常亮。
+    Blink {
+        period_ms: u32,
+    },
+}
+
+pub struct StatusLeds {
+    patterns: [Pattern; 4],
+}
+
+impl StatusLeds {
+    pub const fn new() -> Self {
+        StatusLeds {
+            patterns: [Pattern::Off; 4],
+        }
+    }
+
+    pub fn set_pattern(&mut self, led: usize, pattern: Pattern) {
+        self.patterns[led] = pattern;
+    }
+
+    /// 按局面设置行棋方与将军指示灯。
+    pub fn show_position(&mut self, state: &GameState) {
+        let white = state.side_to_move == Color::White;
+        self.set_pattern(LED_WHITE, if white { Pattern::On } else { Pattern::Off });
+        self.set_pattern(LED_BLACK, if white { Pattern::Off } else { Pattern::On });
+        let check = state.is_in_check(state.side_to_move);
+        self.set_pattern(LED_CHECK, if check { Pattern::On } else { Pattern::Off });
+    }
+
+    pub fn set_thinking(&mut self, thinking: bool) {
+        let pattern = if thinking {
+            Pattern::Blink {
+                period_ms: THINKING_BLINK_MS,
+            }
+        } else {
+            Pattern::Off
+        };
+        self.set_pattern(LED_THINKING, pattern);
+    }
+
+    /// 把各灯图案输出到硬件。
+    pub fn update(&self, leds: &mut Leds, timebase: &Timebase) {
+        for (i, pattern) in self.patterns.iter().enumerate() {
+            let on = match *pattern {
+                Pattern::Off => false,
+                Pattern::On => true,
+                Pattern::Blink { period_ms } => timebase.blink_lit(0, period_ms / 2),
+            };
+            leds.set(i, on);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+// @generated by `cargo xtask assets` from assets/ (the PNGs and assets/sprites.txt) — edit that instead.
+pub const CRAB_W: u16 = 200;
+pub const CRAB_H: u16 = 133;
+pub static CRAB_BITMAP: [u16; 26600] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50C0, 0xC9E0, 0xD200, 0x7920, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3080, 0x8140, 0x6900, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xD200, 0xFA60, 0xFA60, 0xEA40, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x58C0, 0x8140, 0x48C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2060, 0xC9E0, 0xFA60, 0xFA60, 0xA9A0, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9160, 0xFA60, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7920, 0xEA40, 0xFA60, 0xEA40, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7100, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9960, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6100, 0xEA40, 0xFA60, 0xF260, 0xFA60, 0xA9A0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xB1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF240, 0x8140, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xB1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x3080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50C0, 0xE220, 0xFA60, 0xF260, 0xF260, 0xFA60, 0xE220, 0x3060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3080, 0x3880, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x7120, 0x0000, 0x0000, 0x0000, 0x0000, 0x6100, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9980, 0x0800, 0x0000, 0x0000, 0x0000, 0x48A0, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x7100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0x3880, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7920, 0xE220, 0xEA40, 0xA180, 0x2060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8140, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x60E0, 0x0000, 0x0000, 0x2040, 0xCA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x50C0, 0x0000, 0x0000, 0x3080, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB9C0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x7920, 0xE220, 0xEA40, 0xA180, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2060, 0xDA20, 0xFA60, 0xF260, 0xFA60, 0xC9E0, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x6900, 0x48C0, 0xA180, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x58E0, 0x50C0, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x3880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xA9A0, 0xF240, 0xFA60, 0xF260, 0xF260, 0x58C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xEA40, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x7120, 0x0820, 0x0000, 0x0000, 0x0000, 0x48A0, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x8140, 0x0000, 0x0000, 0x0000, 0x0000, 0x50C0, 0xCA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0x8140, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x60E0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xA180, 0x2860, 0x0000, 0x0000, 0x9160, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x1840, 0x0000, 0x1020, 0x7920, 0xE240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA180, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8140, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x7100, 0x9980, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xB1A0, 0x7100, 0xA9A0, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB9C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xA180, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0x3080, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xE240, 0x3080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x3080, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xC9E0, 0xEA40, 0xC1E0, 0x6900, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x50C0, 0xA9A0, 0xE220, 0xDA20, 0x6900, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xA180, 0xFA60, 0xF260, 0xFA60, 0xF260, 0xC9E0, 0x7120, 0x2040, 0x0000, 0x0000, 0x0000, 0x3880, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x7120, 0x0000, 0x0000, 0x0000, 0x1020, 0x58E0, 0xB1C0, 0xEA40, 0xFA60, 0xFA60, 0xFA60, 0xD200, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xD200, 0x8140, 0x2860, 0x2860, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x40A0, 0x1840, 0x60E0, 0xC1E0, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xB1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xDA20, 0xDA00, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0xD200, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA00, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xA180, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0x7100, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9980, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3080, 0x9160, 0xE220, 0xA180, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9160, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0x8140, 0xDA20, 0xFA60, 0xF260, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8960, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x58E0, 0xC9E0, 0xFA60, 0xFA60, 0xFA60, 0xE240, 0x3880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x58E0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7120, 0xC9E0, 0x6100, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0x7920, 0x9980, 0x7120, 0x40A0, 0x1840, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x8960, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x3880, 0x6100, 0x9160, 0x9160, 0x3080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0x9160, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xFA60, 0xD200, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xC9E0, 0x58C0, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x58C0, 0xFA60, 0xF240, 0xB9C0, 0x50C0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8960, 0xFA60, 0xFA60, 0xFA60, 0xEA40, 0xD200, 0xA9A0, 0x7920, 0x48A0, 0x2040, 0x50C0, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x7920, 0x2040, 0x3880, 0x6900, 0x9980, 0xC9E0, 0xE220, 0xF260, 0xFA60, 0xFA60, 0xC1E0, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xB9C0, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB9C0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xEA40, 0xB1C0, 0x1020, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xEA40, 0xFA60, 0xFA60, 0xEA40, 0x9160, 0x2060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xEA40, 0xDA00, 0xE240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xD200, 0xEA40, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xEA40, 0x3880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x48A0, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA1A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7120, 0xFA60, 0xEA40, 0x40A0, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x3080, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xDA20, 0xFA60, 0xF260, 0xFA60, 0xFA60, 0xC9E0, 0x48C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xB1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x48C0, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9160, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xB1C0, 0xFA60, 0xFA60, 0x8140, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x7920, 0xC1E0, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xC9E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x7100, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8140, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB9C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xE220, 0xFA60, 0xFA60, 0xB9C0, 0x1020, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x7920, 0xF240, 0xEA20, 0x3080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0xB1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x8140, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x58E0, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9160, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x6900, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8140, 0xFA60, 0xF260, 0xFA60, 0xDA20, 0x2860, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6900, 0xEA40, 0xFA60, 0xF260, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9160, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0x8140, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8140, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xD200, 0xFA60, 0xF260, 0xFA60, 0xEA40, 0x40A0, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xE220, 0xFA60, 0xF260, 0xFA60, 0x9980, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7120, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x6900, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE240, 0x3880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3080, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7920, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x50C0, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x1020, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xFA60, 0xD200, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x58E0, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x40A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9980, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8940, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0x58C0, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x6900, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6900, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA180, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xCA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x9980, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x50C0, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x1820, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1A0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x48C0, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x8940, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x48A0, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6100, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xEA40, 0x48A0, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x50C0, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xEA40, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB9C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0x3080, 0x2860, 0x2060, 0x2040, 0x1840, 0x1840, 0x1020, 0x2040, 0xB1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA00, 0x40A0, 0x1020, 0x1820, 0x1840, 0x2040, 0x2060, 0x2860, 0x3060, 0x2860, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6900, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x48A0, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x3080, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x8940, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0800, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xEA40, 0x40A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x58E0, 0xD200, 0xEA20, 0xE220, 0xDA20, 0xDA00, 0xD200, 0xCA00, 0xC9E0, 0xD200, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0xC1E0, 0xCA00, 0xD200, 0xDA00, 0xDA20, 0xE220, 0xE220, 0xE220, 0x8140, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x8940, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x58E0, 0x0000, 0x0000, 0x0000, 0x40A0, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x1820, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x9160, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xC9E0, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xEA40, 0x40A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x9160, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x60E0, 0x0000, 0x0000, 0x50C0, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9980, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0820, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x2060, 0x0000, 0x0000, 0x0000, 0x7120, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x8940, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x60E0, 0x1020, 0x8140, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x58E0, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0820, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9980, 0x0820, 0x0000, 0x0000, 0x50C0, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x9980, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xCA00, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x7920, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xB1C0, 0xC1E0, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xCA00, 0x1840, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0xA1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x7120, 0x0000, 0x0000, 0x3880, 0xEA40, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x48A0, 0xEA40, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x8140, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50C0, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x7920, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x58E0, 0x0000, 0x2040, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xB1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x3060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x3880, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x58C0, 0x1020, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x60E0, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9960, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xB1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x6100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0820, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x6900, 0xB1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1C0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xC9E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x40A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6100, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA1A0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x40A0, 0xE240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0xE220, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9160, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7920, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1A0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xCA00, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x8940, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7120, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xEA40, 0x48C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2060, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x2040, 0x8140, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1A0, 0x2860, 0x1020, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x48C0, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xB1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0800, 0x2040, 0x3880, 0x58E0, 0x7920, 0xA180, 0xC1C0, 0xDA00, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xDA20, 0xC9E0, 0xA9A0, 0x8140, 0x60E0, 0x40A0, 0x2060, 0x1020, 0x0000, 0x0000, 0x0000, 0x8140, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0x9980, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x1820, 0x0000, 0x0000, 0x2060, 0xA180, 0xD200, 0xEA40, 0xF240, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xEA40, 0xDA20, 0xB9C0, 0x40A0, 0x0000, 0x0000, 0x1020, 0xA9A0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x7920, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x60E0, 0x0000, 0x0000, 0x0000, 0x9160, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xC1E0, 0x1840, 0x0000, 0x0000, 0x3080, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xB1C0, 0x3080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xB9C0, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1A0, 0x1020, 0x0000, 0x0000, 0x0820, 0xB1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA40, 0xFA40, 0xFA60, 0xF260, 0xF240, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA40, 0xF240, 0xF240, 0xF240, 0xF240, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x2860, 0x0000, 0x0000, 0x60E0, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x8940, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0x7920, 0xDA20, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x38A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x7920, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xF242, 0xEA85, 0xCA46, 0x8962, 0x6100, 0x58E0, 0x60E0, 0x8940, 0xB9C0, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF241, 0xF284, 0xDAA6, 0x89E4, 0x50E1, 0x58C0, 0x60E0, 0x9960, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x0820, 0x0000, 0x1020, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xC9E0, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3060, 0x9160, 0xE220, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x7120, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xF283, 0xECF2, 0xF71C, 0xEF1C, 0xA534, 0x2986, 0x0000, 0x0000, 0x0800, 0x1020, 0x50A0, 0xB9C0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF241, 0xF3EB, 0xFEB9, 0xF75D, 0xCE79, 0x52AB, 0x0841, 0x0000, 0x0800, 0x3880, 0xA980, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x3880, 0x0000, 0x0000, 0x50C0, 0xEA40, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x9160, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xA180, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x2060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF242, 0xFD32, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xB596, 0x18C3, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xA180, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA20, 0xC2C8, 0xF73C, 0xFFFF, 0xFFFF, 0xFFFF, 0xE71C, 0x4228, 0x0020, 0x0000, 0x0000, 0x1820, 0x8940, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x7100, 0x0000, 0x0000, 0x2860, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x48C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x8940, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x9160, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7120, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xFB07, 0xFF5C, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xF7BE, 0x5AEB, 0x0020, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xC1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA1A2, 0xA4F3, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xA514, 0x0861, 0x0000, 0x0000, 0x0000, 0x1820, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x1020, 0x0000, 0x2860, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x9980, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF221, 0xE3EE, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0x9CF3, 0x1082, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50C0, 0xEA40, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x5143, 0xCE79, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xD69A, 0x2104, 0x0000, 0x0000, 0x0000, 0x0000, 0x58C0, 0xEA40, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x3060, 0x0000, 0x3880, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9160, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6100, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xC1E0, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3080, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE201, 0xB470, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xB5B6, 0x10A2, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0xB1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9960, 0x2925, 0xDEFB, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xDEFB, 0x2124, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x60E0, 0x0000, 0x48C0, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1C0, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x8960, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x9160, 0x3060, 0x0000, 0x0000, 0x0000, 0x0000, 0x6100, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A1, 0x8410, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xB596, 0x10A2, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6900, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xEA40, 0x48A0, 0x18E4, 0xD6BA, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xDEDB, 0x2104, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x8140, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9980, 0x1020, 0x6100, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x3060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xA180, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xDA20, 0x8960, 0x3080, 0x0800, 0x0000, 0x6900, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x6921, 0x5B2C, 0xFFDF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0x8C71, 0x0861, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x4080, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x2040, 0x18A2, 0xBDD7, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xBDD7, 0x18C3, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50C0, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9160, 0x8940, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xE220, 0xA180, 0x8140, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA20, 0x3880, 0x2965, 0xDEDB, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xEF5D, 0x4228, 0x0020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x1000, 0x0841, 0x6B4D, 0xFFDF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFDF, 0x632C, 0x0841, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2840, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x8140, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA00, 0x2860, 0x0841, 0x632C, 0xEF7D, 0xFFFF, 0xFFFF, 0xFFDF, 0x8C71, 0x0861, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0xC9E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9160, 0x1000, 0x0000, 0x18C3, 0x9CD3, 0xF7BE, 0xFFFF, 0xF7BE, 0x9CD3, 0x18C3, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2020, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x60E0, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x2040, 0x0000, 0x0841, 0x5AEB, 0xB596, 0xB5B6, 0x73AE, 0x18C3, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0xC9E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x8940, 0x0800, 0x0000, 0x0000, 0x18C3, 0x5ACB, 0x7BEF, 0x5ACB, 0x18C3, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xB9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE240, 0x6100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3080, 0xA9A0, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x2040, 0x0000, 0x0000, 0x0841, 0x0861, 0x0861, 0x0841, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xC9E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9960, 0x1000, 0x0000, 0x0000, 0x0000, 0x0020, 0x0020, 0x0020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xB1A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x6900, 0xD200, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA00, 0x3060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3060, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1A0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0xC1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1A0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0x9160, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xEA40, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50A0, 0xEA40, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA00, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xB1A0, 0x3080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3080, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x9160, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x8940, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x7100, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x6900, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xC1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x40A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xC9E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x8940, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xB1C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x3880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9160, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA180, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2840, 0xA9A0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xB9C0, 0x3880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x40A0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0x7920, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x9140, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0x60E0, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x6100, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xA180, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xF240, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x8120, 0x2860, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x3880, 0xA180, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xA180, 0x3880, 0x1820, 0x0800, 0x0800, 0x1820, 0x2860, 0x60E0, 0xB1A0, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xF240, 0xF240, 0xF240, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x6900, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xD1E0, 0xB180, 0xB980, 0xD1E0, 0xEA40, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xD200, 0x9160, 0x50C0, 0x3880, 0x58C0, 0x7100, 0xA9A0, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0xB9C0, 0x9960, 0x9960, 0xB1A0, 0xD200, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xEA20, 0xDA00, 0xC9C0, 0xB9A0, 0xB180, 0xB980, 0xC1A0, 0xC9E0, 0xE220, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1C0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8960, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0xA980, 0xA160, 0xA140, 0xA140, 0xA140, 0xA960, 0xC1C0, 0xE200, 0xF240, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF240, 0xEA20, 0xF240, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xE220, 0xC1C0, 0xA960, 0xA140, 0xA140, 0xA140, 0x9120, 0x9940, 0xA140, 0xA140, 0xA960, 0xB980, 0xD1E0, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xDA00, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x3880, 0x4080, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA960, 0xB180, 0xC9E0, 0xE220, 0xF240, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF240, 0xDA00, 0xC1A0, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0x7900, 0x4080, 0x9120, 0xA140, 0xA140, 0xA140, 0xA140, 0x7900, 0x50C0, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x7100, 0x0000, 0x0820, 0x7900, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA960, 0xB9A0, 0xD1E0, 0xEA20, 0xF240, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF240, 0xF240, 0xEA40, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF260, 0xEA20, 0xD1E0, 0xB180, 0xA160, 0xA140, 0xA140, 0xA140, 0xA160, 0x9120, 0x48A0, 0x0800, 0x3880, 0xA140, 0xA140, 0xA140, 0xA140, 0x9940, 0x3860, 0x0000, 0x9960, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xD200, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9980, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x8140, 0x0000, 0x0000, 0x2040, 0x9120, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0x68E0, 0x7900, 0xA140, 0xA140, 0xA140, 0xA960, 0xB9A0, 0xD1E0, 0xE220, 0xF240, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xEA40, 0xE220, 0xDA00, 0xC9E0, 0xB9C0, 0xA9A0, 0xA180, 0x9160, 0x8940, 0x68E0, 0x58C0, 0x50C0, 0x50C0, 0xC9E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF240, 0xDA00, 0xC1C0, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0x9120, 0x60C0, 0x1840, 0x0000, 0x0000, 0x70E0, 0xA960, 0xA140, 0xA140, 0xA140, 0x68E0, 0x0000, 0x0000, 0xA180, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB9C0, 0x1020, 0x0000, 0x0000, 0x4080, 0x9940, 0xA140, 0xA140, 0xA140, 0xA160, 0x68E0, 0x1020, 0x4080, 0x7900, 0xA140, 0xA160, 0xA140, 0xA140, 0xA960, 0xB980, 0xC9C0, 0xE200, 0xF240, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x60E0, 0x3860, 0x3060, 0x1820, 0x0800, 0x1000, 0x1000, 0x0800, 0x0800, 0x0000, 0x0000, 0x0000, 0x48A0, 0xEA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF240, 0xE220, 0xC9C0, 0xB180, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0x9120, 0x60C0, 0x2040, 0x0000, 0x0000, 0x0000, 0x2040, 0x9120, 0xA140, 0xA140, 0xA140, 0x8920, 0x1840, 0x0000, 0x1020, 0xC1E0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB9C0, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x60E0, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x48A0, 0x0000, 0x0000, 0x0000, 0x58C0, 0xA140, 0xA140, 0xA140, 0xA140, 0x9940, 0x2840, 0x0000, 0x0800, 0x3060, 0x68E0, 0x9120, 0xA140, 0xA160, 0xA140, 0xA140, 0xA960, 0xB180, 0xC1A0, 0xD1E0, 0xEA20, 0xF240, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x8940, 0x1000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0xA9A0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF240, 0xE220, 0xC9C0, 0xB180, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0xA140, 0x8920, 0x58C0, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50A0, 0xA140, 0xA140, 0xA140, 0xA140, 0x4080, 0x0000, 0x0000, 0x3060, 0xE220, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7920, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA180, 0x0800, 0x0000, 0x0000, 0x0820, 0x70E0, 0xA160, 0xA140, 0xA140, 0xA160, 0x60C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0x50A0, 0x8100, 0x9940, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA960, 0xB180, 0xC9C0, 0xDA00, 0xEA20, 0xF240, 0xF260, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x50C0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0x8940, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xF240, 0xEA40, 0xDA00, 0xC1C0, 0xB180, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0x9940, 0x7900, 0x4080, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x8100, 0xA160, 0xA140, 0xA140, 0x68E0, 0x0800, 0x0000, 0x0000, 0x60E0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x8140, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x8940, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x40A0, 0x0000, 0x0000, 0x0000, 0x1840, 0x8920, 0xA140, 0xA140, 0xA140, 0x9140, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x2860, 0x58C0, 0x8100, 0x9940, 0xA160, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA960, 0xB180, 0xC1C0, 0xD1E0, 0xE220, 0xEA40, 0xF240, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x7100, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x2040, 0x9160, 0xF240, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xF240, 0xEA40, 0xE200, 0xC9E0, 0xB9A0, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0xA140, 0x8920, 0x58C0, 0x2840, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0xA140, 0xA140, 0xA140, 0x8920, 0x1840, 0x0000, 0x0000, 0x0000, 0xA180, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB9C0, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x9160, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA180, 0x0800, 0x0000, 0x0000, 0x0000, 0x2860, 0x9120, 0xA140, 0xA140, 0xA160, 0x60C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x2840, 0x50A0, 0x7900, 0x9120, 0xA140, 0xA960, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA960, 0xB180, 0xB9A0, 0xC9C0, 0xD9E0, 0xE220, 0xEA40, 0xF240, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xB1A0, 0x60E0, 0x3880, 0x2840, 0x40A0, 0x7100, 0xC9E0, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF240, 0xEA20, 0xDA00, 0xC9C0, 0xB9A0, 0xB160, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0xA160, 0x9940, 0x8100, 0x58C0, 0x3060, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x70E0, 0xA160, 0xA140, 0xA140, 0x4080, 0x0000, 0x0000, 0x0000, 0x2040, 0xD200, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x40A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9160, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xEA40, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x4080, 0xA140, 0xA140, 0xA140, 0x9940, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x1840, 0x3880, 0x60C0, 0x8100, 0x9940, 0xA140, 0xA160, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA960, 0xA960, 0xB980, 0xC1C0, 0xC9E0, 0xDA00, 0xE220, 0xEA40, 0xF240, 0xF240, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xF240, 0xE220, 0xDA00, 0xEA20, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF240, 0xEA40, 0xE220, 0xDA00, 0xC9C0, 0xC1A0, 0xB180, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0xA160, 0xA140, 0x9120, 0x78E0, 0x50A0, 0x2860, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2840, 0x9940, 0xA140, 0xA140, 0x60C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x58E0, 0xF240, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x58C0, 0xA140, 0xA140, 0xA160, 0x68C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x2040, 0x3880, 0x58C0, 0x78E0, 0x8920, 0x9940, 0xA140, 0xA960, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA960, 0xA960, 0xB180, 0xB9A0, 0xC1C0, 0xC9E0, 0xD1E0, 0xDA00, 0xE220, 0xEA20, 0xEA40, 0xF240, 0xF240, 0xF240, 0xF260, 0xF260, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xFA60, 0xF260, 0xF260, 0xF240, 0xF240, 0xF240, 0xEA40, 0xEA20, 0xE220, 0xDA00, 0xD1E0, 0xC9C0, 0xB9A0, 0xB180, 0xA960, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0xA960, 0xA140, 0xA140, 0x9120, 0x7900, 0x58C0, 0x3860, 0x1820, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x60C0, 0xA160, 0xA140, 0x8920, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0xA180, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x70E0, 0xA140, 0xA140, 0x9940, 0x3060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x2840, 0x4080, 0x58C0, 0x70E0, 0x8900, 0x9120, 0xA140, 0xA140, 0xA160, 0xA960, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA960, 0xA960, 0xB160, 0xB180, 0xB980, 0xB9A0, 0xC1A0, 0xC1C0, 0xC9C0, 0xC9C0, 0xC9E0, 0xD1E0, 0xD1E0, 0xD1E0, 0xD1E0, 0xDA00, 0xDA00, 0xD1E0, 0xD1E0, 0xD1E0, 0xD1E0, 0xD1E0, 0xC9C0, 0xC9C0, 0xC1C0, 0xC1A0, 0xB9A0, 0xB980, 0xB180, 0xA960, 0xA960, 0xA160, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0xA960, 0xA160, 0xA140, 0x9940, 0x9120, 0x8100, 0x68C0, 0x48A0, 0x3060, 0x1820, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0x9120, 0xA140, 0x9940, 0x3860, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x3880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC9E0, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0x8100, 0xA140, 0xA140, 0x78E0, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x1820, 0x3060, 0x4080, 0x50A0, 0x68E0, 0x7900, 0x8920, 0x9120, 0x9940, 0xA140, 0xA140, 0xA160, 0xA160, 0xA160, 0xA960, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA160, 0xA960, 0xA960, 0xA160, 0xA140, 0xA140, 0x9940, 0x9940, 0x8920, 0x7900, 0x68E0, 0x50A0, 0x4080, 0x2860, 0x1820, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x60C0, 0xA140, 0xA140, 0x58C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6900, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x7100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x8940, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2840, 0x9120, 0xA140, 0xA140, 0x4080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x0820, 0x1820, 0x2040, 0x3060, 0x3880, 0x48A0, 0x58C0, 0x60C0, 0x68E0, 0x7900, 0x8100, 0x8920, 0x8920, 0x9120, 0x9140, 0x9940, 0x9940, 0x9940, 0x9940, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0xA140, 0x9940, 0x9940, 0x9940, 0x9940, 0x9120, 0x9120, 0x8920, 0x8100, 0x7900, 0x70E0, 0x60C0, 0x58A0, 0x4880, 0x3860, 0x2860, 0x2040, 0x1020, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0x9120, 0xA960, 0x8100, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xB9C0, 0xFA60, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xE220, 0x40A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3860, 0x9940, 0xA160, 0x8920, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x1020, 0x1020, 0x1820, 0x1840, 0x2040, 0x2040, 0x2040, 0x2840, 0x2860, 0x3060, 0x3060, 0x3060, 0x3860, 0x3860, 0x3060, 0x3060, 0x3060, 0x2860, 0x2840, 0x2040, 0x2040, 0x1840, 0x1820, 0x1820, 0x1020, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x60C0, 0xA960, 0x9940, 0x3060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xEA40, 0xFA60, 0xF260, 0xF260, 0xFA60, 0xD200, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xB1C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x48A0, 0xA140, 0xA140, 0x50A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0x9120, 0xA140, 0x50A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9160, 0xFA60, 0xF260, 0xF260, 0xFA60, 0xEA40, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0x7120, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x60C0, 0xA140, 0x9120, 0x1820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50A0, 0xA960, 0x78E0, 0x0820, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0xDA20, 0xFA60, 0xF260, 0xF260, 0xFA60, 0x9160, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9980, 0xF260, 0xFA60, 0xF260, 0xF260, 0xFA60, 0xDA20, 0x3880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x70E0, 0xA960, 0x50A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x8100, 0x9120, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7120, 0xF260, 0xF260, 0xF260, 0xFA60, 0xC1E0, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9160, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0xA9A0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1820, 0x8920, 0x8900, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3060, 0x9940, 0x4880, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xC9E0, 0xFA60, 0xF260, 0xFA60, 0xE220, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x9160, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0x8140, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3060, 0x9940, 0x3860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x60C0, 0x7900, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6900, 0xF260, 0xF260, 0xF260, 0xF260, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x8960, 0xF240, 0xFA60, 0xF260, 0xFA60, 0xEA40, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x58C0, 0x68C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x7900, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xC9E0, 0xFA60, 0xF260, 0xFA60, 0xB1A0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x8940, 0xF240, 0xFA60, 0xF260, 0xFA60, 0xC9E0, 0x2060, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x58A0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2840, 0x50A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x6100, 0xF260, 0xF260, 0xFA60, 0xD200, 0x3080, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x8140, 0xF240, 0xFA60, 0xF260, 0xFA60, 0x9160, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xB9C0, 0xFA60, 0xFA60, 0xEA40, 0x60E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x8140, 0xF240, 0xFA60, 0xFA60, 0xEA40, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x48A0, 0xEA40, 0xF260, 0xFA60, 0x9160, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x7920, 0xF240, 0xFA60, 0xFA60, 0xB9C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9160, 0xFA60, 0xFA60, 0xC1E0, 0x2040, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x7920, 0xEA40, 0xFA60, 0xF240, 0x6900, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xD200, 0xFA60, 0xE220, 0x48A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7120, 0xEA40, 0xFA60, 0xC9E0, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x50C0, 0xF240, 0xF260, 0x8140, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0800, 0x8140, 0xF240, 0xF240, 0x6100, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x8940, 0xFA60, 0xC1E0, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0x9160, 0xFA60, 0xB9C0, 0x1020, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0820, 0xB9C0, 0xF240, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xA1A0, 0xEA40, 0x40A0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0xDA20, 0xA180, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0xB1A0, 0x8140, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x40A0, 0xC1E0, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2040, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0x50C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+];
+pub const CRAB_SMALL_W: u16 = 36;
+pub const CRAB_SMALL_H: u16 = 24;
+pub static CRAB_SMALL_BITMAP: [u16; 864] = [
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0x0000, 0xFA60, 0x0000, 0x0000, 0x48C0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xFA60, 0x0000, 0xC1E0, 0xF260, 0x48C0, 0xF260, 0xFA60, 0xF260, 0xF260, 0x0000, 0xF240, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0x0000, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x1840, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x3880, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0xFA60, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xFA60, 0x48A0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x7920, 0xE220, 0x1840, 0x0000, 0x0000, 0x0000, 0xFA60, 0xB9C0, 0x0000, 0xEA40,
+    0x0000, 0xE220, 0x0000, 0x7120, 0xF260, 0x0000, 0x0000, 0x0000, 0x0000, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x0000, 0x0000, 0x0000, 0xFA60, 0xF260, 0x58E0, 0x0000, 0xF260,
+    0x0000, 0xF260, 0xA9A0, 0x0800, 0xF260, 0xF260, 0x0000, 0x58E0, 0xD200, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xC1E0, 0xE220, 0x0000, 0xF260, 0xF260, 0x58E0, 0xD200, 0xF260,
+    0x0000, 0xF260, 0xF260, 0x58C0, 0xF260, 0xF260, 0x0000, 0x0000, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xFA60, 0x0000, 0xF260, 0xF260, 0xF260, 0xF260, 0x6100,
+    0x0000, 0x2060, 0xF260, 0xF260, 0xF260, 0xFA60, 0x0000, 0x0820, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xB1A0, 0x0000, 0x48C0, 0xF260, 0xF260, 0xFA60, 0x0000,
+    0x0000, 0x0000, 0x0000, 0xFA60, 0xF260, 0x0000, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xA534, 0x50A0, 0xF260, 0xF260, 0xF260, 0xF75D, 0x3880, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x0000, 0xF260, 0x2040, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0xF260, 0xF260, 0x0800, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x5B2C, 0xFFFF, 0x0000, 0xFA60, 0xF260, 0x2040, 0xFFFF, 0x0000, 0xEA40, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x9160, 0xF260, 0x48A0, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xD200, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x0000, 0x0000, 0x0000, 0xFA60, 0xF260, 0x1020, 0x0000, 0x0000, 0xC1C0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xB1A0, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xF260, 0xF260, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0x0800, 0x3880, 0xF260, 0xF260, 0xF260, 0x1820, 0xB1A0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF240, 0xF260, 0xF260, 0xF260, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xF260, 0xF260, 0x2040, 0xA140, 0xA960, 0xFA60, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xE220, 0xA180, 0x50C0, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xF260, 0xA960, 0xA160, 0x70E0, 0x68E0, 0xF260, 0xF260, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xF260, 0x48A0, 0xA140, 0x0000, 0x0000, 0x3880, 0xA160, 0xA140, 0xC9E0, 0xF260, 0xFA60, 0xF260, 0xF260, 0xDA00, 0xF260, 0xFA60, 0xF260, 0xC9C0, 0xA140, 0xA160, 0x0820, 0x0000, 0x0000, 0xA140, 0x0000, 0xF260, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x9980, 0xF260, 0x0000, 0x8920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1840, 0x3060, 0x3060, 0x2040, 0x0800, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xEA40, 0x2860, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x1020, 0xEA40, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x2860, 0x0000, 0xFA60, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x7920, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0xD200, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x58E0, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+    0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000, 0x0000,
+];
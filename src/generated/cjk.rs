@@ -0,0 +1,241 @@
+// @generated by `cargo xtask assets` from assets/fonts/cjk12.txt — edit that instead.
+const GLYPHS: [CjkGlyph; 14] = [
+    CjkGlyph {
+        ch: '一',
+        rows: [
+            0x000,
+            0x000,
+            0x000,
+            0x000,
+            0x000,
+            0x7FE,
+            0x000,
+            0x000,
+            0x000,
+            0x000,
+            0x000,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '二',
+        rows: [
+            0x000,
+            0x000,
+            0x3FC,
+            0x000,
+            0x000,
+            0x000,
+            0x000,
+            0x000,
+            0x7FE,
+            0x000,
+            0x000,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '三',
+        rows: [
+            0x000,
+            0x7FE,
+            0x000,
+            0x000,
+            0x000,
+            0x3FC,
+            0x000,
+            0x000,
+            0x000,
+            0x000,
+            0xFFF,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '四',
+        rows: [
+            0x000,
+            0x7FE,
+            0x492,
+            0x492,
+            0x492,
+            0x50A,
+            0x606,
+            0x402,
+            0x402,
+            0x7FE,
+            0x402,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '五',
+        rows: [
+            0x000,
+            0x7FE,
+            0x040,
+            0x040,
+            0x040,
+            0x0FC,
+            0x084,
+            0x084,
+            0x104,
+            0x104,
+            0xFFF,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '六',
+        rows: [
+            0x040,
+            0x020,
+            0x000,
+            0xFFF,
+            0x000,
+            0x108,
+            0x104,
+            0x204,
+            0x202,
+            0x402,
+            0x801,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '七',
+        rows: [
+            0x000,
+            0x080,
+            0x080,
+            0x080,
+            0xFFF,
+            0x080,
+            0x080,
+            0x080,
+            0x080,
+            0x081,
+            0x07E,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '八',
+        rows: [
+            0x000,
+            0x090,
+            0x090,
+            0x088,
+            0x088,
+            0x108,
+            0x104,
+            0x204,
+            0x202,
+            0x401,
+            0x800,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '王',
+        rows: [
+            0x000,
+            0x7FE,
+            0x040,
+            0x040,
+            0x040,
+            0x3FC,
+            0x040,
+            0x040,
+            0x040,
+            0x040,
+            0xFFF,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '后',
+        rows: [
+            0x006,
+            0x3F8,
+            0x200,
+            0x200,
+            0x3FF,
+            0x200,
+            0x27E,
+            0x442,
+            0x442,
+            0x842,
+            0x87E,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '车',
+        rows: [
+            0x040,
+            0x7FE,
+            0x080,
+            0x120,
+            0x220,
+            0x7FC,
+            0x020,
+            0x020,
+            0xFFF,
+            0x020,
+            0x020,
+            0x020,
+        ],
+    },
+    CjkGlyph {
+        ch: '象',
+        rows: [
+            0x100,
+            0x3F0,
+            0x420,
+            0x7F8,
+            0x488,
+            0x7F8,
+            0x188,
+            0x290,
+            0x5D0,
+            0x2A8,
+            0x484,
+            0x182,
+        ],
+    },
+    CjkGlyph {
+        ch: '马',
+        rows: [
+            0x000,
+            0x7F0,
+            0x010,
+            0x220,
+            0x220,
+            0x3FE,
+            0x002,
+            0x002,
+            0x7F2,
+            0x002,
+            0x00E,
+            0x000,
+        ],
+    },
+    CjkGlyph {
+        ch: '兵',
+        rows: [
+            0x018,
+            0x1E0,
+            0x100,
+            0x1FC,
+            0x108,
+            0x108,
+            0xFFF,
+            0x000,
+            0x108,
+            0x204,
+            0x402,
+            0x000,
+        ],
+    },
+];
@@ -0,0 +1,27 @@
+// @generated by `cargo xtask assets` from assets/ (the PNGs and assets/sprites.txt) — edit that instead.
+pub const SPRITE_W: usize = 16;
+pub const SPRITE_H: usize = 20;
+const PAWN: Sprite = Sprite {
+    body: &[0x000007C0, 0x00000FE0, 0x00000FE0, 0x00000FE0, 0x000007C0, 0x000007C0, 0x00000FE0, 0x00001FF0, 0x00003FF8, 0x00007FFC, 0x00007FFC, 0x00007FFC, 0x00003FF8, 0x00001FF0, 0x00000FE0, 0x00001FF0, 0x00003FF8, 0x00003FF8, 0x00007FFC, 0x0000FFFF],
+    detail: None,
+};
+const KNIGHT: Sprite = Sprite {
+    body: &[0x000007F0, 0x00000FF8, 0x00001FFC, 0x00003FFC, 0x00007FF8, 0x0000FFE0, 0x0000FFC0, 0x0000FF00, 0x0000FE00, 0x0000FC00, 0x0000FC00, 0x0000FE00, 0x0000FF00, 0x0000FF80, 0x00007FC0, 0x00003FF0, 0x00001FF8, 0x00000FFC, 0x000007FE, 0x000003FF],
+    detail: None,
+};
+const BISHOP: Sprite = Sprite {
+    body: &[0x000007C0, 0x00000FE0, 0x00001FF0, 0x00003FF8, 0x00007FFC, 0x00007EFC, 0x00007C7C, 0x00003CF8, 0x00001FF0, 0x00000FE0, 0x00001FF0, 0x00003FF8, 0x00007FFC, 0x00007FFC, 0x00007FFC, 0x00003FF8, 0x00001FF0, 0x00000FE0, 0x00000FE0, 0x00001FF0],
+    detail: None,
+};
+const ROOK: Sprite = Sprite {
+    body: &[0x0000F0F0, 0x0000F0F0, 0x0000FFFF, 0x00007FFE, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00007FFE, 0x00007FFE, 0x0000FFFF, 0x0000FFFF],
+    detail: None,
+};
+const QUEEN: Sprite = Sprite {
+    body: &[0x00008001, 0x00004002, 0x00002004, 0x00000FF8, 0x00001FFC, 0x00003FFE, 0x00003FFE, 0x00003FFE, 0x00001FFC, 0x00000FF8, 0x00000FF8, 0x00000FF8, 0x00001FFC, 0x00003FFE, 0x00003FFE, 0x00003FFE, 0x00001FFC, 0x00001FFC, 0x00003FFE, 0x0000FFFF],
+    detail: None,
+};
+const KING: Sprite = Sprite {
+    body: &[0x00000180, 0x000003C0, 0x000003C0, 0x0000FFFF, 0x000003C0, 0x000003C0, 0x000007E0, 0x00000FF0, 0x00001FF8, 0x00003FFC, 0x00007FFE, 0x00007FFE, 0x00007FFE, 0x00007FFE, 0x00007FFE, 0x00003FFC, 0x00003FFC, 0x00003FFC, 0x00007FFE, 0x0000FFFF],
+    detail: None,
+};
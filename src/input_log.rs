@@ -0,0 +1,111 @@
+//! 输入录制与回放（`input-log` 特性），用于在硬件上确定性地复现界面问题（光标、升变流程等）。
+//!
+//! - 录制：`InputMapper::poll` 产出的每个动作连同距上一动作的毫秒数写入内存环形缓冲，
+//!   并以 `act <间隔ms> <动作名>` 一行回显到串口；串口命令 `actions` 可补打缓冲中的最近记录。
+//! - 回放：构建时设置 `RHESS_REPLAY=<录制文本路径>`，开机后按原间隔的 1/`REPLAY_SPEEDUP`
+//!   依次注入脚本中的动作，期间忽略真实按键；非 `act` 开头的行会被跳过，串口日志可原样使用。
+
+use crate::drivers::serial::SerialPort;
+use crate::interaction::Action;
+use core::fmt::Write;
+use core::str::Lines;
+
+/// 内存中保留的最近动作数。
+const LOG_CAP: usize = 128;
+/// 回放加速倍数。
+const REPLAY_SPEEDUP: u32 = 4;
+
+/// build.rs 写入的回放脚本；未设置 `RHESS_REPLAY` 时为空。
+static SCRIPT: &str = include_str!(concat!(env!("OUT_DIR"), "/replay.txt"));
+
+#[derive(Clone, Copy)]
+struct Entry {
+    /// 距上一动作（或开机）的毫秒数。
+    delay_ms: u32,
+    action: Action,
+}
+
+pub struct InputLog {
+    entries: [Entry; LOG_CAP],
+    /// 下一条写入位置与已记录总数（超过容量后覆盖最旧的）。
+    head: usize,
+    count: usize,
+    last_ms: u32,
+    /// 回放脚本剩余部分与下一条待注入的动作。
+    script: Lines<'static>,
+    pending: Option<Entry>,
+}
+
+impl InputLog {
+    pub fn new(now_ms: u32) -> Self {
+        let mut log = InputLog {
+            entries: [Entry {
+                delay_ms: 0,
+                action: Action::MenuSelect,
+            }; LOG_CAP],
+            head: 0,
+            count: 0,
+            last_ms: now_ms,
+            script: SCRIPT.lines(),
+            pending: None,
+        };
+        log.pending = log.next_script_entry();
+        log
+    }
+
+    /// 回放期间返回到期的脚本动作并丢弃真实按键；否则记录并透传 `live`。
+    pub fn filter(
+        &mut self,
+        serial: &mut SerialPort,
+        now_ms: u32,
+        live: Option<Action>,
+    ) -> Option<Action> {
+        if let Some(entry) = self.pending {
+            if now_ms.wrapping_sub(self.last_ms) < entry.delay_ms / REPLAY_SPEEDUP {
+                return None;
+            }
+            self.last_ms = now_ms;
+            self.pending = self.next_script_entry();
+            return Some(entry.action);
+        }
+        let action = live?;
+        let entry = Entry {
+            delay_ms: now_ms.wrapping_sub(self.last_ms),
+            action,
+        };
+        self.last_ms = now_ms;
+        self.entries[self.head] = entry;
+        self.head = (self.head + 1) % LOG_CAP;
+        self.count += 1;
+        let _ = write_entry(serial, entry);
+        Some(action)
+    }
+
+    /// 按时间顺序把内存中的记录打到串口，格式与实时回显相同。
+    pub fn dump(&self, serial: &mut SerialPort) {
+        let kept = self.count.min(LOG_CAP);
+        let oldest = (self.head + LOG_CAP - kept) % LOG_CAP;
+        for i in 0..kept {
+            let _ = write_entry(serial, self.entries[(oldest + i) % LOG_CAP]);
+        }
+    }
+
+    /// 读取脚本中下一条可解析的 `act` 行。
+    fn next_script_entry(&mut self) -> Option<Entry> {
+        self.script.find_map(parse_line)
+    }
+}
+
+fn write_entry(serial: &mut SerialPort, entry: Entry) -> core::fmt::Result {
+    write!(serial, "act {} {}\r\n", entry.delay_ms, entry.action.name())
+}
+
+fn parse_line(line: &str) -> Option<Entry> {
+    let mut fields = line.split_whitespace();
+    if fields.next()? != "act" {
+        return None;
+    }
+    let delay_ms = fields.next()?.parse().ok()?;
+    let action = Action::from_name(fields.next()?)?;
+    Some(Entry { delay_ms, action })
+}
@@ -1,73 +1,331 @@
+//! 输入映射：按当前界面模式查表，把按键事件（哪个键、短按/长按）翻译成动作。
+//! 各模式的键位集中在下方绑定表中，新增界面只需加一个模式和一张表。
+
 use crate::board;
 use crate::drivers::button::PressKind;
 
+/// 按住不足该时长不显示长按进度，普通短按不会闪出进度条。
+const HOLD_HINT_MS: u32 = 120;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Key {
+    Key1,
+    Key2,
+    Key3,
+    Key4,
+}
+
+/// 输入上下文，决定使用哪张绑定表。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputMode {
+    /// 启动菜单与设置页。
+    Menu,
+    /// 对局中移动光标、选子、提交（马步训练同样使用）。
+    Game,
+    /// 升变选子。
+    Promotion,
+    /// 复盘：逐步回看着法。
+    Review,
+    /// 摆局编辑器。
+    Editor,
+    /// 将死结算画面。
+    Summary,
+    /// 名局演示。
+    Demo,
+    /// 局面损坏后的恢复提示。
+    Recovery,
+    /// 电量低而暂停的电脑对电脑对局。
+    Paused,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Action {
+    /// 菜单上移/下移。
+    MenuUp,
+    MenuDown,
+    /// 菜单确认（设置页中为返回）。
+    MenuSelect,
+    /// 菜单次要操作：启动菜单进入设置，设置页切换取值。
+    MenuAlt,
+    /// 长按 KEY1：开局向导返回上一步。
+    MenuBack,
     MoveLeft,
     MoveRight,
     MoveUp,
     MoveDown,
     ToggleSelect,
     SubmitMove,
+    Promote(PromotionChoice),
+    /// 复盘后退/前进一步，跳到终局，返回结算画面。
+    ReviewBack,
+    ReviewForward,
+    ReviewLatest,
+    ReviewExit,
+    /// 复盘：轮换当前着法的标注（! ? !? ??）。
+    ReviewAnnotate,
+    /// 复盘：在显示的局面上试走，记为变着。
+    ReviewTry,
+    /// 摆局：轮换光标格上的棋子；完成摆局。
+    EditorCycle,
+    EditorDone,
+    /// 演示：播放/暂停；换下一盘名局（前进/后退沿用复盘动作）。
+    DemoPlayPause,
+    DemoNextGame,
+    NewGame,
+    Review,
+    Export,
+    /// 结算画面：结束本局，回到开始菜单。
+    MainMenu,
+    /// 局面损坏后恢复到着法历史中最后一个完好的局面。
+    RestoreCheckpoint,
+    /// 低电量暂停：继续对局；整机休眠，按任意键唤醒。
+    ResumeMatch,
+    Sleep,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// 升变菜单中的位置（KEY1~KEY4），对应的棋子由设置中的 `PromotionOrder` 决定。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PromotionChoice {
-    Rook,
-    Knight,
-    Bishop,
-    Queen,
+    First,
+    Second,
+    Third,
+    Fourth,
 }
 
-pub fn poll_action(board: &mut board::Board) -> Option<Action> {
-    if let Some(press) = board.buttons.key1_press(&mut board.delay) {
-        return Some(match press {
-            PressKind::Short => Action::MoveLeft,
-            PressKind::Long => Action::ToggleSelect,
-        });
+impl PromotionChoice {
+    pub fn index(self) -> usize {
+        match self {
+            PromotionChoice::First => 0,
+            PromotionChoice::Second => 1,
+            PromotionChoice::Third => 2,
+            PromotionChoice::Fourth => 3,
+        }
     }
-    if let Some(press) = board.buttons.key2_press(&mut board.delay) {
-        return Some(match press {
-            PressKind::Short => Action::MoveDown,
-            PressKind::Long => Action::SubmitMove,
-        });
+}
+
+/// 动作的文本名，供输入录制/回放使用；新增动作时需同步补上。
+#[cfg(feature = "input-log")]
+const ACTION_NAMES: &[(Action, &str)] = &[
+    (Action::MenuUp, "MenuUp"),
+    (Action::MenuDown, "MenuDown"),
+    (Action::MenuSelect, "MenuSelect"),
+    (Action::MenuAlt, "MenuAlt"),
+    (Action::MenuBack, "MenuBack"),
+    (Action::MoveLeft, "MoveLeft"),
+    (Action::MoveRight, "MoveRight"),
+    (Action::MoveUp, "MoveUp"),
+    (Action::MoveDown, "MoveDown"),
+    (Action::ToggleSelect, "ToggleSelect"),
+    (Action::SubmitMove, "SubmitMove"),
+    (Action::Promote(PromotionChoice::First), "Promote1"),
+    (Action::Promote(PromotionChoice::Second), "Promote2"),
+    (Action::Promote(PromotionChoice::Third), "Promote3"),
+    (Action::Promote(PromotionChoice::Fourth), "Promote4"),
+    (Action::ReviewBack, "ReviewBack"),
+    (Action::ReviewForward, "ReviewForward"),
+    (Action::ReviewLatest, "ReviewLatest"),
+    (Action::ReviewExit, "ReviewExit"),
+    (Action::ReviewAnnotate, "ReviewAnnotate"),
+    (Action::ReviewTry, "ReviewTry"),
+    (Action::EditorCycle, "EditorCycle"),
+    (Action::EditorDone, "EditorDone"),
+    (Action::DemoPlayPause, "DemoPlayPause"),
+    (Action::DemoNextGame, "DemoNextGame"),
+    (Action::NewGame, "NewGame"),
+    (Action::Review, "Review"),
+    (Action::Export, "Export"),
+    (Action::MainMenu, "MainMenu"),
+    (Action::RestoreCheckpoint, "RestoreCheckpoint"),
+    (Action::ResumeMatch, "ResumeMatch"),
+    (Action::Sleep, "Sleep"),
+];
+
+#[cfg(feature = "input-log")]
+impl Action {
+    pub fn name(self) -> &'static str {
+        ACTION_NAMES
+            .iter()
+            .find(|(action, _)| *action == self)
+            .map_or("?", |(_, name)| name)
     }
-    if let Some(press) = board.buttons.key3_press(&mut board.delay) {
-        return match press {
-            PressKind::Short => Some(Action::MoveUp),
-            PressKind::Long => None, // 未定义长按行为
-        };
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        ACTION_NAMES
+            .iter()
+            .find(|(_, n)| *n == name)
+            .map(|(action, _)| *action)
     }
-    if let Some(press) = board.buttons.key4_press(&mut board.delay) {
-        return match press {
-            PressKind::Short => Some(Action::MoveRight),
-            PressKind::Long => None, // 未定义长按行为
-        };
+}
+
+struct Binding {
+    key: Key,
+    press: PressKind,
+    action: Action,
+}
+
+const fn short(key: Key, action: Action) -> Binding {
+    Binding {
+        key,
+        press: PressKind::Short,
+        action,
     }
-    None
 }
 
-/// 升变选择：短按 KEY1..KEY4 分别对应 车/马/象/后。
-pub fn poll_promotion_choice(board: &mut board::Board) -> Option<PromotionChoice> {
-    if let Some(press) = board.buttons.key1_press(&mut board.delay) {
-        if matches!(press, PressKind::Short) {
-            return Some(PromotionChoice::Rook);
-        }
+const fn long(key: Key, action: Action) -> Binding {
+    Binding {
+        key,
+        press: PressKind::Long,
+        action,
     }
-    if let Some(press) = board.buttons.key2_press(&mut board.delay) {
-        if matches!(press, PressKind::Short) {
-            return Some(PromotionChoice::Knight);
+}
+
+// KEY3/KEY4 按住为自动连发（见 drivers::button），因此长按只绑定在 KEY1/KEY2 上。
+const MENU_BINDINGS: &[Binding] = &[
+    short(Key::Key3, Action::MenuUp),
+    short(Key::Key2, Action::MenuDown),
+    short(Key::Key1, Action::MenuSelect),
+    short(Key::Key4, Action::MenuAlt),
+    long(Key::Key1, Action::MenuBack),
+];
+
+const GAME_BINDINGS: &[Binding] = &[
+    short(Key::Key1, Action::MoveLeft),
+    long(Key::Key1, Action::ToggleSelect),
+    short(Key::Key2, Action::MoveDown),
+    long(Key::Key2, Action::SubmitMove),
+    short(Key::Key3, Action::MoveUp),
+    short(Key::Key4, Action::MoveRight),
+];
+
+const PROMOTION_BINDINGS: &[Binding] = &[
+    short(Key::Key1, Action::Promote(PromotionChoice::First)),
+    short(Key::Key2, Action::Promote(PromotionChoice::Second)),
+    short(Key::Key3, Action::Promote(PromotionChoice::Third)),
+    short(Key::Key4, Action::Promote(PromotionChoice::Fourth)),
+];
+
+const REVIEW_BINDINGS: &[Binding] = &[
+    short(Key::Key1, Action::ReviewBack),
+    long(Key::Key1, Action::ReviewAnnotate),
+    short(Key::Key4, Action::ReviewForward),
+    short(Key::Key3, Action::ReviewLatest),
+    short(Key::Key2, Action::ReviewTry),
+    long(Key::Key2, Action::ReviewExit),
+];
+
+const EDITOR_BINDINGS: &[Binding] = &[
+    short(Key::Key1, Action::MoveLeft),
+    long(Key::Key1, Action::EditorCycle),
+    short(Key::Key2, Action::MoveDown),
+    long(Key::Key2, Action::EditorDone),
+    short(Key::Key3, Action::MoveUp),
+    short(Key::Key4, Action::MoveRight),
+];
+
+const SUMMARY_BINDINGS: &[Binding] = &[
+    short(Key::Key1, Action::NewGame),
+    short(Key::Key2, Action::Review),
+    short(Key::Key3, Action::Export),
+    short(Key::Key4, Action::MainMenu),
+];
+
+const DEMO_BINDINGS: &[Binding] = &[
+    short(Key::Key1, Action::ReviewBack),
+    long(Key::Key1, Action::DemoNextGame),
+    short(Key::Key3, Action::DemoPlayPause),
+    short(Key::Key4, Action::ReviewForward),
+];
+
+const RECOVERY_BINDINGS: &[Binding] = &[
+    short(Key::Key1, Action::RestoreCheckpoint),
+    short(Key::Key4, Action::NewGame),
+];
+
+const PAUSED_BINDINGS: &[Binding] = &[
+    short(Key::Key1, Action::ResumeMatch),
+    short(Key::Key4, Action::Sleep),
+];
+
+impl InputMode {
+    fn bindings(self) -> &'static [Binding] {
+        match self {
+            InputMode::Menu => MENU_BINDINGS,
+            InputMode::Game => GAME_BINDINGS,
+            InputMode::Promotion => PROMOTION_BINDINGS,
+            InputMode::Review => REVIEW_BINDINGS,
+            InputMode::Editor => EDITOR_BINDINGS,
+            InputMode::Summary => SUMMARY_BINDINGS,
+            InputMode::Demo => DEMO_BINDINGS,
+            InputMode::Recovery => RECOVERY_BINDINGS,
+            InputMode::Paused => PAUSED_BINDINGS,
         }
     }
-    if let Some(press) = board.buttons.key3_press(&mut board.delay) {
-        if matches!(press, PressKind::Short) {
-            return Some(PromotionChoice::Bishop);
-        }
+}
+
+/// 带模式的输入映射器：界面切换时 `set_mode`，每帧 `poll` 取动作。
+pub struct InputMapper {
+    mode: InputMode,
+}
+
+impl InputMapper {
+    pub const fn new(mode: InputMode) -> Self {
+        InputMapper { mode }
+    }
+
+    pub fn set_mode(&mut self, mode: InputMode) {
+        self.mode = mode;
+    }
+
+    /// 取本帧动作；启用 `input-log` 时经由录制/回放过滤（见 `input_log`）。
+    pub fn poll(&self, board: &mut board::Board) -> Option<Action> {
+        let action = self.poll_keys(board);
+        #[cfg(feature = "input-log")]
+        let action = {
+            let now_ms = board.timebase.now_ms();
+            board.input_log.filter(&mut board.serial, now_ms, action)
+        };
+        action
     }
-    if let Some(press) = board.buttons.key4_press(&mut board.delay) {
-        if matches!(press, PressKind::Short) {
-            return Some(PromotionChoice::Queen);
+
+    /// 当前模式下有长按绑定、正朝长按阈值按住的键已按住的毫秒数，供界面画长按进度
+    /// （见 `ui::hold_bar`）；多个键同时按住时取最久的一个。
+    pub fn long_press_held_ms(&self, board: &board::Board) -> Option<u32> {
+        let held = board.buttons.held_ms(board.timebase.now_ms());
+        let bindings = self.mode.bindings();
+        [Key::Key1, Key::Key2, Key::Key3, Key::Key4]
+            .into_iter()
+            .zip(held)
+            .filter(|(key, _)| {
+                bindings
+                    .iter()
+                    .any(|b| b.key == *key && b.press == PressKind::Long)
+            })
+            .filter_map(|(_, ms)| ms)
+            .filter(|&ms| ms >= HOLD_HINT_MS)
+            .max()
+    }
+
+    /// 依次检查 KEY1..KEY4，返回第一个在当前模式下有绑定的按键动作；未绑定的按键被丢弃。
+    fn poll_keys(&self, board: &mut board::Board) -> Option<Action> {
+        let bindings = self.mode.bindings();
+        for key in [Key::Key1, Key::Key2, Key::Key3, Key::Key4] {
+            let Some(press) = read_key(board, key) else {
+                continue;
+            };
+            if let Some(binding) = bindings.iter().find(|b| b.key == key && b.press == press) {
+                return Some(binding.action);
+            }
         }
+        None
+    }
+}
+
+fn read_key(board: &mut board::Board, key: Key) -> Option<PressKind> {
+    let now_ms = board.timebase.now_ms();
+    match key {
+        Key::Key1 => board.buttons.key1_press(&board.main, now_ms),
+        Key::Key2 => board.buttons.key2_press(&board.main, now_ms),
+        Key::Key3 => board.buttons.key3_press(&board.main, now_ms),
+        Key::Key4 => board.buttons.key4_press(&board.main, now_ms),
     }
-    None
 }
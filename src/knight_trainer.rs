@@ -0,0 +1,286 @@
+//! 马步训练小游戏：在避开障碍格的前提下，用最少步数把马从起点跳到目标格。
+//! 最优步数由板上 BFS 计算，成绩与之对比；沿用对局的光标输入与棋盘绘制。
+
+use crate::board::Board;
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::settings::Settings;
+use crate::ui::hold_bar::HoldBar;
+use crate::ui::theme::Theme;
+use crate::ui::{chessboard, fmt, pieces, text};
+use rhess_core::{Color, PieceKind};
+
+/// 每局障碍格数量。
+const OBSTACLES: usize = 8;
+const UNREACHED: u8 = u8::MAX;
+const KNIGHT_JUMPS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+struct Puzzle {
+    knight: u8,
+    target: u8,
+    blocked: [bool; 64],
+    optimal: u8,
+    moves: u8,
+}
+
+struct Trainer {
+    puzzle: Puzzle,
+    cursor: (u8, u8),
+    rng: u32,
+    theme: &'static Theme,
+    solved: u32,
+    perfect: u32,
+}
+
+/// 进入训练模式（不返回）：KEY1~KEY4 移动光标，KEY2 长按起跳，KEY1 长按换题。
+pub fn run(board: &mut Board, settings: Settings) -> ! {
+    // xorshift32 的种子不能为 0。
+    let seed = board.entropy.next_u32(&board.timebase) | 1;
+    let mut trainer = Trainer {
+        puzzle: Puzzle {
+            knight: 0,
+            target: 0,
+            blocked: [false; 64],
+            optimal: 0,
+            moves: 0,
+        },
+        cursor: (0, 0),
+        rng: seed,
+        theme: settings.theme(),
+        solved: 0,
+        perfect: 0,
+    };
+    trainer.new_puzzle();
+    board.lcd.clear(trainer.theme.ui_bg);
+    trainer.render(board);
+    let input = InputMapper::new(InputMode::Game);
+    let mut hold_bar = HoldBar::sidebar(&board.lcd);
+
+    loop {
+        let held = input.long_press_held_ms(board);
+        hold_bar.update(&mut board.lcd, trainer.theme, held);
+        if let Some(action) = input.poll(board) {
+            let old = trainer.cursor;
+            match action {
+                Action::MoveLeft => trainer.cursor.0 = trainer.cursor.0.saturating_sub(1),
+                Action::MoveRight => trainer.cursor.0 = (trainer.cursor.0 + 1).min(7),
+                Action::MoveUp => trainer.cursor.1 = (trainer.cursor.1 + 1).min(7),
+                Action::MoveDown => trainer.cursor.1 = trainer.cursor.1.saturating_sub(1),
+                Action::ToggleSelect => {
+                    trainer.new_puzzle();
+                    trainer.render(board);
+                    continue;
+                }
+                Action::SubmitMove => {
+                    if trainer.jump() {
+                        trainer.render(board);
+                    }
+                    continue;
+                }
+                _ => continue,
+            }
+            trainer.render_square(board, old.0, old.1);
+            trainer.render_square(board, trainer.cursor.0, trainer.cursor.1);
+        }
+        board.delay.ms(10);
+    }
+}
+
+impl Trainer {
+    fn next_random(&mut self) -> u32 {
+        // xorshift32
+        let mut x = self.rng;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng = x;
+        x
+    }
+
+    fn random_square(&mut self) -> u8 {
+        (self.next_random() % 64) as u8
+    }
+
+    /// 随机起点/目标/障碍，直到目标可达且至少需要两步。
+    fn new_puzzle(&mut self) {
+        loop {
+            let knight = self.random_square();
+            let target = self.random_square();
+            let mut blocked = [false; 64];
+            let mut placed = 0;
+            while placed < OBSTACLES {
+                let sq = self.random_square();
+                if sq != knight && sq != target && !blocked[sq as usize] {
+                    blocked[sq as usize] = true;
+                    placed += 1;
+                }
+            }
+            let optimal = shortest_path(knight, target, &blocked);
+            if optimal != UNREACHED && optimal >= 2 {
+                self.puzzle = Puzzle {
+                    knight,
+                    target,
+                    blocked,
+                    optimal,
+                    moves: 0,
+                };
+                self.cursor = (knight % 8, knight / 8);
+                return;
+            }
+        }
+    }
+
+    /// 马跳到光标格；非马步、障碍格或已完成时忽略。
+    fn jump(&mut self) -> bool {
+        let p = &mut self.puzzle;
+        let dst = self.cursor.1 * 8 + self.cursor.0;
+        if p.knight == p.target
+            || p.blocked[dst as usize]
+            || !knight_neighbors(p.knight).any(|s| s == dst)
+        {
+            return false;
+        }
+        p.knight = dst;
+        p.moves = p.moves.saturating_add(1);
+        if p.knight == p.target {
+            self.solved += 1;
+            if p.moves == p.optimal {
+                self.perfect += 1;
+            }
+        }
+        true
+    }
+
+    fn render(&self, board: &mut Board) {
+        for rank in 0..8 {
+            for file in 0..8 {
+                self.render_square(board, file, rank);
+            }
+        }
+        self.render_side_info(board);
+    }
+
+    fn render_square(&self, board: &mut Board, file: u8, rank: u8) {
+        let idx = rank * 8 + file;
+        let p = &self.puzzle;
+        let theme = self.theme;
+        let color = if self.cursor == (file, rank) {
+            theme.cursor
+        } else if p.blocked[idx as usize] {
+            theme.ui_alert
+        } else if idx == p.target {
+            theme.promotion
+        } else {
+            chessboard::square_color(theme, file, rank)
+        };
+        chessboard::draw_square_with_color(&mut board.lcd, file, rank, color);
+        if idx == p.knight {
+            pieces::draw_piece_on_square(
+                &mut board.lcd,
+                theme,
+                PieceKind::Knight,
+                Color::White,
+                file,
+                rank,
+            );
+        }
+    }
+
+    fn render_side_info(&self, board: &mut Board) {
+        let (fg, bg) = (self.theme.ui_fg, self.theme.ui_bg);
+        let start_x = chessboard::BOARD_SIZE + 4;
+        let width = board.lcd.width.saturating_sub(start_x);
+        board.lcd.fill_rect(start_x, 0, width, board.lcd.height, bg);
+
+        let x = start_x + 2;
+        let p = &self.puzzle;
+        text::draw_text_scaled(&mut board.lcd, "Knight path", x, 6, fg, Some(bg), 2);
+        let mut bufs = [[0u8; 10]; 4];
+        let [moves_buf, best_buf, solved_buf, perfect_buf] = &mut bufs;
+        let mut rate_buf = [0u8; 4];
+        let rows = [
+            ("Moves:", fmt::u32_to_str(p.moves as u32, moves_buf)),
+            ("Best:", fmt::u32_to_str(p.optimal as u32, best_buf)),
+            ("Solved:", fmt::u32_to_str(self.solved, solved_buf)),
+            ("Perfect:", fmt::u32_to_str(self.perfect, perfect_buf)),
+            // 完美解占已解题的比例。
+            (
+                "Rate:",
+                fmt::format_percent(self.perfect, self.solved, &mut rate_buf),
+            ),
+        ];
+        for (i, (label, value)) in rows.iter().enumerate() {
+            let y = 36 + i as u16 * 20;
+            text::draw_text_scaled(&mut board.lcd, label, x, y, fg, Some(bg), 2);
+            text::draw_text_scaled(&mut board.lcd, value, x + 108, y, fg, Some(bg), 2);
+        }
+
+        if p.knight == p.target {
+            let verdict = if p.moves == p.optimal {
+                "Optimal!"
+            } else {
+                "Solved"
+            };
+            text::draw_text_scaled(
+                &mut board.lcd,
+                verdict,
+                x,
+                140,
+                self.theme.cursor,
+                Some(bg),
+                2,
+            );
+        }
+
+        text::draw_text_scaled(&mut board.lcd, "KEY2 hold: jump", x, 230, fg, Some(bg), 1);
+        text::draw_text_scaled(
+            &mut board.lcd,
+            "KEY1 hold: new puzzle",
+            x,
+            246,
+            fg,
+            Some(bg),
+            1,
+        );
+    }
+}
+
+fn knight_neighbors(sq: u8) -> impl Iterator<Item = u8> {
+    let (file, rank) = ((sq % 8) as i8, (sq / 8) as i8);
+    KNIGHT_JUMPS.iter().filter_map(move |(df, dr)| {
+        let (f, r) = (file + df, rank + dr);
+        ((0..8).contains(&f) && (0..8).contains(&r)).then_some((r * 8 + f) as u8)
+    })
+}
+
+/// 广度优先搜索最少马步数，不可达返回 `UNREACHED`。
+fn shortest_path(from: u8, to: u8, blocked: &[bool; 64]) -> u8 {
+    let mut dist = [UNREACHED; 64];
+    let mut queue = [0u8; 64];
+    let (mut head, mut tail) = (0, 1);
+    dist[from as usize] = 0;
+    queue[0] = from;
+    while head < tail {
+        let sq = queue[head];
+        head += 1;
+        if sq == to {
+            return dist[sq as usize];
+        }
+        for next in knight_neighbors(sq) {
+            if !blocked[next as usize] && dist[next as usize] == UNREACHED {
+                dist[next as usize] = dist[sq as usize] + 1;
+                queue[tail] = next;
+                tail += 1;
+            }
+        }
+    }
+    UNREACHED
+}
@@ -0,0 +1,112 @@
+//! 控制台行记录：USART1 上收到的命令行与发出的文字按行存入定长环形缓冲，
+//! 供屏幕上的控制台视图（见 `ui::console_view`）显示最近几行，不接电脑也能看到诊断输出。
+//!
+//! 最多保留 `LINES` 行，每行最多 `LINE_LEN` 字节，超长的行折到下一行；`\r` 忽略，
+//! 非 ASCII 可打印字节记为 `?`。最后一行可能尚未结束，随后续输出继续增长。
+
+/// 保留的行数（含正在写的一行）。
+pub const LINES: usize = 8;
+/// 单行字节数，与侧栏一行小字（scale 1）能放下的字符数相同。
+pub const LINE_LEN: usize = 32;
+
+#[derive(Clone, Copy)]
+struct Line {
+    text: [u8; LINE_LEN],
+    len: usize,
+}
+
+impl Line {
+    const EMPTY: Line = Line {
+        text: [0; LINE_LEN],
+        len: 0,
+    };
+}
+
+pub struct LineLog {
+    lines: [Line; LINES],
+    /// 正在写的一行（最新一行）的位置。
+    head: usize,
+    /// 已开始的行数，不超过 `LINES`。
+    count: usize,
+    /// 上一个字节是换行：下一个字节另起一行。
+    line_done: bool,
+    /// 每次写入加一，视图据此跳过没有新输出的帧。
+    revision: u32,
+}
+
+impl LineLog {
+    pub const fn new() -> Self {
+        LineLog {
+            lines: [Line::EMPTY; LINES],
+            head: 0,
+            count: 0,
+            line_done: true,
+            revision: 0,
+        }
+    }
+
+    /// 追加输出字节，`\n` 结束一行。
+    pub fn write(&mut self, bytes: &[u8]) {
+        self.revision = self.revision.wrapping_add(1);
+        for &byte in bytes {
+            match byte {
+                b'\r' => {}
+                b'\n' => {
+                    if self.line_done {
+                        self.start_line();
+                    }
+                    self.line_done = true;
+                }
+                _ => {
+                    if self.line_done || self.lines[self.head].len == LINE_LEN {
+                        self.start_line();
+                    }
+                    let line = &mut self.lines[self.head];
+                    line.text[line.len] = if byte.is_ascii_graphic() || byte == b' ' {
+                        byte
+                    } else {
+                        b'?'
+                    };
+                    line.len += 1;
+                }
+            }
+        }
+    }
+
+    /// 追加一整行，如带提示符的输入命令；未结束的输出行先行结束。
+    pub fn push_line(&mut self, parts: &[&[u8]]) {
+        self.line_done = true;
+        for part in parts {
+            self.write(part);
+        }
+        self.write(b"\n");
+    }
+
+    pub fn revision(&self) -> u32 {
+        self.revision
+    }
+
+    /// 自旧到新的第 `row` 行（0..`LINES`）；行数不足时靠前的行为空。
+    pub fn row(&self, row: usize) -> &str {
+        if row < LINES - self.count {
+            return "";
+        }
+        let line = &self.lines[(self.head + 1 + row) % LINES];
+        core::str::from_utf8(&line.text[..line.len]).unwrap_or("")
+    }
+
+    fn start_line(&mut self) {
+        if self.count > 0 {
+            self.head = (self.head + 1) % LINES;
+        }
+        self.count = (self.count + 1).min(LINES);
+        self.lines[self.head] = Line::EMPTY;
+        self.line_done = false;
+    }
+}
+
+impl Default for LineLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -5,13 +5,34 @@ use panic_halt as _;
 
 use stm32f4xx_hal as hal;
 
+mod ai_watchdog;
+mod attack_query;
 mod board;
-mod chess_core;
+mod board_editor;
+mod board_profile;
+mod clock;
+mod console;
+mod demo;
 mod drivers;
 mod game;
+#[cfg(feature = "input-log")]
+mod input_log;
 mod interaction;
+mod knight_trainer;
+mod line_log;
+mod new_game;
+mod pack_manager;
+mod packs;
+mod recent_games;
+mod search_bench;
+mod settings;
+mod simul;
+mod stack_guard;
 mod start_menu;
 mod start_menu_crab;
+mod stats;
+mod status;
+mod sync;
 mod ui;
 
 use cortex_m_rt::entry;
@@ -20,18 +41,73 @@ use rtt_target::{rprintln, rtt_init_print};
 
 #[entry]
 fn main() -> ! {
+    // 先于一切初始化自检栈底守卫区并填充空闲栈区，调试叠加层据此估计栈的剩余空间，
+    // 运行中据守卫区察觉栈溢出。
+    let ram_ok = drivers::stack::self_test();
+    drivers::stack::paint();
     rtt_init_print!();
 
     let mut board = board::Board::new();
     board.leds.all_off();
     rprintln!("board init ok");
     board.lcd.clear(0x0000); // 初始清屏为黑
-    let mode = start_menu::select_mode(&mut board);
-    let (ai_sides, human_focus) = match mode {
-        start_menu::Mode::HumanVsHuman => ([false, false], Some(chess_core::Color::White)),
-        start_menu::Mode::HumanVsComputer => ([false, true], Some(chess_core::Color::White)),
-        start_menu::Mode::ComputerVsHuman => ([true, false], Some(chess_core::Color::Black)),
-        start_menu::Mode::ComputerVsComputer => ([true, true], None),
-    };
-    game::Game::run(&mut board, ai_sides, human_focus);
+    stack_guard::report_self_test(&mut board, ram_ok);
+    let mut settings = settings::Settings::default();
+    // AI 思考中途复位留下的检查点：跳过菜单直接续局（设置为默认值），结束后再进菜单。
+    if let Some(checkpoint) = game::checkpoint::load(&board.backup) {
+        let mut rtt_log = game::observer::RttLog;
+        let mut observers = game::observer::Observers::new();
+        let _ = observers.register(&mut rtt_log);
+        game::Game::resume(&mut board, checkpoint, settings, observers);
+    }
+    // 每局结束后在结算画面选择主菜单回到这里。开局向导、最近对局页可退回菜单；向导确认后
+    // 带着选择出来，选中存档时以复盘方式打开。
+    loop {
+        let (choice, saved) = loop {
+            match start_menu::select_mode(&mut board, &mut settings) {
+                start_menu::Mode::NewGame => {
+                    if let Some(choice) = new_game::run(&mut board) {
+                        break (choice, None);
+                    }
+                }
+                start_menu::Mode::RecentGames => {
+                    if let Some(saved) = recent_games::run(&mut board, settings) {
+                        // 复盘存档不用向导的选择，取默认值只为填上位置。
+                        break (new_game::NewGame::default(), Some(saved));
+                    }
+                }
+                start_menu::Mode::Simul => simul::run(&mut board, settings),
+                start_menu::Mode::Stats => stats::run(&mut board, settings),
+                start_menu::Mode::KnightTrainer => knight_trainer::run(&mut board, settings),
+                start_menu::Mode::FamousGames => demo::run(&mut board, settings),
+                start_menu::Mode::SquareAttacks => attack_query::run(&mut board, settings),
+                start_menu::Mode::Packs => pack_manager::run(&mut board, &mut settings),
+            }
+        };
+        let initial = match choice.variant {
+            new_game::Variant::Standard => rhess_core::GameState::start_position(),
+            new_game::Variant::CustomSetup => board_editor::run(&mut board, settings),
+        };
+        let mut rtt_log = game::observer::RttLog;
+        let mut observers = game::observer::Observers::new();
+        let _ = observers.register(&mut rtt_log);
+        // 赛事广播：设置中开启时把第二串口交给 JSON 广播观察者，对局结束后收回。
+        let mut broadcast = settings
+            .broadcast
+            .baud()
+            .zip(board.aux_serial.take())
+            .map(|(baud, port)| game::broadcast::JsonBroadcast::new(port, baud, &initial));
+        if let Some(broadcast) = broadcast.as_mut() {
+            let _ = observers.register(broadcast);
+        }
+        match saved {
+            Some((saved, history)) => {
+                game::Game::review_saved(&mut board, &saved, history, settings, observers)
+            }
+            None => game::Game::run(&mut board, initial, choice.players(), settings, observers),
+        }
+        if let Some(broadcast) = broadcast {
+            board.aux_serial = Some(broadcast.into_port());
+        }
+    }
 }
@@ -0,0 +1,497 @@
+//! 开局向导：对手 → 强度、风格与变体 → 时钟三步，最后一页汇总确认后开局。
+//! 第一步从列表中选对手（KEY1 选定）；之后是选项页，KEY3/KEY2 选条目、KEY4 切换取值、
+//! KEY1 下一步。长按 KEY1 返回上一步，在第一步则回到主菜单。双方都由人执子时没有 AI 可设，
+//! 跳过强度一栏，时钟一步只选时限；电脑对电脑时时钟一步另可打开快速对弈。
+
+use crate::board::Board;
+use crate::clock::TimeControl;
+use crate::game::Players;
+use crate::interaction::{InputMapper, InputMode};
+use crate::ui::fmt;
+use crate::ui::menu::{self, BG, ENTRY_SPACING, MenuEvent};
+use rhess_core::ai::{AiConfig, Variety};
+use rhess_core::profile::EvalProfile;
+use rhess_core::rating;
+
+const X: u16 = 8;
+const STEP_Y: u16 = 36;
+const ROWS_Y: u16 = 60;
+const VALUE_DX: u16 = 160;
+/// 按键提示行：按 480x272 布局排在底部，大屏上同样画在左上区域内。
+const HINT_Y: u16 = 254;
+/// 一页最多的条目数（电脑对电脑的汇总页）。
+const MAX_ROWS: usize = 8;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Opponent {
+    HumanVsHuman,
+    HumanVsComputer,
+    ComputerVsHuman,
+    ComputerVsComputer,
+}
+
+const OPPONENTS: [Opponent; 4] = [
+    Opponent::HumanVsHuman,
+    Opponent::HumanVsComputer,
+    Opponent::ComputerVsHuman,
+    Opponent::ComputerVsComputer,
+];
+
+impl Opponent {
+    fn label(self) -> &'static str {
+        match self {
+            Opponent::HumanVsHuman => "Human vs Human",
+            Opponent::HumanVsComputer => "Human vs Computer",
+            Opponent::ComputerVsHuman => "Computer vs Human",
+            Opponent::ComputerVsComputer => "Computer vs Computer",
+        }
+    }
+
+    fn ai_sides(self) -> [bool; 2] {
+        match self {
+            Opponent::HumanVsHuman => [false, false],
+            Opponent::HumanVsComputer => [false, true],
+            Opponent::ComputerVsHuman => [true, false],
+            Opponent::ComputerVsComputer => [true, true],
+        }
+    }
+}
+
+/// Casual 在与最佳着相差不超过这么多厘兵的几步中随机选一步，免得弱档每局走法都一样。
+pub const CASUAL_VARIETY_MARGIN: i32 = 30;
+
+/// AI 强度：搜索深度与节点预算的三档预设，页面上同时显示对应的名义等级分。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Strength {
+    Casual,
+    Club,
+    Strong,
+}
+
+impl Strength {
+    fn label(self) -> &'static str {
+        match self {
+            Strength::Casual => "Casual",
+            Strength::Club => "Club",
+            Strength::Strong => "Strong",
+        }
+    }
+
+    fn next(self) -> Strength {
+        match self {
+            Strength::Casual => Strength::Club,
+            Strength::Club => Strength::Strong,
+            Strength::Strong => Strength::Casual,
+        }
+    }
+
+    /// Club 即内置默认设置；Strong 在板上每步约需数秒。Casual 另在相近的几步中随机选择，
+    /// 随机种子在开局时换成本局种子。
+    pub fn config(self) -> AiConfig {
+        let (max_depth, node_limit) = match self {
+            Strength::Casual => (3, 5_000),
+            Strength::Club => return AiConfig::default(),
+            Strength::Strong => (8, 60_000),
+        };
+        let variety = (self == Strength::Casual).then_some(Variety {
+            margin: CASUAL_VARIETY_MARGIN,
+            seed: 0,
+        });
+        AiConfig {
+            max_depth,
+            node_limit: Some(node_limit),
+            variety,
+            ..AiConfig::default()
+        }
+    }
+}
+
+/// 评估风格：依次切换 `EvalProfile::PRESETS`。
+fn next_style(profile: EvalProfile) -> EvalProfile {
+    let presets = EvalProfile::PRESETS;
+    let index = presets.iter().position(|&p| p == profile).unwrap_or(0);
+    presets[(index + 1) % presets.len()]
+}
+
+/// 起始局面：标准开局，或先进入摆局编辑器。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    Standard,
+    CustomSetup,
+}
+
+impl Variant {
+    fn label(self) -> &'static str {
+        match self {
+            Variant::Standard => "Standard",
+            Variant::CustomSetup => "Custom setup",
+        }
+    }
+
+    fn next(self) -> Variant {
+        match self {
+            Variant::Standard => Variant::CustomSetup,
+            Variant::CustomSetup => Variant::Standard,
+        }
+    }
+}
+
+/// AI 落子前的最短等待；限时对局中这段等待同样计入 AI 一方的用时。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Pace {
+    Instant,
+    Normal,
+    Relaxed,
+}
+
+impl Pace {
+    fn label(self) -> &'static str {
+        match self {
+            Pace::Instant => "None",
+            Pace::Normal => "1 s",
+            Pace::Relaxed => "3 s",
+        }
+    }
+
+    fn next(self) -> Pace {
+        match self {
+            Pace::Instant => Pace::Normal,
+            Pace::Normal => Pace::Relaxed,
+            Pace::Relaxed => Pace::Instant,
+        }
+    }
+
+    fn delay_ms(self) -> u32 {
+        match self {
+            Pace::Instant => 0,
+            Pace::Normal => 1_000,
+            Pace::Relaxed => 3_000,
+        }
+    }
+}
+
+/// 电脑对电脑的快速对弈：不等待、每隔几个半回合才重画一次棋盘，一局结束即经 USART1
+/// 导出并接着下一局，按任意键回到正常速度（见 `Game::stop_playout`）。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Playout {
+    Off,
+    EachPly,
+    Every10,
+    Every50,
+}
+
+impl Playout {
+    fn label(self) -> &'static str {
+        match self {
+            Playout::Off => "Off",
+            Playout::EachPly => "Each ply",
+            Playout::Every10 => "10 plies",
+            Playout::Every50 => "50 plies",
+        }
+    }
+
+    fn next(self) -> Playout {
+        match self {
+            Playout::Off => Playout::EachPly,
+            Playout::EachPly => Playout::Every10,
+            Playout::Every10 => Playout::Every50,
+            Playout::Every50 => Playout::Off,
+        }
+    }
+
+    /// 重画间隔（半回合）；关闭时为 None。
+    fn every(self) -> Option<u16> {
+        match self {
+            Playout::Off => None,
+            Playout::EachPly => Some(1),
+            Playout::Every10 => Some(10),
+            Playout::Every50 => Some(50),
+        }
+    }
+}
+
+/// 向导的全部选择。
+#[derive(Clone, Copy)]
+pub struct NewGame {
+    pub opponent: Opponent,
+    pub strength: Strength,
+    /// 白/黑 AI 的评估风格，只有 AI 执子的一方生效。
+    pub styles: [EvalProfile; 2],
+    pub variant: Variant,
+    pub time_control: TimeControl,
+    pub pace: Pace,
+    /// 只在电脑对电脑时可选。
+    pub playout: Playout,
+}
+
+/// 向导打开时的初始选择：执白对电脑，默认强度，不限时。
+impl Default for NewGame {
+    fn default() -> Self {
+        NewGame {
+            opponent: Opponent::HumanVsComputer,
+            strength: Strength::Club,
+            styles: [EvalProfile::BALANCED; 2],
+            variant: Variant::Standard,
+            time_control: TimeControl::Untimed,
+            pace: Pace::Normal,
+            playout: Playout::Off,
+        }
+    }
+}
+
+impl NewGame {
+    pub fn players(&self) -> Players {
+        Players {
+            ai_sides: self.opponent.ai_sides(),
+            ai: self.strength.config(),
+            profiles: self.styles,
+            ai_delay_ms: self.pace.delay_ms(),
+            time_control: self.time_control,
+            playout: self.playout.every().filter(|_| self.is_playout_possible()),
+        }
+    }
+
+    fn has_ai(&self) -> bool {
+        self.opponent != Opponent::HumanVsHuman
+    }
+
+    fn is_playout_possible(&self) -> bool {
+        self.opponent == Opponent::ComputerVsComputer
+    }
+
+    /// 强度页的条目：强度、每个 AI 一方的风格、变体。
+    fn strength_entries(&self) -> ([Entry; 4], usize) {
+        let mut entries = [Entry::Variant; 4];
+        let mut len = 0;
+        if self.has_ai() {
+            entries[len] = Entry::Strength;
+            len += 1;
+        }
+        for (side, &ai) in self.opponent.ai_sides().iter().enumerate() {
+            if ai {
+                entries[len] = Entry::Style(side);
+                len += 1;
+            }
+        }
+        entries[len] = Entry::Variant;
+        (entries, len + 1)
+    }
+
+    fn entry_row(&self, entry: Entry) -> (&'static str, &'static str) {
+        match entry {
+            Entry::Strength => ("Strength", self.strength.label()),
+            // 只有一方是 AI 时不必区分颜色。
+            Entry::Style(side) if self.opponent != Opponent::ComputerVsComputer => {
+                ("AI style", self.styles[side].name)
+            }
+            Entry::Style(0) => ("White style", self.styles[0].name),
+            Entry::Style(_) => ("Black style", self.styles[1].name),
+            Entry::Variant => ("Variant", self.variant.label()),
+        }
+    }
+
+    /// 某一步的条目（名称, 取值）及条目数。
+    fn rows(&self, step: Step) -> ([(&'static str, &'static str); MAX_ROWS], usize) {
+        let mut rows = [("", ""); MAX_ROWS];
+        let mut len = 0;
+        let mut push = |row| {
+            rows[len] = row;
+            len += 1;
+        };
+        let (entries, entry_count) = self.strength_entries();
+        match step {
+            Step::Opponent => OPPONENTS.iter().for_each(|o| push((o.label(), ""))),
+            Step::Strength => entries[..entry_count]
+                .iter()
+                .for_each(|&e| push(self.entry_row(e))),
+            Step::Clock => {
+                push(("Time control", self.time_control.label()));
+                if self.has_ai() {
+                    push(("AI delay", self.pace.label()));
+                }
+                if self.is_playout_possible() {
+                    push(("Fast playout", self.playout.label()));
+                }
+            }
+            Step::Summary => {
+                push(("Opponent", short_opponent(self.opponent)));
+                entries[..entry_count]
+                    .iter()
+                    .for_each(|&e| push(self.entry_row(e)));
+                push(("Time control", self.time_control.label()));
+                if self.has_ai() {
+                    push(("AI delay", self.pace.label()));
+                }
+                if self.is_playout_possible() {
+                    push(("Fast playout", self.playout.label()));
+                }
+            }
+        }
+        (rows, len)
+    }
+
+    /// 选项页上 KEY4 切换第 `row` 条的取值，条目顺序同 `rows`。
+    fn change(&mut self, step: Step, row: usize) {
+        match step {
+            Step::Strength => match self.strength_entries().0[row] {
+                Entry::Strength => self.strength = self.strength.next(),
+                Entry::Style(side) => self.styles[side] = next_style(self.styles[side]),
+                Entry::Variant => self.variant = self.variant.next(),
+            },
+            Step::Clock if row == 0 => self.time_control = self.time_control.next(),
+            Step::Clock if row == 1 => self.pace = self.pace.next(),
+            Step::Clock => self.playout = self.playout.next(),
+            Step::Opponent | Step::Summary => {}
+        }
+    }
+}
+
+/// 汇总页的取值列较窄，用缩写。
+fn short_opponent(opponent: Opponent) -> &'static str {
+    match opponent {
+        Opponent::HumanVsHuman => "HvH",
+        Opponent::HumanVsComputer => "You play White",
+        Opponent::ComputerVsHuman => "You play Black",
+        Opponent::ComputerVsComputer => "CvC",
+    }
+}
+
+/// 强度页上的一个条目；`Style` 带着所属一方的序号（0 白 1 黑）。
+#[derive(Clone, Copy)]
+enum Entry {
+    Strength,
+    Style(usize),
+    Variant,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Opponent,
+    Strength,
+    Clock,
+    Summary,
+}
+
+impl Step {
+    fn next(self) -> Option<Step> {
+        match self {
+            Step::Opponent => Some(Step::Strength),
+            Step::Strength => Some(Step::Clock),
+            Step::Clock => Some(Step::Summary),
+            Step::Summary => None,
+        }
+    }
+
+    fn prev(self) -> Option<Step> {
+        match self {
+            Step::Opponent => None,
+            Step::Strength => Some(Step::Opponent),
+            Step::Clock => Some(Step::Strength),
+            Step::Summary => Some(Step::Clock),
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Step::Opponent => "Step 1/3  Opponent",
+            Step::Strength => "Step 2/3  Strength, style & variant",
+            Step::Clock => "Step 3/3  Clock",
+            Step::Summary => "Ready to start",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            Step::Opponent => "KEY3 Up  KEY2 Down  KEY1 Next  hold KEY1 Menu",
+            Step::Summary => "KEY1 Start  hold KEY1 Back",
+            _ => "KEY3 Up  KEY2 Down  KEY4 Change  KEY1 Next  hold KEY1 Back",
+        }
+    }
+}
+
+/// 运行向导；在第一步返回时为 None（回到主菜单）。
+pub fn run(board: &mut Board) -> Option<NewGame> {
+    let input = InputMapper::new(InputMode::Menu);
+    let mut choice = NewGame::default();
+    let mut step = Step::Opponent;
+    let mut selected = opponent_index(choice.opponent);
+    let mut dirty = true;
+    loop {
+        if dirty {
+            render(board, &choice, step, selected);
+            dirty = false;
+        }
+        let (_, len) = choice.rows(step);
+        match menu::navigate(input.poll(board), &mut selected, len) {
+            Some(MenuEvent::Moved) => dirty = true,
+            Some(MenuEvent::Alt) => {
+                choice.change(step, selected);
+                dirty = true;
+            }
+            Some(MenuEvent::Select) => {
+                if step == Step::Opponent {
+                    choice.opponent = OPPONENTS[selected];
+                }
+                let Some(next) = step.next() else {
+                    return Some(choice);
+                };
+                step = next;
+                selected = 0;
+                dirty = true;
+            }
+            Some(MenuEvent::Back) => {
+                step = step.prev()?;
+                selected = match step {
+                    Step::Opponent => opponent_index(choice.opponent),
+                    _ => 0,
+                };
+                dirty = true;
+            }
+            None => {}
+        }
+        board.delay.ms(50);
+    }
+}
+
+fn opponent_index(opponent: Opponent) -> usize {
+    OPPONENTS.iter().position(|&o| o == opponent).unwrap_or(0)
+}
+
+fn render(board: &mut Board, choice: &NewGame, step: Step, selected: usize) {
+    board.lcd.clear(BG);
+    menu::draw_title(&mut board.lcd, "New Game", X, 6);
+    menu::draw_hint(&mut board.lcd, step.title(), X, STEP_Y);
+    let (rows, len) = choice.rows(step);
+    let selected = if step == Step::Summary {
+        usize::MAX
+    } else {
+        selected
+    };
+    menu::draw_rows(&mut board.lcd, &rows[..len], X, ROWS_Y, VALUE_DX, selected);
+
+    // 条目下方的说明行：强度页给出名义等级分，时钟页说明时限的含义。
+    let note_y = ROWS_Y + len as u16 * ENTRY_SPACING + 6;
+    match step {
+        Step::Strength if choice.has_ai() => {
+            let mut buf = [0u8; 10];
+            let elo = rating::engine_rating(&choice.strength.config()) as u32;
+            menu::draw_hint(&mut board.lcd, "Engine rating", X + 12, note_y);
+            menu::draw_hint(
+                &mut board.lcd,
+                fmt::u32_to_str(elo, &mut buf),
+                X + 12 + 14 * 6,
+                note_y,
+            );
+        }
+        Step::Clock => {
+            let note = match choice.time_control {
+                _ if selected == 2 => "Redraw every N plies, games run back to back",
+                TimeControl::Untimed => "Untimed: the game clock counts up",
+                _ => "Minutes + seconds per move; flag loses",
+            };
+            menu::draw_hint(&mut board.lcd, note, X + 12, note_y);
+        }
+        _ => {}
+    }
+    menu::draw_hint(&mut board.lcd, step.hint(), X, HINT_Y);
+}
@@ -0,0 +1,209 @@
+//! 外观包管理页：列出 Flash 中已安装的外观包（格式见 `packs`），并经 USART1 接收新包。
+//!
+//! 上传命令（每行一条，每条回复 `ok` 或 `error: <原因>`，上位机收到回复再发下一行）：
+//! - `pack-begin <字节数>`：开始一个包，不超过一槽（4096 字节）
+//! - `pack-data <十六进制>`：追加包内容，每行最多 59 字节（行长上限见 `LINE_CAP`）
+//! - `pack-end`：长度对上后校验并写进第一个空槽
+//!
+//! 任何一行出错都放弃当前上传，需从 `pack-begin` 重来。
+//!
+//! KEY4 连按两次擦除全部外观包（设置页随之回到 "Off"），KEY1 返回启动菜单。
+
+use crate::board::Board;
+use crate::drivers::flash::GameFlash;
+use crate::drivers::serial::LineTooLong;
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::packs::{self, Pack, SLOT_SIZE};
+use crate::settings::Settings;
+use crate::ui::font::FONT_HEIGHT;
+use crate::ui::menu::{self, BG, FG, HIGHLIGHT};
+use crate::ui::{fmt, pieces, text};
+use core::fmt::Write;
+use rtt_target::rprintln;
+
+const LIST_Y: u16 = 40;
+/// 列表占的行数；32 个槽都装满时只列出前面几个。
+const LIST_ROWS: usize = 9;
+const MESSAGE_Y: u16 = 216;
+
+/// 正在接收的包。
+struct Upload {
+    buf: [u8; SLOT_SIZE],
+    /// `pack-begin` 声明的长度；没有进行中的上传时为 None。
+    expected: Option<usize>,
+    received: usize,
+}
+
+/// 一行命令的处理结果。
+enum Step {
+    /// 已收下，继续等下一行。
+    Progress,
+    Installed(Pack),
+}
+
+impl Upload {
+    fn feed(&mut self, line: &[u8], flash: &mut GameFlash) -> Result<Step, &'static str> {
+        let line = core::str::from_utf8(line).map_err(|_| "unknown command")?;
+        let (command, arg) = line.split_once(' ').unwrap_or((line, ""));
+        match command {
+            "pack-begin" => {
+                let len = arg.trim().parse::<usize>().map_err(|_| "bad length")?;
+                if len > SLOT_SIZE {
+                    return Err("pack too large");
+                }
+                self.expected = Some(len);
+                self.received = 0;
+                Ok(Step::Progress)
+            }
+            "pack-data" => {
+                let expected = self.expected.ok_or("no pack-begin")?;
+                let hex = arg.trim().as_bytes();
+                if hex.len() % 2 != 0 {
+                    return Err("bad hex");
+                }
+                if self.received + hex.len() / 2 > expected {
+                    return Err("too much data");
+                }
+                for pair in hex.chunks(2) {
+                    let digit = |c: u8| (c as char).to_digit(16).ok_or("bad hex");
+                    self.buf[self.received] = (digit(pair[0])? * 16 + digit(pair[1])?) as u8;
+                    self.received += 1;
+                }
+                Ok(Step::Progress)
+            }
+            "pack-end" => {
+                let expected = self.expected.take().ok_or("no pack-begin")?;
+                if self.received != expected {
+                    return Err("length mismatch");
+                }
+                packs::install(flash, &self.buf[..expected]).map(Step::Installed)
+            }
+            _ => Err("unknown command"),
+        }
+    }
+}
+
+pub fn run(board: &mut Board, settings: &mut Settings) {
+    let input = InputMapper::new(InputMode::Menu);
+    let mut upload = Upload {
+        buf: [0; SLOT_SIZE],
+        expected: None,
+        received: 0,
+    };
+    let mut message = "";
+    let mut erase_armed = false;
+    let mut dirty = true;
+    loop {
+        if dirty {
+            render(board, settings, message);
+            dirty = false;
+        }
+        let reply = match board.serial.read_line(&board.main) {
+            None => None,
+            Some(Err(LineTooLong)) => Some(Err("line too long")),
+            Some(Ok(line)) if line.trim_ascii().is_empty() => continue,
+            Some(Ok(line)) => Some(upload.feed(line.trim_ascii(), &mut board.flash)),
+        };
+        match reply {
+            Some(Ok(step)) => {
+                let _ = write!(board.serial, "ok\r\n");
+                if let Step::Installed(pack) = step {
+                    rprintln!("installed pack '{}' in slot {}", pack.name, pack.slot);
+                    message = "Pack installed";
+                    dirty = true;
+                } else {
+                    draw_progress(board, &upload);
+                }
+                // 上位机等着回复才发下一行，收到行时不等待。
+                continue;
+            }
+            Some(Err(reason)) => {
+                let _ = write!(board.serial, "error: {}\r\n", reason);
+                upload.expected = None;
+                message = reason;
+                dirty = true;
+                continue;
+            }
+            None => {}
+        }
+        match input.poll(board) {
+            Some(Action::MenuSelect) => return,
+            Some(Action::MenuAlt) if erase_armed => {
+                // 先停用包再擦除：设置与棋子绘制都直接引用 Flash 中的包。
+                settings.pack = None;
+                pieces::use_set(None);
+                message = match board.flash.erase_packs() {
+                    Ok(()) => "All packs erased",
+                    Err(_) => "Erase failed",
+                };
+                erase_armed = false;
+                dirty = true;
+            }
+            Some(Action::MenuAlt) => {
+                message = "Press KEY4 again to erase all";
+                erase_armed = true;
+                dirty = true;
+            }
+            _ => {}
+        }
+        board.delay.ms(50);
+    }
+}
+
+fn render(board: &mut Board, settings: &Settings, message: &str) {
+    board.lcd.clear(BG);
+    let x = 8;
+    menu::draw_title(&mut board.lcd, "Packs", x, 6);
+    let mut count = 0;
+    for (i, pack) in packs::installed(board.flash.packs()).enumerate() {
+        count += 1;
+        if i >= LIST_ROWS {
+            continue;
+        }
+        let y = LIST_Y + i as u16 * menu::ENTRY_SPACING;
+        text::draw_text_scaled(&mut board.lcd, pack.name, x, y, FG, Some(BG), 2);
+        let contents = match (pack.theme.is_some(), pack.pieces.is_some()) {
+            (true, true) => "Theme+Pieces",
+            (true, false) => "Theme",
+            _ => "Pieces",
+        };
+        text::draw_text_scaled(&mut board.lcd, contents, x + 210, y, FG, Some(BG), 2);
+        if settings.pack.is_some_and(|active| active.slot == pack.slot) {
+            text::draw_text_scaled(&mut board.lcd, "*", x + 370, y, FG, Some(BG), 2);
+        }
+    }
+    let message = if count == 0 && message.is_empty() {
+        "No packs installed"
+    } else {
+        message
+    };
+    text::draw_text_scaled(
+        &mut board.lcd,
+        message,
+        x,
+        MESSAGE_Y,
+        HIGHLIGHT,
+        Some(BG),
+        1,
+    );
+    let help = [
+        "Upload over UART: pack-begin <len>, pack-data <hex>, pack-end",
+        "KEY4 Erase all  KEY1 Back",
+    ];
+    for (i, line) in help.iter().enumerate() {
+        menu::draw_hint(&mut board.lcd, line, x, 240 + i as u16 * 16);
+    }
+}
+
+/// 只重画进度行，避免每行数据都整页重绘。
+fn draw_progress(board: &mut Board, upload: &Upload) {
+    let Some(expected) = upload.expected else {
+        return;
+    };
+    let mut buf = [0u8; 4];
+    let percent = fmt::format_percent(upload.received as u32, expected as u32, &mut buf);
+    let (x, y) = (8, MESSAGE_Y);
+    board.lcd.fill_rect(x, y, 240, FONT_HEIGHT as u16, BG);
+    text::draw_text_scaled(&mut board.lcd, "Receiving", x, y, HIGHLIGHT, Some(BG), 1);
+    text::draw_text_scaled(&mut board.lcd, percent, x + 60, y, HIGHLIGHT, Some(BG), 1);
+}
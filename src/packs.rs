@@ -0,0 +1,157 @@
+//! 外观包：Flash 扇区 9 中的配色与棋子位图（见 `GameFlash::packs`）。区内按 4 KB 分槽，
+//! 每槽一个包，由启动菜单的 "Packs" 页经 USART1 上传（见 `pack_manager`），设置页的
+//! "Pack" 条目在已安装的包之间切换。
+//!
+//! 包格式（小端）：
+//! - 0：魔数 `RHK1`
+//! - 4：总长度 u32（不超过一槽）
+//! - 8：12..总长度 的 FNV-1a 校验和 u32
+//! - 12：名称，16 字节 ASCII，不足补 0
+//! - 28：标志，bit0 含配色、bit1 含棋子；29/30：位图宽/高，须等于 `SPRITE_W`/`SPRITE_H`；
+//!   31：位深 1 或 2（不含棋子时三项填 0）
+//! - 32：配色，12 个 u16，顺序同 `Theme` 的字段；不含配色时也占位
+//! - 56：棋子位图（含棋子时），兵、马、象、车、后、王依次排列，每枚先主体、2 位深时再接
+//!   细节，每行一个 u32、高位在左
+//!
+//! 安装时先写正文、最后写头部，掉电留下的半个包没有魔数，与损坏的槽一样被忽略。
+
+use crate::drivers::flash::{GameFlash, PACK_AREA_SIZE};
+use crate::game::archive::fnv1a;
+use crate::ui::pieces::{PieceSet, SPRITE_H, SPRITE_W};
+use crate::ui::theme::Theme;
+
+pub const SLOT_SIZE: usize = 4 * 1024;
+pub const SLOT_COUNT: usize = PACK_AREA_SIZE / SLOT_SIZE;
+const MAGIC: [u8; 4] = *b"RHK1";
+const HEADER_LEN: usize = 32;
+const NAME_OFFSET: usize = 12;
+const NAME_LEN: usize = 16;
+const THEME_OFFSET: usize = 32;
+const SPRITES_OFFSET: usize = THEME_OFFSET + core::mem::size_of::<Theme>();
+const FLAG_THEME: u8 = 1 << 0;
+const FLAG_PIECES: u8 = 1 << 1;
+const PIECE_COUNT: usize = 6;
+
+/// 一个已安装的外观包；配色与位图直接引用 Flash。
+#[derive(Clone, Copy)]
+pub struct Pack {
+    pub slot: usize,
+    pub name: &'static str,
+    pub theme: Option<&'static Theme>,
+    pub pieces: Option<PieceSet>,
+}
+
+/// 通过校验的包的布局。
+struct Layout {
+    len: usize,
+    name_len: usize,
+    theme: bool,
+    /// 含棋子时为是否 2 位深。
+    pieces: Option<bool>,
+}
+
+/// 校验一个包的字节（可带尾随的擦除字节），失败时返回原因，用于回复上传。
+fn layout(bytes: &[u8]) -> Result<Layout, &'static str> {
+    if bytes.len() < HEADER_LEN || bytes[0..4] != MAGIC {
+        return Err("bad magic");
+    }
+    let word =
+        |at: usize| u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]]);
+    let len = word(4) as usize;
+    if !(SPRITES_OFFSET..=SLOT_SIZE.min(bytes.len())).contains(&len) {
+        return Err("bad length");
+    }
+    if word(8) != fnv1a(&bytes[NAME_OFFSET..len]) {
+        return Err("bad checksum");
+    }
+    let name = &bytes[NAME_OFFSET..NAME_OFFSET + NAME_LEN];
+    let name_len = name.iter().position(|&b| b == 0).unwrap_or(NAME_LEN);
+    if name_len == 0 || !name[..name_len].iter().all(|b| (b' '..=b'~').contains(b)) {
+        return Err("bad name");
+    }
+    let flags = bytes[28];
+    let pieces = if flags & FLAG_PIECES != 0 {
+        if bytes[29] as usize != SPRITE_W || bytes[30] as usize != SPRITE_H {
+            return Err("sprite size mismatch");
+        }
+        match bytes[31] {
+            1 => Some(false),
+            2 => Some(true),
+            _ => return Err("bad depth"),
+        }
+    } else {
+        None
+    };
+    let theme = flags & FLAG_THEME != 0;
+    if !theme && pieces.is_none() {
+        return Err("empty pack");
+    }
+    let rows = pieces.map_or(0, |detailed| {
+        PIECE_COUNT * PieceSet::rows_per_piece(detailed)
+    });
+    if len != SPRITES_OFFSET + rows * 4 {
+        return Err("bad length");
+    }
+    Ok(Layout {
+        len,
+        name_len,
+        theme,
+        pieces,
+    })
+}
+
+/// 读出 `slot` 中的包；空槽、写坏的槽返回 None。
+fn read(area: &'static [u8], slot: usize) -> Option<Pack> {
+    let bytes = &area[slot * SLOT_SIZE..][..SLOT_SIZE];
+    let layout = layout(bytes).ok()?;
+    let name = core::str::from_utf8(&bytes[NAME_OFFSET..][..layout.name_len]).ok()?;
+    // SAFETY: 槽按 4 KB 对齐，配色与位图的偏移分别满足 u16、u32 的对齐；长度已由 `layout`
+    // 核对。Theme 是 repr(C) 的 12 个 u16，任何位模式都合法。
+    let theme = layout
+        .theme
+        .then(|| unsafe { &*(bytes[THEME_OFFSET..].as_ptr() as *const Theme) });
+    let pieces = layout.pieces.map(|detailed| {
+        let len = PIECE_COUNT * PieceSet::rows_per_piece(detailed);
+        // SAFETY: 同上。
+        let rows = unsafe {
+            core::slice::from_raw_parts(bytes[SPRITES_OFFSET..].as_ptr() as *const u32, len)
+        };
+        PieceSet { rows, detailed }
+    });
+    Some(Pack {
+        slot,
+        name,
+        theme,
+        pieces,
+    })
+}
+
+/// 按槽顺序列出已安装的包。
+pub fn installed(area: &'static [u8]) -> impl Iterator<Item = Pack> {
+    (0..SLOT_COUNT).filter_map(move |slot| read(area, slot))
+}
+
+/// 设置页的切换顺序：关闭、各个已安装的包、再回到关闭。
+pub fn next_after(area: &'static [u8], current: Option<Pack>) -> Option<Pack> {
+    let after = current.map_or(0, |pack| pack.slot + 1);
+    installed(area).find(|pack| pack.slot >= after)
+}
+
+/// 校验上传的包并写进第一个空槽，返回写好的包。
+pub fn install(flash: &mut GameFlash, bytes: &[u8]) -> Result<Pack, &'static str> {
+    let len = layout(bytes)?.len;
+    let area = flash.packs();
+    let slot = (0..SLOT_COUNT)
+        .find(|&slot| {
+            area[slot * SLOT_SIZE..][..SLOT_SIZE]
+                .iter()
+                .all(|&b| b == 0xFF)
+        })
+        .ok_or("pack area full")?;
+    let at = slot * SLOT_SIZE;
+    flash
+        .program_packs(at + HEADER_LEN, &bytes[HEADER_LEN..len])
+        .and_then(|()| flash.program_packs(at, &bytes[..HEADER_LEN]))
+        .map_err(|_| "flash write failed")?;
+    read(area, slot).ok_or("verify failed")
+}
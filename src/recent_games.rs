@@ -0,0 +1,177 @@
+//! 最近对局：列出 Flash 存档中最新的几局（开始时间、结果、步数、用时），
+//! KEY1 以复盘方式打开，KEY4 经 USART1 导出着法；选 "Back" 回到启动菜单。
+//! 右下角以缩略棋盘预览选中对局的终局局面。
+
+use crate::board::Board;
+use crate::game::archive::{self, SavedGame};
+use crate::game::history::History;
+use crate::game::pgn;
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::settings::Settings;
+use crate::ui::theme::Theme;
+use crate::ui::{chessboard, fmt, text};
+use rhess_core::GameState;
+use rtt_target::rprintln;
+
+/// 列表显示的局数。
+const LIST_LEN: usize = 8;
+const LIST_Y: u16 = 40;
+const ENTRY_SPACING: u16 = 20;
+const MESSAGE_Y: u16 = 220;
+/// 终局预览的右下角边距；列表最后一行较短，不会与之重叠。
+const PREVIEW_MARGIN: u16 = 4;
+
+/// 浏览存档；选中一局时返回它与读出的着法，选 "Back" 返回 None。
+pub fn run(board: &mut Board, settings: Settings) -> Option<(SavedGame, History)> {
+    let theme = settings.theme();
+    let mut games = [None; LIST_LEN];
+    // 列表末尾是 "Back" 条目。
+    let back = archive::recent(&board.flash, &mut games);
+    let input = InputMapper::new(InputMode::Menu);
+    let mut selected = 0;
+    let mut dirty = true;
+    let mut message = "";
+    loop {
+        if dirty {
+            let preview = games[..back]
+                .get(selected)
+                .copied()
+                .flatten()
+                .and_then(|game| archive::load(&board.flash, &game))
+                .map(|history| history.replay(history.len()));
+            render(board, theme, &games, selected, message, preview.as_ref());
+            dirty = false;
+        }
+        match input.poll(board) {
+            Some(Action::MenuDown) if selected < back => {
+                selected += 1;
+                dirty = true;
+            }
+            Some(Action::MenuUp) if selected > 0 => {
+                selected -= 1;
+                dirty = true;
+            }
+            Some(Action::MenuSelect) => {
+                // 选中 "Back" 时返回 None。
+                let game = games[..back].get(selected).copied().flatten()?;
+                match archive::load(&board.flash, &game) {
+                    Some(history) => return Some((game, history)),
+                    None => message = "Record damaged",
+                }
+                dirty = true;
+            }
+            Some(Action::MenuAlt) => {
+                let Some(game) = games[..back].get(selected).copied().flatten() else {
+                    continue;
+                };
+                message = match archive::load(&board.flash, &game) {
+                    Some(history) => {
+                        let _ = pgn::write(
+                            &mut board.serial,
+                            &history,
+                            game.setup.as_ref(),
+                            &game.started_at,
+                            game.result,
+                        );
+                        rprintln!("exported saved game #{} over USART1", game.seq);
+                        "Exported over UART"
+                    }
+                    None => "Record damaged",
+                };
+                dirty = true;
+            }
+            _ => {}
+        }
+        board.delay.ms(50);
+    }
+}
+
+fn render(
+    board: &mut Board,
+    theme: &Theme,
+    games: &[Option<SavedGame>],
+    selected: usize,
+    message: &str,
+    preview: Option<&GameState>,
+) {
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    board.lcd.clear(bg);
+    let x = 8;
+    text::draw_text_scaled(&mut board.lcd, "Recent Games", x, 6, fg, Some(bg), 3);
+    let mut rows = 0;
+    for (i, game) in games.iter().flatten().enumerate() {
+        let y = LIST_Y + i as u16 * ENTRY_SPACING;
+        draw_arrow(board, theme, x, y, i == selected);
+        draw_entry(board, theme, x + 12, y, game);
+        rows += 1;
+    }
+    let y = LIST_Y + rows as u16 * ENTRY_SPACING;
+    draw_arrow(board, theme, x, y, selected == rows);
+    text::draw_text_scaled(&mut board.lcd, "Back", x + 12, y, fg, Some(bg), 2);
+
+    let message = if rows == 0 && message.is_empty() {
+        "No saved games yet"
+    } else {
+        message
+    };
+    let alert = theme.ui_alert;
+    text::draw_text_scaled(&mut board.lcd, message, x, MESSAGE_Y, alert, Some(bg), 1);
+    let help = ["KEY3 Up  KEY2 Down", "KEY1 Review/Back  KEY4 Export (UART)"];
+    for (i, line) in help.iter().enumerate() {
+        let y = 236 + i as u16 * 16;
+        text::draw_text_scaled(&mut board.lcd, line, x, y, fg, Some(bg), 1);
+    }
+
+    if let Some(state) = preview {
+        let size = chessboard::THUMBNAIL_SIZE + PREVIEW_MARGIN;
+        let (px, py) = (board.lcd.width - size, board.lcd.height - size);
+        chessboard::draw_thumbnail(
+            &mut board.lcd,
+            theme,
+            &state.board,
+            px,
+            py,
+            chessboard::THUMBNAIL_CELL,
+        );
+    }
+}
+
+fn draw_arrow(board: &mut Board, theme: &Theme, x: u16, y: u16, selected: bool) {
+    let arrow = if selected { ">" } else { " " };
+    let bg = theme.ui_bg;
+    text::draw_text_scaled(&mut board.lcd, arrow, x, y, theme.cursor, Some(bg), 2);
+}
+
+/// 一行："10-18 14:05  1-0  23 mv  12:30"（开始时间、结果、回合数、用时）。
+fn draw_entry(board: &mut Board, theme: &Theme, x: u16, y: u16, game: &SavedGame) {
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    let t = game.started_at;
+    let two = |v: u8| [b'0' + v / 10 % 10, b'0' + v % 10];
+    let [mo0, mo1] = two(t.month);
+    let [d0, d1] = two(t.day);
+    let [h0, h1] = two(t.hour);
+    let [mi0, mi1] = two(t.minute);
+    let date = [mo0, mo1, b'-', d0, d1, b' ', h0, h1, b':', mi0, mi1];
+    let date = core::str::from_utf8(&date).unwrap_or("");
+    text::draw_text_scaled(&mut board.lcd, date, x, y, fg, Some(bg), 2);
+    text::draw_text_scaled(
+        &mut board.lcd,
+        game.result.pgn(),
+        x + 144,
+        y,
+        fg,
+        Some(bg),
+        2,
+    );
+
+    let mut moves_buf = [0u8; 10];
+    let moves = fmt::u32_to_str((game.plies as u32).div_ceil(2), &mut moves_buf);
+    let mut px = x + 240;
+    for part in [moves, " mv"] {
+        text::draw_text_scaled(&mut board.lcd, part, px, y, fg, Some(bg), 2);
+        px += part.len() as u16 * 12;
+    }
+    let mut time_buf = [0u8; 8];
+    let time = fmt::format_mm_ss(game.seconds, &mut time_buf);
+    text::draw_text_scaled(&mut board.lcd, time, x + 360, y, fg, Some(bg), 2);
+}
@@ -0,0 +1,76 @@
+//! 搜索基准：控制台 `bench` 命令在板上对几个固定中局局面，分别以深度 4–6、
+//! 关闭/开启机动性评估搜索，逐行打印所选着法、达到的深度、节点数、耗时、每秒节点数
+//! 与评估缓存命中率，用来权衡评估项的单节点开销与同样预算下损失的深度。
+//!
+//! ```text
+//! bench 1 d4 mob off: c3d5 d4 8123 nodes 2310 ms 3516 n/s eval 23% hit
+//! ```
+//!
+//! 全程阻塞主循环（数十秒），使用默认节点预算但关闭自适应缩放。借用对局的置换表，
+//! 每次搜索前清空，结束后也清空，不把基准配置下的条目留给对局。
+
+use crate::board::Board;
+use crate::game::observer::square_name;
+use core::fmt::Write;
+use rhess_core::ai::{AiConfig, TranspositionTable, choose_best_move_with};
+use rhess_core::editor::parse_square_list;
+
+/// 基准局面（`set` 命令格式），均已脱离开局库。
+const POSITIONS: [&str; 3] = [
+    // 意大利式开局后的开放中局。
+    "Kg1 Qd1 Ra1f1 Bc1c4 Nc3f3 Pa2b2c2d3e4f2g2h2 kg8 qd8 ra8f8 bc5c8 nc6f6 pa7b7c7d6e5f7g7h7 w",
+    // 无后中局，黑方走。
+    "Kg1 Rd1e1 Be3 Nd4 Pa2b2c4f2g2h2 kg8 rd8e8 be7 nf6 pa7b7c6f7g7h7 b",
+    // 王翼进攻，战术较多。
+    "Kh1 Qh5 Ra1f1 Bd3 Ne5 Pa2b2c3d4g2h2 kg8 qe7 ra8f8 bd7 nf6 pa7b7c6d5g7h7 w",
+];
+
+pub fn run(board: &mut Board, table: &mut TranspositionTable) {
+    for (index, text) in POSITIONS.iter().enumerate() {
+        let Ok(state) = parse_square_list(text) else {
+            continue;
+        };
+        for max_depth in 4..=6 {
+            for mobility in [false, true] {
+                let cfg = AiConfig {
+                    max_depth,
+                    adaptive_budget: false,
+                    mobility,
+                    ..AiConfig::default()
+                };
+                table.clear();
+                let start = board.timebase.now_ms();
+                let result = choose_best_move_with(&state, state.side_to_move, cfg, table, || {});
+                let ms = board.timebase.elapsed_ms(start).max(1);
+                let _ = write!(
+                    board.serial,
+                    "bench {} d{} mob {}: ",
+                    index + 1,
+                    max_depth,
+                    if mobility { "on" } else { "off" },
+                );
+                match result {
+                    Some(result) => {
+                        let (from, to) = (square_name(result.mv.from), square_name(result.mv.to));
+                        let _ = write!(
+                            board.serial,
+                            "{}{}{}{} d{} {} nodes {} ms {} n/s eval {}% hit\r\n",
+                            from[0] as char,
+                            from[1] as char,
+                            to[0] as char,
+                            to[1] as char,
+                            result.depth,
+                            result.nodes,
+                            ms,
+                            result.nodes as u64 * 1000 / ms as u64,
+                            result.stats.eval_hit_percent(),
+                        );
+                    }
+                    None => board.serial.write_bytes(b"no move\r\n"),
+                }
+            }
+        }
+    }
+    table.clear();
+    board.serial.write_bytes(b"bench done\r\n");
+}
@@ -0,0 +1,182 @@
+use crate::packs::Pack;
+use crate::ui::theme::{Theme, ThemeKind};
+use rhess_core::PieceKind;
+
+/// 棋盘坐标与棋子名的文字风格。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Labels {
+    /// 横排 1~8，棋子英文名。
+    Latin,
+    /// 横排 一~八，棋子中文名（王后车象马兵）。
+    Chinese,
+}
+
+impl Labels {
+    pub fn label(self) -> &'static str {
+        match self {
+            Labels::Latin => "Latin",
+            Labels::Chinese => "Chinese",
+        }
+    }
+
+    pub fn next(self) -> Labels {
+        match self {
+            Labels::Latin => Labels::Chinese,
+            Labels::Chinese => Labels::Latin,
+        }
+    }
+
+    /// 第 `rank`（0..8，自白方底线起）横排的标注。
+    pub fn rank_label(self, rank: u8) -> char {
+        match self {
+            Labels::Latin => (b'1' + rank) as char,
+            Labels::Chinese => crate::ui::cjk::RANK_NUMERALS[rank as usize & 7],
+        }
+    }
+
+    pub fn piece_name(self, kind: PieceKind) -> &'static str {
+        match (self, kind) {
+            (Labels::Latin, PieceKind::King) => "King",
+            (Labels::Latin, PieceKind::Queen) => "Queen",
+            (Labels::Latin, PieceKind::Rook) => "Rook",
+            (Labels::Latin, PieceKind::Bishop) => "Bishop",
+            (Labels::Latin, PieceKind::Knight) => "Knight",
+            (Labels::Latin, PieceKind::Pawn) => "Pawn",
+            (Labels::Chinese, PieceKind::King) => "王",
+            (Labels::Chinese, PieceKind::Queen) => "后",
+            (Labels::Chinese, PieceKind::Rook) => "车",
+            (Labels::Chinese, PieceKind::Bishop) => "象",
+            (Labels::Chinese, PieceKind::Knight) => "马",
+            (Labels::Chinese, PieceKind::Pawn) => "兵",
+        }
+    }
+}
+
+/// 升变菜单中四种棋子的排列，依次对应 KEY1~KEY4。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PromotionOrder {
+    /// 车、马、象、后。
+    Classic,
+    /// 后、车、象、马：最常用的升变放在 KEY1。
+    QueenFirst,
+    /// 马、后、车、象：便于马的低升变。
+    KnightFirst,
+}
+
+impl PromotionOrder {
+    pub fn pieces(self) -> [PieceKind; 4] {
+        use PieceKind::*;
+        match self {
+            PromotionOrder::Classic => [Rook, Knight, Bishop, Queen],
+            PromotionOrder::QueenFirst => [Queen, Rook, Bishop, Knight],
+            PromotionOrder::KnightFirst => [Knight, Queen, Rook, Bishop],
+        }
+    }
+
+    /// 按键顺序的棋子字母，用作设置页取值。
+    pub fn label(self) -> &'static str {
+        match self {
+            PromotionOrder::Classic => "RNBQ",
+            PromotionOrder::QueenFirst => "QRBN",
+            PromotionOrder::KnightFirst => "NQRB",
+        }
+    }
+
+    pub fn next(self) -> PromotionOrder {
+        match self {
+            PromotionOrder::Classic => PromotionOrder::QueenFirst,
+            PromotionOrder::QueenFirst => PromotionOrder::KnightFirst,
+            PromotionOrder::KnightFirst => PromotionOrder::Classic,
+        }
+    }
+
+    /// `kind` 在菜单中的位置（0..4）；不能升变成的棋子返回 None。
+    pub fn slot(self, kind: PieceKind) -> Option<usize> {
+        self.pieces().iter().position(|&k| k == kind)
+    }
+}
+
+/// 赛事广播：对局事件以 JSON 行经第二串口（USART3）输出，及其波特率。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Broadcast {
+    Off,
+    Baud9600,
+    Baud38400,
+    Baud115200,
+}
+
+impl Broadcast {
+    /// 波特率；关闭时为 None。
+    pub fn baud(self) -> Option<u32> {
+        match self {
+            Broadcast::Off => None,
+            Broadcast::Baud9600 => Some(9_600),
+            Broadcast::Baud38400 => Some(38_400),
+            Broadcast::Baud115200 => Some(115_200),
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Broadcast::Off => "Off",
+            Broadcast::Baud9600 => "9600",
+            Broadcast::Baud38400 => "38400",
+            Broadcast::Baud115200 => "115200",
+        }
+    }
+
+    pub fn next(self) -> Broadcast {
+        match self {
+            Broadcast::Off => Broadcast::Baud9600,
+            Broadcast::Baud9600 => Broadcast::Baud38400,
+            Broadcast::Baud38400 => Broadcast::Baud115200,
+            Broadcast::Baud115200 => Broadcast::Off,
+        }
+    }
+}
+
+/// 运行期可调设置：启动菜单中修改，开局时传入 `Game`。
+#[derive(Clone, Copy)]
+pub struct Settings {
+    pub theme: ThemeKind,
+    pub labels: Labels,
+    /// 无障碍显示：光标格定时闪烁、选中的棋子加粗边框，不单靠颜色区分。
+    pub blink_cursor: bool,
+    /// 减少动态效果：关闭闪烁与流水灯，一律静态显示（见 `Timebase::blink_lit`）。
+    pub reduced_motion: bool,
+    pub promotion_order: PromotionOrder,
+    pub broadcast: Broadcast,
+    /// 摸子走子：人人对局中选中有合法着法的己方棋子后不能取消或改选，必须走这枚棋子。
+    pub touch_move: bool,
+    /// AI 落子后光标自动移到其落点（被将军时移到己方王）。
+    pub snap_cursor: bool,
+    /// 自动翻转棋盘：人机对局让人这一方在下（执黑即黑方在下），人人对局每步转向行棋方。
+    pub auto_flip: bool,
+    /// 选用的外观包（见 `crate::packs`）：包里的配色优先于 `theme`，棋子位图见 `pieces::use_set`。
+    pub pack: Option<Pack>,
+}
+
+impl Settings {
+    pub fn theme(&self) -> &'static Theme {
+        self.pack
+            .and_then(|pack| pack.theme)
+            .unwrap_or_else(|| self.theme.theme())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            theme: ThemeKind::Classic,
+            labels: Labels::Latin,
+            blink_cursor: false,
+            reduced_motion: false,
+            promotion_order: PromotionOrder::Classic,
+            broadcast: Broadcast::Off,
+            touch_move: false,
+            snap_cursor: false,
+            auto_flip: false,
+            pack: None,
+        }
+    }
+}
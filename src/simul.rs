@@ -0,0 +1,530 @@
+//! 车轮战：AI 同时与你下 2~4 盘棋，你在第 1、3 盘执白，第 2、4 盘执黑。
+//!
+//! 总览页并排显示各盘的缩略图、双方用时与状态，KEY3/KEY2 选盘、KEY1 走到该盘前。
+//! 在单盘上与对局相同地移动光标、长按 KEY1 选子、长按 KEY2 走子（升变一律升后），
+//! AI 随即在这一盘应着；未选子时在己方棋子以外的格上长按 KEY1 回到总览。
+//! 每盘各有两只钟：你的钟只在你站在这盘前且轮到你时走，AI 的钟记它在这盘上的思考时间。
+//! 各盘只在内存中，不存档；在总览页长按 KEY1 结束车轮战回到主菜单。
+
+use crate::board::Board;
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::new_game::Strength;
+use crate::settings::Settings;
+use crate::stack_guard;
+use crate::ui::menu::{self, BG, MenuEvent};
+use crate::ui::theme::Theme;
+use crate::ui::{chessboard, fmt, pieces, text};
+use rhess_core::ai::{AiConfig, TranspositionTable, choose_best_move_with};
+use rhess_core::rating::GameScore;
+use rhess_core::result::GameResult;
+use rhess_core::tablebase;
+use rhess_core::{Color, GameState, Move, PieceKind};
+
+const MIN_BOARDS: usize = 2;
+const MAX_BOARDS: usize = 4;
+/// 总览页缩略图每格像素（整盘 96x96）与各盘的列宽。
+const THUMB_CELL: u16 = 12;
+const THUMB_SIZE: u16 = THUMB_CELL * 8;
+const COLUMN_W: u16 = 118;
+const THUMB_X: u16 = 8;
+const THUMB_Y: u16 = 44;
+/// 选中盘的边框宽度。
+const FRAME: u16 = 3;
+/// 状态文字一行能放下的字符数（列宽内，1 倍字号）。
+const STATUS_CHARS: usize = 19;
+const BOARD_NAMES: [&str; MAX_BOARDS] = ["Board 1", "Board 2", "Board 3", "Board 4"];
+
+/// 一盘棋：局面、上一步、结果与双方用时。
+#[derive(Clone, Copy)]
+struct Table {
+    state: GameState,
+    human: Color,
+    last_move: Option<(u8, u8)>,
+    result: Option<GameResult>,
+    human_ms: u32,
+    ai_ms: u32,
+}
+
+impl Table {
+    fn new(human: Color) -> Self {
+        Table {
+            state: GameState::start_position(),
+            human,
+            last_move: None,
+            result: None,
+            human_ms: 0,
+            ai_ms: 0,
+        }
+    }
+
+    fn human_to_move(&self) -> bool {
+        self.result.is_none() && self.state.side_to_move == self.human
+    }
+
+    fn play(&mut self, mv: Move) -> bool {
+        let Some(next) = self.state.make_move(mv) else {
+            return false;
+        };
+        self.state = next;
+        self.last_move = Some((mv.from, mv.to));
+        self.result = next.result().or_else(|| tablebase::adjudicate(&next));
+        true
+    }
+
+    /// 总览页的状态文字。
+    fn status(&self) -> &'static str {
+        match self.result {
+            Some(result) => result.reason(),
+            None if self.human_to_move() => "Your move",
+            None => "AI to move",
+        }
+    }
+}
+
+struct Simul {
+    tables: [Table; MAX_BOARDS],
+    count: usize,
+    theme: &'static Theme,
+    ai: AiConfig,
+}
+
+impl Simul {
+    fn new(count: usize, theme: &'static Theme) -> Self {
+        Simul {
+            tables: core::array::from_fn(|i| {
+                Table::new(if i & 1 == 0 {
+                    Color::White
+                } else {
+                    Color::Black
+                })
+            }),
+            count,
+            theme,
+            // 要同时应付几盘，AI 取最快的一档强度。
+            ai: Strength::Casual.config(),
+        }
+    }
+
+    fn tables(&self) -> &[Table] {
+        &self.tables[..self.count]
+    }
+
+    /// AI 在第 `index` 盘走一步（轮到它时），计入它在这盘的用时。
+    fn ai_reply(&mut self, board: &mut Board, index: usize, tt: &mut TranspositionTable) {
+        let table = &mut self.tables[index];
+        if table.result.is_some() || table.state.side_to_move == table.human {
+            return;
+        }
+        let start_ms = board.timebase.now_ms();
+        let side = table.state.side_to_move;
+        let result = choose_best_move_with(&table.state, side, self.ai, tt, || {});
+        table.ai_ms += board.timebase.elapsed_ms(start_ms);
+        if let Some(result) = result {
+            table.play(result.mv);
+        }
+        stack_guard::check(board);
+    }
+
+    /// 你的得分与已结束的盘数，半分计为 1（显示时除以 2）。
+    fn score(&self) -> (u32, u32) {
+        self.tables()
+            .iter()
+            .filter_map(|t| t.result.map(|result| result.score_for(t.human)))
+            .fold((0, 0), |(halves, done), score| {
+                let points = match score {
+                    GameScore::Win => 2,
+                    GameScore::Draw => 1,
+                    GameScore::Loss => 0,
+                };
+                (halves + points, done + 1)
+            })
+    }
+}
+
+/// 进入车轮战：先选盘数，之后在总览与单盘之间往返，直到在总览页长按 KEY1。
+pub fn run(board: &mut Board, settings: Settings) {
+    let Some(count) = choose_count(board) else {
+        return;
+    };
+    let mut simul = Simul::new(count, settings.theme());
+    let mut tt = TranspositionTable::new();
+    // AI 执白的各盘先走出第一步。
+    board.lcd.clear(simul.theme.ui_bg);
+    text::draw_text_scaled(
+        &mut board.lcd,
+        "AI opening moves...",
+        THUMB_X,
+        THUMB_Y,
+        simul.theme.ui_fg,
+        Some(simul.theme.ui_bg),
+        2,
+    );
+    for index in 0..count {
+        simul.ai_reply(board, index, &mut tt);
+    }
+    let mut selected = 0;
+    while let Some(index) = overview(board, &simul, &mut selected) {
+        play_table(board, &mut simul, index, &mut tt);
+    }
+}
+
+/// 选盘数页：KEY4 切换 2~4，KEY1 开始，长按 KEY1 返回主菜单。
+fn choose_count(board: &mut Board) -> Option<usize> {
+    let input = InputMapper::new(InputMode::Menu);
+    let mut count = 3;
+    let mut selected = 0;
+    let mut dirty = true;
+    loop {
+        if dirty {
+            let mut buf = [0u8; 10];
+            board.lcd.clear(BG);
+            menu::draw_title(&mut board.lcd, "Simul", 8, 6);
+            menu::draw_hint(&mut board.lcd, "The computer plays every board", 8, 36);
+            let value = fmt::u32_to_str(count as u32, &mut buf);
+            menu::draw_rows(&mut board.lcd, &[("Boards", value)], 8, 60, 160, 0);
+            menu::draw_hint(
+                &mut board.lcd,
+                "KEY4 Change  KEY1 Start  hold KEY1 Menu",
+                8,
+                254,
+            );
+            dirty = false;
+        }
+        match menu::navigate(input.poll(board), &mut selected, 1) {
+            Some(MenuEvent::Alt) => {
+                count = if count == MAX_BOARDS {
+                    MIN_BOARDS
+                } else {
+                    count + 1
+                };
+                dirty = true;
+            }
+            Some(MenuEvent::Select) => return Some(count),
+            Some(MenuEvent::Back) => return None,
+            _ => {}
+        }
+        board.delay.ms(50);
+    }
+}
+
+/// 总览页：返回选中的盘，长按 KEY1 时为 None。
+fn overview(board: &mut Board, simul: &Simul, selected: &mut usize) -> Option<usize> {
+    let input = InputMapper::new(InputMode::Menu);
+    render_overview(board, simul, *selected);
+    loop {
+        match menu::navigate(input.poll(board), selected, simul.count) {
+            Some(MenuEvent::Moved) => render_frames(board, simul, *selected),
+            Some(MenuEvent::Select) => return Some(*selected),
+            Some(MenuEvent::Back) => return None,
+            _ => {}
+        }
+        board.delay.ms(50);
+    }
+}
+
+fn render_overview(board: &mut Board, simul: &Simul, selected: usize) {
+    let theme = simul.theme;
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    board.lcd.clear(bg);
+    text::draw_text_scaled(&mut board.lcd, "Simul", THUMB_X, 6, fg, Some(bg), 3);
+
+    let (halves, done) = simul.score();
+    let mut points_buf = [0u8; 10];
+    let mut done_buf = [0u8; 10];
+    let points = fmt::u32_to_str(halves / 2, &mut points_buf);
+    let half = if halves & 1 == 1 { ".5" } else { "" };
+    let mut x = 200;
+    for part in [
+        "Score ",
+        points,
+        half,
+        " / ",
+        fmt::u32_to_str(done, &mut done_buf),
+    ] {
+        text::draw_text_scaled(&mut board.lcd, part, x, 14, fg, Some(bg), 2);
+        x += part.len() as u16 * 12;
+    }
+
+    for (i, table) in simul.tables().iter().enumerate() {
+        let x = THUMB_X + i as u16 * COLUMN_W;
+        chessboard::draw_thumbnail(
+            &mut board.lcd,
+            theme,
+            &table.state.board,
+            x,
+            THUMB_Y,
+            THUMB_CELL,
+        );
+        let mut y = THUMB_Y + THUMB_SIZE + 8;
+        let side = match table.human {
+            Color::White => "  you W",
+            Color::Black => "  you B",
+        };
+        text::draw_text_scaled(&mut board.lcd, BOARD_NAMES[i], x, y, fg, Some(bg), 1);
+        text::draw_text_scaled(&mut board.lcd, side, x + 42, y, fg, Some(bg), 1);
+        y += 14;
+        draw_clock(board, theme, "You ", table.human_ms, x, y);
+        y += 12;
+        draw_clock(board, theme, "AI  ", table.ai_ms, x, y);
+        y += 16;
+        let color = if table.human_to_move() {
+            theme.ui_alert
+        } else {
+            fg
+        };
+        if let Some(result) = table.result {
+            text::draw_text_scaled(&mut board.lcd, result.pgn(), x, y, fg, Some(bg), 1);
+            y += 12;
+        }
+        draw_wrapped(board, table.status(), x, y, color, bg);
+    }
+    render_frames(board, simul, selected);
+    menu::draw_hint(
+        &mut board.lcd,
+        "KEY3/KEY2 Choose  KEY1 Play  hold KEY1 End simul",
+        THUMB_X,
+        254,
+    );
+}
+
+/// 选中盘的缩略图加边框，其余擦除。
+fn render_frames(board: &mut Board, simul: &Simul, selected: usize) {
+    for i in 0..simul.count {
+        let color = if i == selected {
+            simul.theme.cursor
+        } else {
+            simul.theme.ui_bg
+        };
+        let x = THUMB_X + i as u16 * COLUMN_W - FRAME;
+        let y = THUMB_Y - FRAME;
+        let size = THUMB_SIZE + 2 * FRAME;
+        board.lcd.fill_rect(x, y, size, FRAME, color);
+        board.lcd.fill_rect(x, y + size - FRAME, size, FRAME, color);
+        board.lcd.fill_rect(x, y, FRAME, size, color);
+        board.lcd.fill_rect(x + size - FRAME, y, FRAME, size, color);
+    }
+}
+
+fn draw_clock(board: &mut Board, theme: &Theme, label: &str, ms: u32, x: u16, y: u16) {
+    let mut buf = [0u8; 8];
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    text::draw_text_scaled(&mut board.lcd, label, x, y, fg, Some(bg), 1);
+    let time = fmt::format_mm_ss(ms / 1000, &mut buf);
+    text::draw_text_scaled(&mut board.lcd, time, x + 24, y, fg, Some(bg), 1);
+}
+
+/// 1 倍字号的状态文字，超出列宽时在空格处折成两行。
+fn draw_wrapped(board: &mut Board, status: &str, x: u16, y: u16, fg: u16, bg: u16) {
+    let (first, rest) = match status.split_once(' ') {
+        Some(parts) if status.len() > STATUS_CHARS => parts,
+        _ => (status, ""),
+    };
+    text::draw_text_scaled(&mut board.lcd, first, x, y, fg, Some(bg), 1);
+    text::draw_text_scaled(&mut board.lcd, rest, x, y + 12, fg, Some(bg), 1);
+}
+
+/// 单盘视图的本地状态。
+struct Seat {
+    index: usize,
+    cursor: (u8, u8),
+    selected: Option<u8>,
+    /// 你的钟本次开始走的时刻。
+    turn_since: Option<u32>,
+    /// 侧栏上显示的你的用时（秒），变化时才重绘。
+    shown_seconds: Option<u32>,
+}
+
+/// 在第 `index` 盘前下棋，回到总览时返回。
+fn play_table(board: &mut Board, simul: &mut Simul, index: usize, tt: &mut TranspositionTable) {
+    let table = simul.tables[index];
+    let start = table.last_move.map_or(
+        if table.human == Color::White { 12 } else { 52 },
+        |(_, to)| to,
+    );
+    let mut seat = Seat {
+        index,
+        cursor: (start % 8, start / 8),
+        selected: None,
+        turn_since: None,
+        shown_seconds: None,
+    };
+    let input = InputMapper::new(InputMode::Game);
+    board.lcd.clear(simul.theme.ui_bg);
+    render_table(board, simul, &mut seat);
+    loop {
+        let now_ms = board.timebase.now_ms();
+        let table = &mut simul.tables[index];
+        if table.human_to_move() {
+            seat.turn_since.get_or_insert(now_ms);
+        }
+        let seconds =
+            (table.human_ms + seat.turn_since.map_or(0, |t| now_ms.wrapping_sub(t))) / 1000;
+        if seat.shown_seconds != Some(seconds) {
+            seat.shown_seconds = Some(seconds);
+            let x = chessboard::BOARD_SIZE + 6;
+            draw_clock(board, simul.theme, "You ", seconds * 1000, x, 56);
+        }
+        let old = seat.cursor;
+        match input.poll(board) {
+            Some(Action::MoveLeft) => seat.cursor.0 = seat.cursor.0.saturating_sub(1),
+            Some(Action::MoveRight) => seat.cursor.0 = (seat.cursor.0 + 1).min(7),
+            Some(Action::MoveUp) => seat.cursor.1 = (seat.cursor.1 + 1).min(7),
+            Some(Action::MoveDown) => seat.cursor.1 = seat.cursor.1.saturating_sub(1),
+            Some(Action::ToggleSelect) => {
+                let sq = seat.cursor.1 * 8 + seat.cursor.0;
+                let own = table.state.board[sq as usize]
+                    .is_some_and(|p| p.color == table.human && table.human_to_move());
+                if own || seat.selected.is_some() {
+                    seat.selected = (own && seat.selected != Some(sq)).then_some(sq);
+                    render_table(board, simul, &mut seat);
+                    continue;
+                }
+                stop_clock(table, &mut seat, now_ms);
+                return;
+            }
+            Some(Action::SubmitMove) => {
+                if submit(table, &mut seat) {
+                    stop_clock(table, &mut seat, now_ms);
+                    render_table(board, simul, &mut seat);
+                    draw_side_status(board, simul.theme, "Thinking...");
+                    simul.ai_reply(board, index, tt);
+                    if let Some((_, to)) = simul.tables[index].last_move {
+                        seat.cursor = (to % 8, to / 8);
+                    }
+                    render_table(board, simul, &mut seat);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        if seat.cursor != old {
+            render_square(board, simul, &seat, old.0, old.1);
+            render_square(board, simul, &seat, seat.cursor.0, seat.cursor.1);
+        }
+        board.delay.ms(10);
+    }
+}
+
+/// 把你本次的思考时间记到这盘的钟上。
+fn stop_clock(table: &mut Table, seat: &mut Seat, now_ms: u32) {
+    if let Some(since) = seat.turn_since.take() {
+        table.human_ms += now_ms.wrapping_sub(since);
+    }
+}
+
+/// 把选中的棋子走到光标格；不合法时保持选中。
+fn submit(table: &mut Table, seat: &mut Seat) -> bool {
+    let Some(from) = seat.selected else {
+        return false;
+    };
+    let to = seat.cursor.1 * 8 + seat.cursor.0;
+    let mv = table
+        .state
+        .generate_legal_moves()
+        .iter()
+        .copied()
+        .find(|mv| {
+            mv.from == from && mv.to == to && matches!(mv.promotion, None | Some(PieceKind::Queen))
+        });
+    let played = mv.is_some_and(|mv| table.play(mv));
+    if played {
+        seat.selected = None;
+    }
+    played
+}
+
+/// 整盘与侧栏重画；你的钟随后在主循环里按实时用时补画。
+fn render_table(board: &mut Board, simul: &Simul, seat: &mut Seat) {
+    seat.shown_seconds = None;
+    for rank in 0..8 {
+        for file in 0..8 {
+            render_square(board, simul, seat, file, rank);
+        }
+    }
+    render_side(board, simul, seat);
+}
+
+fn render_square(board: &mut Board, simul: &Simul, seat: &Seat, file: u8, rank: u8) {
+    let theme = simul.theme;
+    let table = &simul.tables[seat.index];
+    let idx = rank * 8 + file;
+    let is_last_move = table
+        .last_move
+        .is_some_and(|(from, to)| from == idx || to == idx);
+    let color = if seat.cursor == (file, rank) {
+        theme.cursor
+    } else if is_last_move {
+        theme.last_move
+    } else {
+        chessboard::square_color(theme, file, rank)
+    };
+    chessboard::draw_square_with_color(&mut board.lcd, file, rank, color);
+    if let Some(piece) = table.state.board[idx as usize] {
+        let override_color = (seat.selected == Some(idx)).then_some(theme.selected_piece);
+        pieces::draw_piece_on_square_custom(
+            &mut board.lcd,
+            theme,
+            piece.kind,
+            piece.color,
+            file,
+            rank,
+            override_color,
+        );
+    }
+}
+
+fn render_side(board: &mut Board, simul: &Simul, seat: &Seat) {
+    let theme = simul.theme;
+    let table = &simul.tables[seat.index];
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    let start_x = chessboard::BOARD_SIZE + 4;
+    let width = board.lcd.width.saturating_sub(start_x);
+    board.lcd.fill_rect(start_x, 0, width, board.lcd.height, bg);
+    let x = start_x + 2;
+
+    let title = BOARD_NAMES[seat.index];
+    text::draw_text_scaled(&mut board.lcd, title, x, 6, fg, Some(bg), 2);
+    let side = match table.human {
+        Color::White => "You play White",
+        Color::Black => "You play Black",
+    };
+    text::draw_text_scaled(&mut board.lcd, side, x, 32, fg, Some(bg), 1);
+    draw_clock(board, theme, "You ", table.human_ms, x, 56);
+    draw_clock(board, theme, "AI  ", table.ai_ms, x, 70);
+
+    let status = match table.result {
+        Some(result) => result.reason(),
+        None if table.state.is_in_check(table.human) => "Check!",
+        None => table.status(),
+    };
+    draw_side_status(board, theme, status);
+    if let Some(result) = table.result {
+        text::draw_text_scaled(&mut board.lcd, result.pgn(), x, 120, fg, Some(bg), 2);
+    }
+
+    let hints = [
+        "hold KEY1 Pick piece",
+        "hold KEY2 Move there",
+        "hold KEY1 elsewhere: boards",
+    ];
+    for (i, hint) in hints.iter().enumerate() {
+        let y = 214 + i as u16 * 16;
+        text::draw_text_scaled(&mut board.lcd, hint, x, y, fg, Some(bg), 1);
+    }
+}
+
+/// 侧栏的状态行；AI 思考前先画 "Thinking..."，搜索期间屏幕不再刷新。
+fn draw_side_status(board: &mut Board, theme: &Theme, status: &str) {
+    let x = chessboard::BOARD_SIZE + 6;
+    let width = board.lcd.width.saturating_sub(x);
+    board.lcd.fill_rect(x, 96, width, 16, theme.ui_bg);
+    text::draw_text_scaled(
+        &mut board.lcd,
+        status,
+        x,
+        96,
+        theme.ui_alert,
+        Some(theme.ui_bg),
+        2,
+    );
+}
@@ -0,0 +1,68 @@
+//! 栈守卫的处理：上电自检失败或运行中守卫区被改写（见 `drivers::stack`）时，经 RTT 与
+//! USART1 记录原因并整屏提示。
+//!
+//! 自检失败只说明栈底这块 RAM 可能有坏位，按 KEY1 仍可继续使用；栈溢出则可能已改写了
+//! 静态数据，继续运行的结果不可信，提示页按 KEY1 复位重启。
+
+use crate::board::Board;
+use crate::drivers::stack;
+use crate::interaction::{InputMapper, InputMode};
+use crate::ui::menu::{self, BG, MenuEvent};
+use cortex_m::peripheral::SCB;
+use rtt_target::rprintln;
+
+/// 上电自检的结果；失败时提示后等待 KEY1 继续。
+pub fn report_self_test(board: &mut Board, passed: bool) {
+    if passed {
+        return;
+    }
+    rprintln!("RAM self-test failed in the stack guard region");
+    board.serial.write_bytes(b"error: RAM self-test failed\r\n");
+    warn(
+        board,
+        "RAM self-test",
+        &[
+            "The RAM below the stack did not read",
+            "back what was written. The board may",
+            "misbehave.",
+        ],
+        "KEY1 Continue anyway",
+    );
+}
+
+/// 守卫区被改写时记录并提示，按 KEY1 后复位，不再返回；完好时直接返回。
+pub fn check(board: &mut Board) {
+    if stack::guard_intact() {
+        return;
+    }
+    rprintln!(
+        "stack overflow: {}-byte guard region overwritten",
+        stack::GUARD_BYTES
+    );
+    board.serial.write_bytes(b"error: stack overflow\r\n");
+    warn(
+        board,
+        "Stack overflow",
+        &[
+            "The stack ran into its guard region;",
+            "game data may be corrupt.",
+        ],
+        "KEY1 Restart",
+    );
+    SCB::sys_reset();
+}
+
+/// 整屏提示，等待 KEY1 短按后返回。
+fn warn(board: &mut Board, title: &str, lines: &[&str], hint: &str) {
+    let input = InputMapper::new(InputMode::Menu);
+    board.lcd.clear(BG);
+    menu::draw_title(&mut board.lcd, title, 8, 6);
+    for (i, line) in lines.iter().enumerate() {
+        menu::draw_hint(&mut board.lcd, line, 8, 50 + i as u16 * 14);
+    }
+    menu::draw_hint(&mut board.lcd, hint, 8, 254);
+    let mut selected = 0;
+    while menu::navigate(input.poll(board), &mut selected, 1) != Some(MenuEvent::Select) {
+        board.delay.ms(50);
+    }
+}
@@ -1,70 +1,226 @@
 use crate::board::Board;
-use crate::drivers::button::PressKind;
-use crate::start_menu_crab::{CRAB_BITMAP, CRAB_H, CRAB_W};
+use crate::interaction::{InputMapper, InputMode};
+use crate::packs;
+use crate::settings::Settings;
+use crate::start_menu_crab::{self, CRAB_H, CRAB_W, MENU_BOB};
+use crate::ui::animation::Animation;
 use crate::ui::chessboard;
+use crate::ui::fmt;
+use crate::ui::menu::{self, BG, ENTRY_SPACING, FG, MenuEvent};
+use crate::ui::pieces;
 use crate::ui::text;
-
-const BG: u16 = 0x0000;
-const FG: u16 = 0xFFFF;
-const TITLE_COLOR: u16 = 0xFFE0;
-const HIGHLIGHT: u16 = 0xE540; // 柔和橙
+use rhess_core::{Color, PieceKind};
 
 #[derive(Clone, Copy)]
 pub enum Mode {
-    HumanVsHuman,
-    HumanVsComputer,
-    ComputerVsHuman,
-    ComputerVsComputer,
+    /// 开局向导：对手、强度与变体、时钟（见 `new_game`）。
+    NewGame,
+    /// 车轮战：AI 同时与你下 2~4 盘（见 `simul`）。
+    Simul,
+    /// 马步训练小游戏。
+    KnightTrainer,
+    /// 名局演示。
+    FamousGames,
+    /// 格子受攻查询。
+    SquareAttacks,
+    /// 浏览 Flash 中保存的最近对局。
+    RecentGames,
+    /// 对 AI 的等级分与走势。
+    Stats,
+    /// 外观包：列出、经串口安装与擦除（见 `pack_manager`）。
+    Packs,
 }
 
-pub fn select_mode(board: &mut Board) -> Mode {
+/// 菜单条目，按显示顺序排列。
+const MODES: [(&str, Mode); 8] = [
+    ("New Game", Mode::NewGame),
+    ("Simul", Mode::Simul),
+    ("Knight Trainer", Mode::KnightTrainer),
+    ("Famous Games", Mode::FamousGames),
+    ("Square Attacks", Mode::SquareAttacks),
+    ("Recent Games", Mode::RecentGames),
+    ("Stats", Mode::Stats),
+    ("Packs", Mode::Packs),
+];
+/// 模式列表之后的 "Sleep" 条目：整机进入 STOP，按任意键唤醒回到菜单。
+const SLEEP_ENTRY: usize = MODES.len();
+const MENU_ENTRY_COUNT: usize = MODES.len() + 1;
+
+pub fn select_mode(board: &mut Board, settings: &mut Settings) -> Mode {
+    let input = InputMapper::new(InputMode::Menu);
     let mut selected: usize = 0;
     let mut dirty = true;
+    let mut shown_minute = None;
+    let mut crab = crab_animation(board);
     loop {
         if dirty {
             render_menu(board, selected);
+            crab.invalidate();
+            shown_minute = None;
             dirty = false;
         }
-        if let Some(press) = board.buttons.key2_press(&mut board.delay) {
-            if matches!(press, PressKind::Short) {
-                let next = (selected + 1).min(3);
-                if next != selected {
-                    selected = next;
-                    dirty = true;
-                }
-            }
+        crab.update(&mut board.lcd, &board.timebase);
+        // 右上角时钟：分钟变化时才重绘。
+        let now = board.rtc.now();
+        if shown_minute != Some(now.minute) {
+            draw_time_of_day(board, now.seconds_of_day());
+            shown_minute = Some(now.minute);
         }
-        if let Some(press) = board.buttons.key3_press(&mut board.delay) {
-            if matches!(press, PressKind::Short) {
-                let next = selected.saturating_sub(1);
-                if next != selected {
-                    selected = next;
-                    dirty = true;
-                }
+        match menu::navigate(input.poll(board), &mut selected, MENU_ENTRY_COUNT) {
+            Some(MenuEvent::Moved) => dirty = true,
+            // 屏幕内容在休眠期间保留，醒来无需重绘。
+            Some(MenuEvent::Select) if selected == SLEEP_ENTRY => board.sleep(),
+            Some(MenuEvent::Select) => return MODES[selected].1,
+            Some(MenuEvent::Alt) => {
+                settings_menu(board, settings);
+                dirty = true;
             }
+            _ => {}
         }
-        if let Some(press) = board.buttons.key1_press(&mut board.delay) {
-            if matches!(press, PressKind::Short) {
-                return match selected {
-                    0 => Mode::HumanVsHuman,
-                    1 => Mode::HumanVsComputer,
-                    2 => Mode::ComputerVsHuman,
-                    _ => Mode::ComputerVsComputer,
-                };
+        board.delay.ms(50);
+    }
+}
+
+/// 设置页：KEY3/KEY2 选择条目，KEY4 切换取值（时/分逐一递增），KEY1 返回。
+fn settings_menu(board: &mut Board, settings: &mut Settings) {
+    const ENTRY_COUNT: usize = 12;
+    let input = InputMapper::new(InputMode::Menu);
+    let mut selected: usize = 0;
+    let mut dirty = true;
+    loop {
+        if dirty {
+            render_settings(board, settings, selected);
+            dirty = false;
+        }
+        match menu::navigate(input.poll(board), &mut selected, ENTRY_COUNT) {
+            Some(MenuEvent::Moved) => dirty = true,
+            Some(MenuEvent::Alt) => {
+                match selected {
+                    0 => settings.theme = settings.theme.next(),
+                    1 => settings.labels = settings.labels.next(),
+                    2 => settings.blink_cursor = !settings.blink_cursor,
+                    3 => {
+                        settings.reduced_motion = !settings.reduced_motion;
+                        board.timebase.set_reduced_motion(settings.reduced_motion);
+                    }
+                    4 => settings.promotion_order = settings.promotion_order.next(),
+                    5 => settings.broadcast = settings.broadcast.next(),
+                    6 => settings.touch_move = !settings.touch_move,
+                    7 => settings.snap_cursor = !settings.snap_cursor,
+                    8 => settings.auto_flip = !settings.auto_flip,
+                    9 => {
+                        let now = board.rtc.now();
+                        board.rtc.set_time_of_day((now.hour + 1) % 24, now.minute);
+                    }
+                    10 => {
+                        let now = board.rtc.now();
+                        board.rtc.set_time_of_day(now.hour, (now.minute + 1) % 60);
+                    }
+                    _ => {
+                        settings.pack = packs::next_after(board.flash.packs(), settings.pack);
+                        pieces::use_set(settings.pack.and_then(|pack| pack.pieces));
+                    }
+                }
+                dirty = true;
             }
+            Some(MenuEvent::Select) => return,
+            _ => {}
         }
         board.delay.ms(50);
     }
 }
 
+fn render_settings(board: &mut Board, settings: &Settings, selected: usize) {
+    // 十二个条目加提示行，行距取 18、自 36 起才放得进 272 像素高的屏幕；配色预览放在条目右侧。
+    board.lcd.clear(BG);
+    let x = 8;
+    menu::draw_title(&mut board.lcd, "Settings", x, 6);
+
+    let mut clock_buf = [0u8; 8];
+    let clock = fmt::format_hms(board.rtc.now().seconds_of_day(), &mut clock_buf);
+    let entries = [
+        ("Theme", settings.theme.label()),
+        ("Labels", settings.labels.label()),
+        ("Blink", if settings.blink_cursor { "On" } else { "Off" }),
+        (
+            "Motion",
+            if settings.reduced_motion {
+                "Reduced"
+            } else {
+                "Full"
+            },
+        ),
+        ("Promotion", settings.promotion_order.label()),
+        ("Broadcast", settings.broadcast.label()),
+        ("Touch", if settings.touch_move { "On" } else { "Off" }),
+        ("Snap", if settings.snap_cursor { "On" } else { "Off" }),
+        ("Flip", if settings.auto_flip { "Auto" } else { "Off" }),
+        ("Hour", &clock[0..2]),
+        ("Minute", &clock[3..5]),
+        ("Pack", settings.pack.map_or("Off", |pack| pack.name)),
+    ];
+    let start_y = 36u16;
+    menu::draw_rows(&mut board.lcd, &entries, x, start_y, 160, selected);
+
+    // 配色预览：浅格/深格/光标/上一步/升变竖排在右侧，各放一枚棋子验证可读性。
+    let theme = settings.theme();
+    let preview_x = 480 - x - chessboard::SQUARE_SIZE;
+    let swatches = [
+        theme.light_square,
+        theme.dark_square,
+        theme.cursor,
+        theme.last_move,
+        theme.promotion,
+    ];
+    for (i, color) in swatches.iter().enumerate() {
+        let sy = start_y + i as u16 * chessboard::SQUARE_SIZE;
+        board.lcd.fill_rect(
+            preview_x,
+            sy,
+            chessboard::SQUARE_SIZE,
+            chessboard::SQUARE_SIZE,
+            *color,
+        );
+        let piece_color = if i % 2 == 0 {
+            Color::White
+        } else {
+            Color::Black
+        };
+        pieces::draw_piece_icon(
+            &mut board.lcd,
+            theme,
+            PieceKind::Knight,
+            piece_color,
+            preview_x + (chessboard::SQUARE_SIZE - pieces::SPRITE_W as u16) / 2,
+            sy + (chessboard::SQUARE_SIZE - pieces::SPRITE_H as u16) / 2,
+            None,
+        );
+    }
+
+    menu::draw_hint(
+        &mut board.lcd,
+        "KEY3 Up  KEY2 Down  KEY4 Change  KEY1 Back",
+        x,
+        start_y + entries.len() as u16 * ENTRY_SPACING + 6,
+    );
+}
+
 fn render_menu(board: &mut Board, selected: usize) {
     board.lcd.clear(BG);
     let left_width = compute_left_pane_width(board);
     let start_x = left_width.saturating_add(10);
-    draw_title_and_crab(board, left_width);
+    menu::draw_title(&mut board.lcd, "rhess", 8, 6);
     draw_options(board, start_x, selected);
 }
 
+/// 在右上角绘制 "HH:MM"。
+fn draw_time_of_day(board: &mut Board, seconds_of_day: u32) {
+    let mut buf = [0u8; 8];
+    let hm = &fmt::format_hms(seconds_of_day, &mut buf)[..5];
+    let x = board.lcd.width.saturating_sub(5 * 12 + 6);
+    text::draw_text_scaled(&mut board.lcd, hm, x, 6, FG, Some(BG), 2);
+}
+
 fn compute_left_pane_width(board: &Board) -> u16 {
     let total = board.lcd.width;
     let mut left = ((total as u32 * 48) / 100) as u16; // 稍微让出空间给右侧文字
@@ -75,16 +231,12 @@ fn compute_left_pane_width(board: &Board) -> u16 {
     left
 }
 
-fn draw_title_and_crab(board: &mut Board, left_width: u16) {
-    let x = 8;
-    let y = 6;
-    text::draw_text_scaled(&mut board.lcd, "rhess", x, y, TITLE_COLOR, Some(BG), 3);
-
+/// 左栏居中的螃蟹，菜单空闲时上下浮动（见 `start_menu_crab::MENU`）。
+fn crab_animation(board: &Board) -> Animation {
+    let left_width = compute_left_pane_width(board);
     let crab_x = (left_width.saturating_sub(CRAB_W)) / 2;
     let crab_y = (chessboard::BOARD_SIZE.saturating_sub(CRAB_H)) / 2;
-    board
-        .lcd
-        .blit_bitmap(crab_x, crab_y, CRAB_W, CRAB_H, &CRAB_BITMAP);
+    Animation::new(&start_menu_crab::MENU, crab_x, crab_y - MENU_BOB, BG)
 }
 
 fn draw_options(board: &mut Board, start_x: u16, selected: usize) {
@@ -98,34 +250,19 @@ fn draw_options(board: &mut Board, start_x: u16, selected: usize) {
         Some(BG),
         2,
     );
-    let entries = [
-        "Human vs Human",
-        "Human vs Computer",
-        "Computer vs Human",
-        "Computer vs Computer",
-    ];
-    for (i, label) in entries.iter().enumerate() {
-        let y = start_y + i as u16 * 36;
-        let arrow = if i == selected { ">" } else { " " };
-        text::draw_text_scaled(&mut board.lcd, arrow, start_x, y, HIGHLIGHT, Some(BG), 2);
-        text::draw_text_scaled(&mut board.lcd, label, start_x + 12, y, FG, Some(BG), 2);
+    let mut rows = [("", ""); MENU_ENTRY_COUNT];
+    for (row, (label, _)) in rows.iter_mut().zip(MODES.iter()) {
+        *row = (label, "");
     }
-    text::draw_text_scaled(
+    rows[SLEEP_ENTRY] = ("Sleep", "");
+    menu::draw_rows(&mut board.lcd, &rows, start_x, start_y, 0, selected);
+    // 提示紧跟在最后一个条目之下，两行 1 倍字号。
+    let help_y = start_y + MENU_ENTRY_COUNT as u16 * ENTRY_SPACING + 2;
+    menu::draw_hint(&mut board.lcd, "KEY3 Up  KEY2 Down", start_x, help_y);
+    menu::draw_hint(
         &mut board.lcd,
-        "KEY3 Up  KEY2 Down",
+        "KEY1 Start  KEY4 Settings",
         start_x,
-        start_y + 160,
-        FG,
-        Some(BG),
-        1,
-    );
-    text::draw_text_scaled(
-        &mut board.lcd,
-        "KEY1 Start",
-        start_x,
-        start_y + 176,
-        FG,
-        Some(BG),
-        1,
+        help_y + 12,
     );
 }
@@ -0,0 +1,192 @@
+//! 战绩页：人类对 AI 的等级分、AI 的名义等级分（按各计分对局存档设置中的强度取平均）、
+//! 计分对局胜/和/负、存档中计分对局的等级分走势图，以及快速对弈的累计比分（不进存档，
+//! 取自备份 SRAM，见 `game::playout_tally`）。KEY1 返回启动菜单。
+
+use crate::board::Board;
+use crate::game::archive::{self, SavedGame};
+use crate::game::playout_tally;
+use crate::interaction::{Action, InputMapper, InputMode};
+use crate::settings::Settings;
+use crate::ui::theme::Theme;
+use crate::ui::{fmt, text};
+use rhess_core::ai::AiConfig;
+use rhess_core::rating::{self, GameScore};
+
+/// 走势图最多显示的对局数（存档区总槽数）。
+const HISTORY_LEN: usize = 128;
+const GRAPH_X: u16 = 8;
+const GRAPH_Y: u16 = 112;
+const GRAPH_W: u16 = 464;
+const GRAPH_H: u16 = 112;
+/// 纵轴至少覆盖的分差，避免几局小幅波动被放大成锯齿。
+const MIN_SPAN: u16 = 100;
+
+pub fn run(board: &mut Board, settings: Settings) {
+    let mut games = [None; HISTORY_LEN];
+    let count = archive::rated_games(&board.flash, &mut games);
+    let playouts = playout_tally::load(&board.backup);
+    render(board, settings.theme(), &games[..count], playouts);
+    let input = InputMapper::new(InputMode::Menu);
+    loop {
+        if let Some(Action::MenuSelect) = input.poll(board) {
+            return;
+        }
+        board.delay.ms(50);
+    }
+}
+
+fn render(board: &mut Board, theme: &Theme, games: &[Option<SavedGame>], playouts: [u32; 3]) {
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    board.lcd.clear(bg);
+    let x = 8;
+    text::draw_text_scaled(&mut board.lcd, "Stats", x, 6, fg, Some(bg), 3);
+
+    // `rated_games` 只返回计分对局，`rated` 必为 Some。
+    let mut ratings = [0u16; HISTORY_LEN];
+    let (mut wins, mut draws, mut losses) = (0, 0, 0);
+    let mut engine_total = 0u32;
+    let rated = games
+        .iter()
+        .flatten()
+        .filter_map(|g| Some((g.result, g.rated?, g.setup)));
+    for (i, (result, rated, setup)) in rated.enumerate() {
+        ratings[i] = rated.rating;
+        // 旧格式存档没有对局设置，按内置强度计。
+        let ai = setup.map_or_else(AiConfig::default, |setup| setup.ai);
+        engine_total += rating::engine_rating(&ai) as u32;
+        match result.score_for(rated.human) {
+            GameScore::Win => wins += 1,
+            GameScore::Draw => draws += 1,
+            GameScore::Loss => losses += 1,
+        }
+    }
+    let ratings = &ratings[..games.len()];
+
+    let current = ratings.last().copied().unwrap_or(rating::INITIAL_RATING);
+    let engine = match ratings.len() as u32 {
+        0 => rating::engine_rating(&AiConfig::default()) as u32,
+        n => (engine_total + n / 2) / n,
+    };
+    let mut bufs = [[0u8; 10]; 5];
+    let [b0, b1, b2, b3, b4] = &mut bufs;
+    draw_row(
+        board,
+        theme,
+        0,
+        "Rating",
+        &[fmt::u32_to_str(current as u32, b0)],
+    );
+    draw_row(board, theme, 1, "Computer", &[fmt::u32_to_str(engine, b1)]);
+    let record = [
+        fmt::u32_to_str(wins, b2),
+        "/",
+        fmt::u32_to_str(draws, b3),
+        "/",
+        fmt::u32_to_str(losses, b4),
+    ];
+    draw_row(board, theme, 2, "W/D/L", &record);
+
+    draw_graph(board, theme, ratings);
+
+    text::draw_text_scaled(
+        &mut board.lcd,
+        "Rated: vs Computer from the start position",
+        x,
+        GRAPH_Y + GRAPH_H + 12,
+        fg,
+        Some(bg),
+        1,
+    );
+    text::draw_text_scaled(
+        &mut board.lcd,
+        "KEY1 Back",
+        x,
+        GRAPH_Y + GRAPH_H + 28,
+        fg,
+        Some(bg),
+        1,
+    );
+    if playouts != [0; 3] {
+        let mut bufs = [[0u8; 10]; 3];
+        let [b0, b1, b2] = &mut bufs;
+        let [white, draws, black] = playouts;
+        let parts = [
+            "Fast playout (W/D/B): ",
+            fmt::u32_to_str(white, b0),
+            "/",
+            fmt::u32_to_str(draws, b1),
+            "/",
+            fmt::u32_to_str(black, b2),
+        ];
+        let mut x = x + 120;
+        for part in parts {
+            text::draw_text_scaled(
+                &mut board.lcd,
+                part,
+                x,
+                GRAPH_Y + GRAPH_H + 28,
+                fg,
+                Some(bg),
+                1,
+            );
+            x += part.len() as u16 * 6;
+        }
+    }
+}
+
+/// 第 `row` 行：左侧标签，右侧依次拼接 `parts`。
+fn draw_row(board: &mut Board, theme: &Theme, row: u16, label: &str, parts: &[&str]) {
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    let y = 44 + row * 20;
+    text::draw_text_scaled(&mut board.lcd, label, 8, y, fg, Some(bg), 2);
+    let mut x = 140;
+    for part in parts {
+        text::draw_text_scaled(&mut board.lcd, part, x, y, fg, Some(bg), 2);
+        x += part.len() as u16 * 12;
+    }
+}
+
+/// 等级分走势：边框内按对局先后画阶梯折线，左上/左下角标出纵轴上下限。
+fn draw_graph(board: &mut Board, theme: &Theme, ratings: &[u16]) {
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    let lcd = &mut board.lcd;
+    lcd.fill_rect(GRAPH_X, GRAPH_Y, GRAPH_W, 1, fg);
+    lcd.fill_rect(GRAPH_X, GRAPH_Y + GRAPH_H - 1, GRAPH_W, 1, fg);
+    lcd.fill_rect(GRAPH_X, GRAPH_Y, 1, GRAPH_H, fg);
+    lcd.fill_rect(GRAPH_X + GRAPH_W - 1, GRAPH_Y, 1, GRAPH_H, fg);
+    if ratings.is_empty() {
+        let y = GRAPH_Y + GRAPH_H / 2 - 8;
+        text::draw_text_scaled(lcd, "No rated games yet", GRAPH_X + 12, y, fg, Some(bg), 2);
+        return;
+    }
+
+    let lo = ratings.iter().copied().fold(u16::MAX, u16::min);
+    let hi = ratings.iter().copied().fold(0, u16::max);
+    let pad = MIN_SPAN.saturating_sub(hi - lo) / 2;
+    let (lo, hi) = (lo.saturating_sub(pad), hi + pad);
+    let span = (hi - lo).max(1) as u32;
+    let plot_h = (GRAPH_H - 6) as u32;
+    let y_of = |r: u16| GRAPH_Y + 3 + (plot_h - (r - lo) as u32 * plot_h / span) as u16;
+    let plot_w = (GRAPH_W - 6) as u32;
+    // 存档轮转后最早的对局未必从初始分起步，因此从第一局的赛后分画起，每局一段。
+    let steps = ratings.len() as u32;
+    let x_of = |i: u32| GRAPH_X + 3 + (i * plot_w / steps) as u16;
+
+    let line = theme.ui_alert;
+    let mut prev_y = y_of(ratings[0]);
+    for (i, &r) in ratings.iter().enumerate() {
+        let (x0, x1) = (x_of(i as u32), x_of(i as u32 + 1));
+        let y = y_of(r);
+        lcd.fill_rect(x0, prev_y.min(y), 2, prev_y.abs_diff(y) + 2, line);
+        lcd.fill_rect(x0, y, x1 - x0 + 2, 2, line);
+        prev_y = y;
+    }
+
+    let mut buf = [0u8; 10];
+    let top = fmt::u32_to_str(hi as u32, &mut buf);
+    text::draw_text_scaled(lcd, top, GRAPH_X + 4, GRAPH_Y + 4, fg, None, 1);
+    let mut buf = [0u8; 10];
+    let bottom = fmt::u32_to_str(lo as u32, &mut buf);
+    let y = GRAPH_Y + GRAPH_H - 12;
+    text::draw_text_scaled(lcd, bottom, GRAPH_X + 4, y, fg, None, 1);
+}
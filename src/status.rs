@@ -0,0 +1,124 @@
+//! 状态消息队列：对局、AI 与板级代码推送短消息（如 "illegal move"、"AI: wins material"），
+//! 由界面在固定的状态行上依次显示，每条停留各自的时长。
+//!
+//! 全部定长存储：最多排队 `QUEUE_CAP` 条，每条最多 `MESSAGE_LEN` 字节（超出在字符边界截断）；
+//! 队列满时丢弃最早的待显示消息。当前消息的停留时间由 `UI_COUNTDOWN` 在中断里倒数。
+
+use crate::drivers::timebase::UI_COUNTDOWN;
+use crate::sync::MainLoop;
+use core::fmt;
+
+const QUEUE_CAP: usize = 4;
+/// 单条消息的最大字节数，约为侧栏一行（scale 1）能放下的字符数。
+pub const MESSAGE_LEN: usize = 32;
+
+#[derive(Clone, Copy)]
+struct Message {
+    text: [u8; MESSAGE_LEN],
+    len: usize,
+    duration_ms: u32,
+}
+
+impl Message {
+    const EMPTY: Message = Message {
+        text: [0; MESSAGE_LEN],
+        len: 0,
+        duration_ms: 0,
+    };
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.text[..self.len]).unwrap_or("")
+    }
+}
+
+impl fmt::Write for Message {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for ch in s.chars() {
+            let mut buf = [0u8; 4];
+            let bytes = ch.encode_utf8(&mut buf).as_bytes();
+            if self.len + bytes.len() > MESSAGE_LEN {
+                // 截断不算错误，以免格式化提前中止后留下半截参数。
+                return Ok(());
+            }
+            self.text[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+            self.len += bytes.len();
+        }
+        Ok(())
+    }
+}
+
+pub struct StatusQueue {
+    pending: [Message; QUEUE_CAP],
+    /// 最早一条待显示消息的位置与待显示条数。
+    head: usize,
+    count: usize,
+    /// 正在显示的消息（已出队）。
+    current: Option<Message>,
+}
+
+impl StatusQueue {
+    pub const fn new() -> Self {
+        StatusQueue {
+            pending: [Message::EMPTY; QUEUE_CAP],
+            head: 0,
+            count: 0,
+            current: None,
+        }
+    }
+
+    /// 追加一条显示 `duration_ms` 的消息。
+    pub fn push(&mut self, text: &str, duration_ms: u32) {
+        self.push_fmt(format_args!("{}", text), duration_ms);
+    }
+
+    /// 同 [`push`](Self::push)，文字由 `format_args!` 给出，直接写入定长缓冲。
+    pub fn push_fmt(&mut self, args: fmt::Arguments, duration_ms: u32) {
+        let mut message = Message {
+            duration_ms,
+            ..Message::EMPTY
+        };
+        let _ = fmt::write(&mut message, args);
+        if self.count == QUEUE_CAP {
+            self.head = (self.head + 1) % QUEUE_CAP;
+            self.count -= 1;
+        }
+        self.pending[(self.head + self.count) % QUEUE_CAP] = message;
+        self.count += 1;
+    }
+
+    /// 当前消息到期后换上下一条；显示内容有变化时返回 true，调用方据此重绘状态行。
+    pub fn update(&mut self, main: &MainLoop) -> bool {
+        if self.current.is_some() && !UI_COUNTDOWN.expired() {
+            return false;
+        }
+        let next = (self.count > 0).then(|| {
+            let message = self.pending[self.head];
+            self.head = (self.head + 1) % QUEUE_CAP;
+            self.count -= 1;
+            message
+        });
+        let changed = self.current.is_some() || next.is_some();
+        self.current = next;
+        if let Some(message) = next {
+            UI_COUNTDOWN.start(main, message.duration_ms);
+        }
+        changed
+    }
+
+    /// 丢弃正在显示与排队中的全部消息。
+    pub fn clear(&mut self, main: &MainLoop) {
+        self.count = 0;
+        self.current = None;
+        UI_COUNTDOWN.stop(main);
+    }
+
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_ref().map(Message::as_str)
+    }
+}
+
+impl Default for StatusQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
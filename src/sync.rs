@@ -0,0 +1,268 @@
+//! 中断与主循环之间的共享状态，以及两种执行上下文的分工。
+//!
+//! 中断上下文只做常数时间的小操作：时基计数、倒计时递减、串口接收字节与按键电平变化入队；
+//! 渲染、搜索、串口命令等耗时工作一律留在主循环。分工由类型保证：
+//! - 只应在中断里调用的操作要求 [`Isr`] 凭据，它只能在中断处理函数开头以 `unsafe` 构造；
+//! - 只应在主循环调用的操作要求 [`MainLoop`] 凭据，整个程序只能取得一次（由 `Board` 持有）；
+//! - 两种凭据都不是 `Send`/`Sync`，无法存进静态变量或带出所在上下文。
+//!
+//! 读取对两边都开放。共享数据全部基于 `AtomicU32` 等原子类型，无需关中断。
+//!
+//! 中断优先级（数值越小越高，STM32F4 只用高 4 位）：
+//! - TIM3 1 kHz 时基 [`priority::TIMEBASE`]：处理函数极短，可抢占日后加入的其他中断。
+//! - TIM2 100 Hz 棋钟 [`priority::CHESS_CLOCK`]：只递减走钟一方的倒计时（见 `crate::clock`）。
+//! - TIM4 100 Hz AI 看门狗 [`priority::AI_WATCHDOG`]：只递减搜索倒计时、到时置停止标志（见
+//!   `crate::ai_watchdog`）。
+//! - USART1 接收 [`priority::SERIAL_RX`]：每字节一次，只读寄存器并入队；115200 波特下
+//!   字节间隔约 87 µs，被时基抢占也不会错过下一个字节。
+//! - 按键 EXTI [`priority::KEYS`]：按下/松开各一次（抖动时多几次），只读引脚并入队。
+//! - LCD 事务的临界区会短暂屏蔽全部中断；单个事务远小于 1 ms，时基的挂起标志保证节拍不丢。
+
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, Ordering};
+
+/// NVIC 优先级分配。
+pub mod priority {
+    /// 1 kHz 时基中断。
+    pub const TIMEBASE: u8 = 0x40;
+    /// 100 Hz 棋钟中断。
+    pub const CHESS_CLOCK: u8 = 0x60;
+    /// 100 Hz AI 思考看门狗中断。
+    pub const AI_WATCHDOG: u8 = 0x60;
+    /// 串口接收中断。
+    pub const SERIAL_RX: u8 = 0x80;
+    /// 按键电平变化（EXTI）中断。
+    pub const KEYS: u8 = 0x80;
+}
+
+/// 中断上下文凭据。
+pub struct Isr {
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Isr {
+    /// # Safety
+    /// 只能在中断处理函数内调用，且凭据不得流出该函数。
+    pub unsafe fn enter() -> Self {
+        Isr {
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// 主循环凭据。
+pub struct MainLoop {
+    _not_send: PhantomData<*const ()>,
+}
+
+static MAIN_TAKEN: AtomicBool = AtomicBool::new(false);
+
+impl MainLoop {
+    /// 取得主循环凭据；第二次调用返回 None。
+    pub fn take() -> Option<Self> {
+        (!MAIN_TAKEN.swap(true, Ordering::AcqRel)).then_some(MainLoop {
+            _not_send: PhantomData,
+        })
+    }
+}
+
+/// 只由中断递增的计数器（如毫秒节拍），任意上下文可读。
+pub struct TickCounter {
+    ticks: AtomicU32,
+}
+
+impl TickCounter {
+    pub const fn new() -> Self {
+        TickCounter {
+            ticks: AtomicU32::new(0),
+        }
+    }
+
+    pub fn tick(&self, _: &Isr) {
+        // 只有一个写者（中断），读-改-写无需 CAS。
+        let next = self.ticks.load(Ordering::Relaxed).wrapping_add(1);
+        self.ticks.store(next, Ordering::Release);
+    }
+
+    pub fn get(&self) -> u32 {
+        self.ticks.load(Ordering::Acquire)
+    }
+}
+
+impl Default for TickCounter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 毫秒倒计时：主循环设定/暂停，中断逐毫秒递减到 0 为止。
+pub struct Countdown {
+    remaining_ms: AtomicU32,
+    running: AtomicBool,
+}
+
+impl Countdown {
+    pub const fn new() -> Self {
+        Countdown {
+            remaining_ms: AtomicU32::new(0),
+            running: AtomicBool::new(false),
+        }
+    }
+
+    /// 从 `ms` 开始倒数。
+    pub fn start(&self, _: &MainLoop, ms: u32) {
+        self.running.store(false, Ordering::Release);
+        self.remaining_ms.store(ms, Ordering::Release);
+        self.running.store(true, Ordering::Release);
+    }
+
+    /// 停止倒数，剩余时间保持不变。
+    pub fn stop(&self, _: &MainLoop) {
+        self.running.store(false, Ordering::Release);
+    }
+
+    /// 从停下时的剩余时间接着倒数。
+    pub fn resume(&self, _: &MainLoop) {
+        self.running.store(true, Ordering::Release);
+    }
+
+    /// 停止倒数并把剩余时间改为 `ms`（如棋钟加秒）。
+    pub fn stop_at(&self, _: &MainLoop, ms: u32) {
+        self.running.store(false, Ordering::Release);
+        self.remaining_ms.store(ms, Ordering::Release);
+    }
+
+    /// 中断中调用：运行时减去 `elapsed_ms`，到 0 为止。
+    pub fn tick(&self, _: &Isr, elapsed_ms: u32) {
+        if self.running.load(Ordering::Acquire) {
+            let left = self.remaining_ms.load(Ordering::Relaxed);
+            self.remaining_ms
+                .store(left.saturating_sub(elapsed_ms), Ordering::Release);
+        }
+    }
+
+    pub fn remaining_ms(&self) -> u32 {
+        self.remaining_ms.load(Ordering::Acquire)
+    }
+
+    pub fn expired(&self) -> bool {
+        self.remaining_ms() == 0
+    }
+}
+
+impl Default for Countdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 中断写入、主循环读出的字节环形队列（单生产者单消费者）。队列满时丢弃新到的字节并计数，
+/// 已入队的数据不受影响。容量须为 2 的幂，读写序号回绕时取模仍然连续。
+pub struct ByteQueue<const N: usize> {
+    slots: [AtomicU8; N],
+    /// 累计写入的字节数，只由中断推进。
+    head: AtomicU32,
+    /// 累计读出的字节数，只由主循环推进。
+    tail: AtomicU32,
+    dropped: AtomicU32,
+}
+
+impl<const N: usize> ByteQueue<N> {
+    const POWER_OF_TWO: () = assert!(N.is_power_of_two());
+
+    pub const fn new() -> Self {
+        let () = Self::POWER_OF_TWO;
+        ByteQueue {
+            slots: [const { AtomicU8::new(0) }; N],
+            head: AtomicU32::new(0),
+            tail: AtomicU32::new(0),
+            dropped: AtomicU32::new(0),
+        }
+    }
+
+    /// 中断中调用：入队一个字节；队列已满时丢弃并计数，返回 false。
+    pub fn push(&self, _: &Isr, byte: u8) -> bool {
+        let head = self.head.load(Ordering::Relaxed);
+        if head.wrapping_sub(self.tail.load(Ordering::Acquire)) as usize >= N {
+            // 与 `TickCounter` 一样只有中断写，读-改-写无需 CAS。
+            let dropped = self.dropped.load(Ordering::Relaxed).wrapping_add(1);
+            self.dropped.store(dropped, Ordering::Release);
+            return false;
+        }
+        self.slots[head as usize % N].store(byte, Ordering::Relaxed);
+        // Release：主循环看到新的 head 时，槽内字节一定已写好。
+        self.head.store(head.wrapping_add(1), Ordering::Release);
+        true
+    }
+
+    /// 取出最早的一个字节；队列为空时返回 None。
+    pub fn pop(&self, _: &MainLoop) -> Option<u8> {
+        let tail = self.tail.load(Ordering::Relaxed);
+        if self.head.load(Ordering::Acquire) == tail {
+            return None;
+        }
+        let byte = self.slots[tail as usize % N].load(Ordering::Relaxed);
+        // Release：槽读完才让中断复用它。
+        self.tail.store(tail.wrapping_add(1), Ordering::Release);
+        Some(byte)
+    }
+
+    /// 因队列满而丢弃的字节数。
+    pub fn dropped(&self) -> u32 {
+        self.dropped.load(Ordering::Acquire)
+    }
+}
+
+impl<const N: usize> Default for ByteQueue<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 与 [`ByteQueue`] 相同的单生产者单消费者队列，每项为一个 32 位字（如带时间戳的按键事件）。
+pub struct WordQueue<const N: usize> {
+    slots: [AtomicU32; N],
+    head: AtomicU32,
+    tail: AtomicU32,
+}
+
+impl<const N: usize> WordQueue<N> {
+    const POWER_OF_TWO: () = assert!(N.is_power_of_two());
+
+    pub const fn new() -> Self {
+        let () = Self::POWER_OF_TWO;
+        WordQueue {
+            slots: [const { AtomicU32::new(0) }; N],
+            head: AtomicU32::new(0),
+            tail: AtomicU32::new(0),
+        }
+    }
+
+    /// 中断中调用：入队一个字；队列已满时丢弃，返回 false。
+    pub fn push(&self, _: &Isr, word: u32) -> bool {
+        let head = self.head.load(Ordering::Relaxed);
+        if head.wrapping_sub(self.tail.load(Ordering::Acquire)) as usize >= N {
+            return false;
+        }
+        self.slots[head as usize % N].store(word, Ordering::Relaxed);
+        self.head.store(head.wrapping_add(1), Ordering::Release);
+        true
+    }
+
+    /// 取出最早的一个字；队列为空时返回 None。
+    pub fn pop(&self, _: &MainLoop) -> Option<u32> {
+        let tail = self.tail.load(Ordering::Relaxed);
+        if self.head.load(Ordering::Acquire) == tail {
+            return None;
+        }
+        let word = self.slots[tail as usize % N].load(Ordering::Relaxed);
+        self.tail.store(tail.wrapping_add(1), Ordering::Release);
+        Some(word)
+    }
+}
+
+impl<const N: usize> Default for WordQueue<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
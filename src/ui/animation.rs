@@ -0,0 +1,105 @@
+//! 精灵动画：按帧序列定时切换位图与位置，只在帧变化时重画。换帧时只擦去旧帧露出、
+//! 新帧又盖不住的边条，不整块擦除，大图移动也不闪。减弱动效时停在第一帧。
+
+use crate::drivers::lcd::{ClipRect, Lcd};
+use crate::drivers::timebase::Timebase;
+
+/// 一帧：RGB565 位图、相对动画原点的偏移与停留时长。
+#[derive(Clone, Copy)]
+pub struct Frame {
+    pub bitmap: &'static [u16],
+    pub width: u16,
+    pub height: u16,
+    pub dx: u16,
+    pub dy: u16,
+    pub hold_ms: u32,
+}
+
+impl Frame {
+    fn rect(&self, x: u16, y: u16) -> ClipRect {
+        ClipRect::new(x + self.dx, y + self.dy, self.width, self.height)
+    }
+}
+
+pub struct Animation {
+    frames: &'static [Frame],
+    x: u16,
+    y: u16,
+    bg: u16,
+    current: usize,
+    since_ms: u32,
+    /// 屏幕上画着的帧；None 表示没画或已被覆盖，下次 `update` 整帧重画。
+    drawn: Option<usize>,
+}
+
+impl Animation {
+    /// 原点在 (`x`, `y`) 的动画，帧外露出的部分用 `bg` 擦除。
+    pub const fn new(frames: &'static [Frame], x: u16, y: u16, bg: u16) -> Self {
+        Animation {
+            frames,
+            x,
+            y,
+            bg,
+            current: 0,
+            since_ms: 0,
+            drawn: None,
+        }
+    }
+
+    /// 从第一帧重新开始计时。
+    pub fn restart(&mut self, timebase: &Timebase) {
+        self.current = 0;
+        self.since_ms = timebase.now_ms();
+        self.drawn = None;
+    }
+
+    /// 屏幕被整体重绘或清空后调用。
+    pub fn invalidate(&mut self) {
+        self.drawn = None;
+    }
+
+    /// 当前帧停留到时则换到下一帧；帧有变化（或被覆盖过）时重画。
+    pub fn update(&mut self, lcd: &mut Lcd, timebase: &Timebase) {
+        if self.frames.is_empty() {
+            return;
+        }
+        if !timebase.motion_enabled() {
+            self.current = 0;
+        } else if timebase.elapsed_ms(self.since_ms) >= self.frames[self.current].hold_ms {
+            self.current = (self.current + 1) % self.frames.len();
+            self.since_ms = timebase.now_ms();
+        }
+        if self.drawn == Some(self.current) {
+            return;
+        }
+        let frame = self.frames[self.current];
+        let new = frame.rect(self.x, self.y);
+        if let Some(old) = self.drawn {
+            erase_exposed(lcd, self.frames[old].rect(self.x, self.y), new, self.bg);
+        }
+        lcd.blit_bitmap(new.x, new.y, new.width, new.height, frame.bitmap);
+        self.drawn = Some(self.current);
+    }
+
+    /// 擦掉屏幕上的帧，如思考结束时。
+    pub fn clear(&mut self, lcd: &mut Lcd) {
+        if let Some(old) = self.drawn.take() {
+            let r = self.frames[old].rect(self.x, self.y);
+            lcd.fill_rect(r.x, r.y, r.width, r.height, self.bg);
+        }
+    }
+}
+
+/// 擦除 `old` 中不被 `new` 覆盖的部分：上下两条整宽，左右两条限于重叠的行。
+fn erase_exposed(lcd: &mut Lcd, old: ClipRect, new: ClipRect, bg: u16) {
+    let (old_bottom, new_bottom) = (old.y + old.height, new.y + new.height);
+    let (old_right, new_right) = (old.x + old.width, new.x + new.width);
+    let top = new.y.clamp(old.y, old_bottom);
+    let bottom = new_bottom.clamp(top, old_bottom);
+    lcd.fill_rect(old.x, old.y, old.width, top - old.y, bg);
+    lcd.fill_rect(old.x, bottom, old.width, old_bottom - bottom, bg);
+    let left = new.x.clamp(old.x, old_right);
+    let right = new_right.clamp(left, old_right);
+    lcd.fill_rect(old.x, top, left - old.x, bottom - top, bg);
+    lcd.fill_rect(right, top, old_right - right, bottom - top, bg);
+}
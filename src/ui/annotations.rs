@@ -0,0 +1,228 @@
+//! 串口批注：教练或外接引擎 GUI 经 USART1 在棋盘上画的箭头与方格标记（见 `console`
+//! 的 `arrow`/`mark`/`clear-marks` 命令）。批注只是叠加层，不改变局面，走子后仍保留，
+//! 直到 `clear-marks` 或重新摆局面。
+//!
+//! 箭头跨越多格，但画面仍按格重画（见 `SquareCache`）：每格只画落在本格内的那一段，
+//! 所以光标移动等局部重绘不会把箭头擦断。箭头是否经过某格与棋盘朝向无关（翻转是
+//! 整盘旋转 180°），只有逐像素绘制时才按朝向换算起止点。
+
+use crate::drivers::lcd::Lcd;
+use crate::ui::chessboard::SQUARE_SIZE;
+
+/// 同时显示的箭头上限；每支箭头在 `SquareLook::arrows` 中占一位。
+pub const MAX_ARROWS: usize = 8;
+/// 箭杆半宽、箭头长度与箭头半宽（像素）。
+const SHAFT_HALF_WIDTH: i32 = 3;
+const HEAD_LENGTH: i32 = 14;
+const HEAD_HALF_WIDTH: i32 = 9;
+/// 方格标记的边框宽度。
+pub const MARK_BORDER: u16 = 3;
+
+/// 批注颜色，按名字键入；固定取色，不随配色方案变化，与教练口头说的颜色一致。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MarkColor {
+    Green,
+    Red,
+    Blue,
+    Yellow,
+}
+
+impl MarkColor {
+    pub fn parse(name: &str) -> Option<MarkColor> {
+        match name {
+            "green" | "g" => Some(MarkColor::Green),
+            "red" | "r" => Some(MarkColor::Red),
+            "blue" | "b" => Some(MarkColor::Blue),
+            "yellow" | "y" => Some(MarkColor::Yellow),
+            _ => None,
+        }
+    }
+
+    pub fn rgb565(self) -> u16 {
+        match self {
+            MarkColor::Green => 0x0660,
+            MarkColor::Red => 0xD000,
+            MarkColor::Blue => 0x0318,
+            MarkColor::Yellow => 0xFE60,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Arrow {
+    pub from: u8,
+    pub to: u8,
+    pub color: MarkColor,
+}
+
+impl Arrow {
+    /// 起止方格中心的屏幕坐标；`flipped` 时棋盘翻转，第 `idx` 格画在第 `63 - idx` 格的位置。
+    fn endpoints(self, flipped: bool) -> ((i32, i32), (i32, i32)) {
+        let place = |idx: u8| square_center(if flipped { 63 - idx } else { idx });
+        (place(self.from), place(self.to))
+    }
+
+    /// 箭头是否可能画进第 `idx` 格：方格中心到箭杆的距离不超过半条对角线加箭头半宽。
+    fn touches(self, idx: u8) -> bool {
+        let ((ax, ay), (bx, by)) = self.endpoints(false);
+        let (qx, qy) = square_center(idx);
+        let (dx, dy) = ((bx - ax) as i64, (by - ay) as i64);
+        let (px, py) = ((qx - ax) as i64, (qy - ay) as i64);
+        let len2 = dx * dx + dy * dy;
+        let t = (px * dx + py * dy).clamp(0, len2);
+        // 最近点相对中心的偏移放大 len2 倍，避免除法误差。
+        let (ex, ey) = (px * len2 - dx * t, py * len2 - dy * t);
+        let reach = (SQUARE_SIZE as i64 * 3 / 4 + HEAD_HALF_WIDTH as i64) * len2;
+        ex * ex + ey * ey <= reach * reach
+    }
+
+    fn shape(self, flipped: bool) -> Shape {
+        let ((ax, ay), (bx, by)) = self.endpoints(flipped);
+        let (dx, dy) = (bx - ax, by - ay);
+        let len2 = dx * dx + dy * dy;
+        Shape {
+            origin: (ax, ay),
+            dir: (dx, dy),
+            len2,
+            len: len2.isqrt(),
+        }
+    }
+}
+
+/// 逐像素判断用的箭头几何：起点、方向向量及其长度，每支箭头每格只算一次。
+struct Shape {
+    origin: (i32, i32),
+    dir: (i32, i32),
+    len2: i32,
+    len: i32,
+}
+
+impl Shape {
+    /// 屏幕像素 (x, y) 是否落在箭头内：箭杆是定宽矩形，末端 `HEAD_LENGTH` 像素为三角形，
+    /// 尖端在目标格中心。
+    fn covers(&self, x: i32, y: i32) -> bool {
+        let (dx, dy) = self.dir;
+        let (px, py) = (x - self.origin.0, y - self.origin.1);
+        // t 为沿箭杆的距离乘 len，side 为离轴线的距离乘 len。
+        let t = px * dx + py * dy;
+        let side = (px * dy - py * dx).abs();
+        if t < 0 || t > self.len2 {
+            return false;
+        }
+        if t <= self.len2 - HEAD_LENGTH * self.len {
+            side <= SHAFT_HALF_WIDTH * self.len
+        } else {
+            side * HEAD_LENGTH <= (self.len2 - t) * HEAD_HALF_WIDTH
+        }
+    }
+}
+
+pub struct Annotations {
+    arrows: [Option<Arrow>; MAX_ARROWS],
+    /// 第 `rank * 8 + file` 项为该格的标记颜色。
+    marks: [Option<MarkColor>; 64],
+}
+
+impl Annotations {
+    pub const fn new() -> Self {
+        Annotations {
+            arrows: [None; MAX_ARROWS],
+            marks: [None; 64],
+        }
+    }
+
+    pub fn clear(&mut self) {
+        *self = Annotations::new();
+    }
+
+    /// 添加箭头；同一对起止格已有箭头时只换颜色。
+    pub fn add_arrow(&mut self, arrow: Arrow) -> Result<(), &'static str> {
+        if arrow.from == arrow.to {
+            return Err("arrow needs two different squares");
+        }
+        let same =
+            |slot: &Option<Arrow>| slot.is_some_and(|a| (a.from, a.to) == (arrow.from, arrow.to));
+        let slot = match self.arrows.iter().position(same) {
+            Some(i) => i,
+            None => self
+                .arrows
+                .iter()
+                .position(Option::is_none)
+                .ok_or("too many arrows, send clear-marks first")?,
+        };
+        self.arrows[slot] = Some(arrow);
+        Ok(())
+    }
+
+    pub fn set_mark(&mut self, square: u8, color: MarkColor) {
+        self.marks[square as usize] = Some(color);
+    }
+
+    pub fn mark(&self, idx: u8) -> Option<MarkColor> {
+        self.marks[idx as usize]
+    }
+
+    /// 可能画进第 `idx` 格的箭头，按槽位成位掩码。槽位只在清空时整体释放，同一位在两次
+    /// 清空之间始终是同一支箭头（换色除外，见 `colors_of`），掩码因此可作方格缓存的键。
+    pub fn arrows_through(&self, idx: u8) -> u8 {
+        self.arrows
+            .iter()
+            .enumerate()
+            .filter(|(_, arrow)| arrow.is_some_and(|a| a.touches(idx)))
+            .fold(0, |mask, (i, _)| mask | 1 << i)
+    }
+
+    /// 掩码中箭头的颜色，两位一支，与掩码一起区分换过颜色的同一支箭头。
+    pub fn colors_of(&self, mask: u8) -> u16 {
+        self.arrows
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| mask & 1 << i != 0)
+            .filter_map(|(i, arrow)| arrow.map(|a| (a.color as u16) << (2 * i)))
+            .fold(0, |bits, color| bits | color)
+    }
+
+    /// 在屏幕方格 (file, rank) 内画出掩码中各箭头落在本格的部分，逐行合并成横条填充；
+    /// `flipped` 为棋盘是否翻转。
+    pub fn draw_arrows(
+        &self,
+        lcd: &mut Lcd,
+        file: u8,
+        rank_from_bottom: u8,
+        mask: u8,
+        flipped: bool,
+    ) {
+        let x0 = file as i32 * SQUARE_SIZE as i32;
+        let y0 = (7 - rank_from_bottom as i32) * SQUARE_SIZE as i32;
+        for (i, arrow) in self.arrows.iter().enumerate() {
+            let Some(arrow) = arrow.filter(|_| mask & 1 << i != 0) else {
+                continue;
+            };
+            let color = arrow.color.rgb565();
+            let shape = arrow.shape(flipped);
+            for y in y0..y0 + SQUARE_SIZE as i32 {
+                let mut run_start = None;
+                for x in x0..=x0 + SQUARE_SIZE as i32 {
+                    let inside = x < x0 + SQUARE_SIZE as i32 && shape.covers(x, y);
+                    match (inside, run_start) {
+                        (true, None) => run_start = Some(x),
+                        (false, Some(start)) => {
+                            lcd.fill_rect(start as u16, y as u16, (x - start) as u16, 1, color);
+                            run_start = None;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn square_center(idx: u8) -> (i32, i32) {
+    let (file, rank) = ((idx % 8) as i32, (idx / 8) as i32);
+    let half = SQUARE_SIZE as i32 / 2;
+    (
+        file * SQUARE_SIZE as i32 + half,
+        (7 - rank) * SQUARE_SIZE as i32 + half,
+    )
+}
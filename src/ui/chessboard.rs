@@ -1,29 +1,41 @@
-use crate::drivers::lcd::Lcd;
+use crate::drivers::lcd::{ClipRect, Lcd};
+use crate::ui::theme::Theme;
+use crate::ui::{pieces, text};
+use rhess_core::Piece;
 
 // 棋盘与方格尺寸（屏幕左侧 272x272 区域，8x8 棋盘）
 pub const BOARD_SIZE: u16 = 272;
 pub const SQUARE_SIZE: u16 = BOARD_SIZE / 8;
+// 角标方块的边长与离方格边缘的距离。
+const MARKER_SIZE: u16 = 10;
+const MARKER_INSET: u16 = 2;
+// 落点提示：空格中央圆点的半径与吃子格四角三角的直角边长。
+const HINT_DOT_RADIUS: i32 = 5;
+const HINT_CORNER: u16 = 9;
 
-// 16-bit RGB565 颜色
-const LIGHT_SQUARE: u16 = 0xC618; // 浅灰
-const DARK_SQUARE: u16 = 0x8410; // 深灰
-pub const HIGHLIGHT_COLOR: u16 = 0xFFE0; // 亮黄
-pub const PROMOTION_COLOR: u16 = 0x07E0; // 绿色用于升变提示
+/// 叠加在方格底色上、棋子之下的落点提示（见 `draw_overlay`）。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SquareOverlay {
+    /// 选中的棋子可走到的空格：中央圆点。
+    Move,
+    /// 可吃子的格（含吃过路兵）：四角三角，不被格中的棋子挡住。
+    Capture,
+}
 
-pub fn draw_board(lcd: &mut Lcd) {
+pub fn draw_board(lcd: &mut Lcd, theme: &Theme) {
     for rank in 0..8 {
         for file in 0..8 {
-            draw_square(lcd, file, rank);
+            draw_square(lcd, theme, file, rank);
         }
     }
 }
 
-pub fn draw_square(lcd: &mut Lcd, file: u8, rank_from_bottom: u8) {
+pub fn draw_square(lcd: &mut Lcd, theme: &Theme, file: u8, rank_from_bottom: u8) {
     draw_square_with_color(
         lcd,
         file,
         rank_from_bottom,
-        square_color(file, rank_from_bottom),
+        square_color(theme, file, rank_from_bottom),
     );
 }
 
@@ -31,16 +43,192 @@ pub fn draw_square_with_color(lcd: &mut Lcd, file: u8, rank_from_bottom: u8, col
     if file >= 8 || rank_from_bottom >= 8 {
         return;
     }
-    let x = file as u16 * SQUARE_SIZE;
+    let r = square_rect(file, rank_from_bottom);
+    lcd.fill_rect(r.x, r.y, r.width, r.height, color);
+}
+
+/// 屏幕方格 (file, rank_from_bottom) 所占的区域。
+pub fn square_rect(file: u8, rank_from_bottom: u8) -> ClipRect {
     // rank_from_bottom=0 在屏幕底部
     let y = (7 - rank_from_bottom as u16) * SQUARE_SIZE;
-    lcd.fill_rect(x, y, SQUARE_SIZE, SQUARE_SIZE, color);
+    ClipRect::new(file as u16 * SQUARE_SIZE, y, SQUARE_SIZE, SQUARE_SIZE)
+}
+
+/// 沿方格内侧描 `thickness` 像素宽的边框（不覆盖方格中部）。
+pub fn draw_square_border(
+    lcd: &mut Lcd,
+    file: u8,
+    rank_from_bottom: u8,
+    thickness: u16,
+    color: u16,
+) {
+    if file >= 8 || rank_from_bottom >= 8 {
+        return;
+    }
+    let x = file as u16 * SQUARE_SIZE;
+    let y = (7 - rank_from_bottom as u16) * SQUARE_SIZE;
+    let t = thickness.min(SQUARE_SIZE / 2);
+    let inner = SQUARE_SIZE - 2 * t;
+    lcd.fill_rect(x, y, SQUARE_SIZE, t, color);
+    lcd.fill_rect(x, y + SQUARE_SIZE - t, SQUARE_SIZE, t, color);
+    lcd.fill_rect(x, y + t, t, inner, color);
+    lcd.fill_rect(x + SQUARE_SIZE - t, y + t, t, inner, color);
+}
+
+/// 方格左上角的小方块标记：`outline` 色外框、`fill` 色填充，叠加在方格与棋子之上。
+pub fn draw_corner_marker(lcd: &mut Lcd, file: u8, rank_from_bottom: u8, fill: u16, outline: u16) {
+    if file >= 8 || rank_from_bottom >= 8 {
+        return;
+    }
+    let x = file as u16 * SQUARE_SIZE + MARKER_INSET;
+    let y = (7 - rank_from_bottom as u16) * SQUARE_SIZE + MARKER_INSET;
+    lcd.fill_rect(x, y, MARKER_SIZE, MARKER_SIZE, outline);
+    lcd.fill_rect(x + 1, y + 1, MARKER_SIZE - 2, MARKER_SIZE - 2, fill);
+}
+
+/// 方格右上角的单字符角标（如升变候选的序号）：`bg` 色底块上画 `fg` 色字符。
+pub fn draw_corner_label(
+    lcd: &mut Lcd,
+    file: u8,
+    rank_from_bottom: u8,
+    label: char,
+    fg: u16,
+    bg: u16,
+) {
+    if file >= 8 || rank_from_bottom >= 8 {
+        return;
+    }
+    let x = (file as u16 + 1) * SQUARE_SIZE - MARKER_INSET - MARKER_SIZE;
+    let y = (7 - rank_from_bottom as u16) * SQUARE_SIZE + MARKER_INSET;
+    lcd.fill_rect(x, y, MARKER_SIZE, MARKER_SIZE, bg);
+    text::draw_char(lcd, label, x + 2, y + 1, fg, None);
+}
+
+/// 画落点提示，取另一种方格色，浅色格上显深、深色格上显浅。
+pub fn draw_overlay(
+    lcd: &mut Lcd,
+    theme: &Theme,
+    file: u8,
+    rank_from_bottom: u8,
+    overlay: SquareOverlay,
+) {
+    if file >= 8 || rank_from_bottom >= 8 {
+        return;
+    }
+    let r = square_rect(file, rank_from_bottom);
+    let color = contrast_color(theme, file, rank_from_bottom);
+    match overlay {
+        SquareOverlay::Move => {
+            let (cx, cy) = (r.x + SQUARE_SIZE / 2, r.y + SQUARE_SIZE / 2);
+            for dy in -HINT_DOT_RADIUS..=HINT_DOT_RADIUS {
+                let half = (HINT_DOT_RADIUS * HINT_DOT_RADIUS - dy * dy).isqrt() as u16;
+                let y = (cy as i32 + dy) as u16;
+                lcd.fill_rect(cx - half, y, 2 * half + 1, 1, color);
+            }
+        }
+        SquareOverlay::Capture => {
+            let (right, bottom) = (r.x + SQUARE_SIZE, r.y + SQUARE_SIZE);
+            for i in 0..HINT_CORNER {
+                let w = HINT_CORNER - i;
+                lcd.fill_rect(r.x, r.y + i, w, 1, color);
+                lcd.fill_rect(right - w, r.y + i, w, 1, color);
+                lcd.fill_rect(r.x, bottom - 1 - i, w, 1, color);
+                lcd.fill_rect(right - w, bottom - 1 - i, w, 1, color);
+            }
+        }
+    }
 }
 
-pub fn square_color(file: u8, rank_from_bottom: u8) -> u16 {
+/// 与本格底色相对的方格色，用于叠加在格上的标注。
+fn contrast_color(theme: &Theme, file: u8, rank_from_bottom: u8) -> u16 {
+    if square_color(theme, file, rank_from_bottom) == theme.light_square {
+        theme.dark_square
+    } else {
+        theme.light_square
+    }
+}
+
+pub fn square_color(theme: &Theme, file: u8, rank_from_bottom: u8) -> u16 {
     if (file + rank_from_bottom) % 2 == 0 {
-        LIGHT_SQUARE
+        theme.light_square
     } else {
-        DARK_SQUARE
+        theme.dark_square
+    }
+}
+
+/// 方格角标：屏幕最左一列方格左上角标横排（`rank_label`），最下一排方格右下角标列字母
+/// （`file_label`；棋盘翻转时两者都由调用方按逻辑方格给出）；颜色取另一种方格色，
+/// 透明背景叠加在已绘制的方格上。
+pub fn draw_coordinates(
+    lcd: &mut Lcd,
+    theme: &Theme,
+    file: u8,
+    rank_from_bottom: u8,
+    rank_label: char,
+    file_label: char,
+) {
+    if file >= 8 || rank_from_bottom >= 8 || (file != 0 && rank_from_bottom != 0) {
+        return;
+    }
+    let x = file as u16 * SQUARE_SIZE;
+    let y = (7 - rank_from_bottom as u16) * SQUARE_SIZE;
+    let color = contrast_color(theme, file, rank_from_bottom);
+    if file == 0 {
+        let mut buf = [0u8; 4];
+        text::draw_text_scaled(
+            lcd,
+            rank_label.encode_utf8(&mut buf),
+            x + 1,
+            y + 1,
+            color,
+            None,
+            1,
+        );
+    }
+    if rank_from_bottom == 0 {
+        text::draw_char(
+            lcd,
+            file_label,
+            x + SQUARE_SIZE - 6,
+            y + SQUARE_SIZE - 8,
+            color,
+            None,
+        );
+    }
+}
+
+/// 菜单预览用的缩略棋盘每格像素（整盘 64x64）。
+pub const THUMBNAIL_CELL: u16 = 8;
+pub const THUMBNAIL_SIZE: u16 = THUMBNAIL_CELL * 8;
+
+/// 缩略棋盘：左上角 (x, y)，每格 `cell` 像素，棋子画成 4x4 字形（见 `pieces::draw_mini_piece`），
+/// 每 8 像素格宽放大一倍，居中于方格。
+pub fn draw_thumbnail(
+    lcd: &mut Lcd,
+    theme: &Theme,
+    squares: &[Option<Piece>; 64],
+    x: u16,
+    y: u16,
+    cell: u16,
+) {
+    let scale = (cell / THUMBNAIL_CELL).max(1);
+    let inset = cell.saturating_sub(pieces::MINI_GLYPH * scale) / 2;
+    for rank in 0..8u8 {
+        for file in 0..8u8 {
+            let sx = x + file as u16 * cell;
+            let sy = y + (7 - rank as u16) * cell;
+            lcd.fill_rect(sx, sy, cell, cell, square_color(theme, file, rank));
+            if let Some(piece) = squares[(rank * 8 + file) as usize] {
+                pieces::draw_mini_piece(
+                    lcd,
+                    theme,
+                    piece.kind,
+                    piece.color,
+                    sx + inset,
+                    sy + inset,
+                    scale,
+                );
+            }
+        }
     }
 }
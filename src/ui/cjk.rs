@@ -0,0 +1,21 @@
+//! 12x12 中文点阵字形：只收录棋盘坐标（一~八）与棋子名（王后车象马兵）所需的字。
+//! 点阵以字符画形式写在 assets/fonts/cjk12.txt 里，由 `cargo xtask assets` 转换为每行一个
+//! `u16`（低 12 位，bit11 为最左列）。
+
+pub const CJK_SIZE: u8 = 12;
+
+struct CjkGlyph {
+    ch: char,
+    rows: [u16; CJK_SIZE as usize],
+}
+
+// 提供 GLYPHS。
+include!("../generated/cjk.rs");
+
+/// 查找中文字形，未收录返回 `None`。
+pub fn cjk_glyph(ch: char) -> Option<&'static [u16; CJK_SIZE as usize]> {
+    GLYPHS.iter().find(|g| g.ch == ch).map(|g| &g.rows)
+}
+
+/// 一~八，对应第 1~8 横排。
+pub const RANK_NUMERALS: [char; 8] = ['一', '二', '三', '四', '五', '六', '七', '八'];
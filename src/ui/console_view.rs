@@ -0,0 +1,81 @@
+//! 屏幕控制台：同时按下 KEY2 与 KEY3 开关，在侧栏着法列表的位置以小字显示 USART1
+//! 最近的收发行（见 `line_log`），输入的命令带 "> " 前缀。只重画内容有变化的行，
+//! 新输出逐字增长时每帧只动最后一行。
+
+use crate::drivers::lcd::Lcd;
+use crate::line_log::{LINE_LEN, LINES, LineLog};
+use crate::ui::text;
+use crate::ui::theme::Theme;
+
+const TITLE: &str = "Console (UART)";
+const TITLE_H: u16 = 14;
+const ROW_H: u16 = 12;
+const CHAR_W: u16 = 6;
+
+pub struct ConsoleView {
+    x: u16,
+    y: u16,
+    enabled: bool,
+    /// 上次绘制时记录的版本；None 表示需要连同标题整体重画。
+    drawn_revision: Option<u32>,
+    /// 各行上次绘制内容的指纹，相同的行跳过。
+    drawn_rows: [u32; LINES],
+}
+
+impl ConsoleView {
+    /// 左上角在 (`x`, `y`) 的视图，默认关闭。
+    pub fn new(x: u16, y: u16) -> Self {
+        ConsoleView {
+            x,
+            y,
+            enabled: false,
+            drawn_revision: None,
+            drawn_rows: [0; LINES],
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// 开关视图；调用方随后整体重绘侧栏（打开时盖住着法列表，关闭时恢复）。
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+        self.drawn_revision = None;
+    }
+
+    /// 屏幕被整体重绘或清空后调用。
+    pub fn invalidate(&mut self) {
+        self.drawn_revision = None;
+    }
+
+    pub fn update(&mut self, lcd: &mut Lcd, theme: &Theme, log: &LineLog) {
+        if !self.enabled || self.drawn_revision == Some(log.revision()) {
+            return;
+        }
+        let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+        let full = self.drawn_revision.is_none();
+        if full {
+            text::draw_text_scaled(lcd, TITLE, self.x, self.y, fg, Some(bg), 1);
+        }
+        for row in 0..LINES {
+            let line = log.row(row);
+            let fingerprint = fingerprint(line);
+            if !full && self.drawn_rows[row] == fingerprint {
+                continue;
+            }
+            let y = self.y + TITLE_H + row as u16 * ROW_H;
+            lcd.fill_rect(self.x, y, LINE_LEN as u16 * CHAR_W, ROW_H, bg);
+            text::draw_text_scaled(lcd, line, self.x, y, fg, Some(bg), 1);
+            self.drawn_rows[row] = fingerprint;
+        }
+        self.drawn_revision = Some(log.revision());
+    }
+}
+
+/// FNV-1a，区分行内容足够，不必保存整行副本。
+fn fingerprint(line: &str) -> u32 {
+    line.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
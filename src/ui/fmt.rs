@@ -0,0 +1,130 @@
+//! 定长缓冲区数字格式化：整数、时钟、百分比、厘兵分数与带单位的计数/时长。
+//! 全部写入调用方提供的栈上数组并返回其中的 `&str`，不分配、不会 panic；缓冲区按最坏情况定长。
+
+/// 无符号十进制，如 "1234"。
+pub fn u32_to_str(value: u32, buf: &mut [u8; 10]) -> &str {
+    let start = write_digits(value, buf);
+    as_str(&buf[start..])
+}
+
+/// 有符号十进制，负数带 "-"，如 "-12"。
+pub fn i32_to_str(value: i32, buf: &mut [u8; 11]) -> &str {
+    let mut start = write_digits(value.unsigned_abs(), buf);
+    if value < 0 {
+        start -= 1;
+        buf[start] = b'-';
+    }
+    as_str(&buf[start..])
+}
+
+/// 秒数格式化为 "HH:MM:SS"（小时按 100 取模）；取前 5 个字符即 "HH:MM"。
+pub fn format_hms(total_seconds: u32, buf: &mut [u8; 8]) -> &str {
+    let fields = [
+        (total_seconds / 3600) % 100,
+        (total_seconds / 60) % 60,
+        total_seconds % 60,
+    ];
+    for (i, value) in fields.iter().enumerate() {
+        buf[i * 3] = b'0' + (value / 10) as u8;
+        buf[i * 3 + 1] = b'0' + (value % 10) as u8;
+        if i < 2 {
+            buf[i * 3 + 2] = b':';
+        }
+    }
+    as_str(buf)
+}
+
+/// 秒数格式化为 "MM:SS"；分钟不取模，超过 99 时自然变宽（封顶 99999）。
+pub fn format_mm_ss(total_seconds: u32, buf: &mut [u8; 8]) -> &str {
+    let minutes = (total_seconds / 60).min(99_999);
+    let seconds = total_seconds % 60;
+    // 至少两位分钟，不足以预填的 '0' 补齐。
+    let mut digits = [b'0'; 5];
+    let start = write_digits(minutes, &mut digits).min(3);
+    let len = digits.len() - start;
+    buf[..len].copy_from_slice(&digits[start..]);
+    buf[len] = b':';
+    buf[len + 1] = b'0' + (seconds / 10) as u8;
+    buf[len + 2] = b'0' + (seconds % 10) as u8;
+    as_str(&buf[..len + 3])
+}
+
+/// `part / whole` 的整数百分比，如 "75%"；`whole` 为 0 时为 "0%"，结果封顶 100%。
+pub fn format_percent(part: u32, whole: u32, buf: &mut [u8; 4]) -> &str {
+    let pct = if whole == 0 {
+        0
+    } else {
+        (part as u64 * 100 / whole as u64).min(100) as u32
+    };
+    let mut digits = [0u8; 3];
+    let start = write_digits(pct, &mut digits);
+    let len = digits.len() - start;
+    buf[..len].copy_from_slice(&digits[start..]);
+    buf[len] = b'%';
+    as_str(&buf[..len + 1])
+}
+
+/// 厘兵分数以兵为单位、保留两位小数并总带符号，如 "+1.25"、"-0.50"、"+0.00"。
+pub fn format_centipawns(cp: i32, buf: &mut [u8; 12]) -> &str {
+    let abs = cp.unsigned_abs();
+    let mut digits = [0u8; 10];
+    let start = write_digits(abs / 100, &mut digits);
+    let whole = &digits[start..];
+    buf[0] = if cp < 0 { b'-' } else { b'+' };
+    buf[1..1 + whole.len()].copy_from_slice(whole);
+    let dot = 1 + whole.len();
+    buf[dot] = b'.';
+    buf[dot + 1] = b'0' + (abs % 100 / 10) as u8;
+    buf[dot + 2] = b'0' + (abs % 10) as u8;
+    as_str(&buf[..dot + 3])
+}
+
+/// 计数按量级缩写、保留一位小数，如 "812"、"18.4k"、"1.2M"（小数截断而非四舍五入）。
+pub fn format_count(value: u32, buf: &mut [u8; 8]) -> &str {
+    match value {
+        0..1_000 => {
+            let mut digits = [0u8; 10];
+            let start = write_digits(value, &mut digits);
+            let len = digits.len() - start;
+            buf[..len].copy_from_slice(&digits[start..]);
+            as_str(&buf[..len])
+        }
+        1_000..1_000_000 => write_tenths(value / 100, b'k', buf),
+        _ => write_tenths(value / 100_000, b'M', buf),
+    }
+}
+
+/// 毫秒数以秒为单位、保留一位小数，如 "0.9s"、"12.3s"（小数截断）。
+pub fn format_seconds_tenths(ms: u32, buf: &mut [u8; 8]) -> &str {
+    write_tenths((ms / 100).min(99_999), b's', buf)
+}
+
+/// `tenths / 10` 写成 "整数.一位小数" 加单位字符；整数部分最多 5 位。
+fn write_tenths(tenths: u32, unit: u8, buf: &mut [u8; 8]) -> &str {
+    let mut digits = [0u8; 10];
+    let start = write_digits((tenths / 10).min(99_999), &mut digits);
+    let whole = &digits[start..];
+    buf[..whole.len()].copy_from_slice(whole);
+    let dot = whole.len();
+    buf[dot] = b'.';
+    buf[dot + 1] = b'0' + (tenths % 10) as u8;
+    buf[dot + 2] = unit;
+    as_str(&buf[..dot + 3])
+}
+
+/// 从缓冲区末尾向前写入十进制数字，返回首位下标（0 写作 "0"）。
+fn write_digits<const N: usize>(mut value: u32, buf: &mut [u8; N]) -> usize {
+    let mut i = N;
+    loop {
+        i -= 1;
+        buf[i] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 || i == 0 {
+            return i;
+        }
+    }
+}
+
+fn as_str(bytes: &[u8]) -> &str {
+    core::str::from_utf8(bytes).unwrap_or("")
+}
@@ -0,0 +1,100 @@
+//! 帧耗时调试叠加层：同时按下 KEY1 与 KEY4 开关，在侧栏状态行下方以小字显示上一帧总耗时、
+//! 其中渲染阶段耗时、开机以来最慢的一帧，以及栈的剩余空间估计（见 `drivers::stack`），
+//! 便于在板上直接观察渲染与搜索改动的效果。每 `REFRESH_MS` 刷新一次，避免自身拖慢渲染。
+
+use crate::drivers::lcd::Lcd;
+use crate::drivers::stack;
+use crate::ui::chessboard::BOARD_SIZE;
+use crate::ui::theme::Theme;
+use crate::ui::{fmt, text};
+
+/// 两行小字的位置：状态行（222）之下、长按进度条（260）之上。
+const Y: u16 = 236;
+const LINE_SPACING: u16 = 10;
+const MARGIN: u16 = 6;
+const CHAR_W: u16 = 6;
+const REFRESH_MS: u32 = 250;
+
+/// 帧调度器的计数（毫秒）。
+#[derive(Clone, Copy)]
+pub struct FrameStats {
+    pub frame_ms: u32,
+    pub render_ms: u32,
+    pub worst_ms: u32,
+}
+
+pub struct FrameOverlay {
+    x: u16,
+    width: u16,
+    enabled: bool,
+    /// 上次绘制的时刻；None 表示需要立即重画。
+    drawn_at: Option<u32>,
+}
+
+impl FrameOverlay {
+    /// 棋盘右侧侧栏中的叠加层，默认关闭。
+    pub fn sidebar(lcd: &Lcd) -> Self {
+        let x = BOARD_SIZE + MARGIN;
+        FrameOverlay {
+            x,
+            width: lcd.width.saturating_sub(x + MARGIN),
+            enabled: false,
+            drawn_at: None,
+        }
+    }
+
+    /// 开关叠加层；关闭时擦除所占区域。
+    pub fn toggle(&mut self, lcd: &mut Lcd, theme: &Theme) {
+        self.enabled = !self.enabled;
+        self.drawn_at = None;
+        if !self.enabled {
+            lcd.fill_rect(self.x, Y, self.width, LINE_SPACING * 2, theme.ui_bg);
+        }
+    }
+
+    pub fn update(&mut self, lcd: &mut Lcd, theme: &Theme, now_ms: u32, stats: FrameStats) {
+        if !self.enabled
+            || self
+                .drawn_at
+                .is_some_and(|at| now_ms.wrapping_sub(at) < REFRESH_MS)
+        {
+            return;
+        }
+        let mut frame_buf = [0u8; 10];
+        let mut render_buf = [0u8; 10];
+        let mut worst_buf = [0u8; 10];
+        let mut stack_buf = [0u8; 8];
+        let frame = fmt::u32_to_str(stats.frame_ms, &mut frame_buf);
+        let render = fmt::u32_to_str(stats.render_ms, &mut render_buf);
+        let worst = fmt::u32_to_str(stats.worst_ms, &mut worst_buf);
+        let free = fmt::format_count(stack::free_bytes(), &mut stack_buf);
+        self.draw_line(
+            lcd,
+            theme,
+            0,
+            &["frame ", frame, " ms  render ", render, " ms"],
+        );
+        self.draw_line(
+            lcd,
+            theme,
+            1,
+            &["worst ", worst, " ms  stack ", free, "B free"],
+        );
+        self.drawn_at = Some(now_ms);
+    }
+
+    /// 屏幕被整体重绘或清空后调用。
+    pub fn invalidate(&mut self) {
+        self.drawn_at = None;
+    }
+
+    fn draw_line(&self, lcd: &mut Lcd, theme: &Theme, line: u16, parts: &[&str]) {
+        let y = Y + line * LINE_SPACING;
+        lcd.fill_rect(self.x, y, self.width, LINE_SPACING, theme.ui_bg);
+        let mut x = self.x;
+        for part in parts {
+            text::draw_text_scaled(lcd, part, x, y, theme.ui_fg, Some(theme.ui_bg), 1);
+            x += part.len() as u16 * CHAR_W;
+        }
+    }
+}
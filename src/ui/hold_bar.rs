@@ -0,0 +1,60 @@
+//! 长按进度条：按住带长按功能的键时，在侧栏底部（状态行下方）逐渐填满，填满即触发长按。
+//! 只在填充宽度变化时重绘，松手或长按触发后擦除。
+
+use crate::drivers::button::LONG_PRESS_MS;
+use crate::drivers::lcd::Lcd;
+use crate::ui::chessboard::BOARD_SIZE;
+use crate::ui::theme::Theme;
+
+/// 进度条位置：侧栏最下方，避开状态行与各界面 246 行的按键提示。
+const Y: u16 = 260;
+const HEIGHT: u16 = 6;
+const MARGIN: u16 = 6;
+
+pub struct HoldBar {
+    x: u16,
+    width: u16,
+    /// 已画出的填充宽度；None 表示进度条未显示。
+    shown: Option<u16>,
+    /// 整屏重绘后屏幕内容与 `shown` 不再一致，下次更新时整条重画或擦除。
+    stale: bool,
+}
+
+impl HoldBar {
+    /// 棋盘右侧侧栏中的进度条，宽度随屏幕宽度。
+    pub fn sidebar(lcd: &Lcd) -> Self {
+        let x = BOARD_SIZE + MARGIN;
+        HoldBar {
+            x,
+            width: lcd.width.saturating_sub(x + MARGIN),
+            shown: None,
+            stale: false,
+        }
+    }
+
+    /// 按已按住的毫秒数更新；None（未按住）时擦除。
+    pub fn update(&mut self, lcd: &mut Lcd, theme: &Theme, held_ms: Option<u32>) {
+        let inner = self.width.saturating_sub(2);
+        let fill = held_ms.map(|ms| (ms.min(LONG_PRESS_MS) * inner as u32 / LONG_PRESS_MS) as u16);
+        if fill == self.shown && !self.stale {
+            return;
+        }
+        match fill {
+            None => lcd.fill_rect(self.x, Y, self.width, HEIGHT, theme.ui_bg),
+            Some(fill) => {
+                if self.shown.is_none() || self.stale {
+                    lcd.fill_rect(self.x, Y, self.width, HEIGHT, theme.ui_fg);
+                    lcd.fill_rect(self.x + 1, Y + 1, inner, HEIGHT - 2, theme.ui_bg);
+                }
+                lcd.fill_rect(self.x + 1, Y + 1, fill, HEIGHT - 2, theme.cursor);
+            }
+        }
+        self.shown = fill;
+        self.stale = false;
+    }
+
+    /// 屏幕被整体重绘或清空后调用。
+    pub fn invalidate(&mut self) {
+        self.stale = true;
+    }
+}
@@ -0,0 +1,96 @@
+//! 屏幕失效区域：64 个方格各占一位，侧栏再按用途分为几个命名区域。各子系统只登记
+//! 自己改动了哪里（时钟跳秒只标时钟，光标移动只标新旧两格），渲染阶段由持有者统一
+//! `take` 出本帧的失效集合，恰好重绘其中的部分。
+
+/// 棋盘以外的命名区域。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Region {
+    /// 侧栏对局时长。
+    Clock,
+    /// 侧栏底部的状态提示行。
+    StatusLine,
+    /// 侧栏其余内容：行棋方、评估、着法列表与各类提示（重绘时连同状态行）。
+    SidePanel,
+    /// 自行增量绘制的叠加层（长按进度条、帧统计、屏幕控制台）：标记后整体重画。
+    Overlays,
+}
+
+impl Region {
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+const ALL_REGIONS: u8 = Region::Clock.bit()
+    | Region::StatusLine.bit()
+    | Region::SidePanel.bit()
+    | Region::Overlays.bit();
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Invalidation {
+    /// 第 `rank * 8 + file` 位对应方格 (file, rank)。
+    squares: u64,
+    regions: u8,
+}
+
+impl Invalidation {
+    pub const fn new() -> Self {
+        Invalidation {
+            squares: 0,
+            regions: 0,
+        }
+    }
+
+    pub fn square(&mut self, file: u8, rank: u8) {
+        self.squares |= 1 << (rank * 8 + file);
+    }
+
+    /// 按位登记一组方格，第 `rank * 8 + file` 位对应方格 (file, rank)。
+    pub fn squares_in(&mut self, mask: u64) {
+        self.squares |= mask;
+    }
+
+    pub fn region(&mut self, region: Region) {
+        self.regions |= region.bit();
+    }
+
+    /// 整屏失效：全部方格与全部区域。
+    pub fn all(&mut self) {
+        self.squares = u64::MAX;
+        self.regions = ALL_REGIONS;
+    }
+
+    pub fn is_all(&self) -> bool {
+        self.squares == u64::MAX && self.regions == ALL_REGIONS
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.squares == 0 && self.regions == 0
+    }
+
+    pub fn has_squares(&self) -> bool {
+        self.squares != 0
+    }
+
+    pub fn contains(&self, region: Region) -> bool {
+        self.regions & region.bit() != 0
+    }
+
+    /// 取出当前的失效集合并清空，供一次重绘使用。
+    pub fn take(&mut self) -> Invalidation {
+        core::mem::take(self)
+    }
+
+    /// 失效方格的 (file, rank)，按 rank 再按 file 递增。
+    pub fn squares(&self) -> impl Iterator<Item = (u8, u8)> {
+        let mut bits = self.squares;
+        core::iter::from_fn(move || {
+            if bits == 0 {
+                return None;
+            }
+            let idx = bits.trailing_zeros() as u8;
+            bits &= bits - 1;
+            Some((idx % 8, idx / 8))
+        })
+    }
+}
@@ -0,0 +1,75 @@
+//! 菜单页的公共部分：配色、标题、"名称  取值" 条目列表与上下导航。启动菜单、设置页与
+//! 开局向导都由这几样拼成，各页只决定条目内容以及 KEY1/KEY4 的含义。
+
+use crate::drivers::lcd::Lcd;
+use crate::interaction::Action;
+use crate::ui::text;
+
+pub const BG: u16 = 0x0000;
+pub const FG: u16 = 0xFFFF;
+pub const TITLE_COLOR: u16 = 0xFFE0;
+pub const HIGHLIGHT: u16 = 0xE540; // 柔和橙
+/// 2 倍字号条目的行距。
+pub const ENTRY_SPACING: u16 = 18;
+
+/// 导航之外留给页面处理的菜单事件。
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MenuEvent {
+    /// 当前条目变了，需要重绘。
+    Moved,
+    /// KEY1 短按。
+    Select,
+    /// KEY4 短按。
+    Alt,
+    /// KEY1 长按。
+    Back,
+}
+
+/// 处理一帧的菜单动作：KEY3/KEY2 在 `len` 个条目间移动 `selected`（到头不回绕），
+/// 其余动作原样转成事件。
+pub fn navigate(action: Option<Action>, selected: &mut usize, len: usize) -> Option<MenuEvent> {
+    match action? {
+        Action::MenuDown if *selected + 1 < len => {
+            *selected += 1;
+            Some(MenuEvent::Moved)
+        }
+        Action::MenuUp if *selected > 0 => {
+            *selected -= 1;
+            Some(MenuEvent::Moved)
+        }
+        Action::MenuSelect => Some(MenuEvent::Select),
+        Action::MenuAlt => Some(MenuEvent::Alt),
+        Action::MenuBack => Some(MenuEvent::Back),
+        _ => None,
+    }
+}
+
+/// 3 倍字号的页面标题。
+pub fn draw_title(lcd: &mut Lcd, title: &str, x: u16, y: u16) {
+    text::draw_text_scaled(lcd, title, x, y, TITLE_COLOR, Some(BG), 3);
+}
+
+/// 自 `y` 起逐行绘制条目：选中行前画箭头，取值非空时画在 `x + value_dx` 处。
+pub fn draw_rows(
+    lcd: &mut Lcd,
+    rows: &[(&str, &str)],
+    x: u16,
+    y: u16,
+    value_dx: u16,
+    selected: usize,
+) {
+    for (i, (label, value)) in rows.iter().enumerate() {
+        let row_y = y + i as u16 * ENTRY_SPACING;
+        let arrow = if i == selected { ">" } else { " " };
+        text::draw_text_scaled(lcd, arrow, x, row_y, HIGHLIGHT, Some(BG), 2);
+        text::draw_text_scaled(lcd, label, x + 12, row_y, FG, Some(BG), 2);
+        if !value.is_empty() {
+            text::draw_text_scaled(lcd, value, x + value_dx, row_y, FG, Some(BG), 2);
+        }
+    }
+}
+
+/// 1 倍字号的说明或按键提示行。
+pub fn draw_hint(lcd: &mut Lcd, hint: &str, x: u16, y: u16) {
+    text::draw_text_scaled(lcd, hint, x, y, FG, Some(BG), 1);
+}
@@ -1,4 +1,16 @@
+pub mod animation;
+pub mod annotations;
 pub mod chessboard;
+pub mod cjk;
+pub mod console_view;
+pub mod fmt;
 pub mod font;
+pub mod frame_overlay;
+pub mod hold_bar;
+pub mod invalidation;
+pub mod menu;
+pub mod movelist;
 pub mod pieces;
+pub mod square_cache;
 pub mod text;
+pub mod theme;
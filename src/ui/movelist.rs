@@ -0,0 +1,124 @@
+//! 着法列表控件：每行两个半回合，按标准代数记法，如 " 12. Nbd2     Nc6"。编号右对齐、两列等宽，
+//! 超出列宽的着法（多为带标注的升变）以 `~` 截断；始终滚动到最新一步并高亮显示。
+//! 可在某一步所在行的右侧附加一个小标签（如 AI 着法的来源 "book"、"TT d6"）。
+//! 离开开局库的一着前画一个醒目颜色的 `*`。
+
+use crate::drivers::lcd::{ClipRect, Lcd};
+use crate::game::history::History;
+use crate::ui::fmt::u32_to_str;
+use crate::ui::text;
+use crate::ui::theme::Theme;
+use rhess_core::Color;
+
+const CHAR_W: u16 = 6;
+const ROW_H: u16 = 12;
+/// 编号列（"999." 右对齐）与每个着法列的宽度，单位字符。
+const NUM_CHARS: usize = 4;
+const COL_CHARS: usize = 8;
+const WHITE_X: u16 = (NUM_CHARS as u16 + 1) * CHAR_W;
+const BLACK_X: u16 = WHITE_X + (COL_CHARS as u16 + 1) * CHAR_W;
+/// 行尾标签的位置与最大宽度（字符）。
+const TAG_X: u16 = BLACK_X + (COL_CHARS as u16 + 1) * CHAR_W;
+const TAG_CHARS: usize = 6;
+/// 控件总宽度（像素），含标签列。
+pub const WIDTH: u16 = TAG_X + TAG_CHARS as u16 * CHAR_W;
+
+/// 在 (x, y) 起绘制最近 `rows` 行着法，先清空整个控件区域；绘制不会越出该区域。
+/// `tag` 为 (半回合序号, 文字)，画在该步所在行的标签列（超出 `TAG_CHARS` 的部分被裁掉）。
+pub fn render(
+    lcd: &mut Lcd,
+    theme: &Theme,
+    history: &History,
+    x: u16,
+    y: u16,
+    rows: usize,
+    tag: Option<(usize, &str)>,
+) {
+    let area = ClipRect::new(x, y, WIDTH, rows as u16 * ROW_H);
+    lcd.with_clip(area, |lcd| {
+        render_rows(lcd, theme, history, x, y, rows, tag)
+    });
+}
+
+fn render_rows(
+    lcd: &mut Lcd,
+    theme: &Theme,
+    history: &History,
+    x: u16,
+    y: u16,
+    rows: usize,
+    tag: Option<(usize, &str)>,
+) {
+    let (fg, bg) = (theme.ui_fg, theme.ui_bg);
+    lcd.fill_rect(x, y, WIDTH, rows as u16 * ROW_H, bg);
+
+    // 黑方先走的局面首行白方一栏留作 "..."。
+    let start = history.start();
+    let offset = (start.side_to_move == Color::Black) as usize;
+    let total_rows = (history.len() + offset).div_ceil(2);
+    let first_row = total_rows.saturating_sub(rows);
+    let latest = history.len().checked_sub(1);
+    let book_exit = history.book_exit();
+
+    for row in first_row..total_rows {
+        let row_y = y + (row - first_row) as u16 * ROW_H;
+        let mut num_buf = [0u8; 10];
+        let number = u32_to_str(start.fullmove_number as u32 + row as u32, &mut num_buf);
+        let pad = (NUM_CHARS - 1).saturating_sub(number.len()) as u16;
+        let num_x = x + pad * CHAR_W;
+        text::draw_text_scaled(lcd, number, num_x, row_y, fg, Some(bg), 1);
+        text::draw_char(
+            lcd,
+            '.',
+            num_x + number.len() as u16 * CHAR_W,
+            row_y,
+            fg,
+            Some(bg),
+        );
+
+        for (side, col_x) in [WHITE_X, BLACK_X].into_iter().enumerate() {
+            let Some(ply) = (row * 2 + side).checked_sub(offset) else {
+                text::draw_text_scaled(lcd, "...", x + col_x, row_y, fg, Some(bg), 1);
+                continue;
+            };
+            if ply >= history.len() {
+                break;
+            }
+            let color = if Some(ply) == latest {
+                theme.cursor
+            } else {
+                fg
+            };
+            if Some(ply) == book_exit {
+                let marker_x = x + col_x - CHAR_W;
+                text::draw_char(lcd, '*', marker_x, row_y, theme.ui_alert, Some(bg));
+            }
+            let mut buf = [0u8; 12];
+            draw_clipped(
+                lcd,
+                history.san_text(ply, &mut buf),
+                x + col_x,
+                row_y,
+                color,
+                bg,
+            );
+            if let Some((tag_ply, label)) = tag
+                && tag_ply == ply
+            {
+                let label = &label[..label.len().min(TAG_CHARS)];
+                text::draw_text_scaled(lcd, label, x + TAG_X, row_y, fg, Some(bg), 1);
+            }
+        }
+    }
+}
+
+/// 按列宽绘制着法；超长时保留前 `COL_CHARS - 1` 个字符并以 `~` 结尾。
+fn draw_clipped(lcd: &mut Lcd, mv: &str, x: u16, y: u16, color: u16, bg: u16) {
+    if mv.len() <= COL_CHARS {
+        text::draw_text_scaled(lcd, mv, x, y, color, Some(bg), 1);
+        return;
+    }
+    let head = &mv[..COL_CHARS - 1];
+    text::draw_text_scaled(lcd, head, x, y, color, Some(bg), 1);
+    text::draw_char(lcd, '~', x + head.len() as u16 * CHAR_W, y, color, Some(bg));
+}
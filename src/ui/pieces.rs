@@ -1,74 +1,78 @@
-use crate::chess_core::{Color, PieceKind};
 use crate::drivers::lcd::Lcd;
 use crate::ui::chessboard::SQUARE_SIZE;
+use crate::ui::theme::Theme;
+use core::cell::Cell;
+use cortex_m::interrupt::{self, Mutex};
+use rhess_core::{Color, PieceKind};
 
-// 统一的棋子位图尺寸（16x20 单色，居中绘制）
-pub const SPRITE_W: usize = 16;
-pub const SPRITE_H: usize = 20;
-
-// RGB565: 白棋纯白，黑棋纯黑
-const WHITE_FG: u16 = 0xFFFF;
-const BLACK_FG: u16 = 0x0000;
+// 棋子位图由 `cargo xtask assets` 根据 assets/pieces/*.png 生成（尺寸与位深见
+// assets/sprites.txt），提供 SPRITE_W/SPRITE_H 以及 PAWN..KING 常量。
+include!("../generated/pieces.rs");
 
+/// 单个棋子位图：顶部到尾部逐行，高位在左。
+#[derive(Clone, Copy)]
 struct Sprite {
-    rows: &'static [u16; SPRITE_H],
+    /// 主体像素，用棋子颜色绘制。
+    body: &'static [u32; SPRITE_H],
+    /// 2 位深时的细节/描边像素，用对方颜色绘制以勾勒轮廓；1 位深为 None。
+    detail: Option<&'static [u32; SPRITE_H]>,
+}
+
+/// 外观包中的一套棋子位图（见 `crate::packs`），直接引用 Flash 中的行数据。
+#[derive(Clone, Copy)]
+pub struct PieceSet {
+    /// 兵、马、象、车、后、王依次排列，每枚 `SPRITE_H` 行主体，2 位深时再接 `SPRITE_H` 行细节。
+    pub rows: &'static [u32],
+    pub detailed: bool,
 }
 
-// 位图：顶部到尾部逐行，高位在左。
-const PAWN: Sprite = Sprite {
-    rows: &[
-        0x07C0, 0x0FE0, 0x0FE0, 0x0FE0, 0x07C0, 0x07C0, 0x0FE0, 0x1FF0, 0x3FF8, 0x7FFC, 0x7FFC,
-        0x7FFC, 0x3FF8, 0x1FF0, 0x0FE0, 0x1FF0, 0x3FF8, 0x3FF8, 0x7FFC, 0xFFFF,
-    ],
-};
-
-const ROOK: Sprite = Sprite {
-    rows: &[
-        0xF0F0, 0xF0F0, 0xFFFF, 0x7FFE, 0x3FFC, 0x3FFC, 0x3FFC, 0x3FFC, 0x3FFC, 0x3FFC, 0x3FFC,
-        0x3FFC, 0x3FFC, 0x3FFC, 0x3FFC, 0x3FFC, 0x7FFE, 0x7FFE, 0xFFFF, 0xFFFF,
-    ],
-};
-
-const BISHOP: Sprite = Sprite {
-    rows: &[
-        0x07C0, 0x0FE0, 0x1FF0, 0x3FF8, 0x7FFC, 0x7EFC, 0x7C7C, 0x3CF8, 0x1FF0, 0x0FE0, 0x1FF0,
-        0x3FF8, 0x7FFC, 0x7FFC, 0x7FFC, 0x3FF8, 0x1FF0, 0x0FE0, 0x0FE0, 0x1FF0,
-    ],
-};
-
-const KNIGHT: Sprite = Sprite {
-    rows: &[
-        0x07F0, 0x0FF8, 0x1FFC, 0x3FFC, 0x7FF8, 0xFFE0, 0xFFC0, 0xFF00, 0xFE00, 0xFC00, 0xFC00,
-        0xFE00, 0xFF00, 0xFF80, 0x7FC0, 0x3FF0, 0x1FF8, 0x0FFC, 0x07FE, 0x03FF,
-    ],
-};
-
-const QUEEN: Sprite = Sprite {
-    rows: &[
-        0x8001, 0x4002, 0x2004, 0x0FF8, 0x1FFC, 0x3FFE, 0x3FFE, 0x3FFE, 0x1FFC, 0x0FF8, 0x0FF8,
-        0x0FF8, 0x1FFC, 0x3FFE, 0x3FFE, 0x3FFE, 0x1FFC, 0x1FFC, 0x3FFE, 0xFFFF,
-    ],
-};
-
-const KING: Sprite = Sprite {
-    rows: &[
-        0x0180, 0x03C0, 0x03C0, 0xFFFF, 0x03C0, 0x03C0, 0x07E0, 0x0FF0, 0x1FF8, 0x3FFC, 0x7FFE,
-        0x7FFE, 0x7FFE, 0x7FFE, 0x7FFE, 0x3FFC, 0x3FFC, 0x3FFC, 0x7FFE, 0xFFFF,
-    ],
-};
+impl PieceSet {
+    /// 每枚棋子占的行数。
+    pub const fn rows_per_piece(detailed: bool) -> usize {
+        if detailed { 2 * SPRITE_H } else { SPRITE_H }
+    }
+
+    fn sprite(self, kind: PieceKind) -> Sprite {
+        let index = match kind {
+            PieceKind::Pawn => 0,
+            PieceKind::Knight => 1,
+            PieceKind::Bishop => 2,
+            PieceKind::Rook => 3,
+            PieceKind::Queen => 4,
+            PieceKind::King => 5,
+        };
+        let per_piece = Self::rows_per_piece(self.detailed);
+        let rows = &self.rows[index * per_piece..][..per_piece];
+        let (body, detail) = rows.split_at(SPRITE_H);
+        Sprite {
+            body: body.try_into().expect("sprite rows"),
+            detail: detail.try_into().ok(),
+        }
+    }
+}
+
+/// 正在使用的外观包棋子；None 时用内置位图。只在主循环读写，Mutex 只为放进静态变量。
+static ACTIVE_SET: Mutex<Cell<Option<PieceSet>>> = Mutex::new(Cell::new(None));
+
+/// 换用外观包的棋子位图，None 恢复内置位图；之后的绘制立即生效，已画出的棋子不重绘。
+pub fn use_set(set: Option<PieceSet>) {
+    interrupt::free(|cs| ACTIVE_SET.borrow(cs).set(set));
+}
 
 pub fn draw_piece_on_square(
     lcd: &mut Lcd,
+    theme: &Theme,
     kind: PieceKind,
     color: Color,
     file: u8,
     rank_from_bottom: u8,
 ) {
-    draw_piece_on_square_custom(lcd, kind, color, file, rank_from_bottom, None);
+    draw_piece_on_square_custom(lcd, theme, kind, color, file, rank_from_bottom, None);
 }
 
 pub fn draw_piece_on_square_custom(
     lcd: &mut Lcd,
+    theme: &Theme,
     kind: PieceKind,
     color: Color,
     file: u8,
@@ -79,7 +83,7 @@ pub fn draw_piece_on_square_custom(
         return;
     }
     let sprite = sprite_for_kind(kind);
-    let fg = override_color.unwrap_or_else(|| default_piece_color(color));
+    let fg = override_color.unwrap_or_else(|| piece_color(theme, color));
 
     let base_x = file as u16 * SQUARE_SIZE;
     // rank_from_bottom=0 表示底部（白方后排），因此需要从屏幕顶部反转
@@ -88,12 +92,13 @@ pub fn draw_piece_on_square_custom(
     let offset_x = base_x + (SQUARE_SIZE - SPRITE_W as u16) / 2;
     let offset_y = base_y + (SQUARE_SIZE - SPRITE_H as u16) / 2;
 
-    draw_sprite_at(lcd, sprite, fg, offset_x, offset_y);
+    draw_sprite_at(lcd, theme, &sprite, fg, color, offset_x, offset_y);
 }
 
 /// 在任意像素坐标绘制棋子图标（左上角对齐），可传入自定义颜色。
 pub fn draw_piece_icon(
     lcd: &mut Lcd,
+    theme: &Theme,
     kind: PieceKind,
     color: Color,
     x: u16,
@@ -101,19 +106,91 @@ pub fn draw_piece_icon(
     override_color: Option<u16>,
 ) {
     let sprite = sprite_for_kind(kind);
-    let fg = override_color.unwrap_or_else(|| default_piece_color(color));
-    draw_sprite_at(lcd, sprite, fg, x, y);
+    let fg = override_color.unwrap_or_else(|| piece_color(theme, color));
+    draw_sprite_at(lcd, theme, &sprite, fg, color, x, y);
+}
+
+/// 缩略棋盘用的棋子字形边长（像素，放大前）。
+pub const MINI_GLYPH: u16 = 4;
+
+/// 4x4 棋子字形，每行低 4 位、高位在左；底部一行是底座。
+const fn mini_glyph(kind: PieceKind) -> [u8; MINI_GLYPH as usize] {
+    match kind {
+        PieceKind::Pawn => [0b0000, 0b0110, 0b0110, 0b1111],
+        PieceKind::Knight => [0b0110, 0b1110, 0b0110, 0b1111],
+        PieceKind::Bishop => [0b0110, 0b1011, 0b0110, 0b1111],
+        PieceKind::Rook => [0b1001, 0b1111, 0b0110, 0b1111],
+        PieceKind::Queen => [0b1111, 0b0110, 0b0110, 0b1111],
+        PieceKind::King => [0b0110, 0b1111, 0b0110, 0b1111],
+    }
+}
+
+/// 缩略棋盘上的棋子：4x4 字形左上角对齐 (x, y)，每个字形像素放大为 `scale` 像素见方。
+/// 对比不足时与大棋子一样用对方颜色描外轮廓，轮廓可向字形外侧伸出一格。
+pub fn draw_mini_piece(
+    lcd: &mut Lcd,
+    theme: &Theme,
+    kind: PieceKind,
+    color: Color,
+    x: u16,
+    y: u16,
+    scale: u16,
+) {
+    let glyph = mini_glyph(kind);
+    let fg = piece_color(theme, color);
+    let mut cell = |col: i16, row: i16, c: u16| {
+        let px = x as i16 + col * scale as i16;
+        let py = y as i16 + row * scale as i16;
+        if px >= 0 && py >= 0 {
+            lcd.fill_rect(px as u16, py as u16, scale, scale, c);
+        }
+    };
+    if theme.needs_outline(fg) {
+        // 行左移一位，让左侧一格外轮廓也落在位图内。
+        let shape = |row: i16| match usize::try_from(row) {
+            Ok(r) if r < glyph.len() => glyph[r] << 1,
+            _ => 0,
+        };
+        for row in -1..=MINI_GLYPH as i16 {
+            let here = shape(row);
+            let grown = (here << 1) | (here >> 1) | shape(row - 1) | shape(row + 1);
+            let outline = grown & !here;
+            for bit in 0..MINI_GLYPH as i16 + 2 {
+                if outline & (1 << (MINI_GLYPH as i16 + 1 - bit)) != 0 {
+                    cell(bit - 1, row, detail_color(theme, color));
+                }
+            }
+        }
+    }
+    for (row, bits) in glyph.iter().enumerate() {
+        for bit in 0..MINI_GLYPH as i16 {
+            if bits & (1 << (MINI_GLYPH as i16 - 1 - bit)) != 0 {
+                cell(bit, row as i16, fg);
+            }
+        }
+    }
 }
 
-fn default_piece_color(color: Color) -> u16 {
+fn piece_color(theme: &Theme, color: Color) -> u16 {
     match color {
-        Color::White => WHITE_FG,
-        Color::Black => BLACK_FG,
+        Color::White => theme.white_piece,
+        Color::Black => theme.black_piece,
     }
 }
 
-fn sprite_for_kind(kind: PieceKind) -> &'static Sprite {
-    match kind {
+/// 细节/描边取对方棋子颜色，保证与主体对比明显。
+fn detail_color(theme: &Theme, color: Color) -> u16 {
+    match color {
+        Color::White => theme.black_piece,
+        Color::Black => theme.white_piece,
+    }
+}
+
+fn sprite_for_kind(kind: PieceKind) -> Sprite {
+    if let Some(set) = interrupt::free(|cs| ACTIVE_SET.borrow(cs).get()) {
+        return set.sprite(kind);
+    }
+    *match kind {
         PieceKind::Pawn => &PAWN,
         PieceKind::Rook => &ROOK,
         PieceKind::Knight => &KNIGHT,
@@ -123,20 +200,65 @@ fn sprite_for_kind(kind: PieceKind) -> &'static Sprite {
     }
 }
 
-fn draw_sprite_at(lcd: &mut Lcd, sprite: &Sprite, fg: u16, start_x: u16, start_y: u16) {
-    for (row_idx, bits) in sprite.rows.iter().enumerate() {
+/// 主体色在某种底色上对比不足时（见 `Theme::needs_outline`），先用对方颜色描一圈外轮廓。
+fn draw_sprite_at(
+    lcd: &mut Lcd,
+    theme: &Theme,
+    sprite: &Sprite,
+    fg: u16,
+    color: Color,
+    start_x: u16,
+    start_y: u16,
+) {
+    let detail_fg = detail_color(theme, color);
+    if theme.needs_outline(fg) {
+        draw_outline(lcd, sprite, detail_fg, start_x, start_y);
+    }
+    let clip = lcd.clip();
+    for row_idx in 0..SPRITE_H {
         let y = start_y + row_idx as u16;
-        if y >= lcd.height {
-            break;
+        // 裁剪矩形外的整行直接跳过，逐像素的裁剪由 Lcd 完成。
+        if !clip.contains_row(y) {
+            continue;
         }
+        let body = sprite.body[row_idx];
+        let detail = sprite.detail.map_or(0, |rows| rows[row_idx]);
         for bit in 0..SPRITE_W {
             let mask = 1 << (SPRITE_W - 1 - bit);
-            if bits & mask != 0 {
-                let x = start_x + bit as u16;
-                if x >= lcd.width {
-                    break;
-                }
-                lcd.draw_pixel(x, y, fg);
+            let color = if body & mask != 0 {
+                fg
+            } else if detail & mask != 0 {
+                detail_fg
+            } else {
+                continue;
+            };
+            lcd.draw_pixel(start_x + bit as u16, y, color);
+        }
+    }
+}
+
+/// 外轮廓：与棋子像素上下左右相邻、自身透明的像素（限于位图范围内）。
+fn draw_outline(lcd: &mut Lcd, sprite: &Sprite, color: u16, start_x: u16, start_y: u16) {
+    let width_mask = u32::MAX >> (32 - SPRITE_W);
+    let shape = |row: usize| sprite.body[row] | sprite.detail.map_or(0, |rows| rows[row]);
+    let clip = lcd.clip();
+    for row_idx in 0..SPRITE_H {
+        let y = start_y + row_idx as u16;
+        if !clip.contains_row(y) {
+            continue;
+        }
+        let here = shape(row_idx);
+        let above = row_idx.checked_sub(1).map_or(0, shape);
+        let below = if row_idx + 1 < SPRITE_H {
+            shape(row_idx + 1)
+        } else {
+            0
+        };
+        let grown = (here << 1) | (here >> 1) | above | below;
+        let outline = grown & !here & width_mask;
+        for bit in 0..SPRITE_W {
+            if outline & (1 << (SPRITE_W - 1 - bit)) != 0 {
+                lcd.draw_pixel(start_x + bit as u16, y, color);
             }
         }
     }
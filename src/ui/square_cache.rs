@@ -0,0 +1,70 @@
+//! 棋盘方格缓存：记下每格上次实际画出的样子（底色、棋子、边框与角标）。重画某格前先
+//! 与缓存比较，样子没变就不再经 FSMC 重写这 34x34 像素。失效标记决定“哪些格可能变了”，
+//! 缓存再筛掉其中其实没变的，整盘失效（如走子后）时也只重画真正变化的几格。
+//!
+//! 屏幕被清空或被别的画面盖住后必须 `invalidate`，否则缓存会以为格子还在。
+
+use crate::ui::chessboard::SquareOverlay;
+use rhess_core::Piece;
+
+/// 方格右上角的单字符角标。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CornerLabel {
+    /// 升变候选的序号（0 起），对应 KEY1~KEY4。
+    PromotionSlot(u8),
+    /// 选中的棋子无着可走。
+    NoMoves,
+}
+
+/// 决定一格画面的全部要素；两次相同即无需重画。
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SquareLook {
+    /// 底色，已按光标、预走、上一步等高亮选定。
+    pub fill: u16,
+    /// 选中棋子的内边框。
+    pub selected: bool,
+    /// 讲解叠加层中棋子走法范围的边框。
+    pub tutor_pattern: bool,
+    /// 被将军的王的警示色边框。
+    pub check: bool,
+    /// 串口批注的方格标记边框颜色。
+    pub mark: Option<u16>,
+    pub piece: Option<Piece>,
+    /// 替代主题配色的棋子颜色（选中的棋子）。
+    pub piece_color: Option<u16>,
+    /// 讲解叠加层中合法落点的角标。
+    pub tutor_legal: bool,
+    /// 选中棋子的落点提示。
+    pub hint: Option<SquareOverlay>,
+    pub corner: Option<CornerLabel>,
+    /// 画进本格的批注箭头（按槽位的位掩码）与它们的颜色（见 `Annotations::colors_of`）。
+    pub arrows: u8,
+    pub arrow_colors: u16,
+}
+
+pub struct SquareCache {
+    /// 第 `rank * 8 + file` 项为该格上次画出的样子；None 表示屏幕上的内容未知。
+    drawn: [Option<SquareLook>; 64],
+}
+
+impl SquareCache {
+    pub const fn new() -> Self {
+        SquareCache { drawn: [None; 64] }
+    }
+
+    /// 屏幕被清空或覆盖后调用，之后每格都会重画一次。
+    pub fn invalidate(&mut self) {
+        self.drawn = [None; 64];
+    }
+
+    /// 第 `idx` 格要画成 `look`：与上次画出的相同时返回 false；否则记下并返回 true，
+    /// 调用方随即重画。
+    pub fn update(&mut self, idx: u8, look: SquareLook) -> bool {
+        let slot = &mut self.drawn[idx as usize];
+        if *slot == Some(look) {
+            return false;
+        }
+        *slot = Some(look);
+        true
+    }
+}
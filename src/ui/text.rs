@@ -1,4 +1,5 @@
 use crate::drivers::lcd::Lcd;
+use crate::ui::cjk::{CJK_SIZE, cjk_glyph};
 use crate::ui::font::{FONT_HEIGHT, FONT_SPACING, FONT_WIDTH, glyph};
 
 /// 绘制单个 ASCII 字符；若提供 bg，将覆盖背景。
@@ -87,7 +88,38 @@ pub fn draw_char_scaled(
     }
 }
 
-/// 绘制字符串（可缩放），scale=2 推荐用于右侧 UI 提高清晰度。
+/// 绘制 12x12 中文字形（整型缩放）。
+pub fn draw_cjk_scaled(
+    lcd: &mut Lcd,
+    ch: char,
+    x: u16,
+    y: u16,
+    color: u16,
+    bg: Option<u16>,
+    scale: u8,
+) {
+    let Some(rows) = cjk_glyph(ch) else {
+        return;
+    };
+    let s = scale.max(1) as u16;
+    let size = CJK_SIZE as u16;
+    for (row, bits) in rows.iter().enumerate() {
+        for col in 0..size {
+            let draw_fg = bits & (1 << (size - 1 - col)) != 0;
+            let fill = if draw_fg { Some(color) } else { bg };
+            if let Some(fill) = fill {
+                lcd.fill_rect(x + col * s, y + row as u16 * s, s, s, fill);
+            }
+        }
+    }
+}
+
+/// 中英混排时中文字形的缩放：12 像素高的中文约等于 2 倍 ASCII，故取一半（至少 1）。
+fn cjk_scale(scale: u8) -> u8 {
+    (scale / 2).max(1)
+}
+
+/// 绘制字符串（可缩放），scale=2 推荐用于右侧 UI 提高清晰度；收录的中文字自动改用中文点阵。
 pub fn draw_text_scaled(
     lcd: &mut Lcd,
     text: &str,
@@ -106,6 +138,12 @@ pub fn draw_text_scaled(
             y = y.saturating_add(step_y);
             continue;
         }
+        if cjk_glyph(ch).is_some() {
+            let cs = cjk_scale(scale);
+            draw_cjk_scaled(lcd, ch, x, y, color, bg, cs);
+            x = x.saturating_add(CJK_SIZE as u16 * cs as u16 + s);
+            continue;
+        }
         draw_char_scaled(lcd, ch, x, y, color, bg, scale);
         x = x.saturating_add(step_x);
     }
@@ -0,0 +1,148 @@
+// 棋盘与侧边栏配色（RGB565）。所有界面颜色集中在此，便于切换色盲友好/高对比方案。
+
+/// 一套完整配色：棋盘方格、高亮、棋子与右侧信息区。字段顺序即外观包中配色的存放顺序
+/// （见 `crate::packs`），`repr(C)` 保证可以直接引用 Flash 中的 12 个 u16。
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Theme {
+    pub light_square: u16,
+    pub dark_square: u16,
+    /// 光标所在方格。
+    pub cursor: u16,
+    /// 上一步起止方格。
+    pub last_move: u16,
+    /// 升变目标方格。
+    pub promotion: u16,
+    /// 预走（AI 思考时登记的着法）起止方格。
+    pub premove: u16,
+    /// 被选中棋子的前景色。
+    pub selected_piece: u16,
+    pub white_piece: u16,
+    pub black_piece: u16,
+    pub ui_bg: u16,
+    pub ui_fg: u16,
+    pub ui_alert: u16,
+}
+
+/// 默认配色：灰色棋盘 + 黄色光标 + 橙色上一步。
+pub const CLASSIC: Theme = Theme {
+    light_square: 0xC618, // 浅灰
+    dark_square: 0x8410,  // 深灰
+    cursor: 0xFFE0,       // 亮黄
+    last_move: 0xE540,    // 柔和橙色，区分光标
+    promotion: 0x07E0,    // 绿色用于升变提示
+    premove: 0x041F,      // 蓝色预走
+    selected_piece: 0xF800,
+    white_piece: 0xFFFF,
+    black_piece: 0x0000,
+    ui_bg: 0x0000,
+    ui_fg: 0xFFFF,
+    ui_alert: 0xF800,
+};
+
+/// 红绿色盲（deuteranopia）友好：仅用蓝/橙/黄区分状态（Okabe-Ito 色板）。
+pub const COLOR_BLIND: Theme = Theme {
+    light_square: 0xC618,
+    dark_square: 0x8410,
+    cursor: 0x55BD,         // 天蓝
+    last_move: 0xE4E0,      // 橙
+    promotion: 0xF728,      // 黄
+    premove: 0xCBD4,        // 红紫
+    selected_piece: 0x0396, // 深蓝
+    white_piece: 0xFFFF,
+    black_piece: 0x0000,
+    ui_bg: 0x0000,
+    ui_fg: 0xFFFF,
+    ui_alert: 0xD2E0, // 朱红，亮度与白字可区分
+};
+
+/// 高对比：黑/白/黄三色，方格取中间灰度以同时衬托黑白棋子。
+pub const HIGH_CONTRAST: Theme = Theme {
+    light_square: 0xAD55,
+    dark_square: 0x52AA,
+    cursor: 0xFFE0,
+    last_move: 0x7BE0, // 暗黄
+    promotion: 0xFFFF,
+    premove: 0x07FF, // 青
+    selected_piece: 0xFFE0,
+    white_piece: 0xFFFF,
+    black_piece: 0x0000,
+    ui_bg: 0x0000,
+    ui_fg: 0xFFFF,
+    ui_alert: 0xFFE0,
+};
+
+/// 棋子与其底色的最低对比度（×100），取 WCAG 对非文字图形的 3:1。
+const MIN_PIECE_CONTRAST: u32 = 300;
+
+impl Theme {
+    /// 棋子可能落在的所有底色：两种方格以及光标、上一步、预走、升变高亮。
+    fn piece_backgrounds(&self) -> [u16; 6] {
+        [
+            self.light_square,
+            self.dark_square,
+            self.cursor,
+            self.last_move,
+            self.premove,
+            self.promotion,
+        ]
+    }
+
+    /// 前景色 `fg` 在某个棋子底色上对比不足，需要描边才能看清。
+    pub fn needs_outline(&self, fg: u16) -> bool {
+        self.piece_backgrounds()
+            .iter()
+            .any(|&bg| contrast_ratio(fg, bg) < MIN_PIECE_CONTRAST)
+    }
+}
+
+/// 两种颜色的对比度 (L亮 + 0.05) / (L暗 + 0.05)，×100；1:1 为 100，黑白为 2100。
+pub fn contrast_ratio(a: u16, b: u16) -> u32 {
+    // 0.05 按亮度满量程 65025 折算。
+    const FLARE: u32 = 3251;
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    let (hi, lo) = if la >= lb { (la, lb) } else { (lb, la) };
+    (hi + FLARE) * 100 / (lo + FLARE)
+}
+
+/// RGB565 的相对亮度，0..=65025。各通道扩展到 8 位后平方，近似 sRGB 的 2.2 次伽马。
+fn relative_luminance(color: u16) -> u32 {
+    let r = ((color >> 11) & 0x1F) as u32 * 255 / 31;
+    let g = ((color >> 5) & 0x3F) as u32 * 255 / 63;
+    let b = (color & 0x1F) as u32 * 255 / 31;
+    (2126 * r * r + 7152 * g * g + 722 * b * b) / 10_000
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ThemeKind {
+    Classic,
+    ColorBlind,
+    HighContrast,
+}
+
+impl ThemeKind {
+    pub fn theme(self) -> &'static Theme {
+        match self {
+            ThemeKind::Classic => &CLASSIC,
+            ThemeKind::ColorBlind => &COLOR_BLIND,
+            ThemeKind::HighContrast => &HIGH_CONTRAST,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ThemeKind::Classic => "Classic",
+            ThemeKind::ColorBlind => "Color-blind",
+            ThemeKind::HighContrast => "High contrast",
+        }
+    }
+
+    /// 设置菜单中循环切换。
+    pub fn next(self) -> ThemeKind {
+        match self {
+            ThemeKind::Classic => ThemeKind::ColorBlind,
+            ThemeKind::ColorBlind => ThemeKind::HighContrast,
+            ThemeKind::HighContrast => ThemeKind::Classic,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! 开局库：把 `assets/book.txt` 中的 SAN 开局线逐着在规则引擎上走一遍，写成 rhess-core
+//! `book` 模块引用的着法常量。
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use rhess_core::notation::MoveText;
+use rhess_core::san::san;
+use rhess_core::{Color, GameState, Move};
+
+use crate::{Output, header};
+
+const BOOK: &str = "assets/book.txt";
+
+/// 一条开局线：常量名与自起始局面起的着法，每着附上注释用的 SAN。
+struct Line {
+    name: String,
+    moves: Vec<(Move, String)>,
+}
+
+fn parse_line(name: &str, tokens: &[&str], n: usize) -> Line {
+    let mut state = GameState::start_position();
+    let mut moves = Vec::new();
+    for token in tokens {
+        let resolved = MoveText::parse(token)
+            .map_err(|e| format!("{e:?}"))
+            .and_then(|text| text.resolve(&state).map_err(|e| format!("{e:?}")));
+        let mv = resolved.unwrap_or_else(|e| panic!("{BOOK}:{n}: {name}: `{token}`: {e}"));
+        assert!(
+            mv.promotion.is_none() && !mv.is_en_passant,
+            "{BOOK}:{n}: {name}: `{token}`: book moves cannot promote or capture en passant"
+        );
+        // 注释用引擎写出的 SAN，保留源文件中的 ! ? 注释。
+        let marks = token.trim_start_matches(|c| c != '!' && c != '?');
+        let text = format!(
+            "{}{marks}",
+            san(&state, mv).expect("resolved moves are legal")
+        );
+        state = state.make_move(mv).expect("resolved moves are legal");
+        moves.push((mv, text));
+    }
+    Line {
+        name: name.to_string(),
+        moves,
+    }
+}
+
+fn write_line(out: &mut String, line: &Line) {
+    let calls: Vec<String> = line
+        .moves
+        .iter()
+        .map(|(mv, _)| match mv.is_castling {
+            true => format!("castle({}, {}),", mv.from, mv.to),
+            false => format!("q({}, {}),", mv.from, mv.to),
+        })
+        .collect();
+    let width = calls.iter().map(String::len).max().unwrap_or(0);
+    writeln!(out, "\nconst {}: &[Move] = &[", line.name).unwrap();
+    let mut side = Color::White;
+    for (ply, (call, (_, text))) in calls.iter().zip(&line.moves).enumerate() {
+        let number = match side {
+            Color::White => format!("{}.", ply / 2 + 1),
+            Color::Black => "...".to_string(),
+        };
+        writeln!(out, "    {call:width$} // {number} {text}").unwrap();
+        side = side.opposite();
+    }
+    out.push_str("];\n");
+}
+
+fn write_table(out: &mut String, name: &str, lines: &[Line]) {
+    writeln!(out, "\nconst {name}: &[BookLine] = &[").unwrap();
+    for line in lines {
+        writeln!(out, "    BookLine {{ moves: {} }},", line.name).unwrap();
+    }
+    out.push_str("];\n");
+}
+
+pub fn generate(root: &Path) -> Output {
+    let text = fs::read_to_string(root.join(BOOK)).unwrap_or_else(|e| panic!("{BOOK}: {e}"));
+    let (mut main, mut traps) = (Vec::new(), Vec::new());
+    let mut section = None;
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = match name {
+                "main" => Some(&mut main),
+                "traps" => Some(&mut traps),
+                _ => panic!("{BOOK}:{}: unknown section `{line}`", i + 1),
+            };
+            continue;
+        }
+        let Some(lines) = section.as_mut() else {
+            panic!("{BOOK}:{}: line outside [main] or [traps]", i + 1);
+        };
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        lines.push(parse_line(tokens[0], &tokens[1..], i + 1));
+    }
+
+    let mut out = header(BOOK);
+    for line in &main {
+        write_line(&mut out, line);
+    }
+    write_table(&mut out, "BOOK_LINES", &main);
+    for line in &traps {
+        write_line(&mut out, line);
+    }
+    write_table(&mut out, "TRAP_LINES", &traps);
+    Output {
+        path: "rhess-core/src/generated/book.rs",
+        contents: out.into_bytes(),
+    }
+}
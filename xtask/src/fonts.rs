@@ -0,0 +1,131 @@
+//! 点阵字体：把 `assets/fonts/` 下的字符画转成固件的字形表。
+//!
+//! 字符画文件中，只由 `.` 与 `#` 组成的行是字形的一行像素（`#` 为前景），`# ` 开头的行
+//! 是注释，其余非空行开始一个新字形并给出它的字符。
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::{Output, header};
+
+const ASCII: &str = "assets/fonts/ascii5x7.txt";
+const CJK: &str = "assets/fonts/cjk12.txt";
+const ASCII_W: usize = 5;
+const ASCII_H: usize = 7;
+const ASCII_FIRST: u32 = 0x20;
+const CJK_SIZE: usize = 12;
+
+/// 一个字形：标题行与像素行（`true` 为前景）。
+struct Glyph {
+    label: String,
+    rows: Vec<Vec<bool>>,
+}
+
+fn parse(root: &Path, file: &str, width: usize, height: usize) -> Vec<Glyph> {
+    let text = fs::read_to_string(root.join(file)).unwrap_or_else(|e| panic!("{file}: {e}"));
+    let mut glyphs: Vec<Glyph> = Vec::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim_end();
+        let is_row = !line.is_empty() && line.chars().all(|c| c == '.' || c == '#');
+        if is_row {
+            let Some(glyph) = glyphs.last_mut() else {
+                panic!("{file}:{}: pixel row before any glyph", n + 1);
+            };
+            assert!(
+                line.len() == width,
+                "{file}:{}: row is {} wide, expected {width}",
+                n + 1,
+                line.len()
+            );
+            glyph.rows.push(line.chars().map(|c| c == '#').collect());
+        } else if !line.is_empty() && !line.starts_with("# ") {
+            glyphs.push(Glyph {
+                label: line.to_string(),
+                rows: Vec::new(),
+            });
+        }
+    }
+    for glyph in &glyphs {
+        assert!(
+            glyph.rows.len() == height,
+            "{file}: glyph `{}` has {} rows, expected {height}",
+            glyph.label,
+            glyph.rows.len()
+        );
+    }
+    glyphs
+}
+
+/// 5x7 ASCII 字体：每字符 5 列，列优先，bit0 对应最上方像素；标题行为 `0x41 'A'` 形式，
+/// 须自 0x20 起按编码连续排列。
+fn ascii(root: &Path) -> String {
+    let glyphs = parse(root, ASCII, ASCII_W, ASCII_H);
+    let mut out = header(ASCII);
+    out.push_str("// 每字符 5 列，列优先；bit0 对应最上方像素。\n");
+    writeln!(
+        out,
+        "const FONT_BITMAP: [u8; {} * {ASCII_W}] = [",
+        glyphs.len()
+    )
+    .unwrap();
+    for (i, glyph) in glyphs.iter().enumerate() {
+        let code = glyph
+            .label
+            .split_whitespace()
+            .next()
+            .and_then(|code| u32::from_str_radix(code.trim_start_matches("0x"), 16).ok());
+        assert_eq!(
+            code,
+            Some(ASCII_FIRST + i as u32),
+            "{ASCII}: glyph `{}` out of order",
+            glyph.label
+        );
+        out.push_str("   ");
+        for col in 0..ASCII_W {
+            let bits = (0..ASCII_H)
+                .filter(|&row| glyph.rows[row][col])
+                .fold(0u8, |bits, row| bits | 1 << row);
+            write!(out, " 0x{bits:02x},").unwrap();
+        }
+        writeln!(out, " // {}", glyph.label).unwrap();
+    }
+    out.push_str("];\n");
+    out
+}
+
+/// 12x12 中文字形：每行一个 `u16`，低 12 位，bit11 为最左列；标题行为字形本身。
+fn cjk(root: &Path) -> String {
+    let glyphs = parse(root, CJK, CJK_SIZE, CJK_SIZE);
+    let mut out = header(CJK);
+    writeln!(out, "const GLYPHS: [CjkGlyph; {}] = [", glyphs.len()).unwrap();
+    for glyph in &glyphs {
+        let mut chars = glyph.label.chars();
+        let (Some(ch), None) = (chars.next(), chars.next()) else {
+            panic!("{CJK}: glyph title `{}` is not one character", glyph.label);
+        };
+        writeln!(out, "    CjkGlyph {{\n        ch: '{ch}',\n        rows: [").unwrap();
+        for row in &glyph.rows {
+            let bits = row
+                .iter()
+                .fold(0u16, |bits, &lit| bits << 1 | u16::from(lit));
+            writeln!(out, "            0x{bits:03X},").unwrap();
+        }
+        out.push_str("        ],\n    },\n");
+    }
+    out.push_str("];\n");
+    out
+}
+
+pub fn generate(root: &Path) -> [Output; 2] {
+    [
+        Output {
+            path: "src/generated/font.rs",
+            contents: ascii(root).into_bytes(),
+        },
+        Output {
+            path: "src/generated/cjk.rs",
+            contents: cjk(root).into_bytes(),
+        },
+    ]
+}
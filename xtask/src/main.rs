@@ -0,0 +1,70 @@
+//! 宿主端工具。`cargo xtask assets` 从源资源重新生成全部预计算数据并写入源码树，生成的
+//! 文件随源码提交，固件与 rhess-core 构建时直接引用：
+//!
+//! - `assets/sprites.txt` 与 PNG → `src/generated/pieces.rs`、`src/generated/bitmaps.rs`
+//! - `assets/fonts/*.txt` → `src/generated/font.rs`、`src/generated/cjk.rs`
+//! - `assets/book.txt` → `rhess-core/src/generated/book.rs`
+//! - 三子残局 KQK/KRK/KPK 的逆向求解 → `rhess-core/src/generated/{kqk,krk,kpk}.bin`
+//!
+//! `cargo xtask assets --check` 只生成不写入，与已提交的文件不一致时列出并以非零状态退出。
+
+mod book;
+mod fonts;
+mod sprites;
+mod tablebase;
+
+use std::path::Path;
+use std::{env, fs, process};
+
+/// 一个生成的文件：相对仓库根目录的路径与内容。
+pub struct Output {
+    pub path: &'static str,
+    pub contents: Vec<u8>,
+}
+
+/// 生成的 Rust 文件的首行说明。
+pub fn header(source: &str) -> String {
+    format!("// @generated by `cargo xtask assets` from {source} — edit that instead.\n")
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let check = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["assets"] => false,
+        ["assets", "--check"] => true,
+        _ => {
+            eprintln!("usage: cargo xtask assets [--check]");
+            process::exit(2);
+        }
+    };
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask/ lives in the repository root");
+
+    let mut outputs = Vec::new();
+    outputs.extend(sprites::generate(root));
+    outputs.extend(fonts::generate(root));
+    outputs.push(book::generate(root));
+    outputs.extend(tablebase::generate());
+
+    let mut stale = 0;
+    for output in &outputs {
+        let path = root.join(output.path);
+        if check {
+            if fs::read(&path).ok().as_ref() != Some(&output.contents) {
+                eprintln!("out of date: {}", output.path);
+                stale += 1;
+            }
+            continue;
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).unwrap_or_else(|e| panic!("{}: {e}", dir.display()));
+        }
+        fs::write(&path, &output.contents).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        println!("wrote {}", output.path);
+    }
+    if stale > 0 {
+        eprintln!("{stale} generated file(s) differ; run `cargo xtask assets`");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,178 @@
+//! 精灵：按 `assets/sprites.txt` 把 PNG 转成固件使用的 const 数组，输出 `pieces.rs` 与
+//! `bitmaps.rs`。
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::{Output, header};
+
+const ASSETS: &str = "assets";
+const MANIFEST: &str = "assets/sprites.txt";
+const PIECES: [&str; 6] = ["pawn", "knight", "bishop", "rook", "queen", "king"];
+
+/// 解码后的 RGBA8 图像。
+struct Image {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+}
+
+impl Image {
+    fn load(path: &Path) -> Image {
+        let file = fs::File::open(path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let mut decoder = png::Decoder::new(std::io::BufReader::new(file));
+        // 统一展开为 8 位通道，调色板/灰度图也能直接处理。
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder
+            .read_info()
+            .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader
+            .next_frame(&mut buf)
+            .unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+        let channels = info.color_type.samples();
+        let mut rgba = Vec::with_capacity(info.width as usize * info.height as usize * 4);
+        for px in buf[..info.buffer_size()].chunks(channels) {
+            let (r, g, b, a) = match channels {
+                1 => (px[0], px[0], px[0], 255),
+                2 => (px[0], px[0], px[0], px[1]),
+                3 => (px[0], px[1], px[2], 255),
+                _ => (px[0], px[1], px[2], px[3]),
+            };
+            rgba.extend_from_slice(&[r, g, b, a]);
+        }
+        Image {
+            width: info.width,
+            height: info.height,
+            rgba,
+        }
+    }
+
+    /// 最近邻采样到目标尺寸下的 (x, y)。
+    fn sample(&self, x: u32, y: u32, width: u32, height: u32) -> [u8; 4] {
+        let sx = x * self.width / width;
+        let sy = y * self.height / height;
+        let i = ((sy * self.width + sx) * 4) as usize;
+        [
+            self.rgba[i],
+            self.rgba[i + 1],
+            self.rgba[i + 2],
+            self.rgba[i + 3],
+        ]
+    }
+}
+
+fn parse_size(s: &str) -> (u32, u32) {
+    let (w, h) = s
+        .split_once('x')
+        .unwrap_or_else(|| panic!("bad size `{s}`, expected <w>x<h>"));
+    (w.parse().unwrap(), h.parse().unwrap())
+}
+
+fn luminance([r, g, b, _]: [u8; 4]) -> u32 {
+    (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000
+}
+
+fn gen_pieces(root: &Path, out: &mut String, width: u32, height: u32, depth: u32) {
+    assert!(
+        width <= 32,
+        "piece sprites are stored as u32 rows (width <= 32)"
+    );
+    assert!(depth == 1 || depth == 2, "piece depth must be 1 or 2");
+    writeln!(out, "pub const SPRITE_W: usize = {width};").unwrap();
+    writeln!(out, "pub const SPRITE_H: usize = {height};").unwrap();
+    for name in PIECES {
+        let img = Image::load(&root.join(ASSETS).join("pieces").join(format!("{name}.png")));
+        let mut body = Vec::new();
+        let mut detail = Vec::new();
+        for y in 0..height {
+            let (mut b, mut d) = (0u32, 0u32);
+            for x in 0..width {
+                let px = img.sample(x, y, width, height);
+                if px[3] < 128 {
+                    continue;
+                }
+                let bit = 1 << (width - 1 - x);
+                if depth == 1 || luminance(px) >= 128 {
+                    b |= bit;
+                } else {
+                    d |= bit;
+                }
+            }
+            body.push(b);
+            detail.push(d);
+        }
+        let upper = name.to_uppercase();
+        writeln!(out, "const {upper}: Sprite = Sprite {{").unwrap();
+        writeln!(out, "    body: &{},", hex_rows(&body)).unwrap();
+        if depth == 2 {
+            writeln!(out, "    detail: Some(&{}),", hex_rows(&detail)).unwrap();
+        } else {
+            writeln!(out, "    detail: None,").unwrap();
+        }
+        writeln!(out, "}};").unwrap();
+    }
+}
+
+fn hex_rows(rows: &[u32]) -> String {
+    let items: Vec<String> = rows.iter().map(|r| format!("0x{r:08X}")).collect();
+    format!("[{}]", items.join(", "))
+}
+
+fn gen_bitmap(root: &Path, out: &mut String, name: &str, file: &str, width: u32, height: u32) {
+    let img = Image::load(&root.join(ASSETS).join(file));
+    writeln!(out, "pub const {name}_W: u16 = {width};").unwrap();
+    writeln!(out, "pub const {name}_H: u16 = {height};").unwrap();
+    writeln!(
+        out,
+        "pub static {name}_BITMAP: [u16; {}] = [",
+        width * height
+    )
+    .unwrap();
+    for y in 0..height {
+        out.push_str("   ");
+        for x in 0..width {
+            let [r, g, b, _] = img.sample(x, y, width, height);
+            let rgb565 = ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3);
+            write!(out, " 0x{rgb565:04X},").unwrap();
+        }
+        out.push('\n');
+    }
+    writeln!(out, "];").unwrap();
+}
+
+pub fn generate(root: &Path) -> [Output; 2] {
+    let header = header("assets/ (the PNGs and assets/sprites.txt)");
+    let mut pieces = header.clone();
+    let mut bitmaps = header;
+    let manifest = fs::read_to_string(root.join(MANIFEST)).expect("read assets/sprites.txt");
+    for line in manifest.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        match fields.as_slice() {
+            ["piece", size, depth] => {
+                let (w, h) = parse_size(size);
+                gen_pieces(root, &mut pieces, w, h, depth.parse().unwrap());
+            }
+            ["bitmap", name, file, size] => {
+                let (w, h) = parse_size(size);
+                gen_bitmap(root, &mut bitmaps, name, file, w, h);
+            }
+            _ => panic!("assets/sprites.txt: unrecognized line `{line}`"),
+        }
+    }
+    [
+        Output {
+            path: "src/generated/pieces.rs",
+            contents: pieces.into_bytes(),
+        },
+        Output {
+            path: "src/generated/bitmaps.rs",
+            contents: bitmaps.into_bytes(),
+        },
+    ]
+}
@@ -0,0 +1,310 @@
+//! 三子残局库：逆向分析求解 KQK、KRK 与 KPK，写出 rhess-core `tablebase` 模块嵌入的
+//! `kqk.bin`、`krk.bin`、`kpk.bin`，表的布局见该模块文档。
+//!
+//! 多出的子一律放在白方求解。两王与该子的每种摆法按双方行棋各编一个下标；由将死局面出发
+//! 沿着法逆向广度优先遍历，每个局面第一次被走到时即为它到将死（KPK 中为到必胜的升变）的
+//! 最短距离。
+
+use std::collections::VecDeque;
+
+use crate::Output;
+
+/// 摆法数乘以行棋方：白方（持有多出的子）或黑方。
+const POSITIONS: usize = 2 * 64 * 64 * 64;
+const WHITE: usize = 0;
+const BLACK: usize = 1;
+/// （尚）未知白胜。
+const UNSOLVED: u8 = u8::MAX;
+/// 黑方能吃掉该子或被逼和时加到其未解应着计数上：计数永不归零，局面保持为和。
+const DRAW_EXIT: u8 = 100;
+
+/// KQK 与 KRK 中强方王被映射进的格子（a1-d1-d4 三角），按表中顺序。
+const TRIANGLE: [usize; 10] = [0, 1, 2, 3, 9, 10, 11, 18, 19, 27];
+const KING_STEPS: [(i8, i8); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+const ROOK_RAYS: [(i8, i8); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const QUEEN_RAYS: [(i8, i8); 8] = KING_STEPS;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Extra {
+    Queen,
+    Rook,
+    Pawn,
+}
+
+impl Extra {
+    fn rays(self) -> &'static [(i8, i8)] {
+        match self {
+            Extra::Queen => &QUEEN_RAYS,
+            Extra::Rook => &ROOK_RAYS,
+            Extra::Pawn => &[],
+        }
+    }
+}
+
+fn index(side: usize, wk: usize, bk: usize, piece: usize) -> usize {
+    ((side * 64 + wk) * 64 + bk) * 64 + piece
+}
+
+fn unpack(idx: usize) -> (usize, usize, usize, usize) {
+    (idx >> 18, (idx >> 12) & 63, (idx >> 6) & 63, idx & 63)
+}
+
+fn step(sq: usize, (df, dr): (i8, i8)) -> Option<usize> {
+    let file = (sq % 8) as i8 + df;
+    let rank = (sq / 8) as i8 + dr;
+    ((0..8).contains(&file) && (0..8).contains(&rank)).then(|| (rank * 8 + file) as usize)
+}
+
+fn king_steps(sq: usize) -> impl Iterator<Item = usize> {
+    KING_STEPS.iter().filter_map(move |&dir| step(sq, dir))
+}
+
+fn adjacent(a: usize, b: usize) -> bool {
+    (a % 8).abs_diff(b % 8) <= 1 && (a / 8).abs_diff(b / 8) <= 1
+}
+
+/// `from` 上的长程子能到达的格子，遇到任一王即停。
+fn slides(extra: Extra, from: usize, wk: usize, bk: usize) -> Vec<usize> {
+    let mut out = Vec::new();
+    for &dir in extra.rays() {
+        let mut sq = from;
+        while let Some(next) = step(sq, dir) {
+            if next == wk || next == bk {
+                break;
+            }
+            out.push(next);
+            sq = next;
+        }
+    }
+    out
+}
+
+/// `from` 上的子是否攻击 `target`，白王是唯一的阻挡。
+fn attacks(extra: Extra, from: usize, target: usize, wk: usize) -> bool {
+    if extra == Extra::Pawn {
+        return target / 8 == from / 8 + 1 && (target % 8).abs_diff(from % 8) == 1;
+    }
+    extra.rays().iter().any(|&dir| {
+        let mut sq = from;
+        while let Some(next) = step(sq, dir) {
+            if next == target {
+                return true;
+            }
+            if next == wk {
+                return false;
+            }
+            sq = next;
+        }
+        false
+    })
+}
+
+fn legal(extra: Extra, side: usize, wk: usize, bk: usize, piece: usize) -> bool {
+    wk != bk
+        && wk != piece
+        && bk != piece
+        && !adjacent(wk, bk)
+        && (extra != Extra::Pawn || (1..7).contains(&(piece / 8)))
+        && (side == BLACK || !attacks(extra, piece, bk, wk))
+}
+
+/// 求解一种残局。`promotions` 为 KPK 的兵升变进入的已解 KQK 与 KRK 表；必胜的升变在
+/// 推兵后一个半回合处结束计数。
+fn solve(extra: Extra, promotions: Option<(&[u8], &[u8])>) -> Vec<u8> {
+    let mut plies = vec![UNSOLVED; POSITIONS];
+    // 黑方局面：尚未确知输掉的应着数，有出路时再加 DRAW_EXIT。
+    let mut pending = vec![0u8; POSITIONS];
+    let mut queue = VecDeque::new();
+
+    for wk in 0..64 {
+        for bk in 0..64 {
+            for piece in 0..64 {
+                if !legal(extra, BLACK, wk, bk, piece) {
+                    continue;
+                }
+                let mut replies = 0;
+                let mut exit = false;
+                for to in king_steps(bk) {
+                    if to == wk || adjacent(to, wk) {
+                        continue;
+                    }
+                    if to == piece {
+                        exit = true;
+                    } else if !attacks(extra, piece, to, wk) {
+                        replies += 1;
+                    }
+                }
+                let idx = index(BLACK, wk, bk, piece);
+                if replies == 0 && !exit {
+                    if attacks(extra, piece, bk, wk) {
+                        plies[idx] = 0;
+                        queue.push_back(idx);
+                    } else {
+                        exit = true;
+                    }
+                }
+                pending[idx] = replies + if exit { DRAW_EXIT } else { 0 };
+            }
+        }
+    }
+
+    if let Some((kqk, krk)) = promotions {
+        for wk in 0..64 {
+            for bk in 0..64 {
+                for piece in 48..56 {
+                    let to = piece + 8;
+                    if !legal(extra, WHITE, wk, bk, piece) || to == wk || to == bk {
+                        continue;
+                    }
+                    let after = index(BLACK, wk, bk, to);
+                    if kqk[after] != UNSOLVED || krk[after] != UNSOLVED {
+                        let idx = index(WHITE, wk, bk, piece);
+                        plies[idx] = 1;
+                        queue.push_back(idx);
+                    }
+                }
+            }
+        }
+    }
+
+    while let Some(idx) = queue.pop_front() {
+        let (side, wk, bk, piece) = unpack(idx);
+        let next = plies[idx] + 1;
+        if side == BLACK {
+            // 黑方在此必败：白方走进这里的每一着都赢。
+            let mut parents = Vec::new();
+            for from in king_steps(wk) {
+                if from != bk && from != piece {
+                    parents.push(index(WHITE, from, bk, piece));
+                }
+            }
+            if extra == Extra::Pawn {
+                if piece >= 16 && piece - 8 != wk && piece - 8 != bk {
+                    parents.push(index(WHITE, wk, bk, piece - 8));
+                    if piece / 8 == 3 && piece - 16 != wk && piece - 16 != bk {
+                        parents.push(index(WHITE, wk, bk, piece - 16));
+                    }
+                }
+            } else {
+                // 长程子的着法可沿同一射线逆走。
+                for from in slides(extra, piece, wk, bk) {
+                    parents.push(index(WHITE, wk, bk, from));
+                }
+            }
+            for parent in parents {
+                let (_, pwk, pbk, ppiece) = unpack(parent);
+                if plies[parent] == UNSOLVED && legal(extra, WHITE, pwk, pbk, ppiece) {
+                    plies[parent] = next;
+                    queue.push_back(parent);
+                }
+            }
+        } else {
+            // 白方在此必胜：以此为最后一个未解应着的黑方局面随之必败。
+            for from in king_steps(bk) {
+                if from == wk || from == piece || !legal(extra, BLACK, wk, from, piece) {
+                    continue;
+                }
+                let parent = index(BLACK, wk, from, piece);
+                if plies[parent] != UNSOLVED {
+                    continue;
+                }
+                pending[parent] -= 1;
+                if pending[parent] == 0 {
+                    plies[parent] = next;
+                    queue.push_back(parent);
+                }
+            }
+        }
+    }
+    plies
+}
+
+/// 白方行棋时到目标的回合数；白方不胜时为 0。
+fn moves(plies: &[u8], idx: usize) -> u8 {
+    match plies[idx] {
+        UNSOLVED => 0,
+        n => n.div_ceil(2),
+    }
+}
+
+fn pack(nibbles: &[u8]) -> Vec<u8> {
+    nibbles
+        .chunks(2)
+        .map(|pair| pair[0] | pair[1] << 4)
+        .collect()
+}
+
+/// KQK 与 KRK：白方行棋必胜，每项为到将死的回合数减一。
+fn mate_table(extra: Extra, plies: &[u8]) -> Vec<u8> {
+    let mut nibbles = Vec::with_capacity(TRIANGLE.len() * 64 * 64);
+    for &wk in &TRIANGLE {
+        for bk in 0..64 {
+            for piece in 0..64 {
+                let mut nibble = 0;
+                if legal(extra, WHITE, wk, bk, piece) {
+                    let n = moves(plies, index(WHITE, wk, bk, piece));
+                    assert!((1..=16).contains(&n), "mate in {n} does not fit");
+                    nibble = n - 1;
+                }
+                nibbles.push(nibble);
+            }
+        }
+    }
+    pack(&nibbles)
+}
+
+/// 第 `rank` 横排（0 起）的兵升变至少需要的白方着数，计入双步。
+fn pushes_to_promote(rank: usize) -> u8 {
+    (7 - rank).min(5) as u8
+}
+
+/// KPK：兵在 a-d 直线、第 2~7 横排。和棋为 0，否则为到必胜升变的回合数超出兵最少推进
+/// 步数的部分加一：总数不超过 19 回合，减去这一偏移后每项都放得进半字节。
+fn pawn_table(plies: &[u8]) -> Vec<u8> {
+    let mut nibbles = Vec::with_capacity(24 * 64 * 64);
+    for rank in 1..7 {
+        for file in 0..4 {
+            for wk in 0..64 {
+                for bk in 0..64 {
+                    let mut nibble = 0;
+                    let n = moves(plies, index(WHITE, wk, bk, rank * 8 + file));
+                    if n > 0 {
+                        nibble = n + 1 - pushes_to_promote(rank);
+                        assert!((1..=15).contains(&nibble), "promotion in {n} does not fit");
+                    }
+                    nibbles.push(nibble);
+                }
+            }
+        }
+    }
+    pack(&nibbles)
+}
+
+pub fn generate() -> [Output; 3] {
+    let kqk = solve(Extra::Queen, None);
+    let krk = solve(Extra::Rook, None);
+    let kpk = solve(Extra::Pawn, Some((&kqk, &krk)));
+    [
+        Output {
+            path: "rhess-core/src/generated/kqk.bin",
+            contents: mate_table(Extra::Queen, &kqk),
+        },
+        Output {
+            path: "rhess-core/src/generated/krk.bin",
+            contents: mate_table(Extra::Rook, &krk),
+        },
+        Output {
+            path: "rhess-core/src/generated/kpk.bin",
+            contents: pawn_table(&kpk),
+        },
+    ]
+}